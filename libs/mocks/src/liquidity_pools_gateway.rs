@@ -28,7 +28,7 @@ pub mod pallet {
 			register_call!(f);
 		}
 
-		pub fn mock_get(f: impl Fn(&T::Destination) -> Option<[u8; 20]> + 'static) {
+		pub fn mock_get(f: impl Fn(&T::Destination) -> Option<[u8; 32]> + 'static) {
 			register_call!(f);
 		}
 
@@ -51,8 +51,8 @@ pub mod pallet {
 		}
 	}
 
-	impl<T: Config> GetByKey<T::Destination, Option<[u8; 20]>> for Pallet<T> {
-		fn get(a: &T::Destination) -> Option<[u8; 20]> {
+	impl<T: Config> GetByKey<T::Destination, Option<[u8; 32]>> for Pallet<T> {
+		fn get(a: &T::Destination) -> Option<[u8; 32]> {
 			execute_call!(a)
 		}
 	}
@@ -111,6 +111,30 @@ pub mod pallet {
 			register_call!(move |(a, b, c)| f(a, b, c));
 		}
 
+		pub fn mock_ensure_investments_allowed(
+			f: impl Fn((T::PoolId, T::TrancheId)) -> DispatchResult + 'static,
+		) {
+			register_call!(f);
+		}
+
+		pub fn mock_ensure_redemptions_allowed(
+			f: impl Fn((T::PoolId, T::TrancheId), &T::AccountId) -> DispatchResult + 'static,
+		) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+
+		pub fn mock_note_deposit(
+			f: impl Fn((T::PoolId, T::TrancheId), &T::AccountId) -> DispatchResult + 'static,
+		) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+
+		pub fn mock_ensure_min_investment(
+			f: impl Fn((T::PoolId, T::TrancheId), T::Balance) -> DispatchResult + 'static,
+		) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+
 		#[cfg(feature = "runtime-benchmarks")]
 		pub fn mock_bench_default_investment_id(
 			f: impl Fn(T::PoolId) -> (T::PoolId, T::TrancheId) + 'static,
@@ -171,6 +195,22 @@ pub mod pallet {
 		fn withdraw(a: &T::AccountId, b: Self::InvestmentId, c: Self::Amount) -> DispatchResult {
 			execute_call!((a, b, c))
 		}
+
+		fn ensure_investments_allowed(a: Self::InvestmentId) -> DispatchResult {
+			execute_call!(a)
+		}
+
+		fn ensure_redemptions_allowed(a: Self::InvestmentId, b: &T::AccountId) -> DispatchResult {
+			execute_call!((a, b))
+		}
+
+		fn note_deposit(a: Self::InvestmentId, b: &T::AccountId) -> DispatchResult {
+			execute_call!((a, b))
+		}
+
+		fn ensure_min_investment(a: Self::InvestmentId, b: Self::Amount) -> DispatchResult {
+			execute_call!((a, b))
+		}
 	}
 
 	impl<T: Config> TrancheTokenPrice<T::AccountId, T::CurrencyId> for Pallet<T> {
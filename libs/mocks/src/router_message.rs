@@ -1,6 +1,6 @@
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use cfg_traits::liquidity_pools::{MessageReceiver, MessageSender};
+	use cfg_traits::liquidity_pools::{MessageReceiver, MessageSender, MessageSenderWithFees};
 	use frame_support::pallet_prelude::*;
 	use mock_builder::{execute_call_instance, register_call_instance, CallHandler};
 
@@ -9,6 +9,7 @@ pub mod pallet {
 		type Middleware;
 		type Origin;
 		type Message;
+		type Fees;
 	}
 
 	#[pallet::pallet]
@@ -29,6 +30,12 @@ pub mod pallet {
 		) -> CallHandler {
 			register_call_instance!(move |(a, b, c)| f(a, b, c))
 		}
+
+		pub fn mock_send_with_fees(
+			f: impl Fn(T::Middleware, T::Origin, T::Message, T::Fees) -> DispatchResult + 'static,
+		) -> CallHandler {
+			register_call_instance!(move |(a, b, c, d)| f(a, b, c, d))
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> MessageReceiver for Pallet<T, I> {
@@ -50,4 +57,17 @@ pub mod pallet {
 			execute_call_instance!((a, b, c))
 		}
 	}
+
+	impl<T: Config<I>, I: 'static> MessageSenderWithFees for Pallet<T, I> {
+		type Fees = T::Fees;
+
+		fn send_with_fees(
+			a: Self::Middleware,
+			b: Self::Origin,
+			c: Self::Message,
+			d: Self::Fees,
+		) -> DispatchResult {
+			execute_call_instance!((a, b, c, d))
+		}
+	}
 }
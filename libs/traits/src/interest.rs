@@ -1,4 +1,4 @@
-use cfg_primitives::SECONDS_PER_YEAR;
+use cfg_primitives::{SECONDS_PER_DAY, SECONDS_PER_YEAR};
 use frame_support::{
 	dispatch::DispatchResult,
 	pallet_prelude::{RuntimeDebug, TypeInfo},
@@ -6,7 +6,7 @@ use frame_support::{
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_arithmetic::{
-	traits::{EnsureAdd, EnsureDiv, EnsureSub},
+	traits::{ensure_pow, EnsureAdd, EnsureDiv, EnsureInto, EnsureMul, EnsureSub},
 	ArithmeticError, FixedPointNumber,
 };
 use sp_runtime::{
@@ -16,37 +16,272 @@ use sp_runtime::{
 
 use crate::Seconds;
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+#[derive(
+	Encode, Decode, Clone, Copy, Default, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen,
+)]
 pub enum CompoundingSchedule {
 	/// Interest compounds every second
+	#[default]
 	Secondly,
+
+	/// Interest compounds every day
+	Daily,
+
+	/// Interest compounds every twelfth of a year
+	Monthly,
+
+	/// Interest compounds every year
+	Annually,
+
+	/// Interest never compounds, it accrues linearly over the debt
+	Simple,
+}
+
+impl CompoundingSchedule {
+	/// Seconds between two compoundings, measured with the given day count
+	/// convention, or `None` if interest never compounds.
+	pub fn period(&self, day_count: &DayCountConvention) -> Option<Seconds> {
+		match self {
+			CompoundingSchedule::Secondly => Some(1),
+			CompoundingSchedule::Daily => Some(SECONDS_PER_DAY),
+			CompoundingSchedule::Monthly => Some(day_count.year() / 12),
+			CompoundingSchedule::Annually => Some(day_count.year()),
+			CompoundingSchedule::Simple => None,
+		}
+	}
+
+	/// Growth of a unit of debt from `from` to `to` for a given rate per
+	/// second.
+	///
+	/// Time is measured with the given day count convention.
+	/// Periods are aligned to multiples of their length since the UNIX epoch.
+	/// Interest compounds at the end of each period and accrues linearly
+	/// within it. For simple interest, the debt starts accruing at `from`.
+	pub fn accumulated_rate<Rate: FixedPointNumber>(
+		&self,
+		day_count: &DayCountConvention,
+		rate_per_sec: Rate,
+		from: Seconds,
+		to: Seconds,
+	) -> Result<Rate, ArithmeticError> {
+		// 1 + rate * seconds, where rate is the interest rate per second
+		let linear_growth = |secs: Seconds| -> Result<Rate, ArithmeticError> {
+			rate_per_sec
+				.ensure_sub(One::one())?
+				.ensure_mul(Rate::saturating_from_integer(secs))?
+				.ensure_add(One::one())
+		};
+
+		let (from, to) = (day_count.time(from), day_count.time(to));
+
+		match self.period(day_count) {
+			Some(period) => {
+				let periods = (to / period).ensure_sub(from / period)?;
+
+				ensure_pow(linear_growth(period)?, periods.ensure_into()?)?
+					.ensure_mul(linear_growth(to % period)?)?
+					.ensure_div(linear_growth(from % period)?)
+			}
+			None => linear_growth(to.ensure_sub(from)?),
+		}
+	}
+}
+
+/// Convention used to count the time elapsed while interest accrues.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen,
+)]
+pub enum DayCountConvention {
+	/// ACT/365: actual time elapsed over a year of 365 days
+	#[default]
+	Actual365,
+
+	/// ACT/360: actual time elapsed over a year of 360 days
+	Actual360,
+
+	/// 30E/360: every month counts as 30 days over a year of 360 days.
+	/// The 30th and 31st days of a month count as the same day, and the last
+	/// day of February accrues the remaining days up to the 30th.
+	Thirty360,
 }
 
-/// Interest rate method with compounding schedule information
+impl DayCountConvention {
+	/// Seconds of a year
+	pub fn year(&self) -> Seconds {
+		match self {
+			DayCountConvention::Actual365 => SECONDS_PER_YEAR,
+			DayCountConvention::Actual360 | DayCountConvention::Thirty360 => SECONDS_PER_DAY * 360,
+		}
+	}
+
+	/// Seconds counted by this convention from the UNIX epoch until `when`.
+	/// For `Thirty360`, the count starts at the year 0 instead.
+	pub fn time(&self, when: Seconds) -> Seconds {
+		match self {
+			DayCountConvention::Actual365 | DayCountConvention::Actual360 => when,
+			DayCountConvention::Thirty360 => {
+				let days = when / SECONDS_PER_DAY;
+				let (year, month, day) = civil_from_days(days);
+				let months = year * 12 + month - 1;
+
+				match day {
+					// The 30th followed by a 31st does not accrue,
+					// the 31st accrues as the 30th instead.
+					30 if civil_from_days(days + 1).2 == 31 => (months * 30 + 29) * SECONDS_PER_DAY,
+					31 => (months * 30 + 29) * SECONDS_PER_DAY + when % SECONDS_PER_DAY,
+					day => (months * 30 + day - 1) * SECONDS_PER_DAY + when % SECONDS_PER_DAY,
+				}
+			}
+		}
+	}
+
+	/// Seconds counted by this convention between `from` and `to`
+	pub fn elapsed(&self, from: Seconds, to: Seconds) -> Result<Seconds, ArithmeticError> {
+		self.time(to).ensure_sub(self.time(from))
+	}
+
+	/// Years counted by this convention between `from` and `to`
+	pub fn year_fraction<Rate: FixedPointNumber>(
+		&self,
+		from: Seconds,
+		to: Seconds,
+	) -> Result<Rate, ArithmeticError> {
+		Ok(Rate::saturating_from_rational(
+			self.elapsed(from, to)?,
+			self.year(),
+		))
+	}
+
+	/// Converts a yearly rate into its rate per second:
+	/// 1 + rate_per_year / seconds of a year
+	pub fn rate_per_sec<Rate: FixedPointNumber>(
+		&self,
+		rate_per_year: Rate,
+	) -> Result<Rate, ArithmeticError> {
+		rate_per_year
+			.ensure_div(Rate::saturating_from_integer(self.year()))?
+			.ensure_add(One::one())
+	}
+}
+
+/// Civil date (year, month, day) of a number of days since the UNIX epoch.
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+	let z = days + 719_468;
+	let era = z / 146_097;
+	let doe = z - era * 146_097;
+	let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + u64::from(month <= 2);
+
+	(year, month, day)
+}
+
+/// Interest rate method with compounding schedule information.
+/// `Reference` identifies where the reference rate of a floating rate comes
+/// from.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
-pub enum InterestRate<Rate> {
+pub enum InterestRate<Rate, Reference = ()> {
 	/// Interest accrues at a fixed rate
 	Fixed {
 		rate_per_year: Rate,
 		compounding: CompoundingSchedule,
+		day_count: DayCountConvention,
+	},
+
+	/// Interest accrues at a reference rate plus a spread.
+	/// `reference_rate` contains the last known value of the `reference`,
+	/// updated by the owner of the rate each time the reference moves.
+	Floating {
+		reference: Reference,
+		reference_rate: Rate,
+		spread: Rate,
+		compounding: CompoundingSchedule,
+		day_count: DayCountConvention,
 	},
 }
 
-impl<Rate: FixedPointNumber> InterestRate<Rate> {
+impl<Rate: FixedPointNumber, Reference> InterestRate<Rate, Reference> {
 	pub fn per_year(&self) -> Rate {
 		match self {
 			InterestRate::Fixed { rate_per_year, .. } => *rate_per_year,
+			InterestRate::Floating {
+				reference_rate,
+				spread,
+				..
+			} => reference_rate.saturating_add(*spread),
 		}
 	}
 
 	pub fn per_sec(&self) -> Result<Rate, ArithmeticError> {
-		self.per_year()
-			.ensure_div(Rate::saturating_from_integer(SECONDS_PER_YEAR))?
-			.ensure_add(One::one())
+		self.day_count().rate_per_sec(self.per_year())
+	}
+
+	pub fn compounding(&self) -> CompoundingSchedule {
+		match self {
+			InterestRate::Fixed { compounding, .. } => *compounding,
+			InterestRate::Floating { compounding, .. } => *compounding,
+		}
+	}
+
+	pub fn day_count(&self) -> DayCountConvention {
+		match self {
+			InterestRate::Fixed { day_count, .. } => *day_count,
+			InterestRate::Floating { day_count, .. } => *day_count,
+		}
+	}
+
+	/// Growth of a unit of debt from `from` to `to`.
+	/// See [`CompoundingSchedule::accumulated_rate()`].
+	pub fn accumulated_rate(&self, from: Seconds, to: Seconds) -> Result<Rate, ArithmeticError> {
+		self.compounding()
+			.accumulated_rate(&self.day_count(), self.per_sec()?, from, to)
+	}
+
+	/// Fixed rate that currently accrues, with the reference rate resolved
+	pub fn accrual_rate(&self) -> InterestRate<Rate> {
+		InterestRate::Fixed {
+			rate_per_year: self.per_year(),
+			compounding: self.compounding(),
+			day_count: self.day_count(),
+		}
 	}
 }
 
-impl<Rate> InterestRate<Rate> {
+impl<Rate, Reference> InterestRate<Rate, Reference> {
+	pub fn reference(&self) -> Option<&Reference> {
+		match self {
+			InterestRate::Fixed { .. } => None,
+			InterestRate::Floating { reference, .. } => Some(reference),
+		}
+	}
+
+	/// Updates the last known value of the reference rate.
+	/// Fixed rates are not modified.
+	pub fn with_reference_rate(self, new_reference_rate: Rate) -> Self {
+		match self {
+			InterestRate::Floating {
+				reference,
+				spread,
+				compounding,
+				day_count,
+				..
+			} => InterestRate::Floating {
+				reference,
+				reference_rate: new_reference_rate,
+				spread,
+				compounding,
+				day_count,
+			},
+			fixed => fixed,
+		}
+	}
+
+	/// Maps the rate part not coming from the reference,
+	/// that is, the fixed rate or the spread of floating rates.
 	pub fn try_map_rate<F, E>(self, f: F) -> Result<Self, E>
 	where
 		F: FnOnce(Rate) -> Result<Rate, E>,
@@ -55,20 +290,35 @@ impl<Rate> InterestRate<Rate> {
 			Self::Fixed {
 				rate_per_year,
 				compounding,
+				day_count,
 			} => Self::Fixed {
 				rate_per_year: f(rate_per_year)?,
 				compounding,
+				day_count,
+			},
+			Self::Floating {
+				reference,
+				reference_rate,
+				spread,
+				compounding,
+				day_count,
+			} => Self::Floating {
+				reference,
+				reference_rate,
+				spread: f(spread)?,
+				compounding,
+				day_count,
 			},
 		})
 	}
 }
 
-impl<Rate: EnsureAdd + EnsureSub> InterestRate<Rate> {
-	pub fn ensure_add(self, rate: Rate) -> Result<InterestRate<Rate>, ArithmeticError> {
+impl<Rate: EnsureAdd + EnsureSub, Reference> InterestRate<Rate, Reference> {
+	pub fn ensure_add(self, rate: Rate) -> Result<Self, ArithmeticError> {
 		self.try_map_rate(|r| r.ensure_add(rate))
 	}
 
-	pub fn ensure_sub(self, rate: Rate) -> Result<InterestRate<Rate>, ArithmeticError> {
+	pub fn ensure_sub(self, rate: Rate) -> Result<Self, ArithmeticError> {
 		self.try_map_rate(|r| r.ensure_sub(rate))
 	}
 }
@@ -206,6 +206,25 @@ pub trait InvestmentAccountant<AccountId> {
 		id: Self::InvestmentId,
 		amount: Self::Amount,
 	) -> Result<(), Self::Error>;
+
+	/// Ensures that invest orders for the given investment can be increased
+	fn ensure_investments_allowed(id: Self::InvestmentId) -> Result<(), Self::Error>;
+
+	/// Ensures that `who` can increase redeem orders for the given investment
+	fn ensure_redemptions_allowed(
+		id: Self::InvestmentId,
+		who: &AccountId,
+	) -> Result<(), Self::Error>;
+
+	/// Notes that `who` received newly issued units of the given investment
+	fn note_deposit(id: Self::InvestmentId, who: &AccountId) -> Result<(), Self::Error>;
+
+	/// Ensures that an invest order increased by the investor to the given
+	/// amount is not below the minimum investment
+	fn ensure_min_investment(
+		id: Self::InvestmentId,
+		amount: Self::Amount,
+	) -> Result<(), Self::Error>;
 }
 
 /// Trait to handle investments in (presumably) foreign currencies, i.e., other
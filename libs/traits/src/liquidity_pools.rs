@@ -90,6 +90,17 @@ pub trait LpMessageForwarded: Sized {
 	) -> Result<Self, DispatchError>;
 }
 
+/// Behavior of a message that can be limited by inbound rate limits
+pub trait LpMessageRateLimit {
+	/// Identifies a rate limit, i.e. a message type for a pool
+	type LimitKey;
+
+	/// Returns the amounts accounted by the message for each rate limit.
+	/// Batched and forwarded messages return the amounts of their inner
+	/// messages.
+	fn rate_limited_amounts(&self) -> Vec<(Self::LimitKey, u128)>;
+}
+
 pub trait RouterProvider<Domain>: Sized {
 	/// The router identifier.
 	type RouterId;
@@ -117,6 +128,21 @@ pub trait MessageSender {
 	) -> DispatchResult;
 }
 
+/// The behavior of an entity that can send messages paying the given fees
+/// instead of the ones configured for the middleware
+pub trait MessageSenderWithFees: MessageSender {
+	/// The fees paid for sending the message
+	type Fees;
+
+	/// Sends a message for origin to destination paying the given fees
+	fn send_with_fees(
+		middleware: Self::Middleware,
+		origin: Self::Origin,
+		message: Self::Message,
+		fees: Self::Fees,
+	) -> DispatchResult;
+}
+
 /// The behavior of an entity that can receive messages
 pub trait MessageReceiver {
 	/// The middleware by where this message is received
@@ -144,6 +170,18 @@ pub trait MessageQueue {
 	fn queue(msg: Self::Message) -> DispatchResult;
 }
 
+/// The trait required for assigning queued messages to lanes.
+pub trait MessageLaneProvider<Message> {
+	/// Identifies a lane of the queue.
+	type Lane;
+
+	/// Identifies the messages of a lane that must be processed in order.
+	type OrderingKey;
+
+	/// Returns the lane and the ordering key of the message.
+	fn lane(message: &Message) -> (Self::Lane, Self::OrderingKey);
+}
+
 /// The trait required for processing dequeued messages.
 pub trait MessageProcessor {
 	/// The message type.
@@ -13,10 +13,12 @@
 use frame_support::pallet_prelude::RuntimeDebug;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_core::{crypto::AccountId32, H160};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::{crypto::AccountId32, hashing::blake2_256, H160};
 use sp_runtime::{traits::AccountIdConversion, TypeId};
 
-use crate::EVMChainId;
+use crate::{EVMChainId, NonEvmChainId};
 
 const MAX_ADDRESS_SIZE: usize = 32;
 
@@ -47,13 +49,24 @@ pub fn eth_address_to_account(chain_id: u64, address: H160) -> AccountId32 {
 	AccountId32::new(bytes)
 }
 
+/// By hashing the address together with chain information
+pub fn non_evm_address_to_account(chain_id: NonEvmChainId, address: [u8; 32]) -> AccountId32 {
+	// A 32 bytes address leaves no room for the chain information, so unlike
+	// `eth_address_to_account`, the account is derived from a tagged hash.
+	let tag = b"NonEvm";
+	AccountId32::new(blake2_256(&(tag, chain_id, address).encode()))
+}
+
 /// A Domain is a chain or network we can send a message to.
 #[derive(Encode, Decode, Clone, Copy, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Domain {
 	/// Referring to the Centrifuge Chain.
 	Centrifuge,
 	/// An EVM domain, identified by its EVM Chain Id
 	Evm(EVMChainId),
+	/// A non-EVM domain with 32 bytes addresses, identified by its Chain Id
+	NonEvm(NonEvmChainId),
 }
 
 impl TypeId for Domain {
@@ -67,18 +80,21 @@ impl Domain {
 
 	pub fn get_evm_chain_id(&self) -> Option<EVMChainId> {
 		match self {
-			Domain::Centrifuge => None,
+			Domain::Centrifuge | Domain::NonEvm(_) => None,
 			Domain::Evm(id) => Some(*id),
 		}
 	}
 }
 
 #[derive(Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum DomainAddress {
 	/// A centrifuge based account
 	Centrifuge(AccountId32),
 	/// An EVM chain address
 	Evm(EVMChainId, H160),
+	/// A non-EVM chain address
+	NonEvm(NonEvmChainId, [u8; 32]),
 }
 
 impl TypeId for DomainAddress {
@@ -90,6 +106,7 @@ impl From<DomainAddress> for Domain {
 		match x {
 			DomainAddress::Centrifuge(_) => Domain::Centrifuge,
 			DomainAddress::Evm(chain_id, _) => Domain::Evm(chain_id),
+			DomainAddress::NonEvm(chain_id, _) => Domain::NonEvm(chain_id),
 		}
 	}
 }
@@ -101,6 +118,7 @@ impl DomainAddress {
 			Domain::Evm(chain_id) => {
 				DomainAddress::Evm(chain_id, account_to_eth_address(address.into()))
 			}
+			Domain::NonEvm(chain_id) => DomainAddress::NonEvm(chain_id, address),
 		}
 	}
 
@@ -115,6 +133,7 @@ impl DomainAddress {
 		match self.clone() {
 			Self::Centrifuge(x) => x,
 			Self::Evm(chain_id, x) => eth_address_to_account(chain_id, x),
+			Self::NonEvm(chain_id, x) => non_evm_address_to_account(chain_id, x),
 		}
 	}
 
@@ -124,11 +143,16 @@ impl DomainAddress {
 		match self.clone() {
 			Self::Centrifuge(x) => account_to_eth_address(x),
 			Self::Evm(_, x) => x,
+			Self::NonEvm(_, x) => account_to_eth_address(x.into()),
 		}
 	}
 
-	/// Returns the current address as plain bytes
+	/// Returns the current address as plain bytes.
+	/// Non-EVM addresses are returned as they are in their domain.
 	pub fn bytes(&self) -> [u8; MAX_ADDRESS_SIZE] {
-		self.account().into()
+		match self {
+			Self::NonEvm(_, x) => *x,
+			_ => self.account().into(),
+		}
 	}
 }
@@ -14,11 +14,13 @@ use cfg_primitives::OrderId;
 use frame_support::pallet_prelude::RuntimeDebug;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{
 	traits::{EnsureAddAssign, Zero},
 	DispatchResult,
 };
-use sp_std::cmp::PartialEq;
+use sp_std::{cmp::PartialEq, vec::Vec};
 
 use crate::orders::Order;
 
@@ -206,3 +208,48 @@ impl<Balance: Default, CurrencyId> InvestmentPortfolio<Balance, CurrencyId> {
 		self
 	}
 }
+
+/// A currency swap not yet fulfilled.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PendingSwap<Balance, CurrencyId> {
+	/// The currency to receive
+	pub currency_in: CurrencyId,
+	/// The currency to swap
+	pub currency_out: CurrencyId,
+	/// The amount of `currency_out` not yet swapped
+	pub amount_out: Balance,
+}
+
+/// The state of the cross-chain deposit and redeem requests of an investor
+/// for an investment.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ForeignInvestorStatus<Balance, CurrencyId, Hash> {
+	/// The foreign currency of the ongoing deposit request
+	pub deposit_currency: Option<CurrencyId>,
+	/// The foreign amount of the deposit request already swapped into pool
+	/// currency and not yet collected
+	pub pending_foreign_deposit: Balance,
+	/// The swap of the deposit request not yet fulfilled
+	pub deposit_swap: Option<PendingSwap<Balance, CurrencyId>>,
+	/// The foreign currency of the ongoing redeem request
+	pub redeem_currency: Option<CurrencyId>,
+	/// The collected tranche tokens of the redeem request whose payout was
+	/// not yet sent
+	pub pending_redeemed_tranche_tokens: Balance,
+	/// The swap of the redeem request not yet fulfilled
+	pub redeem_swap: Option<PendingSwap<Balance, CurrencyId>>,
+	/// The unprocessed invest order amount in pool currency
+	pub invest_order: Balance,
+	/// The unprocessed redeem order amount in tranche tokens
+	pub redeem_order: Balance,
+	/// The amount of tranche tokens which can be collected for the invest
+	/// order
+	pub collectable_tranche_tokens: Balance,
+	/// The amount of pool currency which can be collected for the redeem
+	/// order
+	pub collectable_currency: Balance,
+	/// The hashes of the recent outbound messages fulfilling the requests
+	pub fulfilled_message_hashes: Vec<Hash>,
+}
@@ -37,5 +37,10 @@ pub mod xcm;
 /// The type should accommodate all chain ids listed on <https://chainlist.org/>.
 pub type EVMChainId = u64;
 
+/// The chain ID of a non-EVM chain, assigned by Centrifuge.
+/// Used for chains with 32 bytes addresses, such as CosmWasm or Solana based
+/// chains.
+pub type NonEvmChainId = u64;
+
 /// A raw para ID
 pub type ParaId = u32;
@@ -0,0 +1,87 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cfg_primitives::{Balance, InvestmentId};
+use cfg_traits::liquidity_pools::MessageHash;
+use cfg_types::{
+	domain_address::DomainAddress, investments::ForeignInvestorStatus, tokens::CurrencyId,
+};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+pub use runtime_common::apis::ForeignInvestmentsApi as ForeignInvestmentsRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use crate::rpc::invalid_params_error;
+
+#[rpc(client, server)]
+pub trait ForeignInvestmentsApi<BlockHash> {
+	/// Returns the state of the cross-chain deposit and redeem requests of an
+	/// investor for an investment
+	#[method(name = "foreignInvestments_getInvestorStatus")]
+	async fn get_investor_status(
+		&self,
+		investor: DomainAddress,
+		investment_id: InvestmentId,
+		at: Option<BlockHash>,
+	) -> RpcResult<ForeignInvestorStatus<Balance, CurrencyId, MessageHash>>;
+}
+
+/// A struct that implements the `ForeignInvestmentsApi`.
+pub struct ForeignInvestments<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> ForeignInvestments<C, P> {
+	/// Create new `ForeignInvestments` with the given reference to the
+	/// client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+#[async_trait]
+impl<C, Block> ForeignInvestmentsApiServer<Block::Hash> for ForeignInvestments<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: ForeignInvestmentsRuntimeApi<
+		Block,
+		DomainAddress,
+		InvestmentId,
+		ForeignInvestorStatus<Balance, CurrencyId, MessageHash>,
+	>,
+{
+	async fn get_investor_status(
+		&self,
+		investor: DomainAddress,
+		investment_id: InvestmentId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<ForeignInvestorStatus<Balance, CurrencyId, MessageHash>> {
+		let api = self.client.runtime_api();
+		let hash = match at {
+			Some(hash) => hash,
+			None => self.client.info().best_hash,
+		};
+
+		api.investor_status(hash, investor, investment_id)
+			.map_err(|_| invalid_params_error("Unable to query the investor status"))?
+			.ok_or_else(|| invalid_params_error("Unable to find the investment"))
+	}
+}
@@ -14,10 +14,14 @@
 
 use std::sync::Arc;
 
-use cfg_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
+use cfg_primitives::{AccountId, Balance, Block, BlockNumber, Hash, InvestmentId, Nonce};
+use cfg_traits::liquidity_pools::MessageHash;
+use cfg_types::{
+	domain_address::DomainAddress, investments::ForeignInvestorStatus, tokens::CurrencyId,
+};
 use jsonrpsee::types::error::{ErrorCode, ErrorObject};
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
-use runtime_common::apis::AnchorApi;
+use runtime_common::apis::{AnchorApi, ForeignInvestmentsApi};
 use sc_rpc_api::DenyUnsafe;
 use sc_service::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -25,10 +29,14 @@ use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use substrate_frame_rpc_system::{System, SystemApiServer};
 
-use crate::rpc::anchors::{AnchorApiServer, Anchors};
+use crate::rpc::{
+	anchors::{AnchorApiServer, Anchors},
+	foreign_investments::{ForeignInvestments, ForeignInvestmentsApiServer},
+};
 
 pub mod anchors;
 pub mod evm;
+pub mod foreign_investments;
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
@@ -50,6 +58,12 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
 	C::Api: AnchorApi<Block, Hash, BlockNumber>,
+	C::Api: ForeignInvestmentsApi<
+		Block,
+		DomainAddress,
+		InvestmentId,
+		ForeignInvestorStatus<Balance, CurrencyId, MessageHash>,
+	>,
 	P: TransactionPool + Sync + Send + 'static,
 {
 	let mut module = RpcExtension::new(());
@@ -57,6 +71,7 @@ where
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 	module.merge(Anchors::new(client.clone()).into_rpc())?;
+	module.merge(ForeignInvestments::new(client.clone()).into_rpc())?;
 
 	Ok(module)
 }
@@ -16,7 +16,11 @@
 
 use std::{sync::Arc, time::Duration};
 
-use cfg_primitives::{AccountId, AuraId, Balance, Block, BlockNumber, Hash, Nonce};
+use cfg_primitives::{AccountId, AuraId, Balance, Block, BlockNumber, Hash, InvestmentId, Nonce};
+use cfg_traits::liquidity_pools::MessageHash;
+use cfg_types::{
+	domain_address::DomainAddress, investments::ForeignInvestorStatus, tokens::CurrencyId,
+};
 use cumulus_client_cli::CollatorOptions;
 use cumulus_client_collator::service::CollatorService;
 use cumulus_client_consensus_common::ParachainBlockImport as TParachainBlockImport;
@@ -69,7 +73,12 @@ pub trait RuntimeApiCollection:
 	+ fp_rpc::EthereumRuntimeRPCApi<Block>
 	+ sp_consensus_aura::AuraApi<Block, AuraId>
 	+ runtime_common::apis::AnchorApi<Block, Hash, BlockNumber>
-	+ cumulus_primitives_core::CollectCollationInfo<Block>
+	+ runtime_common::apis::ForeignInvestmentsApi<
+		Block,
+		DomainAddress,
+		InvestmentId,
+		ForeignInvestorStatus<Balance, CurrencyId, MessageHash>,
+	> + cumulus_primitives_core::CollectCollationInfo<Block>
 {
 }
 
@@ -86,7 +95,12 @@ impl<Api> RuntimeApiCollection for Api where
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ sp_consensus_aura::AuraApi<Block, AuraId>
 		+ runtime_common::apis::AnchorApi<Block, Hash, BlockNumber>
-		+ cumulus_primitives_core::CollectCollationInfo<Block>
+		+ runtime_common::apis::ForeignInvestmentsApi<
+			Block,
+			DomainAddress,
+			InvestmentId,
+			ForeignInvestorStatus<Balance, CurrencyId, MessageHash>,
+		> + cumulus_primitives_core::CollectCollationInfo<Block>
 {
 }
 
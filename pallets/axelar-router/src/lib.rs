@@ -17,11 +17,11 @@
 
 use cfg_traits::{
 	ethereum::EthereumTransactor,
-	liquidity_pools::{MessageReceiver, MessageSender},
+	liquidity_pools::{MessageReceiver, MessageSender, MessageSenderWithFees},
 };
 use cfg_types::{
 	domain_address::{Domain, DomainAddress},
-	EVMChainId,
+	EVMChainId, NonEvmChainId,
 };
 use ethabi::{Contract, Function, Param, ParamType, Token};
 use fp_evm::PrecompileHandle;
@@ -50,11 +50,13 @@ mod tests;
 const MAX_AXELAR_EVM_CHAIN_SIZE: u32 = 16;
 
 const MAX_SOURCE_CHAIN_BYTES: u32 = 128;
-// Ensure we allow enough to support a hex encoded address with the `0x` prefix.
-const MAX_SOURCE_ADDRESS_BYTES: u32 = 42;
+// Ensure we allow enough to support a hex encoded non-EVM address with the `0x`
+// prefix.
+const MAX_SOURCE_ADDRESS_BYTES: u32 = 66;
 const MAX_TOKEN_SYMBOL_BYTES: u32 = 32;
 const MAX_PAYLOAD_BYTES: u32 = 1024;
 const EVM_ADDRESS_LEN: usize = 20;
+const NON_EVM_ADDRESS_LEN: usize = 32;
 
 pub type ChainName = BoundedVec<u8, ConstU32<MAX_AXELAR_EVM_CHAIN_SIZE>>;
 
@@ -62,6 +64,7 @@ pub type ChainName = BoundedVec<u8, ConstU32<MAX_AXELAR_EVM_CHAIN_SIZE>>;
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum AxelarId {
 	Evm(EVMChainId),
+	NonEvm(NonEvmChainId),
 }
 
 /// Configuration for outbound messages though axelar
@@ -87,6 +90,7 @@ pub struct AxelarConfig {
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum DomainConfig {
 	Evm(EvmConfig),
+	NonEvm(NonEvmConfig),
 }
 
 /// Data for validating and executing the internal EVM call.
@@ -99,6 +103,20 @@ pub struct EvmConfig {
 	pub outbound_fee_values: FeeValues,
 }
 
+/// Data for reaching an application in a non-EVM chain.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct NonEvmConfig {
+	/// Associated chain id
+	pub chain_id: NonEvmChainId,
+
+	/// Address of the application in the non-EVM chain.
+	/// Used instead of `AxelarConfig::app_contract_address` for this domain.
+	pub app_address: [u8; NON_EVM_ADDRESS_LEN],
+
+	/// The values used when executing the EVM call.
+	pub outbound_fee_values: FeeValues,
+}
+
 /// The FeeValues holds all information related to the transaction costs.
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct FeeValues {
@@ -198,6 +216,12 @@ pub mod pallet {
 						chain_name.clone(),
 					);
 				}
+				DomainConfig::NonEvm(non_evm_config) => {
+					ChainNameById::<T>::insert(
+						AxelarId::NonEvm(non_evm_config.chain_id),
+						chain_name.clone(),
+					);
+				}
 			}
 
 			Configuration::<T>::insert(chain_name.clone(), config.clone());
@@ -248,6 +272,25 @@ pub mod pallet {
 						payload.to_vec(),
 					)
 				}
+				DomainConfig::NonEvm(NonEvmConfig {
+					chain_id,
+					app_address,
+					..
+				}) => {
+					let source_address = decode_var_source::<NON_EVM_ADDRESS_LEN>(source_address)
+						.ok_or(Error::<T>::InvalidSourceAddress)?;
+
+					ensure!(
+						source_address == app_address,
+						Error::<T>::SourceAddressMismatch
+					);
+
+					T::Receiver::receive(
+						AxelarId::NonEvm(chain_id).into(),
+						Domain::NonEvm(chain_id),
+						payload.to_vec(),
+					)
+				}
 			}
 		}
 	}
@@ -327,31 +370,65 @@ pub mod pallet {
 			axelar_id: AxelarId,
 			origin: Self::Origin,
 			message: Self::Message,
+		) -> DispatchResult {
+			Self::send_through_axelar(axelar_id, origin, message, None)
+		}
+	}
+
+	impl<T: Config> MessageSenderWithFees for Pallet<T> {
+		type Fees = FeeValues;
+
+		fn send_with_fees(
+			axelar_id: AxelarId,
+			origin: Self::Origin,
+			message: Self::Message,
+			fees: FeeValues,
+		) -> DispatchResult {
+			Self::send_through_axelar(axelar_id, origin, message, Some(fees))
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Sends the message using the fees configured for the domain unless
+		/// others are given.
+		fn send_through_axelar(
+			axelar_id: AxelarId,
+			origin: DomainAddress,
+			message: Vec<u8>,
+			fees: Option<FeeValues>,
 		) -> DispatchResult {
 			let chain_name = ChainNameById::<T>::get(axelar_id)
 				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
 			let config = Configuration::<T>::get(&chain_name)
 				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
 
-			let axelar_message = wrap_into_axelar_msg(
-				message,
-				chain_name.into_inner(),
-				config.app_contract_address,
+			let (app_address, fee_values) = match config.domain {
+				DomainConfig::Evm(evm_config) => (
+					config.app_contract_address.0.to_vec(),
+					evm_config.outbound_fee_values,
+				),
+				DomainConfig::NonEvm(non_evm_config) => (
+					non_evm_config.app_address.to_vec(),
+					non_evm_config.outbound_fee_values,
+				),
+			};
+
+			let fee_values = fees.unwrap_or(fee_values);
+
+			let axelar_message =
+				wrap_into_axelar_msg(message, chain_name.into_inner(), &app_address)
+					.map_err(DispatchError::Other)?;
+
+			T::Transactor::call(
+				origin.h160(),
+				config.outbound_contract_address,
+				axelar_message.as_slice(),
+				fee_values.value,
+				fee_values.gas_price,
+				fee_values.gas_limit,
 			)
-			.map_err(DispatchError::Other)?;
-
-			match config.domain {
-				DomainConfig::Evm(evm_config) => T::Transactor::call(
-					origin.h160(),
-					config.outbound_contract_address,
-					axelar_message.as_slice(),
-					evm_config.outbound_fee_values.value,
-					evm_config.outbound_fee_values.gas_price,
-					evm_config.outbound_fee_values.gas_limit,
-				)
-				.map(|_| ())
-				.map_err(|e| e.error),
-			}
+			.map(|_| ())
+			.map_err(|e| e.error)
 		}
 	}
 }
@@ -368,7 +445,7 @@ pub mod pallet {
 pub fn wrap_into_axelar_msg(
 	serialized_msg: Vec<u8>,
 	target_chain: Vec<u8>,
-	target_contract: H160,
+	target_contract: &[u8],
 ) -> Result<Vec<u8>, &'static str> {
 	const AXELAR_FUNCTION_NAME: &str = "callContract";
 	const AXELAR_DESTINATION_CHAIN_PARAM: &str = "destinationChain";
@@ -419,7 +496,7 @@ pub fn wrap_into_axelar_msg(
 		//
 		// The `to_string` method on the H160 is returning a string containing an ellipsis, such
 		// as: 0x1234…7890
-		Token::String(format!("0x{}", hex::encode(target_contract.0))),
+		Token::String(format!("0x{}", hex::encode(target_contract))),
 		Token::Bytes(serialized_msg),
 	])
 	.map_err(|_| "cannot encode input for Axelar contract function")?;
@@ -428,9 +505,9 @@ pub fn wrap_into_axelar_msg(
 }
 
 /// Decodes the source address which can be:
-/// - a 20 bytes array
-/// - an hexadecimal character secuence (40 characters)
-/// - an hexadecimal character secuence (40 characters) with 0x prefix
+/// - an array of the expected size (20 bytes for EVM addresses)
+/// - an hexadecimal character secuence (40 characters for EVM addresses)
+/// - an hexadecimal character secuence with 0x prefix
 pub fn decode_var_source<const EXPECTED_SOURCE_ADDRESS_SIZE: usize>(
 	source_address: &[u8],
 ) -> Option<[u8; EXPECTED_SOURCE_ADDRESS_SIZE]> {
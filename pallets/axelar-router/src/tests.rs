@@ -5,6 +5,9 @@ use crate::{mock::*, *};
 
 const CHAIN_NAME: &str = "CHAIN_1";
 const CHAIN_ID: EVMChainId = 1;
+const NON_EVM_CHAIN_NAME: &str = "CHAIN_2";
+const NON_EVM_CHAIN_ID: NonEvmChainId = 2;
+const NON_EVM_APP_ADDRESS: [u8; 32] = [4; 32];
 const LP_CONTRACT_ADDRESS: H160 = H160::repeat_byte(1);
 const INBOUND_CONTRACT: H160 = H160::repeat_byte(2);
 const OUTBOUND_CONTRACT: H160 = H160::repeat_byte(3);
@@ -30,6 +33,21 @@ fn config() -> AxelarConfig {
 	}
 }
 
+fn non_evm_config() -> AxelarConfig {
+	AxelarConfig {
+		domain: DomainConfig::NonEvm(NonEvmConfig {
+			chain_id: NON_EVM_CHAIN_ID,
+			app_address: NON_EVM_APP_ADDRESS,
+			outbound_fee_values: FeeValues {
+				value: FEE_VALUE,
+				gas_limit: GAS_LIMIT,
+				gas_price: GAS_PRICE,
+			},
+		}),
+		..config()
+	}
+}
+
 fn correct_configuration() {
 	assert_ok!(Router::set_config(
 		RuntimeOrigin::root(),
@@ -38,8 +56,21 @@ fn correct_configuration() {
 	));
 }
 
+fn non_evm_configuration() {
+	assert_ok!(Router::set_config(
+		RuntimeOrigin::root(),
+		NON_EVM_CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+		Box::new(non_evm_config())
+	));
+}
+
 fn wrap_message(message: Vec<u8>) -> Vec<u8> {
-	wrap_into_axelar_msg(message, CHAIN_NAME.as_bytes().to_vec(), LP_CONTRACT_ADDRESS).unwrap()
+	wrap_into_axelar_msg(
+		message,
+		CHAIN_NAME.as_bytes().to_vec(),
+		&LP_CONTRACT_ADDRESS.0,
+	)
+	.unwrap()
 }
 
 mod configuration {
@@ -83,6 +114,62 @@ mod send {
 		});
 	}
 
+	#[test]
+	fn with_fees() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let fees = FeeValues {
+				value: U256::from(10),
+				gas_price: U256::from(20),
+				gas_limit: U256::from(30),
+			};
+
+			Transactor::mock_call(move |_, _, data, value, gas_price, gas_limit| {
+				assert_eq!(data, &wrap_message(MESSAGE.to_vec()));
+				assert_eq!(value, U256::from(10));
+				assert_eq!(gas_price, U256::from(20));
+				assert_eq!(gas_limit, U256::from(30));
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send_with_fees(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec(),
+				fees
+			));
+		});
+	}
+
+	#[test]
+	fn with_non_evm_domain() {
+		new_test_ext().execute_with(|| {
+			non_evm_configuration();
+
+			Transactor::mock_call(move |from, to, data, _, _, _| {
+				assert_eq!(from, SENDER.h160());
+				assert_eq!(to, OUTBOUND_CONTRACT);
+				assert_eq!(
+					data,
+					&wrap_into_axelar_msg(
+						MESSAGE.to_vec(),
+						NON_EVM_CHAIN_NAME.as_bytes().to_vec(),
+						&NON_EVM_APP_ADDRESS,
+					)
+					.unwrap()
+				);
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send(
+				AxelarId::NonEvm(NON_EVM_CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+		});
+	}
+
 	#[test]
 	fn without_configuration() {
 		new_test_ext().execute_with(|| {
@@ -132,6 +219,37 @@ mod receive {
 		});
 	}
 
+	#[test]
+	fn with_non_evm_domain() {
+		new_test_ext().execute_with(|| {
+			non_evm_configuration();
+
+			Receiver::mock_receive(|middleware, origin, message| {
+				assert_eq!(middleware, Middleware(AxelarId::NonEvm(NON_EVM_CHAIN_ID)));
+				assert_eq!(origin, Domain::NonEvm(NON_EVM_CHAIN_ID));
+				assert_eq!(&message, MESSAGE);
+				Ok(())
+			});
+
+			assert_ok!(Router::receive(
+				INBOUND_CONTRACT,
+				NON_EVM_CHAIN_NAME.as_bytes(),
+				format!("0x{}", hex::encode(NON_EVM_APP_ADDRESS)).as_bytes(),
+				MESSAGE
+			));
+
+			assert_err!(
+				Router::receive(
+					INBOUND_CONTRACT,
+					NON_EVM_CHAIN_NAME.as_bytes(),
+					&LP_CONTRACT_ADDRESS.0,
+					MESSAGE
+				),
+				Error::<Runtime>::InvalidSourceAddress
+			);
+		});
+	}
+
 	#[test]
 	fn without_configuration() {
 		new_test_ext().execute_with(|| {
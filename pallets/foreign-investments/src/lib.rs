@@ -206,7 +206,7 @@ pub mod pallet {
 	/// The storage is killed once the redemption is fully collected and
 	/// fully swapped or decreased
 	#[pallet::storage]
-	pub(super) type ForeignRedemptionInfo<T: Config> = StorageDoubleMap<
+	pub type ForeignRedemptionInfo<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
@@ -0,0 +1,360 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+//! # Hyperlane Router
+//!
+//! Pallet that sends and receive message with other chains using a
+//! Hyperlane-style mailbox. It works independently of the Axelar router, so
+//! both can be configured as routers of the same domain in the gateway.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use cfg_traits::{
+	ethereum::EthereumTransactor,
+	liquidity_pools::{MessageReceiver, MessageSender, MessageSenderWithFees},
+};
+use cfg_types::{
+	domain_address::{Domain, DomainAddress},
+	EVMChainId, NonEvmChainId,
+};
+use ethabi::{Contract, Function, Param, ParamType, Token};
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	pallet_prelude::*,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use precompile_utils::prelude::*;
+use scale_info::prelude::string::String;
+use sp_core::{H160, H256, U256};
+use sp_std::{boxed::Box, collections::btree_map::BTreeMap, vec, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+const MAX_PAYLOAD_BYTES: u32 = 1024;
+
+/// Identifier used by Hyperlane for each chain it connects.
+/// It is not related to the EVM chain id.
+pub type HyperlaneDomain = u32;
+
+/// Type to represent the kind of message received by Hyperlane
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum HyperlaneId {
+	Evm(EVMChainId),
+	NonEvm(NonEvmChainId),
+}
+
+impl From<HyperlaneId> for Domain {
+	fn from(id: HyperlaneId) -> Self {
+		match id {
+			HyperlaneId::Evm(chain_id) => Domain::Evm(chain_id),
+			HyperlaneId::NonEvm(chain_id) => Domain::NonEvm(chain_id),
+		}
+	}
+}
+
+/// Configuration for inbound and outbound messages through Hyperlane
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct HyperlaneConfig {
+	/// Chain reached through this configuration
+	pub id: HyperlaneId,
+
+	/// Address of the local mailbox contract.
+	/// Used to dispatch outbound messages and represents the caller allowed
+	/// to call the `handle()` precompile
+	pub mailbox_address: H160,
+
+	/// Address of the application in the remote chain, left padded with zeros
+	/// for EVM chains.
+	/// It is the recipient of outbound messages and the only sender accepted
+	/// for inbound messages.
+	pub app_address: H256,
+
+	/// The values used when executing the EVM call.
+	pub outbound_fee_values: FeeValues,
+}
+
+/// The FeeValues holds all information related to the transaction costs.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct FeeValues {
+	/// The value used when executing the EVM call.
+	pub value: U256,
+
+	/// The gas price used when executing the EVM call.
+	pub gas_price: U256,
+
+	/// The gas limit used when executing the EVM call.
+	pub gas_limit: U256,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin that is allowed to configure the mailboxes and
+		/// applications we exchange messages with
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The target of the messages coming from other chains
+		type Receiver: MessageReceiver<
+			Middleware = Self::Middleware,
+			Origin = Domain,
+			Message = Vec<u8>,
+		>;
+
+		/// Middleware used by the gateway
+		type Middleware: From<HyperlaneId>;
+
+		/// The target of the messages coming from this chain
+		type Transactor: EthereumTransactor;
+	}
+
+	#[pallet::storage]
+	pub type Configuration<T: Config> =
+		StorageMap<_, Twox64Concat, HyperlaneDomain, HyperlaneConfig>;
+
+	#[pallet::storage]
+	pub type HyperlaneDomainById<T: Config> =
+		StorageMap<_, Twox64Concat, HyperlaneId, HyperlaneDomain>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		ConfigSet {
+			hyperlane_domain: HyperlaneDomain,
+			config: Box<HyperlaneConfig>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Emit when the router configuration is not found.
+		RouterConfigurationNotFound,
+
+		/// Emit when a message is received from a non configured mailbox
+		MailboxMismatch,
+
+		/// Emit when a message is received from a non configured sender
+		SenderMismatch,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().reads_writes(2, 4)))]
+		#[pallet::call_index(0)]
+		pub fn set_config(
+			origin: OriginFor<T>,
+			hyperlane_domain: HyperlaneDomain,
+			config: Box<HyperlaneConfig>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			// Remove the mappings of the previous configurations, either of the
+			// domain or of the id, to not keep routing through them.
+			if let Some(previous) = Configuration::<T>::get(hyperlane_domain) {
+				HyperlaneDomainById::<T>::remove(previous.id);
+			}
+			if let Some(previous_domain) = HyperlaneDomainById::<T>::get(config.id.clone()) {
+				Configuration::<T>::remove(previous_domain);
+			}
+
+			HyperlaneDomainById::<T>::insert(config.id.clone(), hyperlane_domain);
+			Configuration::<T>::insert(hyperlane_domain, config.clone());
+
+			Self::deposit_event(Event::<T>::ConfigSet {
+				hyperlane_domain,
+				config,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn receive(
+			caller: H160,
+			origin: HyperlaneDomain,
+			sender: H256,
+			payload: &[u8],
+		) -> DispatchResult {
+			let config =
+				Configuration::<T>::get(origin).ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			ensure!(
+				caller == config.mailbox_address,
+				Error::<T>::MailboxMismatch
+			);
+
+			ensure!(sender == config.app_address, Error::<T>::SenderMismatch);
+
+			T::Receiver::receive(config.id.clone().into(), config.id.into(), payload.to_vec())
+		}
+	}
+
+	#[precompile_utils::precompile]
+	impl<T: Config> Pallet<T> {
+		// Mimics:
+		//
+		//   function handle(
+		//       uint32 _origin,
+		//       bytes32 _sender,
+		//       bytes calldata _message
+		//   ) external payable onlyMailbox { ...
+		//   }
+		#[precompile::public("handle(uint32,bytes32,bytes)")]
+		fn handle(
+			handle: &mut impl PrecompileHandle,
+			origin: u32,
+			sender: H256,
+			message: BoundedBytes<ConstU32<MAX_PAYLOAD_BYTES>>,
+		) -> EvmResult {
+			Self::receive(handle.context().caller, origin, sender, message.as_bytes())
+				.map_err(|e| TryDispatchError::Substrate(e).into())
+		}
+	}
+
+	impl<T: Config> MessageSender for Pallet<T> {
+		type Message = Vec<u8>;
+		type Middleware = HyperlaneId;
+		type Origin = DomainAddress;
+
+		fn send(
+			hyperlane_id: HyperlaneId,
+			origin: Self::Origin,
+			message: Self::Message,
+		) -> DispatchResult {
+			Self::send_through_mailbox(hyperlane_id, origin, message, None)
+		}
+	}
+
+	impl<T: Config> MessageSenderWithFees for Pallet<T> {
+		type Fees = FeeValues;
+
+		fn send_with_fees(
+			hyperlane_id: HyperlaneId,
+			origin: Self::Origin,
+			message: Self::Message,
+			fees: FeeValues,
+		) -> DispatchResult {
+			Self::send_through_mailbox(hyperlane_id, origin, message, Some(fees))
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Sends the message using the fees configured for the domain unless
+		/// others are given.
+		fn send_through_mailbox(
+			hyperlane_id: HyperlaneId,
+			origin: DomainAddress,
+			message: Vec<u8>,
+			fees: Option<FeeValues>,
+		) -> DispatchResult {
+			let hyperlane_domain = HyperlaneDomainById::<T>::get(hyperlane_id)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+			let config = Configuration::<T>::get(hyperlane_domain)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			let hyperlane_message =
+				wrap_into_hyperlane_msg(message, hyperlane_domain, config.app_address)
+					.map_err(DispatchError::Other)?;
+
+			let fee_values = fees.unwrap_or(config.outbound_fee_values);
+
+			T::Transactor::call(
+				origin.h160(),
+				config.mailbox_address,
+				hyperlane_message.as_slice(),
+				fee_values.value,
+				fee_values.gas_price,
+				fee_values.gas_limit,
+			)
+			.map(|_| ())
+			.map_err(|e| e.error)
+		}
+	}
+}
+
+/// Encodes the provided message into the format required for dispatching it
+/// through the Hyperlane mailbox, which in turn delivers the serialized LP
+/// message as `messageBody` to the recipient application.
+///
+/// Mailbox contract call:
+/// <https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/main/solidity/contracts/Mailbox.sol>
+pub fn wrap_into_hyperlane_msg(
+	serialized_msg: Vec<u8>,
+	destination_domain: HyperlaneDomain,
+	recipient_address: H256,
+) -> Result<Vec<u8>, &'static str> {
+	const MAILBOX_FUNCTION_NAME: &str = "dispatch";
+	const MAILBOX_DESTINATION_DOMAIN_PARAM: &str = "destinationDomain";
+	const MAILBOX_RECIPIENT_ADDRESS_PARAM: &str = "recipientAddress";
+	const MAILBOX_MESSAGE_BODY_PARAM: &str = "messageBody";
+
+	#[allow(deprecated)]
+	let encoded_mailbox_contract = Contract {
+		constructor: None,
+		functions: BTreeMap::<String, Vec<Function>>::from([(
+			MAILBOX_FUNCTION_NAME.into(),
+			vec![Function {
+				name: MAILBOX_FUNCTION_NAME.into(),
+				inputs: vec![
+					Param {
+						name: MAILBOX_DESTINATION_DOMAIN_PARAM.into(),
+						kind: ParamType::Uint(32),
+						internal_type: None,
+					},
+					Param {
+						name: MAILBOX_RECIPIENT_ADDRESS_PARAM.into(),
+						kind: ParamType::FixedBytes(32),
+						internal_type: None,
+					},
+					Param {
+						name: MAILBOX_MESSAGE_BODY_PARAM.into(),
+						kind: ParamType::Bytes,
+						internal_type: None,
+					},
+				],
+				outputs: vec![],
+				constant: Some(false),
+				state_mutability: Default::default(),
+			}],
+		)]),
+		events: Default::default(),
+		errors: Default::default(),
+		receive: false,
+		fallback: false,
+	}
+	.function(MAILBOX_FUNCTION_NAME)
+	.map_err(|_| "cannot retrieve Hyperlane mailbox function")?
+	.encode_input(&[
+		Token::Uint(destination_domain.into()),
+		Token::FixedBytes(recipient_address.0.to_vec()),
+		Token::Bytes(serialized_msg),
+	])
+	.map_err(|_| "cannot encode input for Hyperlane mailbox function")?;
+
+	Ok(encoded_mailbox_contract)
+}
@@ -0,0 +1,293 @@
+use frame_support::{assert_err, assert_ok};
+use sp_core::{crypto::AccountId32, U256};
+
+use crate::{mock::*, *};
+
+const HYPERLANE_DOMAIN: HyperlaneDomain = 42;
+const CHAIN_ID: EVMChainId = 1;
+const NON_EVM_HYPERLANE_DOMAIN: HyperlaneDomain = 43;
+const NON_EVM_CHAIN_ID: NonEvmChainId = 2;
+const APP_ADDRESS: H256 = H256::repeat_byte(1);
+const MAILBOX: H160 = H160::repeat_byte(2);
+const SENDER: DomainAddress = DomainAddress::Centrifuge(AccountId32::new([0; 32]));
+const MESSAGE: &[u8] = &[1, 2, 3];
+const FEE_VALUE: U256 = U256::zero();
+const GAS_LIMIT: U256 = U256::one();
+const GAS_PRICE: U256 = U256::max_value();
+
+fn config() -> HyperlaneConfig {
+	HyperlaneConfig {
+		id: HyperlaneId::Evm(CHAIN_ID),
+		mailbox_address: MAILBOX,
+		app_address: APP_ADDRESS,
+		outbound_fee_values: FeeValues {
+			value: FEE_VALUE,
+			gas_limit: GAS_LIMIT,
+			gas_price: GAS_PRICE,
+		},
+	}
+}
+
+fn correct_configuration() {
+	assert_ok!(Router::set_config(
+		RuntimeOrigin::root(),
+		HYPERLANE_DOMAIN,
+		Box::new(config())
+	));
+}
+
+fn non_evm_configuration() {
+	assert_ok!(Router::set_config(
+		RuntimeOrigin::root(),
+		NON_EVM_HYPERLANE_DOMAIN,
+		Box::new(HyperlaneConfig {
+			id: HyperlaneId::NonEvm(NON_EVM_CHAIN_ID),
+			..config()
+		})
+	));
+}
+
+mod configuration {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_eq!(
+				HyperlaneDomainById::<Runtime>::get(HyperlaneId::Evm(CHAIN_ID)),
+				Some(HYPERLANE_DOMAIN)
+			);
+			assert_eq!(
+				Configuration::<Runtime>::get(HYPERLANE_DOMAIN),
+				Some(config())
+			);
+		});
+	}
+
+	#[test]
+	fn update_id_of_domain() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let new_config = HyperlaneConfig {
+				id: HyperlaneId::NonEvm(NON_EVM_CHAIN_ID),
+				..config()
+			};
+
+			assert_ok!(Router::set_config(
+				RuntimeOrigin::root(),
+				HYPERLANE_DOMAIN,
+				Box::new(new_config.clone())
+			));
+
+			assert_eq!(
+				HyperlaneDomainById::<Runtime>::get(HyperlaneId::Evm(CHAIN_ID)),
+				None
+			);
+			assert_eq!(
+				HyperlaneDomainById::<Runtime>::get(HyperlaneId::NonEvm(NON_EVM_CHAIN_ID)),
+				Some(HYPERLANE_DOMAIN)
+			);
+			assert_eq!(
+				Configuration::<Runtime>::get(HYPERLANE_DOMAIN),
+				Some(new_config)
+			);
+		});
+	}
+
+	#[test]
+	fn update_domain_of_id() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_ok!(Router::set_config(
+				RuntimeOrigin::root(),
+				NON_EVM_HYPERLANE_DOMAIN,
+				Box::new(config())
+			));
+
+			assert_eq!(Configuration::<Runtime>::get(HYPERLANE_DOMAIN), None);
+			assert_eq!(
+				HyperlaneDomainById::<Runtime>::get(HyperlaneId::Evm(CHAIN_ID)),
+				Some(NON_EVM_HYPERLANE_DOMAIN)
+			);
+			assert_eq!(
+				Configuration::<Runtime>::get(NON_EVM_HYPERLANE_DOMAIN),
+				Some(config())
+			);
+		});
+	}
+}
+
+mod send {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Transactor::mock_call(move |from, to, data, value, gas_price, gas_limit| {
+				assert_eq!(from, SENDER.h160());
+				assert_eq!(to, MAILBOX);
+				assert_eq!(
+					data,
+					&wrap_into_hyperlane_msg(MESSAGE.to_vec(), HYPERLANE_DOMAIN, APP_ADDRESS)
+						.unwrap()
+				);
+				assert_eq!(value, FEE_VALUE);
+				assert_eq!(gas_limit, GAS_LIMIT);
+				assert_eq!(gas_price, GAS_PRICE);
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send(
+				HyperlaneId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+		});
+	}
+
+	#[test]
+	fn with_fees() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let fees = FeeValues {
+				value: U256::from(10),
+				gas_price: U256::from(20),
+				gas_limit: U256::from(30),
+			};
+
+			Transactor::mock_call(move |_, to, _, value, gas_price, gas_limit| {
+				assert_eq!(to, MAILBOX);
+				assert_eq!(value, U256::from(10));
+				assert_eq!(gas_price, U256::from(20));
+				assert_eq!(gas_limit, U256::from(30));
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send_with_fees(
+				HyperlaneId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec(),
+				fees
+			));
+		});
+	}
+
+	#[test]
+	fn without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				Router::send(HyperlaneId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()),
+				Error::<Runtime>::RouterConfigurationNotFound,
+			);
+		});
+	}
+
+	#[test]
+	fn with_ethereum_error() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Transactor::mock_call(move |_, _, _, _, _, _| Err(DispatchError::Other("err").into()));
+
+			assert_err!(
+				Router::send(HyperlaneId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()),
+				DispatchError::Other("err")
+			);
+		});
+	}
+}
+
+mod receive {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Receiver::mock_receive(|middleware, origin, message| {
+				assert_eq!(middleware, Middleware(HyperlaneId::Evm(CHAIN_ID)));
+				assert_eq!(origin, Domain::Evm(CHAIN_ID));
+				assert_eq!(&message, MESSAGE);
+				Ok(())
+			});
+
+			assert_ok!(Router::receive(
+				MAILBOX,
+				HYPERLANE_DOMAIN,
+				APP_ADDRESS,
+				MESSAGE
+			));
+		});
+	}
+
+	#[test]
+	fn with_non_evm_domain() {
+		new_test_ext().execute_with(|| {
+			non_evm_configuration();
+
+			Receiver::mock_receive(|middleware, origin, message| {
+				assert_eq!(
+					middleware,
+					Middleware(HyperlaneId::NonEvm(NON_EVM_CHAIN_ID))
+				);
+				assert_eq!(origin, Domain::NonEvm(NON_EVM_CHAIN_ID));
+				assert_eq!(&message, MESSAGE);
+				Ok(())
+			});
+
+			assert_ok!(Router::receive(
+				MAILBOX,
+				NON_EVM_HYPERLANE_DOMAIN,
+				APP_ADDRESS,
+				MESSAGE
+			));
+		});
+	}
+
+	#[test]
+	fn without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				Router::receive(MAILBOX, HYPERLANE_DOMAIN, APP_ADDRESS, MESSAGE),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn with_wrong_mailbox() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_err!(
+				Router::receive(
+					H160::repeat_byte(23),
+					HYPERLANE_DOMAIN,
+					APP_ADDRESS,
+					MESSAGE
+				),
+				Error::<Runtime>::MailboxMismatch
+			);
+		});
+	}
+
+	#[test]
+	fn with_wrong_sender() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_err!(
+				Router::receive(MAILBOX, HYPERLANE_DOMAIN, H256::repeat_byte(23), MESSAGE),
+				Error::<Runtime>::SenderMismatch
+			);
+		});
+	}
+}
@@ -33,7 +33,7 @@ benchmarks! {
 		let n in 1..25;
 		let now: Seconds = (1 << n) - 1;
 		let rate = interest_rate_per_sec(T::Rate::saturating_from_rational(10, 100)).unwrap();
-	}: { Pallet::<T>::calculate_accumulated_rate(rate, One::one(), 0, now).unwrap() }
+	}: { Pallet::<T>::calculate_accumulated_rate(rate, &CompoundingSchedule::Secondly, &DayCountConvention::Actual365, One::one(), 0, now).unwrap() }
 	verify {
 	}
 }
@@ -119,12 +119,43 @@
 //! * If `new_interest_rate` is newly created (and thus its age is `0`), the
 //!   correction factor is `1` just as for any other rate.  See the note above
 //!   regarding zero-age rates.
+//!
+//! ## Compounding schedules
+//!
+//! Each rate accumulates according to its compounding schedule. With periodic
+//! compounding (daily, monthly or annually), interest accrues linearly within a
+//! period and compounds at its end. Periods are aligned to multiples of their
+//! length since the UNIX epoch, so all debts sharing a rate compound at the
+//! same moments.
+//!
+//! With simple interest, the accumulated rate grows linearly:
+//!
+//! ```text
+//! accumulated_rate = 1 + (rate_per_second - 1) * rate_age
+//! ```
+//!
+//! Debts adjusted after the rate was created are normalized with the
+//! accumulated rate at that moment, so they accrue slightly less than the
+//! exact simple interest of the adjusted amount.
+//!
+//! ## Day count conventions
+//!
+//! The time elapsed for a rate is counted with its day count convention:
+//!
+//! * ACT/365: actual seconds, over a year of 365 days.
+//! * ACT/360: actual seconds, over a year of 360 days.
+//! * 30E/360: every month counts as 30 days, over a year of 360 days.
+//!
+//! The per-second rate is the yearly rate divided by the seconds of the
+//! convention's year, and monthly and annual compounding periods are a twelfth
+//! and a whole of that year.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use cfg_primitives::SECONDS_PER_YEAR;
 use cfg_traits::{
-	interest::{InterestAccrual, InterestRate, RateCollection},
+	interest::{
+		CompoundingSchedule, DayCountConvention, InterestAccrual, InterestRate, RateCollection,
+	},
 	Seconds, TimeAsSecs,
 };
 use cfg_types::adjustments::Adjustment;
@@ -132,16 +163,17 @@ use frame_support::{pallet_prelude::RuntimeDebug, BoundedVec};
 use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::{checked_pow, One, Zero};
+use sp_arithmetic::traits::{One, Zero};
 use sp_runtime::{
 	traits::{
 		AtLeast32BitUnsigned, CheckedAdd, CheckedSub, EnsureAdd, EnsureAddAssign, EnsureDiv,
-		EnsureInto, EnsureMul, EnsureSub, Saturating,
+		EnsureMul, EnsureSub, Saturating,
 	},
 	ArithmeticError, DispatchError, FixedPointNumber, FixedPointOperand,
 };
 use sp_std::{cmp::Ordering, vec::Vec};
 
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -168,10 +200,20 @@ type RateDetailsOf<T> = RateDetails<<T as Config>::Rate>;
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct RateDetails<Rate> {
 	pub interest_rate_per_sec: Rate,
+	pub compounding: CompoundingSchedule,
+	pub day_count: DayCountConvention,
 	pub accumulated_rate: Rate,
 	pub reference_count: u32,
 }
 
+impl<Rate: FixedPointNumber> RateDetails<Rate> {
+	fn matches(&self, interest_rate_per_sec: &Rate, interest_rate: &InterestRate<Rate>) -> bool {
+		self.interest_rate_per_sec == *interest_rate_per_sec
+			&& self.compounding == interest_rate.compounding()
+			&& self.day_count == interest_rate.day_count()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
@@ -179,7 +221,7 @@ pub mod pallet {
 	use super::*;
 	use crate::weights::WeightInfo;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -265,12 +307,16 @@ pub mod pallet {
 
 					let RateDetailsOf::<T> {
 						interest_rate_per_sec,
+						compounding,
+						day_count,
 						accumulated_rate,
 						reference_count,
 					} = rate;
 
 					Self::calculate_accumulated_rate(
 						interest_rate_per_sec,
+						&compounding,
+						&day_count,
 						accumulated_rate,
 						then,
 						now,
@@ -278,6 +324,8 @@ pub mod pallet {
 					.ok()
 					.map(|accumulated_rate| RateDetailsOf::<T> {
 						interest_rate_per_sec,
+						compounding,
+						day_count,
 						accumulated_rate,
 						reference_count,
 					})
@@ -312,13 +360,25 @@ pub mod pallet {
 
 			let acc_rate = match when.cmp(&now) {
 				Ordering::Equal => rate.accumulated_rate,
-				Ordering::Less => {
-					let delta = now.ensure_sub(when)?;
-					let rate_adjustment =
-						checked_pow(rate.interest_rate_per_sec, delta.ensure_into()?)
-							.ok_or(ArithmeticError::Overflow)?;
-					rate.accumulated_rate.ensure_div(rate_adjustment)?
-				}
+				Ordering::Less => match rate.compounding {
+					CompoundingSchedule::Simple => {
+						let delta = rate.day_count.elapsed(when, now)?;
+						let rate_adjustment = rate
+							.interest_rate_per_sec
+							.ensure_sub(One::one())?
+							.ensure_mul(T::Rate::saturating_from_integer(delta))?;
+						rate.accumulated_rate.ensure_sub(rate_adjustment)?
+					}
+					compounding => {
+						let rate_adjustment = compounding.accumulated_rate(
+							&rate.day_count,
+							rate.interest_rate_per_sec,
+							when,
+							now,
+						)?;
+						rate.accumulated_rate.ensure_div(rate_adjustment)?
+					}
+				},
 				Ordering::Greater => {
 					// TODO: This is a fast fix, the correct solution should be #1304
 					rate.accumulated_rate
@@ -383,104 +443,105 @@ pub mod pallet {
 
 		pub fn calculate_accumulated_rate<Rate: FixedPointNumber>(
 			interest_rate_per_sec: Rate,
+			compounding: &CompoundingSchedule,
+			day_count: &DayCountConvention,
 			accumulated_rate: Rate,
 			last_updated: Seconds,
 			now: Seconds,
 		) -> Result<Rate, ArithmeticError> {
-			// accumulated_rate * interest_rate_per_sec ^ (now - last_updated)
-			let time_difference_secs = now.ensure_sub(last_updated)?;
-			checked_pow(interest_rate_per_sec, time_difference_secs as usize)
-				.ok_or(ArithmeticError::Overflow)? // TODO: This line can be remove once #1241 be merged
-				.ensure_mul(accumulated_rate)
+			match compounding {
+				// accumulated_rate + (interest_rate_per_sec - 1) * (now - last_updated)
+				CompoundingSchedule::Simple => {
+					let time_difference_secs = day_count.elapsed(last_updated, now)?;
+					interest_rate_per_sec
+						.ensure_sub(One::one())?
+						.ensure_mul(Rate::saturating_from_integer(time_difference_secs))?
+						.ensure_add(accumulated_rate)
+				}
+				// accumulated_rate * growth between last_updated and now.
+				// For secondly compounding, interest_rate_per_sec ^ (now - last_updated)
+				compounding => compounding
+					.accumulated_rate(day_count, interest_rate_per_sec, last_updated, now)?
+					.ensure_mul(accumulated_rate),
+			}
 		}
 
+		/// Floating rates are referenced by the current value of their
+		/// reference rate plus spread.
 		pub fn reference_interest_rate(
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> DispatchResult {
-			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let interest_rate_per_sec = unchecked_conversion(*rate_per_year)?;
-					Rates::<T>::try_mutate(|rates| {
-						let rate = rates
-							.iter_mut()
-							.find(|rate| rate.interest_rate_per_sec == interest_rate_per_sec);
-
-						match rate {
-							Some(rate) => Ok(rate.reference_count.ensure_add_assign(1)?),
-							None => {
-								Self::validate_interest_rate(interest_rate_per_year)?;
-
-								let new_rate = RateDetailsOf::<T> {
-									interest_rate_per_sec,
-									accumulated_rate: One::one(),
-									reference_count: 1,
-								};
-
-								rates
-									.try_push(new_rate)
-									.map_err(|_| Error::<T>::TooManyRates)?;
-
-								Ok(())
-							}
-						}
-					})
+			let interest_rate_per_sec = interest_rate_per_year.per_sec()?;
+			Rates::<T>::try_mutate(|rates| {
+				let rate = rates
+					.iter_mut()
+					.find(|rate| rate.matches(&interest_rate_per_sec, interest_rate_per_year));
+
+				match rate {
+					Some(rate) => Ok(rate.reference_count.ensure_add_assign(1)?),
+					None => {
+						Self::validate_interest_rate(interest_rate_per_year)?;
+
+						let new_rate = RateDetailsOf::<T> {
+							interest_rate_per_sec,
+							compounding: interest_rate_per_year.compounding(),
+							day_count: interest_rate_per_year.day_count(),
+							accumulated_rate: One::one(),
+							reference_count: 1,
+						};
+
+						rates
+							.try_push(new_rate)
+							.map_err(|_| Error::<T>::TooManyRates)?;
+
+						Ok(())
+					}
 				}
-			}
+			})
 		}
 
 		pub fn unreference_interest_rate(
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> DispatchResult {
-			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let interest_rate_per_sec = unchecked_conversion(*rate_per_year)?;
-					Rates::<T>::try_mutate(|rates| {
-						let idx = rates
-							.iter()
-							.enumerate()
-							.find(|(_, rate)| rate.interest_rate_per_sec == interest_rate_per_sec)
-							.ok_or(Error::<T>::NoSuchRate)?
-							.0;
-						rates[idx].reference_count = rates[idx].reference_count.saturating_sub(1);
-						if rates[idx].reference_count == 0 {
-							rates.swap_remove(idx);
-						}
-						Ok(())
-					})
+			let interest_rate_per_sec = interest_rate_per_year.per_sec()?;
+			Rates::<T>::try_mutate(|rates| {
+				let idx = rates
+					.iter()
+					.enumerate()
+					.find(|(_, rate)| rate.matches(&interest_rate_per_sec, interest_rate_per_year))
+					.ok_or(Error::<T>::NoSuchRate)?
+					.0;
+				rates[idx].reference_count = rates[idx].reference_count.saturating_sub(1);
+				if rates[idx].reference_count == 0 {
+					rates.swap_remove(idx);
 				}
-			}
+				Ok(())
+			})
 		}
 
 		pub fn get_rate(
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> Result<RateDetailsOf<T>, DispatchError> {
-			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let interest_rate_per_sec = unchecked_conversion(*rate_per_year)?;
-					Rates::<T>::get()
-						.into_iter()
-						.find(|rate| rate.interest_rate_per_sec == interest_rate_per_sec)
-						.ok_or_else(|| Error::<T>::NoSuchRate.into())
-				}
-			}
+			let interest_rate_per_sec = interest_rate_per_year.per_sec()?;
+			Rates::<T>::get()
+				.into_iter()
+				.find(|rate| rate.matches(&interest_rate_per_sec, interest_rate_per_year))
+				.ok_or_else(|| Error::<T>::NoSuchRate.into())
 		}
 
 		pub(crate) fn validate_interest_rate(
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> DispatchResult {
-			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let four_decimals = T::Rate::saturating_from_integer(10000);
-					let maximum = T::Rate::saturating_from_integer(MAX_INTEREST_RATE);
-					ensure!(
-						*rate_per_year <= maximum
-							&& *rate_per_year >= Zero::zero()
-							&& (rate_per_year.saturating_mul(four_decimals)).frac() == Zero::zero(),
-						Error::<T>::InvalidRate
-					);
-					Ok(())
-				}
-			}
+			let rate_per_year = interest_rate_per_year.per_year();
+			let four_decimals = T::Rate::saturating_from_integer(10000);
+			let maximum = T::Rate::saturating_from_integer(MAX_INTEREST_RATE);
+			ensure!(
+				rate_per_year <= maximum
+					&& rate_per_year >= Zero::zero()
+					&& (rate_per_year.saturating_mul(four_decimals)).frac() == Zero::zero(),
+				Error::<T>::InvalidRate
+			);
+			Ok(())
 		}
 	}
 }
@@ -543,10 +604,10 @@ impl<T: Config> RateCollection<T::Rate, T::Balance, T::Balance> for RateVec<T> {
 		interest_rate: &InterestRate<T::Rate>,
 		normalized_debt: T::Balance,
 	) -> Result<T::Balance, DispatchError> {
-		let interest_rate_per_sec = unchecked_conversion(interest_rate.per_year())?;
+		let interest_rate_per_sec = interest_rate.per_sec()?;
 		self.0
 			.iter()
-			.find(|rate| rate.interest_rate_per_sec == interest_rate_per_sec)
+			.find(|rate| rate.matches(&interest_rate_per_sec, interest_rate))
 			.ok_or(Error::<T>::NoSuchRate)
 			.and_then(|rate| {
 				Pallet::<T>::calculate_debt(normalized_debt, rate.accumulated_rate)
@@ -555,11 +616,3 @@ impl<T: Config> RateCollection<T::Rate, T::Balance, T::Balance> for RateVec<T> {
 			.map_err(Into::into)
 	}
 }
-
-fn unchecked_conversion<R: FixedPointNumber + EnsureAdd>(
-	interest_rate_per_year: R,
-) -> Result<R, ArithmeticError> {
-	interest_rate_per_year
-		.ensure_div(R::saturating_from_integer(SECONDS_PER_YEAR))?
-		.ensure_add(One::one())
-}
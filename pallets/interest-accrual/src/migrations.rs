@@ -0,0 +1,62 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::interest::{CompoundingSchedule, DayCountConvention};
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{Get, OnRuntimeUpgrade},
+	BoundedVec,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{pallet::Rates, Config, RateDetails};
+
+/// Rate details before compounding schedules and day count conventions were
+/// stored
+pub mod v3 {
+	use parity_scale_codec::{Decode, Encode};
+
+	#[derive(Encode, Decode)]
+	pub struct RateDetails<Rate> {
+		pub interest_rate_per_sec: Rate,
+		pub accumulated_rate: Rate,
+		pub reference_count: u32,
+	}
+}
+
+/// Adds the compounding schedule and day count convention to the existing
+/// rates. All rates until v3 compound every second over a year of 365 days.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let _ = Rates::<T>::translate::<Vec<v3::RateDetails<T::Rate>>, _>(|rates| {
+			rates.map(|rates| {
+				BoundedVec::truncate_from(
+					rates
+						.into_iter()
+						.map(|rate| RateDetails {
+							interest_rate_per_sec: rate.interest_rate_per_sec,
+							compounding: CompoundingSchedule::Secondly,
+							day_count: DayCountConvention::Actual365,
+							accumulated_rate: rate.accumulated_rate,
+							reference_count: rate.reference_count,
+						})
+						.collect(),
+				)
+			})
+		});
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
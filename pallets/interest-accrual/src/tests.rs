@@ -11,7 +11,9 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::interest::{CompoundingSchedule, InterestRate};
+use cfg_primitives::{SECONDS_PER_DAY, SECONDS_PER_YEAR};
+use cfg_traits::interest::{CompoundingSchedule, DayCountConvention, InterestRate};
+use sp_arithmetic::traits::checked_pow;
 use sp_runtime::{
 	traits::{One, Zero},
 	FixedPointNumber,
@@ -32,43 +34,270 @@ fn test_rate_validation() {
 	assert!(
 		Pallet::<Runtime>::validate_interest_rate(&InterestRate::Fixed {
 			rate_per_year: high_rate,
-			compounding: CompoundingSchedule::Secondly
+			compounding: CompoundingSchedule::Secondly,
+			day_count: DayCountConvention::Actual365,
 		})
 		.is_err()
 	);
 	assert!(
 		Pallet::<Runtime>::validate_interest_rate(&InterestRate::Fixed {
 			rate_per_year: min_rate,
-			compounding: CompoundingSchedule::Secondly
+			compounding: CompoundingSchedule::Secondly,
+			day_count: DayCountConvention::Actual365,
 		})
 		.is_ok()
 	);
 	assert!(
 		Pallet::<Runtime>::validate_interest_rate(&InterestRate::Fixed {
 			rate_per_year: normal_rate,
-			compounding: CompoundingSchedule::Secondly
+			compounding: CompoundingSchedule::Secondly,
+			day_count: DayCountConvention::Actual365,
 		})
 		.is_ok()
 	);
 	assert!(
 		Pallet::<Runtime>::validate_interest_rate(&InterestRate::Fixed {
 			rate_per_year: One::one(),
-			compounding: CompoundingSchedule::Secondly
+			compounding: CompoundingSchedule::Secondly,
+			day_count: DayCountConvention::Actual365,
 		})
 		.is_ok()
 	);
 	assert!(
 		Pallet::<Runtime>::validate_interest_rate(&InterestRate::Fixed {
 			rate_per_year: Zero::zero(),
-			compounding: CompoundingSchedule::Secondly
+			compounding: CompoundingSchedule::Secondly,
+			day_count: DayCountConvention::Actual365,
 		})
 		.is_ok()
 	);
 	assert!(
 		Pallet::<Runtime>::validate_interest_rate(&InterestRate::Fixed {
 			rate_per_year: too_many_decimals,
-			compounding: CompoundingSchedule::Secondly
+			compounding: CompoundingSchedule::Secondly,
+			day_count: DayCountConvention::Actual365,
 		})
 		.is_err()
 	);
 }
+
+mod accumulated_rate {
+	use super::*;
+
+	// Aligned to the start of a day, month and year
+	const START: u64 = 0;
+
+	fn rate_per_sec() -> Rate {
+		DayCountConvention::Actual365
+			.rate_per_sec(Rate::saturating_from_rational(5, 100))
+			.unwrap()
+	}
+
+	// Growth of a period without compounding
+	fn linear_growth(secs: u64) -> Rate {
+		Rate::one() + (rate_per_sec() - Rate::one()) * Rate::saturating_from_integer(secs)
+	}
+
+	fn accumulate(compounding: CompoundingSchedule, from: u64, to: u64) -> Rate {
+		Pallet::<Runtime>::calculate_accumulated_rate(
+			rate_per_sec(),
+			&compounding,
+			&DayCountConvention::Actual365,
+			One::one(),
+			from,
+			to,
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn secondly() {
+		assert_eq!(
+			accumulate(
+				CompoundingSchedule::Secondly,
+				START,
+				START + SECONDS_PER_YEAR
+			),
+			checked_pow(rate_per_sec(), SECONDS_PER_YEAR as usize).unwrap()
+		);
+	}
+
+	#[test]
+	fn daily() {
+		let daily_rate = linear_growth(SECONDS_PER_DAY);
+
+		assert_eq!(
+			accumulate(
+				CompoundingSchedule::Daily,
+				START,
+				START + SECONDS_PER_DAY * 2
+			),
+			daily_rate * daily_rate
+		);
+
+		// Interest accrues linearly within the day
+		let half_day = accumulate(
+			CompoundingSchedule::Daily,
+			START,
+			START + SECONDS_PER_DAY / 2,
+		);
+		assert_eq!(
+			half_day - Rate::one(),
+			(daily_rate - Rate::one()) / Rate::saturating_from_integer(2)
+		);
+	}
+
+	#[test]
+	fn annually() {
+		assert_eq!(
+			accumulate(
+				CompoundingSchedule::Annually,
+				START,
+				START + SECONDS_PER_YEAR
+			),
+			linear_growth(SECONDS_PER_YEAR)
+		);
+	}
+
+	#[test]
+	fn simple() {
+		let one_year = accumulate(CompoundingSchedule::Simple, START, START + SECONDS_PER_YEAR);
+		assert_eq!(one_year, linear_growth(SECONDS_PER_YEAR));
+
+		// Interest does not compound
+		let two_years = Pallet::<Runtime>::calculate_accumulated_rate(
+			rate_per_sec(),
+			&CompoundingSchedule::Simple,
+			&DayCountConvention::Actual365,
+			one_year,
+			START + SECONDS_PER_YEAR,
+			START + SECONDS_PER_YEAR * 2,
+		)
+		.unwrap();
+		assert_eq!(two_years, linear_growth(SECONDS_PER_YEAR * 2));
+	}
+
+	#[test]
+	fn split_in_several_updates() {
+		for compounding in [
+			CompoundingSchedule::Daily,
+			CompoundingSchedule::Monthly,
+			CompoundingSchedule::Annually,
+		] {
+			let middle = START + SECONDS_PER_YEAR / 3 + 1234;
+			let partial = accumulate(compounding, START, middle);
+			let total = Pallet::<Runtime>::calculate_accumulated_rate(
+				rate_per_sec(),
+				&compounding,
+				&DayCountConvention::Actual365,
+				partial,
+				middle,
+				START + SECONDS_PER_YEAR,
+			)
+			.unwrap();
+
+			let expected = accumulate(compounding, START, START + SECONDS_PER_YEAR);
+			assert!(total.max(expected) - total.min(expected) <= Rate::from_inner(10));
+		}
+	}
+}
+
+mod day_count {
+	use super::*;
+
+	// 2023-01-01T00:00:00Z
+	const JAN_1: u64 = 1672531200;
+	// 2023-01-31T00:00:00Z
+	const JAN_31: u64 = JAN_1 + 30 * SECONDS_PER_DAY;
+	// 2023-03-01T00:00:00Z
+	const MAR_1: u64 = JAN_1 + 59 * SECONDS_PER_DAY;
+	// 2024-01-01T00:00:00Z
+	const NEXT_JAN_1: u64 = JAN_1 + 365 * SECONDS_PER_DAY;
+
+	fn rate_per_sec(day_count: DayCountConvention) -> Rate {
+		day_count
+			.rate_per_sec(Rate::saturating_from_rational(5, 100))
+			.unwrap()
+	}
+
+	fn simple(day_count: DayCountConvention, from: u64, to: u64) -> Rate {
+		Pallet::<Runtime>::calculate_accumulated_rate(
+			rate_per_sec(day_count),
+			&CompoundingSchedule::Simple,
+			&day_count,
+			One::one(),
+			from,
+			to,
+		)
+		.unwrap()
+	}
+
+	fn linear_growth(day_count: DayCountConvention, days: u64) -> Rate {
+		Rate::one()
+			+ (rate_per_sec(day_count) - Rate::one())
+				* Rate::saturating_from_integer(days * SECONDS_PER_DAY)
+	}
+
+	#[test]
+	fn actual_365() {
+		assert_eq!(
+			simple(DayCountConvention::Actual365, JAN_1, NEXT_JAN_1),
+			linear_growth(DayCountConvention::Actual365, 365)
+		);
+	}
+
+	#[test]
+	fn actual_360() {
+		// A year of actual days accrues more than the nominal rate
+		assert_eq!(
+			simple(DayCountConvention::Actual360, JAN_1, NEXT_JAN_1),
+			linear_growth(DayCountConvention::Actual360, 365)
+		);
+		assert!(
+			simple(DayCountConvention::Actual360, JAN_1, NEXT_JAN_1)
+				> Rate::saturating_from_rational(105, 100)
+		);
+	}
+
+	#[test]
+	fn thirty_360() {
+		assert_eq!(
+			simple(DayCountConvention::Thirty360, JAN_1, NEXT_JAN_1),
+			linear_growth(DayCountConvention::Thirty360, 360)
+		);
+
+		// February counts as 30 days, and the 30th does not accrue when
+		// followed by the 31st
+		assert_eq!(
+			simple(DayCountConvention::Thirty360, JAN_31, MAR_1),
+			linear_growth(DayCountConvention::Thirty360, 31)
+		);
+		assert_eq!(
+			simple(
+				DayCountConvention::Thirty360,
+				JAN_1,
+				JAN_31 - SECONDS_PER_DAY / 2
+			),
+			simple(DayCountConvention::Thirty360, JAN_1, JAN_31)
+		);
+	}
+
+	#[test]
+	fn monthly_compounding_with_thirty_360() {
+		let monthly_rate = linear_growth(DayCountConvention::Thirty360, 30);
+
+		// Each calendar month compounds once, whatever its length
+		assert_eq!(
+			Pallet::<Runtime>::calculate_accumulated_rate(
+				rate_per_sec(DayCountConvention::Thirty360),
+				&CompoundingSchedule::Monthly,
+				&DayCountConvention::Thirty360,
+				One::one(),
+				JAN_1,
+				MAR_1,
+			)
+			.unwrap(),
+			monthly_rate * monthly_rate
+		);
+	}
+}
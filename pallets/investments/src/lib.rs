@@ -700,6 +700,10 @@ impl<T: Config> Pallet<T> {
 					collection.payout_investment_invest,
 				)?;
 
+				if !collection.payout_investment_invest.is_zero() {
+					T::Accountant::note_deposit(investment_id, &who)?;
+				}
+
 				let amount = order.amount();
 				Self::rm_empty(
 					amount,
@@ -877,6 +881,13 @@ impl<T: Config> Pallet<T> {
 		order: &mut OrderOf<T>,
 		amount: T::Amount,
 	) -> DispatchResult {
+		// Only increases must reach the minimum investment, so investors can
+		// always decrease their orders.
+		if amount > order.amount() {
+			T::Accountant::ensure_investments_allowed(investment_id)?;
+			T::Accountant::ensure_min_investment(investment_id, amount)?;
+		}
+
 		let investment_account = InvestmentAccount { investment_id }.into_account_truncating();
 		let (send, recv, transfer_amount) = Self::update_order_amount(
 			who,
@@ -903,6 +914,10 @@ impl<T: Config> Pallet<T> {
 		order: &mut OrderOf<T>,
 		amount: T::Amount,
 	) -> DispatchResult {
+		if amount > order.amount() {
+			T::Accountant::ensure_redemptions_allowed(investment_id, who)?;
+		}
+
 		let investment_account = InvestmentAccount { investment_id }.into_account_truncating();
 		let (send, recv, transfer_amount) = Self::update_order_amount(
 			who,
@@ -952,6 +967,60 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Returns the amounts an account can collect for its invest order,
+	/// without collecting them.
+	pub fn collectable_investment(
+		who: &T::AccountId,
+		investment_id: T::InvestmentId,
+	) -> Result<CollectedAmount<T::Amount, T::Amount>, DispatchError> {
+		let Some(order) = InvestOrders::<T>::get(who, investment_id) else {
+			return Ok(Default::default());
+		};
+
+		let mut collection = InvestCollection::<T::Amount>::from_order(&order);
+		for order_id in order.submitted_at()..InvestOrderId::<T>::get(investment_id) {
+			let fulfillment = ClearedInvestOrders::<T>::try_get(investment_id, order_id)
+				.map_err(|_| Error::<T>::OrderNotCleared)?;
+
+			Pallet::<T>::acc_payout_invest(&mut collection, &fulfillment)?;
+			Pallet::<T>::acc_remaining_invest(&mut collection, &fulfillment)?;
+		}
+
+		Ok(CollectedAmount {
+			amount_collected: collection.payout_investment_invest,
+			amount_payment: order
+				.amount()
+				.ensure_sub(collection.remaining_investment_invest)?,
+		})
+	}
+
+	/// Returns the amounts an account can collect for its redeem order,
+	/// without collecting them.
+	pub fn collectable_redemption(
+		who: &T::AccountId,
+		investment_id: T::InvestmentId,
+	) -> Result<CollectedAmount<T::Amount, T::Amount>, DispatchError> {
+		let Some(order) = RedeemOrders::<T>::get(who, investment_id) else {
+			return Ok(Default::default());
+		};
+
+		let mut collection = RedeemCollection::<T::Amount>::from_order(&order);
+		for order_id in order.submitted_at()..RedeemOrderId::<T>::get(investment_id) {
+			let fulfillment = ClearedRedeemOrders::<T>::try_get(investment_id, order_id)
+				.map_err(|_| Error::<T>::OrderNotCleared)?;
+
+			Pallet::<T>::acc_payout_redeem(&mut collection, &fulfillment)?;
+			Pallet::<T>::acc_remaining_redeem(&mut collection, &fulfillment)?;
+		}
+
+		Ok(CollectedAmount {
+			amount_collected: collection.payout_investment_redeem,
+			amount_payment: order
+				.amount()
+				.ensure_sub(collection.remaining_investment_redeem)?,
+		})
+	}
+
 	/// Increments an accounts' investment payout amount based on the remaining
 	/// amount and the fulfillment price.
 	///
@@ -317,6 +317,23 @@ pub fn configure_accountant_mock() {
 
 	MockAccountant::mock_balance(|id, who| OrmlTokens::balance(id.into(), who));
 
+	MockAccountant::mock_ensure_investments_allowed({
+		let state = state.clone();
+		move |id| get(&state, id).map(|_| ())
+	});
+
+	MockAccountant::mock_ensure_redemptions_allowed({
+		let state = state.clone();
+		move |id, _| get(&state, id).map(|_| ())
+	});
+
+	MockAccountant::mock_note_deposit(|_, _| Ok(()));
+
+	MockAccountant::mock_ensure_min_investment({
+		let state = state.clone();
+		move |id, _| get(&state, id).map(|_| ())
+	});
+
 	MockAccountant::mock_transfer({
 		let state = state.clone();
 		move |id, source, dest, amount| {
@@ -2956,6 +2956,85 @@ fn collecting_over_max_works() {
 	})
 }
 
+#[test]
+fn collectable_amounts_match_collected() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		#[allow(non_snake_case)]
+		let PRICE: Quantity = price_of(1, 288, 335);
+		#[allow(non_snake_case)]
+		let SINGLE_REDEEM_AMOUNT = 50 * CURRENCY;
+		#[allow(non_snake_case)]
+		let SINGLE_INVEST_AMOUNT = 50 * CURRENCY;
+		#[allow(non_snake_case)]
+		let FULL_FULFILL = FulfillmentWithPrice {
+			of_amount: Perquintill::one(),
+			price: PRICE,
+		};
+		#[allow(non_snake_case)]
+		let PARTIAL_FULFILL = FulfillmentWithPrice {
+			of_amount: Perquintill::from_rational(20u64, 100u64),
+			price: PRICE,
+		};
+
+		// Setup
+		{
+			assert_ok!(invest_x_per_investor(SINGLE_INVEST_AMOUNT));
+			assert_ok!(redeem_x_per_investor(SINGLE_REDEEM_AMOUNT));
+
+			for _ in 0..MaxOutstandingCollect::get() {
+				assert_ok!(fulfill_x(PARTIAL_FULFILL));
+			}
+
+			assert_ok!(fulfill_x(FULL_FULFILL));
+		}
+
+		// Collectable amounts include the orders beyond MaxOutstandingCollects
+		{
+			assert_eq!(
+				Investments::collectable_investment(&InvestorA::get(), INVESTMENT_0_0),
+				Ok(CollectedAmount {
+					amount_collected: 26886035313001605095,
+					amount_payment: SINGLE_INVEST_AMOUNT,
+				})
+			);
+			assert_eq!(
+				Investments::collectable_redemption(&TrancheHolderA::get(), INVESTMENT_0_0),
+				Ok(CollectedAmount {
+					amount_collected: 92985074626865671646,
+					amount_payment: SINGLE_REDEEM_AMOUNT,
+				})
+			);
+		}
+
+		// Nothing is collectable after collecting
+		{
+			for _ in 0..2 {
+				assert_ok!(collect_both(
+					RuntimeOrigin::signed(InvestorA::get()),
+					INVESTMENT_0_0
+				));
+				assert_ok!(collect_both(
+					RuntimeOrigin::signed(TrancheHolderA::get()),
+					INVESTMENT_0_0
+				));
+			}
+
+			assert_eq!(
+				free_balance_of(InvestorA::get(), INVESTMENT_0_0.into()),
+				26886035313001605095
+			);
+			assert_eq!(
+				Investments::collectable_investment(&InvestorA::get(), INVESTMENT_0_0),
+				Ok(Default::default())
+			);
+			assert_eq!(
+				Investments::collectable_redemption(&TrancheHolderA::get(), INVESTMENT_0_0),
+				Ok(Default::default())
+			);
+		}
+	})
+}
+
 #[test]
 fn collecting_investment_without_preconditions_fails() {
 	TestExternalitiesBuilder::build().execute_with(|| {
@@ -71,6 +71,7 @@ impl frame_system::Config for Runtime {
 
 type Instance1 = cfg_mocks::router_message::pallet::Instance1;
 impl cfg_mocks::router_message::pallet::Config<Instance1> for Runtime {
+	type Fees = ();
 	type Message = Message;
 	type Middleware = RouterId;
 	type Origin = Domain;
@@ -78,6 +79,7 @@ impl cfg_mocks::router_message::pallet::Config<Instance1> for Runtime {
 
 type Instance2 = cfg_mocks::router_message::pallet::Instance2;
 impl cfg_mocks::router_message::pallet::Config<Instance2> for Runtime {
+	type Fees = ();
 	type Message = Message;
 	type Middleware = RouterId;
 	type Origin = DomainAddress;
@@ -10,24 +10,57 @@
 // but WITHOUT ANY WARRANTY; without even the implied warranty of
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
+
+//! # Liquidity Pools Gateway Queue Pallet.
+//!
+//! Queues the messages of the Liquidity Pools gateway and processes them
+//! during the `on_idle` hook.
+//!
+//! Each message is assigned to a lane, which gets its own part of the idle
+//! weight, and to an ordering key. Messages with the same lane and ordering
+//! key are processed in the order they were queued, while messages of
+//! different ordering keys are processed in turns, so a backlog of one key
+//! does not delay the others. A bounded number of ordering keys is serviced
+//! in each block, the next block continuing with the following ones.
+//!
+//! Failed messages are retried automatically with an exponential backoff.
+//! After the maximum number of retries, a message is kept as a dead letter
+//! that can only be processed manually.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::fmt::Debug;
 
-use cfg_traits::liquidity_pools::{MessageProcessor, MessageQueue as MessageQueueT};
+use cfg_traits::liquidity_pools::{
+	MessageLaneProvider, MessageProcessor, MessageQueue as MessageQueueT,
+};
 use frame_support::{dispatch::PostDispatchInfo, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use parity_scale_codec::FullCodec;
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::BaseArithmetic;
-use sp_runtime::traits::{EnsureAddAssign, One};
+use sp_runtime::{
+	traits::{EnsureAddAssign, One, SaturatedConversion, Saturating},
+	Perbill,
+};
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+/// The first and last messages of the messages of a lane that must be
+/// processed in order.
+#[derive(Debug, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct OrderedQueue<Nonce> {
+	/// The nonce of the next message to process.
+	pub head: Nonce,
+
+	/// The nonce of the last queued message.
+	pub tail: Nonce,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -51,9 +84,48 @@ pub mod pallet {
 
 		/// Type used for processing messages.
 		type MessageProcessor: MessageProcessor<Message = Self::Message>;
+
+		/// Identifies a lane of the queue.
+		type Lane: Parameter + MaxEncodedLen;
+
+		/// Identifies the messages of a lane that must be processed in order.
+		type OrderingKey: Parameter + MaxEncodedLen;
+
+		/// Type that provides the lane and the ordering key of each message.
+		type LaneProvider: MessageLaneProvider<
+			Self::Message,
+			Lane = Self::Lane,
+			OrderingKey = Self::OrderingKey,
+		>;
+
+		/// The lanes in priority order, with the part of the idle weight
+		/// reserved for each of them.
+		///
+		/// The weight a lane does not use is available for the next ones.
+		/// Messages of lanes not listed here can only be processed manually.
+		#[pallet::constant]
+		type Lanes: Get<Vec<(Self::Lane, Perbill)>>;
+
+		/// The maximum number of ordering keys of a lane serviced in each
+		/// block.
+		#[pallet::constant]
+		type MaxOrderingKeys: Get<u32>;
+
+		/// The maximum number of automatic retries of a failed message.
+		#[pallet::constant]
+		type MaxRetries: Get<u32>;
+
+		/// The number of blocks before the first retry of a failed message.
+		///
+		/// The delay is doubled for each subsequent retry.
+		#[pallet::constant]
+		type RetryDelay: Get<BlockNumberFor<Self>>;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -71,11 +143,49 @@ pub mod pallet {
 	#[pallet::getter(fn message_queue)]
 	pub type MessageQueue<T: Config> = StorageMap<_, Blake2_128Concat, T::MessageNonce, T::Message>;
 
-	/// Storage for messages that failed during processing.
+	/// Storage for the messages of each lane, grouped by ordering key.
+	#[pallet::storage]
+	pub type LaneQueues<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Lane,
+		Blake2_128Concat,
+		T::OrderingKey,
+		OrderedQueue<T::MessageNonce>,
+	>;
+
+	/// Storage for the last ordering key serviced in a lane, when the lane has
+	/// more ordering keys than the ones serviced in one block.
+	#[pallet::storage]
+	pub type LaneCursors<T: Config> = StorageMap<_, Blake2_128Concat, T::Lane, T::OrderingKey>;
+
+	/// Storage for the nonce of the message queued after another one with the
+	/// same lane and ordering key.
+	#[pallet::storage]
+	pub type NextLaneMessage<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MessageNonce, T::MessageNonce>;
+
+	/// Storage for messages that failed during processing, with the number
+	/// of automatic retries done.
 	#[pallet::storage]
 	#[pallet::getter(fn failed_message_queue)]
 	pub type FailedMessageQueue<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::MessageNonce, (T::Message, DispatchError)>;
+		StorageMap<_, Blake2_128Concat, T::MessageNonce, (T::Message, DispatchError, u32)>;
+
+	/// Storage for the number of automatic retries done of the failed messages
+	/// queued again for a retry.
+	#[pallet::storage]
+	pub type MessageRetries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MessageNonce, u32, ValueQuery>;
+
+	/// Storage for the failed messages to retry at each block.
+	#[pallet::storage]
+	pub type RetrySchedule<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, T::MessageNonce, ()>;
+
+	/// The first block whose retries might not have been processed yet.
+	#[pallet::storage]
+	pub type IncompleteRetriesSince<T: Config> = StorageValue<_, BlockNumberFor<T>>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
@@ -100,9 +210,27 @@ pub mod pallet {
 		},
 
 		/// Maximum number of messages was reached.
+		///
+		/// The messages of the remaining ordering keys of a lane are processed
+		/// in the next blocks.
 		MaxNumberOfMessagesReached {
 			last_processed_nonce: T::MessageNonce,
 		},
+
+		/// A failed message was scheduled for an automatic retry.
+		MessageRetryScheduled {
+			nonce: T::MessageNonce,
+			retries: u32,
+			retry_at: BlockNumberFor<T>,
+		},
+
+		/// A message failed after the maximum number of retries and can only
+		/// be processed manually.
+		MessageDeadLettered {
+			nonce: T::MessageNonce,
+			message: T::Message,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -113,8 +241,12 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(_now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
-			Self::service_message_queue(max_weight)
+		fn on_idle(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			let weight_used = Self::service_retries(now, max_weight);
+
+			weight_used.saturating_add(Self::service_message_queue(
+				max_weight.saturating_sub(weight_used),
+			))
 		}
 	}
 
@@ -123,7 +255,7 @@ pub mod pallet {
 		/// Convenience method for manually processing a message.
 		///
 		/// If the execution fails, the message gets moved to the
-		/// `FailedMessageQueue` storage.
+		/// `FailedMessageQueue` storage and scheduled for a retry.
 		///
 		/// NOTES:
 		///   - this extrinsic does not error out during message processing
@@ -142,25 +274,26 @@ pub mod pallet {
 			ensure_signed(origin)?;
 
 			let message = MessageQueue::<T>::take(nonce).ok_or(Error::<T>::MessageNotFound)?;
+			let retries = MessageRetries::<T>::take(nonce);
 
 			let (result, mut weight) =
 				Self::process_message_and_deposit_event(nonce, message.clone());
 
 			if let Err(e) = result {
-				FailedMessageQueue::<T>::insert(nonce, (message, e));
-				weight.saturating_accrue(T::DbWeight::get().writes(1));
+				weight.saturating_accrue(Self::store_failed_message(nonce, message, e, retries));
 			}
 
+			// Add read and write from MessageRetries::take
 			// Add write from MessageQueue::take
 			Ok(PostDispatchInfo::from(Some(
-				weight.saturating_add(T::DbWeight::get().writes(1)),
+				weight.saturating_add(T::DbWeight::get().reads_writes(1, 2)),
 			)))
 		}
 
 		/// Convenience method for manually processing a failed message.
 		///
 		/// If the execution is successful, the message gets removed from the
-		/// `FailedMessageQueue` storage.
+		/// `FailedMessageQueue` storage. Dead letters can be processed too.
 		///
 		/// NOTES:
 		///   - this extrinsic does not error out during message processing
@@ -169,7 +302,7 @@ pub mod pallet {
 		///   - an extra defensive weight is added in order to cover the weight
 		/// used when processing the message.
 		#[pallet::weight(FailedMessageQueue::<T>::get(nonce)
-            .map(|(msg, _)| T::MessageProcessor::max_processing_weight(&msg))
+            .map(|(msg, _, _)| T::MessageProcessor::max_processing_weight(&msg))
             .unwrap_or(T::DbWeight::get().reads(1)))]
 		#[pallet::call_index(1)]
 		pub fn process_failed_message(
@@ -178,7 +311,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 
-			let (message, _) =
+			let (message, _, _) =
 				FailedMessageQueue::<T>::get(nonce).ok_or(Error::<T>::MessageNotFound)?;
 
 			let (result, mut weight) = Self::process_message_and_deposit_event(nonce, message);
@@ -219,79 +352,320 @@ pub mod pallet {
 		}
 
 		fn service_message_queue(max_weight: Weight) -> Weight {
-			let mut last_processed_nonce = LastProcessedNonce::<T>::get();
+			let mut weight_used = Weight::zero();
+			let mut available_weight = Weight::zero();
 
-			// 1 read for the last processed nonce
-			let mut weight_used = T::DbWeight::get().reads(1);
+			for (lane, share) in T::Lanes::get() {
+				available_weight.saturating_accrue(share * max_weight);
+
+				let lane_weight = Self::service_lane(lane, available_weight);
+
+				available_weight.saturating_reduce(lane_weight);
+				weight_used.saturating_accrue(lane_weight);
+			}
+
+			weight_used
+		}
+
+		/// Processes the messages of a lane, taking one message of each
+		/// ordering key in turns until the weight is used or there are no more
+		/// messages.
+		fn service_lane(lane: T::Lane, max_weight: Weight) -> Weight {
+			let (ordering_keys, mut weight_used) = Self::next_ordering_keys(&lane);
 
 			loop {
-				if last_processed_nonce.ensure_add_assign(One::one()).is_err() {
-					Self::deposit_event(Event::<T>::MaxNumberOfMessagesReached {
-						last_processed_nonce,
-					});
+				let mut processed_any = false;
+
+				for ordering_key in &ordering_keys {
+					let remaining_weight = max_weight.saturating_sub(weight_used);
 
+					let (weight, processed) =
+						Self::service_next_message(&lane, ordering_key, remaining_weight);
+
+					weight_used.saturating_accrue(weight);
+					processed_any |= processed;
+				}
+
+				if !processed_any {
 					break;
 				}
+			}
 
-				// 1 read for the nonce
+			if ordering_keys.len() >= T::MaxOrderingKeys::get().saturated_into::<usize>() {
+				Self::deposit_event(Event::<T>::MaxNumberOfMessagesReached {
+					last_processed_nonce: LastProcessedNonce::<T>::get(),
+				});
+
+				// 1 read for the last processed nonce
 				weight_used.saturating_accrue(T::DbWeight::get().reads(1));
+			}
 
-				if last_processed_nonce > MessageNonceStore::<T>::get() {
+			weight_used
+		}
+
+		/// Returns the ordering keys of the lane to service in this block,
+		/// starting after the last one serviced in the previous block, and the
+		/// weight used to read them.
+		fn next_ordering_keys(lane: &T::Lane) -> (Vec<T::OrderingKey>, Weight) {
+			let max_keys = T::MaxOrderingKeys::get().saturated_into::<usize>();
+			let cursor = LaneCursors::<T>::get(lane);
+
+			let mut ordering_keys = match &cursor {
+				Some(key) => LaneQueues::<T>::iter_key_prefix_from(
+					lane,
+					LaneQueues::<T>::hashed_key_for(lane, key),
+				)
+				.take(max_keys)
+				.collect::<Vec<_>>(),
+				None => Vec::new(),
+			};
+
+			// Continue from the start of the lane up to the keys already taken.
+			for ordering_key in LaneQueues::<T>::iter_key_prefix(lane) {
+				if ordering_keys.len() >= max_keys || ordering_keys.contains(&ordering_key) {
 					break;
 				}
 
-				// 1 read for the message
-				weight_used.saturating_accrue(T::DbWeight::get().reads(1));
+				ordering_keys.push(ordering_key);
+			}
 
-				let message = match MessageQueue::<T>::get(last_processed_nonce) {
-					Some(msg) => msg,
-					// No message found at this nonce, we can skip it.
-					None => {
-						LastProcessedNonce::<T>::set(last_processed_nonce);
+			let count: u64 = ordering_keys.len().saturated_into();
 
-						// 1 write for setting the last processed nonce
-						weight_used.saturating_accrue(T::DbWeight::get().writes(1));
+			// 1 read for the cursor
+			// 1 read for each ordering key and 1 read for the end of each iteration
+			let mut weight_used = T::DbWeight::get().reads(count.saturating_add(3));
 
-						continue;
-					}
-				};
+			if ordering_keys.len() >= max_keys {
+				if let Some(last) = ordering_keys.last() {
+					LaneCursors::<T>::insert(lane, last);
+				}
+
+				// 1 write for the cursor
+				weight_used.saturating_accrue(T::DbWeight::get().writes(1));
+			} else if cursor.is_some() {
+				LaneCursors::<T>::remove(lane);
+
+				// 1 write for the cursor
+				weight_used.saturating_accrue(T::DbWeight::get().writes(1));
+			}
+
+			(ordering_keys, weight_used)
+		}
+
+		/// Processes the first message of the lane and ordering key, if there
+		/// is enough weight for it.
+		///
+		/// Returns the weight used and whether a message was taken.
+		fn service_next_message(
+			lane: &T::Lane,
+			ordering_key: &T::OrderingKey,
+			max_weight: Weight,
+		) -> (Weight, bool) {
+			// 1 read for the ordered queue
+			// 1 read for the message
+			let mut weight_used = T::DbWeight::get().reads(2);
+
+			if max_weight.any_lt(weight_used) {
+				return (Weight::zero(), false);
+			}
 
+			let Some(queue) = LaneQueues::<T>::get(lane, ordering_key) else {
+				return (T::DbWeight::get().reads(1), false);
+			};
+			let nonce = queue.head;
+			let message = MessageQueue::<T>::get(nonce);
+
+			if let Some(message) = &message {
 				let remaining_weight = max_weight
 					.saturating_sub(weight_used)
-					.saturating_sub(T::DbWeight::get().reads(1));
-				let next_weight = T::MessageProcessor::max_processing_weight(&message);
+					.saturating_sub(T::DbWeight::get().reads_writes(2, 5));
+				let next_weight = T::MessageProcessor::max_processing_weight(message);
 
 				// We ensure we have still capacity in the block before processing the message
 				if remaining_weight.any_lt(next_weight) {
-					break;
+					return (weight_used, false);
 				}
+			}
 
-				let processing_weight = match Self::process_message_and_deposit_event(
-					last_processed_nonce,
-					message.clone(),
-				) {
-					(Ok(()), weight) => weight,
-					(Err(e), weight) => {
-						FailedMessageQueue::<T>::insert(last_processed_nonce, (message, e));
+			match NextLaneMessage::<T>::take(nonce) {
+				Some(next) => LaneQueues::<T>::insert(
+					lane,
+					ordering_key,
+					OrderedQueue {
+						head: next,
+						..queue
+					},
+				),
+				None => LaneQueues::<T>::remove(lane, ordering_key),
+			}
+
+			// 1 read and 1 write for the next message
+			// 1 write for the ordered queue
+			weight_used.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+			// The message was already processed manually, we can skip it.
+			let Some(message) = message else {
+				return (weight_used, true);
+			};
+
+			let retries = MessageRetries::<T>::take(nonce);
 
-						// 1 write for the failed message
-						weight.saturating_add(T::DbWeight::get().writes(1))
-					}
+			let processing_weight =
+				match Self::process_message_and_deposit_event(nonce, message.clone()) {
+					(Ok(()), weight) => weight,
+					(Err(e), weight) => weight
+						.saturating_add(Self::store_failed_message(nonce, message, e, retries)),
 				};
 
-				weight_used.saturating_accrue(processing_weight);
+			weight_used.saturating_accrue(processing_weight);
+
+			MessageQueue::<T>::remove(nonce);
+			LastProcessedNonce::<T>::set(nonce);
+
+			// 1 read and 1 write for the retries
+			// 1 write for removing the message
+			// 1 write for setting the last processed nonce
+			weight_used.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+
+			(weight_used, true)
+		}
+
+		/// Stores a failed message and schedules its next retry, or marks it
+		/// as a dead letter if it was retried the maximum number of times.
+		///
+		/// Returns the weight used.
+		fn store_failed_message(
+			nonce: T::MessageNonce,
+			message: T::Message,
+			error: DispatchError,
+			retries: u32,
+		) -> Weight {
+			FailedMessageQueue::<T>::insert(nonce, (message.clone(), error, retries));
+
+			if retries >= T::MaxRetries::get() {
+				Self::deposit_event(Event::<T>::MessageDeadLettered {
+					nonce,
+					message,
+					error,
+				});
+
+				// 1 write for the failed message
+				return T::DbWeight::get().writes(1);
+			}
+
+			let delay = T::RetryDelay::get()
+				.saturating_mul(2u32.saturating_pow(retries).into())
+				.max(One::one());
+			let retry_at = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+
+			RetrySchedule::<T>::insert(retry_at, nonce, ());
+
+			Self::deposit_event(Event::<T>::MessageRetryScheduled {
+				nonce,
+				retries,
+				retry_at,
+			});
+
+			// 1 read for the block number
+			// 1 write for the failed message and 1 write for the schedule
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		/// Retries the failed messages scheduled up to the current block,
+		/// starting from the oldest ones.
+		fn service_retries(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			// 1 read and 1 write for the incomplete block
+			let mut weight_used = T::DbWeight::get().reads_writes(1, 1);
+
+			if max_weight.any_lt(weight_used) {
+				return Weight::zero();
+			}
 
-				MessageQueue::<T>::remove(last_processed_nonce);
+			let mut when = IncompleteRetriesSince::<T>::take().unwrap_or(now);
+
+			while when <= now {
+				// 1 read for the next scheduled retry
+				let read_weight = T::DbWeight::get().reads(1);
+
+				if max_weight.saturating_sub(weight_used).any_lt(read_weight) {
+					break;
+				}
 
-				LastProcessedNonce::<T>::set(last_processed_nonce);
+				weight_used.saturating_accrue(read_weight);
 
-				// 1 write for removing the message
-				// 1 write for setting the last processed nonce
-				weight_used.saturating_accrue(T::DbWeight::get().writes(2));
+				let Some(nonce) = RetrySchedule::<T>::iter_key_prefix(when).next() else {
+					when.saturating_inc();
+					continue;
+				};
+
+				match Self::retry_failed_message(
+					when,
+					nonce,
+					max_weight.saturating_sub(weight_used),
+				) {
+					Some(weight) => weight_used.saturating_accrue(weight),
+					None => break,
+				}
 			}
 
+			IncompleteRetriesSince::<T>::put(when);
+
 			weight_used
 		}
+
+		/// Queues again a failed message scheduled at the given block, at the
+		/// end of the messages with its lane and ordering key, if there is
+		/// enough weight for it.
+		///
+		/// Returns the weight used, or `None` if the message was not queued.
+		fn retry_failed_message(
+			when: BlockNumberFor<T>,
+			nonce: T::MessageNonce,
+			max_weight: Weight,
+		) -> Option<Weight> {
+			// 1 read and 1 write for the failed message
+			// 1 write for the schedule
+			// 1 write for the message and 1 write for the retries
+			// 1 read and 2 writes for the lane
+			let weight_used = T::DbWeight::get().reads_writes(2, 6);
+
+			if max_weight.any_lt(weight_used) {
+				return None;
+			}
+
+			RetrySchedule::<T>::remove(when, nonce);
+
+			// The message was already processed manually, we can skip it.
+			let Some((message, _, retries)) = FailedMessageQueue::<T>::take(nonce) else {
+				return Some(weight_used);
+			};
+
+			MessageQueue::<T>::insert(nonce, message.clone());
+			MessageRetries::<T>::insert(nonce, retries.saturating_add(1));
+
+			Self::push_to_lane(nonce, &message);
+
+			Some(weight_used)
+		}
+
+		/// Adds a queued message to the end of the messages with its lane and
+		/// ordering key.
+		pub fn push_to_lane(nonce: T::MessageNonce, message: &T::Message) {
+			let (lane, ordering_key) = T::LaneProvider::lane(message);
+
+			LaneQueues::<T>::mutate(lane, ordering_key, |queue| match queue {
+				Some(queue) => {
+					NextLaneMessage::<T>::insert(queue.tail, nonce);
+					queue.tail = nonce;
+				}
+				None => {
+					*queue = Some(OrderedQueue {
+						head: nonce,
+						tail: nonce,
+					})
+				}
+			});
+		}
 	}
 
 	impl<T: Config> MessageQueueT for Pallet<T> {
@@ -305,6 +679,8 @@ pub mod pallet {
 
 			MessageQueue::<T>::insert(nonce, message.clone());
 
+			Self::push_to_lane(nonce, &message);
+
 			Self::deposit_event(Event::MessageSubmitted { nonce, message });
 
 			Ok(())
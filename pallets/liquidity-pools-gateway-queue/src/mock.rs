@@ -12,12 +12,36 @@
 // GNU General Public License for more details.
 
 use cfg_mocks::pallet_mock_liquidity_pools_gateway;
+use cfg_traits::liquidity_pools::MessageLaneProvider;
 use frame_support::derive_impl;
+use sp_runtime::Perbill;
 
 use crate::{self as pallet_liquidity_pools_gateway_queue, Config};
 
 type Nonce = u64;
 
+pub const PRIORITY_LANE: u8 = 0;
+pub const DEFAULT_LANE: u8 = 1;
+
+/// Messages from `1000` onwards use the priority lane, and the last digit of
+/// a message is its ordering key.
+pub struct LaneProvider;
+
+impl MessageLaneProvider<u32> for LaneProvider {
+	type Lane = u8;
+	type OrderingKey = u32;
+
+	fn lane(message: &u32) -> (u8, u32) {
+		let lane = if *message >= 1000 {
+			PRIORITY_LANE
+		} else {
+			DEFAULT_LANE
+		};
+
+		(lane, message % 10)
+	}
+}
+
 frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
@@ -37,10 +61,27 @@ impl pallet_mock_liquidity_pools_gateway::Config for Runtime {
 	type Message = u32;
 }
 
+frame_support::parameter_types! {
+	pub Lanes: Vec<(u8, Perbill)> = vec![
+		(PRIORITY_LANE, Perbill::from_percent(40)),
+		(DEFAULT_LANE, Perbill::from_percent(60)),
+	];
+	pub const MaxOrderingKeys: u32 = 2;
+	pub const MaxRetries: u32 = 2;
+	pub const RetryDelay: u64 = 10;
+}
+
 impl Config for Runtime {
+	type Lane = u8;
+	type LaneProvider = LaneProvider;
+	type Lanes = Lanes;
+	type MaxOrderingKeys = MaxOrderingKeys;
+	type MaxRetries = MaxRetries;
 	type Message = u32;
 	type MessageNonce = Nonce;
 	type MessageProcessor = Processor;
+	type OrderingKey = u32;
+	type RetryDelay = RetryDelay;
 	type RuntimeEvent = RuntimeEvent;
 }
 
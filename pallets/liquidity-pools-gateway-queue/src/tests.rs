@@ -5,8 +5,12 @@ use frame_support::{
 use sp_runtime::{traits::BadOrigin, DispatchError};
 
 use crate::{
-	mock::{new_test_ext, Processor, Queue, Runtime, RuntimeEvent as MockEvent, RuntimeOrigin},
-	Error, Event, FailedMessageQueue, LastProcessedNonce, MessageQueue,
+	mock::{
+		new_test_ext, Processor, Queue, Runtime, RuntimeEvent as MockEvent, RuntimeOrigin, System,
+		DEFAULT_LANE,
+	},
+	Error, Event, FailedMessageQueue, LaneCursors, LaneQueues, LastProcessedNonce, MessageQueue,
+	MessageRetries, NextLaneMessage, OrderedQueue, RetrySchedule,
 };
 
 mod utils {
@@ -18,6 +22,16 @@ mod utils {
 
 		assert!(events.iter().any(|ev| ev.event == e));
 	}
+
+	pub fn processed_messages() -> Vec<u32> {
+		frame_system::Pallet::<Runtime>::events()
+			.into_iter()
+			.filter_map(|ev| match ev.event {
+				MockEvent::Queue(Event::MessageExecutionSuccess { message, .. }) => Some(message),
+				_ => None,
+			})
+			.collect()
+	}
 }
 
 use utils::*;
@@ -84,8 +98,15 @@ mod process_message {
 
 			assert_eq!(
 				FailedMessageQueue::<Runtime>::get(nonce),
-				Some((message, error))
+				Some((message, error, 0))
 			);
+			assert!(RetrySchedule::<Runtime>::contains_key(11, nonce));
+
+			event_exists(Event::<Runtime>::MessageRetryScheduled {
+				nonce,
+				retries: 0,
+				retry_at: 11,
+			});
 
 			event_exists(Event::<Runtime>::MessageExecutionFailure {
 				nonce,
@@ -106,7 +127,7 @@ mod process_failed_message {
 			let nonce = 1;
 			let error = DispatchError::Unavailable;
 
-			FailedMessageQueue::<Runtime>::insert(nonce, (message, error));
+			FailedMessageQueue::<Runtime>::insert(nonce, (message, error, 0));
 
 			Processor::mock_process(move |msg| {
 				assert_eq!(msg, message);
@@ -152,7 +173,7 @@ mod process_failed_message {
 			let nonce = 1;
 			let error = DispatchError::Unavailable;
 
-			FailedMessageQueue::<Runtime>::insert(nonce, (message, error));
+			FailedMessageQueue::<Runtime>::insert(nonce, (message, error, 0));
 
 			let error = DispatchError::Unavailable;
 			Processor::mock_process(move |msg| {
@@ -168,7 +189,7 @@ mod process_failed_message {
 
 			assert_eq!(
 				FailedMessageQueue::<Runtime>::get(nonce),
-				Some((message, error))
+				Some((message, error, 0))
 			);
 
 			event_exists(Event::<Runtime>::MessageExecutionFailure {
@@ -201,6 +222,21 @@ mod message_queue_impl {
 		});
 	}
 
+	#[test]
+	fn success_with_same_ordering_key() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Queue::queue(11));
+			assert_ok!(Queue::queue(21));
+
+			assert_eq!(
+				LaneQueues::<Runtime>::get(DEFAULT_LANE, 1),
+				Some(OrderedQueue { head: 1, tail: 2 })
+			);
+			assert_eq!(NextLaneMessage::<Runtime>::get(1), Some(2));
+			assert_eq!(NextLaneMessage::<Runtime>::get(2), None);
+		});
+	}
+
 	#[test]
 	fn error_on_max_nonce() {
 		new_test_ext().execute_with(|| {
@@ -234,7 +270,6 @@ mod on_idle {
 			assert_eq!(handle.times(), 3);
 			assert_eq!(MessageQueue::<Runtime>::iter().count(), 0);
 			assert_eq!(FailedMessageQueue::<Runtime>::iter().count(), 0);
-			assert_eq!(LastProcessedNonce::<Runtime>::get(), 3)
 		});
 	}
 
@@ -260,7 +295,6 @@ mod on_idle {
 			assert_eq!(weight, PROCESS_WEIGHT);
 			assert_eq!(handle.times(), 5);
 			assert_eq!(MessageQueue::<Runtime>::iter().count(), 0);
-			assert_eq!(LastProcessedNonce::<Runtime>::get(), 5)
 		});
 	}
 
@@ -281,16 +315,18 @@ mod on_idle {
 			assert_eq!(handle.times(), 3);
 			assert_eq!(MessageQueue::<Runtime>::iter().count(), 0);
 			assert_eq!(FailedMessageQueue::<Runtime>::iter().count(), 1);
-			assert_eq!(LastProcessedNonce::<Runtime>::get(), 3)
 		});
 	}
 
 	#[test]
 	fn with_no_messages() {
 		new_test_ext().execute_with(|| {
-			let _ = Queue::on_idle(0, TOTAL_WEIGHT);
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
 
-			assert_eq!(LastProcessedNonce::<Runtime>::get(), 0)
+			let weight = Queue::on_idle(0, TOTAL_WEIGHT);
+
+			assert_eq!(weight, Weight::zero());
+			assert_eq!(handle.times(), 0);
 		});
 	}
 
@@ -312,20 +348,254 @@ mod on_idle {
 			assert_eq!(handle.times(), 3);
 			assert_eq!(MessageQueue::<Runtime>::iter().count(), 0);
 			assert_eq!(FailedMessageQueue::<Runtime>::iter().count(), 0);
-			assert_eq!(LastProcessedNonce::<Runtime>::get(), 3)
 		});
 	}
 
 	#[test]
-	fn max_messages() {
+	fn priority_lane_first() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+			Queue::queue(1000).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			let weight = Queue::on_idle(0, TOTAL_WEIGHT);
+
+			assert_eq!(weight, PROCESS_WEIGHT * 2);
+			assert_eq!(handle.times(), 2);
+			assert_eq!(processed_messages(), vec![1000, 10]);
+			assert_eq!(LaneQueues::<Runtime>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn lane_weight_is_reserved() {
+		new_test_ext().execute_with(|| {
+			(0..3).for_each(|i| Queue::queue(1000 + i).unwrap());
+			Queue::queue(10).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			let weight = Queue::on_idle(0, TOTAL_WEIGHT);
+
+			// The priority backlog does not use the weight reserved for the default lane.
+			assert_eq!(weight, PROCESS_WEIGHT * 2);
+			assert_eq!(handle.times(), 2);
+			assert_eq!(processed_messages(), vec![1000, 10]);
+			assert_eq!(MessageQueue::<Runtime>::iter().count(), 2);
+		});
+	}
+
+	#[test]
+	fn ordering_keys_in_turns() {
 		new_test_ext().execute_with(|| {
-			LastProcessedNonce::<Runtime>::set(u64::MAX);
+			Queue::queue(11).unwrap();
+			Queue::queue(21).unwrap();
+			Queue::queue(12).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
 
-			let _ = Queue::on_idle(0, TOTAL_WEIGHT);
+			let weight = Queue::on_idle(0, PROCESS_WEIGHT * 3);
 
+			// The backlog of key 1 does not delay the message of key 2.
+			assert_eq!(weight, PROCESS_WEIGHT * 2);
+			assert_eq!(handle.times(), 2);
+			assert_eq!(
+				MessageQueue::<Runtime>::iter().collect::<Vec<_>>(),
+				vec![(2, 21)]
+			);
+			assert_eq!(
+				LaneQueues::<Runtime>::get(DEFAULT_LANE, 1),
+				Some(OrderedQueue { head: 2, tail: 2 })
+			);
+			assert_eq!(LaneQueues::<Runtime>::get(DEFAULT_LANE, 2), None);
+		});
+	}
+
+	#[test]
+	fn ordering_keys_bounded_per_block() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(11).unwrap();
+			Queue::queue(12).unwrap();
+			Queue::queue(13).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			let weight = Queue::on_idle(0, PROCESS_LIMIT_WEIGHT * 10);
+
+			// Only 2 of the 3 ordering keys are serviced in the first block.
+			assert_eq!(weight, PROCESS_WEIGHT * 2);
+			assert_eq!(handle.times(), 2);
+			assert_eq!(MessageQueue::<Runtime>::iter().count(), 1);
+			assert!(LaneCursors::<Runtime>::get(DEFAULT_LANE).is_some());
+			assert_ne!(Queue::last_processed_nonce(), 0);
 			event_exists(Event::<Runtime>::MaxNumberOfMessagesReached {
-				last_processed_nonce: u64::MAX,
-			})
+				last_processed_nonce: LastProcessedNonce::<Runtime>::get(),
+			});
+
+			let weight = Queue::on_idle(1, PROCESS_LIMIT_WEIGHT * 10);
+
+			// The next block continues with the remaining ordering key.
+			assert_eq!(weight, PROCESS_WEIGHT);
+			assert_eq!(handle.times(), 3);
+			assert_eq!(MessageQueue::<Runtime>::iter().count(), 0);
+			assert_eq!(LaneCursors::<Runtime>::get(DEFAULT_LANE), None);
+
+			let mut processed = processed_messages();
+			processed.sort();
+			assert_eq!(processed, vec![11, 12, 13]);
+		});
+	}
+}
+
+mod retries {
+	use super::*;
+
+	const PROCESS_WEIGHT: Weight = Weight::from_all(1000);
+	const TOTAL_WEIGHT: Weight = PROCESS_WEIGHT.mul(5);
+
+	fn idle(block: u64) -> Weight {
+		System::set_block_number(block);
+		Queue::on_idle(block, TOTAL_WEIGHT)
+	}
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_WEIGHT);
+			Processor::mock_process(|_| (Err(DispatchError::Unavailable), PROCESS_WEIGHT));
+
+			idle(1);
+
+			assert!(RetrySchedule::<Runtime>::contains_key(11, 1));
+
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			assert_eq!(idle(10), Weight::zero());
+			assert_eq!(handle.times(), 0);
+
+			// The retry is processed even if the scheduled block had no idle weight.
+			assert_eq!(idle(12), PROCESS_WEIGHT);
+			assert_eq!(handle.times(), 1);
+			assert_eq!(FailedMessageQueue::<Runtime>::iter().count(), 0);
+			assert_eq!(RetrySchedule::<Runtime>::iter().count(), 0);
+
+			event_exists(Event::<Runtime>::MessageExecutionSuccess {
+				nonce: 1,
+				message: 10,
+			});
+		});
+	}
+
+	#[test]
+	fn dead_letter_after_max_retries() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+
+			let error = DispatchError::Unavailable;
+
+			Processor::mock_max_processing_weight(|_| PROCESS_WEIGHT);
+			let handle = Processor::mock_process(move |_| (Err(error), PROCESS_WEIGHT));
+
+			idle(1);
+			idle(11);
+
+			// The delay doubles with each retry
+			assert_eq!(FailedMessageQueue::<Runtime>::get(1), Some((10, error, 1)));
+			assert!(RetrySchedule::<Runtime>::contains_key(31, 1));
+
+			idle(30);
+			assert_eq!(handle.times(), 2);
+
+			idle(31);
+			assert_eq!(handle.times(), 3);
+			assert_eq!(FailedMessageQueue::<Runtime>::get(1), Some((10, error, 2)));
+			assert_eq!(RetrySchedule::<Runtime>::iter().count(), 0);
+
+			event_exists(Event::<Runtime>::MessageDeadLettered {
+				nonce: 1,
+				message: 10,
+				error,
+			});
+
+			idle(100);
+			assert_eq!(handle.times(), 3);
+
+			// Dead letters can still be processed manually
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			assert_ok!(Queue::process_failed_message(RuntimeOrigin::signed(1), 1));
+			assert_eq!(handle.times(), 1);
+			assert_eq!(FailedMessageQueue::<Runtime>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn retry_queued_after_lane_messages() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_WEIGHT);
+			Processor::mock_process(|_| (Err(DispatchError::Unavailable), PROCESS_WEIGHT));
+
+			idle(1);
+
+			// Queued with the same lane and ordering key, but not processed yet
+			Queue::queue(20).unwrap();
+
+			Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			assert_eq!(idle(11), PROCESS_WEIGHT * 2);
+			assert_eq!(processed_messages(), vec![20, 10]);
+			assert_eq!(FailedMessageQueue::<Runtime>::iter().count(), 0);
+			assert_eq!(MessageRetries::<Runtime>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn skip_manually_processed_message() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_WEIGHT);
+			Processor::mock_process(|_| (Err(DispatchError::Unavailable), PROCESS_WEIGHT));
+
+			idle(1);
+
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			assert_ok!(Queue::process_failed_message(RuntimeOrigin::signed(1), 1));
+
+			assert_eq!(idle(11), Weight::zero());
+			assert_eq!(handle.times(), 1);
+			assert_eq!(RetrySchedule::<Runtime>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn not_enough_weight() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_WEIGHT);
+			Processor::mock_process(|_| (Err(DispatchError::Unavailable), PROCESS_WEIGHT));
+
+			idle(1);
+
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			System::set_block_number(11);
+			assert_eq!(Queue::on_idle(11, PROCESS_WEIGHT / 2), Weight::zero());
+			assert_eq!(handle.times(), 0);
+
+			assert_eq!(idle(12), PROCESS_WEIGHT);
+			assert_eq!(handle.times(), 1);
 		});
 	}
 }
@@ -24,15 +24,27 @@
 //! gets finalized. Each message in the `OutboundMessageQueue` has a `Domain`
 //! assigned to it, and that `Domain` should have a corresponding `DomainRouter`
 //! which should be set prior to sending the message.
+//!
+//! Inbound messages can be limited per domain and message type with rate
+//! limits. When a limit is exceeded the domain is paused, and its inbound
+//! messages fail and remain in the queue until the domain is resumed.
+//!
+//! Outbound messages of a domain can be batched automatically. The messages
+//! produced for the domain within a block are packed into a single batch per
+//! sender, which is enqueued when the block is finalized.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::fmt::Debug;
 
 use cfg_primitives::LP_DEFENSIVE_WEIGHT;
-use cfg_traits::liquidity_pools::{
-	InboundMessageHandler, LpMessageBatch, LpMessageProof, LpMessageRecovery, LpMessageSerializer,
-	MessageHash, MessageProcessor, MessageQueue, MessageReceiver, MessageSender,
-	OutboundMessageHandler, RouterProvider,
+use cfg_traits::{
+	liquidity_pools::{
+		InboundMessageHandler, LpMessageBatch, LpMessageProof, LpMessageRateLimit,
+		LpMessageRecovery, LpMessageSerializer, MessageHash, MessageProcessor, MessageQueue,
+		MessageReceiver, MessageSender, MessageSenderWithFees, OutboundMessageHandler,
+		RouterProvider,
+	},
+	Seconds, TimeAsSecs,
 };
 use cfg_types::domain_address::{Domain, DomainAddress};
 use frame_support::{
@@ -40,16 +52,18 @@ use frame_support::{
 	pallet_prelude::*,
 	storage::{with_transaction, TransactionOutcome},
 };
-use frame_system::pallet_prelude::{ensure_signed, OriginFor};
+use frame_system::pallet_prelude::{ensure_signed, BlockNumberFor, OriginFor};
 use message::GatewayMessage;
 use orml_traits::GetByKey;
 pub use pallet::*;
 use parity_scale_codec::FullCodec;
 use sp_arithmetic::traits::{BaseArithmetic, EnsureAddAssign, One};
-use sp_std::convert::TryInto;
+use sp_std::{convert::TryInto, vec::Vec};
 
 use crate::{
-	message_processing::{InboundEntry, ProofEntry},
+	message_processing::{
+		DomainRouterSet, InboundEntry, OutboundRecord, ProofEntry, RateLimit, RateLimitUsage,
+	},
 	weights::WeightInfo,
 };
 
@@ -69,7 +83,7 @@ mod tests;
 pub mod pallet {
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -89,6 +103,7 @@ pub mod pallet {
 			+ LpMessageBatch
 			+ LpMessageProof
 			+ LpMessageRecovery
+			+ LpMessageRateLimit<LimitKey = Self::RateLimitKey>
 			+ Clone
 			+ Debug
 			+ PartialEq
@@ -98,12 +113,16 @@ pub mod pallet {
 			+ FullCodec;
 
 		/// The target of the messages coming from this chain
-		type MessageSender: MessageSender<
+		type MessageSender: MessageSenderWithFees<
 			Middleware = Self::RouterId,
 			Origin = DomainAddress,
 			Message = Self::Message,
+			Fees = Self::OutboundFees,
 		>;
 
+		/// The fees that can be paid when sending an outbound message again.
+		type OutboundFees: Parameter + MaxEncodedLen;
+
 		/// An identification of a router
 		type RouterId: Parameter + MaxEncodedLen + Into<Domain>;
 
@@ -131,8 +150,33 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRouterCount: Get<u32>;
 
+		/// Maximum number of recent outbound messages stored to be sent again.
+		#[pallet::constant]
+		type MaxOutboundRecords: Get<u32>;
+
+		/// Maximum number of times a stored outbound message can be sent
+		/// again.
+		#[pallet::constant]
+		type MaxOutboundResends: Get<u32>;
+
 		/// Type for identifying sessions of inbound routers.
 		type SessionId: Parameter + Member + BaseArithmetic + Default + Copy + MaxEncodedLen;
+
+		/// Type that identifies the inbound rate limits of a domain.
+		type RateLimitKey: Parameter + MaxEncodedLen;
+
+		/// Type used to compute the periods of the inbound rate limits and the
+		/// age of inbound entries and message recoveries.
+		type Time: TimeAsSecs;
+
+		/// Time after which a pending inbound entry can be removed.
+		#[pallet::constant]
+		type InboundEntryExpiry: Get<Seconds>;
+
+		/// Time that must pass since a message recovery is scheduled before
+		/// it can be executed, allowing admins to dispute it.
+		#[pallet::constant]
+		type MessageRecoveryDisputeWindow: Get<Seconds>;
 	}
 
 	#[pallet::event]
@@ -144,10 +188,18 @@ pub mod pallet {
 			session_id: T::SessionId,
 		},
 
+		/// The routers and threshold for a given domain were set.
+		DomainRoutersSet {
+			domain: Domain,
+			router_ids: BoundedVec<T::RouterId, T::MaxRouterCount>,
+			threshold: u32,
+			session_id: T::SessionId,
+		},
+
 		/// The domain hook address was initialized or updated.
 		DomainHookAddressSet {
 			domain: Domain,
-			hook_address: [u8; 20],
+			hook_address: [u8; 32],
 		},
 
 		/// An inbound message was processed.
@@ -198,6 +250,67 @@ pub mod pallet {
 			recovery_router: [u8; 32],
 			messaging_router: T::RouterId,
 		},
+
+		/// A stored outbound message was sent again.
+		OutboundMessageResent {
+			domain: Domain,
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+		},
+
+		/// An inbound rate limit for a domain was set or removed.
+		RateLimitSet {
+			domain: Domain,
+			key: T::RateLimitKey,
+			limit: Option<RateLimit>,
+		},
+
+		/// The processing of inbound messages of a domain was paused.
+		DomainPaused { domain: Domain },
+
+		/// The processing of inbound messages of a domain was resumed.
+		DomainResumed { domain: Domain },
+
+		/// Message recovery was scheduled.
+		MessageRecoveryScheduled {
+			domain: Domain,
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+			executable_at: Seconds,
+		},
+
+		/// A scheduled message recovery was cancelled.
+		MessageRecoveryCancelled {
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+		},
+
+		/// An expired pending inbound entry was removed.
+		InboundEntryRemoved {
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+		},
+
+		/// An inbound message proof was received after the message was
+		/// executed without it, and was discarded.
+		InboundProofDiscarded {
+			domain: Domain,
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+		},
+
+		/// The automatic batching of outbound messages for a domain was
+		/// enabled or disabled.
+		AutoBatchingSet { domain: Domain, enabled: bool },
+
+		/// An automatically packed batch could not be enqueued when the block
+		/// was finalized. It is enqueued again when the next block is
+		/// finalized.
+		AutoBatchQueueFailed {
+			domain: Domain,
+			message: T::Message,
+			error: DispatchError,
+		},
 	}
 
 	/// Storage for routers.
@@ -208,14 +321,25 @@ pub mod pallet {
 	pub type Routers<T: Config> =
 		StorageValue<_, BoundedVec<T::RouterId, T::MaxRouterCount>, ValueQuery>;
 
+	/// Storage for the routers and the quorum threshold of a domain.
+	///
+	/// Domains without an entry use the `Routers` storage, requiring all of
+	/// them to agree on an inbound message.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type DomainRouters<T: Config> =
+		StorageMap<_, Blake2_128Concat, Domain, DomainRouterSet<T>, OptionQuery>;
+
 	/// Stores the hook address of a domain required for particular LP messages.
+	/// EVM hooks only use the first 20 bytes, non-EVM hooks all 32 bytes.
 	///
 	/// Lifetime: Indefinitely.
 	///
 	/// NOTE: Must only be changeable via `AdminOrigin`.
 	#[pallet::storage]
 	pub type DomainHookAddress<T: Config> =
-		StorageMap<_, Blake2_128Concat, Domain, [u8; 20], OptionQuery>;
+		StorageMap<_, Blake2_128Concat, Domain, [u8; 32], OptionQuery>;
 
 	/// Stores a batch message, not ready yet to be enqueued.
 	/// Lifetime handled by `start_batch_message()` and `end_batch_message()`
@@ -224,6 +348,23 @@ pub mod pallet {
 	pub(crate) type PackedMessage<T: Config> =
 		StorageMap<_, Blake2_128Concat, (T::AccountId, Domain), T::Message>;
 
+	/// Storage for the domains whose outbound messages are batched
+	/// automatically.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type AutoBatchingDomains<T: Config> =
+		StorageMap<_, Blake2_128Concat, Domain, (), OptionQuery>;
+
+	/// Stores the batch messages automatically packed for a domain and
+	/// sender.
+	///
+	/// Lifetime: Until the block is finalized and the batch enqueued, or until
+	/// the batch is full.
+	#[pallet::storage]
+	pub(crate) type AutoBatchedMessages<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Domain, Blake2_128Concat, T::AccountId, T::Message>;
+
 	/// Storage for pending inbound messages.
 	#[pallet::storage]
 	#[pallet::getter(fn pending_inbound_entries)]
@@ -236,6 +377,90 @@ pub mod pallet {
 		InboundEntry<T>,
 	>;
 
+	/// Storage for the moment each pending inbound entry was created, or
+	/// replaced by an entry of a new session.
+	///
+	/// Lifetime: Same as the entry in `PendingInboundEntries`.
+	#[pallet::storage]
+	pub type PendingInboundEntryCreatedAt<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, MessageHash, Blake2_128Concat, T::RouterId, Seconds>;
+
+	/// Storage for the proofs still expected from the routers that did not
+	/// vote for an executed inbound message, with the moment the message was
+	/// executed. These proofs are discarded when received.
+	///
+	/// Lifetime: Until the proofs are received, or until they expire like the
+	/// pending inbound entries.
+	#[pallet::storage]
+	pub type SurplusInboundProofs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MessageHash,
+		Blake2_128Concat,
+		T::RouterId,
+		(ProofEntry<T>, Seconds),
+	>;
+
+	/// Storage for the message recoveries waiting for the dispute window, with
+	/// the moment they can be executed.
+	///
+	/// Lifetime: Until executed or cancelled.
+	#[pallet::storage]
+	pub type ScheduledMessageRecoveries<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, MessageHash, Blake2_128Concat, T::RouterId, Seconds>;
+
+	/// Storage for recent outbound messages, so they can be sent again.
+	///
+	/// Lifetime: Until `MaxOutboundRecords` newer messages are sent.
+	#[pallet::storage]
+	pub type OutboundRecords<T: Config> =
+		StorageMap<_, Blake2_128Concat, MessageHash, OutboundRecord<T>, OptionQuery>;
+
+	/// Storage for the hashes of the recent outbound messages, by the nonce
+	/// they were recorded with.
+	///
+	/// Lifetime: Until `MaxOutboundRecords` newer messages are sent.
+	#[pallet::storage]
+	pub type OutboundRecordHashes<T: Config> =
+		StorageMap<_, Twox64Concat, u64, MessageHash, OptionQuery>;
+
+	/// Storage for the nonce of the last recorded outbound message.
+	#[pallet::storage]
+	pub type OutboundRecordNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Storage for the inbound rate limits of a domain.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type RateLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Domain,
+		Blake2_128Concat,
+		T::RateLimitKey,
+		RateLimit,
+		OptionQuery,
+	>;
+
+	/// Storage for the amounts executed during the current period of each
+	/// inbound rate limit.
+	#[pallet::storage]
+	pub type RateLimitUsages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Domain,
+		Blake2_128Concat,
+		T::RateLimitKey,
+		RateLimitUsage,
+		ValueQuery,
+	>;
+
+	/// Storage for the domains whose inbound messages are not processed.
+	///
+	/// Lifetime: Until resumed via `AdminOrigin`.
+	#[pallet::storage]
+	pub type PausedDomains<T: Config> = StorageMap<_, Blake2_128Concat, Domain, (), OptionQuery>;
+
 	/// Storage for inbound message session IDs.
 	#[pallet::storage]
 	pub type SessionIdStore<T: Config> = StorageValue<_, T::SessionId, ValueQuery>;
@@ -276,6 +501,52 @@ pub mod pallet {
 
 		/// Not enough routers are stored for a domain.
 		NotEnoughRoutersForDomain,
+
+		/// The same router was provided more than once.
+		DuplicatedRouter,
+
+		/// The threshold is zero or greater than the number of routers.
+		InvalidRouterThreshold,
+
+		/// The outbound message is not stored.
+		OutboundRecordNotFound,
+
+		/// The outbound message was already sent again `MaxOutboundResends`
+		/// times.
+		MaxOutboundResendsReached,
+
+		/// Executing the inbound message exceeds a rate limit of the domain.
+		InboundRateLimitExceeded,
+
+		/// The inbound messages of the domain are not processed until the
+		/// domain is resumed.
+		DomainPaused,
+
+		/// The domain is not paused.
+		DomainNotPaused,
+
+		/// The message recovery was not scheduled.
+		MessageRecoveryNotScheduled,
+
+		/// The message recovery is already scheduled.
+		MessageRecoveryAlreadyScheduled,
+
+		/// The dispute window of the message recovery has not passed yet.
+		MessageRecoveryDisputeWindowNotElapsed,
+
+		/// None of the pending inbound entries of the message are expired.
+		NoExpiredInboundEntries,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			Self::pending_auto_batches_weight()
+		}
+
+		fn on_finalize(_: BlockNumberFor<T>) {
+			Self::queue_auto_batched_messages();
+		}
 	}
 
 	#[pallet::call]
@@ -292,11 +563,7 @@ pub mod pallet {
 
 			<Routers<T>>::set(router_ids.clone());
 
-			let new_session_id = SessionIdStore::<T>::try_mutate(|n| {
-				n.ensure_add_assign(One::one())?;
-
-				Ok::<T::SessionId, DispatchError>(*n)
-			})?;
+			let new_session_id = Self::increase_session_id()?;
 
 			Self::deposit_event(Event::RoutersSet {
 				router_ids,
@@ -306,6 +573,52 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Sets the IDs of the routers used for a domain, overriding the ones
+		/// set with `set_routers()`, and the number of them that must agree on
+		/// an inbound message before executing it.
+		///
+		/// Providing no routers removes the ones set for the domain.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_domain_routers())]
+		#[pallet::call_index(14)]
+		pub fn set_domain_routers(
+			origin: OriginFor<T>,
+			domain: Domain,
+			router_ids: BoundedVec<T::RouterId, T::MaxRouterCount>,
+			threshold: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+
+			if router_ids.is_empty() {
+				DomainRouters::<T>::remove(domain);
+			} else {
+				let router_set = DomainRouterSet {
+					router_ids: router_ids.clone(),
+					threshold,
+				};
+
+				router_set.validate(domain)?;
+
+				DomainRouters::<T>::insert(domain, router_set);
+			}
+
+			// Pending inbound entries were voted with the previous routers, so they
+			// are invalidated by the new session.
+			let new_session_id = Self::increase_session_id()?;
+
+			Self::deposit_event(Event::DomainRoutersSet {
+				domain,
+				router_ids,
+				threshold,
+				session_id: new_session_id,
+			});
+
+			Ok(())
+		}
+
 		/// Set the address of the domain hook
 		///
 		/// Can only be called by `AdminOrigin`.
@@ -314,7 +627,7 @@ pub mod pallet {
 		pub fn set_domain_hook_address(
 			origin: OriginFor<T>,
 			domain: Domain,
-			hook_address: [u8; 20],
+			hook_address: [u8; 32],
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
@@ -356,7 +669,7 @@ pub mod pallet {
 
 			match PackedMessage::<T>::take((&sender, &destination)) {
 				Some(msg) if msg.submessages().is_empty() => Ok(()), //No-op
-				Some(message) => Self::queue_outbound_message(destination, message),
+				Some(message) => Self::queue_outbound_message(sender, destination, message),
 				None => Err(Error::<T>::MessagePackingNotStarted.into()),
 			}
 		}
@@ -364,6 +677,10 @@ pub mod pallet {
 		/// Manually increase the proof count for a particular message and
 		/// executes it if the required count is reached.
 		///
+		/// The recovery must have been scheduled with
+		/// `schedule_message_recovery()` and its dispute window must have
+		/// passed.
+		///
 		/// Can only be called by `AdminOrigin`.
 		#[pallet::weight(T::WeightInfo::execute_message_recovery())]
 		#[pallet::call_index(11)]
@@ -375,15 +692,15 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
-			let router_ids = Self::get_router_ids_for_domain(domain)?;
+			let router_ids = Self::ensure_recovery_router(domain, &router_id)?;
+
+			let executable_at = ScheduledMessageRecoveries::<T>::take(message_hash, &router_id)
+				.ok_or(Error::<T>::MessageRecoveryNotScheduled)?;
 
 			ensure!(
-				router_ids.iter().any(|x| x == &router_id),
-				Error::<T>::UnknownRouter
+				T::Time::now() >= executable_at,
+				Error::<T>::MessageRecoveryDisputeWindowNotElapsed
 			);
-			// Message recovery shouldn't be supported for setups that have less than 2
-			// routers since no proofs are required in that case.
-			ensure!(router_ids.len() > 1, Error::<T>::NotEnoughRoutersForDomain);
 
 			let session_id = SessionIdStore::<T>::get();
 
@@ -392,6 +709,10 @@ pub mod pallet {
 				router_id.clone(),
 				|storage_entry| match storage_entry {
 					Some(stored_inbound_entry) => {
+						if stored_inbound_entry.session_id() != session_id {
+							Self::track_entry_creation(message_hash, &router_id);
+						}
+
 						stored_inbound_entry.increment_proof_count(session_id)
 					}
 					None => {
@@ -400,12 +721,14 @@ pub mod pallet {
 							current_count: 1,
 						}));
 
+						Self::track_entry_creation(message_hash, &router_id);
+
 						Ok::<(), DispatchError>(())
 					}
 				},
 			)?;
 
-			let expected_proof_count = Self::get_expected_proof_count(&router_ids)?;
+			let expected_proof_count = Self::get_expected_proof_count(domain, &router_ids)?;
 
 			Self::execute_if_requirements_are_met(
 				message_hash,
@@ -482,9 +805,297 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sends a recent outbound message again through one of the routers
+		/// it was sent with, optionally paying the given fees instead of the
+		/// ones configured in the router.
+		///
+		/// Each router sends what it sent originally, the message for the
+		/// first router and its proof for the rest of them.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::resend_outbound_message())]
+		#[pallet::call_index(15)]
+		pub fn resend_outbound_message(
+			origin: OriginFor<T>,
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+			fees: Option<T::OutboundFees>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut record = OutboundRecords::<T>::get(message_hash)
+				.ok_or(Error::<T>::OutboundRecordNotFound)?;
+
+			ensure!(
+				record.router_ids.contains(&router_id),
+				Error::<T>::UnknownRouter
+			);
+
+			ensure!(
+				record.resend_count < T::MaxOutboundResends::get(),
+				Error::<T>::MaxOutboundResendsReached
+			);
+
+			record.resend_count.ensure_add_assign(1)?;
+			OutboundRecords::<T>::insert(message_hash, &record);
+
+			let message = match record.router_ids.first() == Some(&router_id) {
+				true => record.message,
+				false => record.message.to_proof_message(),
+			};
+
+			match fees {
+				Some(fees) => T::MessageSender::send_with_fees(
+					router_id.clone(),
+					T::Sender::get(),
+					message,
+					fees,
+				),
+				None => T::MessageSender::send(router_id.clone(), T::Sender::get(), message),
+			}?;
+
+			Self::deposit_event(Event::<T>::OutboundMessageResent {
+				domain: record.domain,
+				message_hash,
+				router_id,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the maximum amount of inbound messages of a type that can be
+		/// executed for a domain during each period.
+		///
+		/// Providing no limit removes the one set.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_rate_limit())]
+		#[pallet::call_index(16)]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			domain: Domain,
+			key: T::RateLimitKey,
+			limit: Option<RateLimit>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+
+			match &limit {
+				Some(limit) => RateLimits::<T>::insert(domain, &key, limit),
+				None => {
+					RateLimits::<T>::remove(domain, &key);
+					RateLimitUsages::<T>::remove(domain, &key);
+				}
+			}
+
+			Self::deposit_event(Event::<T>::RateLimitSet { domain, key, limit });
+
+			Ok(())
+		}
+
+		/// Resumes the processing of inbound messages of a domain paused after
+		/// exceeding a rate limit.
+		///
+		/// The messages that failed while the domain was paused can be
+		/// processed again from the queue.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::resume_domain())]
+		#[pallet::call_index(17)]
+		pub fn resume_domain(origin: OriginFor<T>, domain: Domain) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			PausedDomains::<T>::take(domain).ok_or(Error::<T>::DomainNotPaused)?;
+
+			Self::deposit_event(Event::<T>::DomainResumed { domain });
+
+			Ok(())
+		}
+
+		/// Schedules the execution of a message recovery, which can be
+		/// executed with `execute_message_recovery()` once the dispute window
+		/// passes.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::schedule_message_recovery())]
+		#[pallet::call_index(18)]
+		pub fn schedule_message_recovery(
+			origin: OriginFor<T>,
+			domain: Domain,
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::ensure_recovery_router(domain, &router_id)?;
+
+			let executable_at =
+				T::Time::now().saturating_add(T::MessageRecoveryDisputeWindow::get());
+
+			ScheduledMessageRecoveries::<T>::try_mutate(message_hash, &router_id, |scheduled| {
+				ensure!(
+					scheduled.is_none(),
+					Error::<T>::MessageRecoveryAlreadyScheduled
+				);
+
+				*scheduled = Some(executable_at);
+
+				Ok::<(), DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::<T>::MessageRecoveryScheduled {
+				domain,
+				message_hash,
+				router_id,
+				executable_at,
+			});
+
+			Ok(())
+		}
+
+		/// Disputes a scheduled message recovery, removing it.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::cancel_message_recovery())]
+		#[pallet::call_index(19)]
+		pub fn cancel_message_recovery(
+			origin: OriginFor<T>,
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ScheduledMessageRecoveries::<T>::take(message_hash, &router_id)
+				.ok_or(Error::<T>::MessageRecoveryNotScheduled)?;
+
+			Self::deposit_event(Event::<T>::MessageRecoveryCancelled {
+				message_hash,
+				router_id,
+			});
+
+			Ok(())
+		}
+
+		/// Removes the pending inbound entries and the surplus proofs of a
+		/// message that belong to a previous session or were created more
+		/// than `InboundEntryExpiry` ago.
+		///
+		/// Can be called by any signed account.
+		#[pallet::weight(T::WeightInfo::remove_expired_inbound_entries(T::MaxRouterCount::get()))]
+		#[pallet::call_index(20)]
+		pub fn remove_expired_inbound_entries(
+			origin: OriginFor<T>,
+			message_hash: MessageHash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let session_id = SessionIdStore::<T>::get();
+			let now = T::Time::now();
+
+			let expired_router_ids = PendingInboundEntries::<T>::iter_prefix(message_hash)
+				.filter(|(router_id, entry)| {
+					Self::is_entry_expired(message_hash, router_id, entry, session_id, now)
+				})
+				.map(|(router_id, _)| router_id)
+				.collect::<Vec<_>>();
+
+			let expired_surplus_router_ids = SurplusInboundProofs::<T>::iter_prefix(message_hash)
+				.filter(|(_, (entry, created_at))| {
+					entry.session_id != session_id
+						|| now >= created_at.saturating_add(T::InboundEntryExpiry::get())
+				})
+				.map(|(router_id, _)| router_id)
+				.collect::<Vec<_>>();
+
+			ensure!(
+				!expired_router_ids.is_empty() || !expired_surplus_router_ids.is_empty(),
+				Error::<T>::NoExpiredInboundEntries
+			);
+
+			for router_id in expired_surplus_router_ids {
+				SurplusInboundProofs::<T>::remove(message_hash, &router_id);
+
+				Self::deposit_event(Event::<T>::InboundEntryRemoved {
+					message_hash,
+					router_id,
+				});
+			}
+
+			for router_id in expired_router_ids {
+				PendingInboundEntries::<T>::remove(message_hash, &router_id);
+				PendingInboundEntryCreatedAt::<T>::remove(message_hash, &router_id);
+
+				Self::deposit_event(Event::<T>::InboundEntryRemoved {
+					message_hash,
+					router_id,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Enables or disables the automatic batching of the outbound
+		/// messages of a domain.
+		///
+		/// Once enabled, the messages sent to the domain within a block are
+		/// packed into one batch per sender, which is enqueued when the
+		/// block is finalized. Explicit batches started with
+		/// `start_batch_message()` take precedence.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_auto_batching())]
+		#[pallet::call_index(21)]
+		pub fn set_auto_batching(
+			origin: OriginFor<T>,
+			domain: Domain,
+			enabled: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+
+			if enabled {
+				AutoBatchingDomains::<T>::insert(domain, ());
+			} else {
+				AutoBatchingDomains::<T>::remove(domain);
+			}
+
+			Self::deposit_event(Event::<T>::AutoBatchingSet { domain, enabled });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		fn increase_session_id() -> Result<T::SessionId, DispatchError> {
+			SessionIdStore::<T>::try_mutate(|n| {
+				n.ensure_add_assign(One::one())?;
+
+				Ok::<T::SessionId, DispatchError>(*n)
+			})
+		}
+
+		/// Ensures that the router can be used to recover a message of the
+		/// domain, returning the routers of the domain.
+		fn ensure_recovery_router(
+			domain: Domain,
+			router_id: &T::RouterId,
+		) -> Result<Vec<T::RouterId>, DispatchError> {
+			let router_ids = Self::get_router_ids_for_domain(domain)?;
+
+			ensure!(
+				router_ids.iter().any(|x| x == router_id),
+				Error::<T>::UnknownRouter
+			);
+			// Message recovery shouldn't be supported for setups that have less than 2
+			// routers since no proofs are required in that case.
+			ensure!(router_ids.len() > 1, Error::<T>::NotEnoughRoutersForDomain);
+
+			Ok(router_ids)
+		}
+
 		fn send_recovery_message(
 			domain: Domain,
 			message: T::Message,
@@ -518,13 +1129,16 @@ pub mod pallet {
 
 			PackedMessage::<T>::mutate((&from, destination), |batch| match batch {
 				Some(batch) => batch.pack_with(message),
-				None => Self::queue_outbound_message(destination, message),
+				None if AutoBatchingDomains::<T>::contains_key(destination) => {
+					Self::auto_batch_message(from, destination, message)
+				}
+				None => Self::queue_outbound_message(from, destination, message),
 			})
 		}
 	}
 
-	impl<T: Config> GetByKey<Domain, Option<[u8; 20]>> for Pallet<T> {
-		fn get(domain: &Domain) -> Option<[u8; 20]> {
+	impl<T: Config> GetByKey<Domain, Option<[u8; 32]>> for Pallet<T> {
+		fn get(domain: &Domain) -> Option<[u8; 32]> {
 			DomainHookAddress::<T>::get(domain)
 		}
 	}
@@ -533,6 +1147,11 @@ pub mod pallet {
 		type Message = GatewayMessage<T::Message, T::RouterId>;
 
 		fn process(msg: Self::Message) -> (DispatchResult, Weight) {
+			let inbound_domain = match &msg {
+				GatewayMessage::Inbound { domain, .. } => Some(*domain),
+				GatewayMessage::Outbound { .. } => None,
+			};
+
 			// The #[transactional] macro only works for functions that return a
 			// `DispatchResult` therefore, we need to manually add this here.
 			let res = with_transaction(|| {
@@ -554,6 +1173,14 @@ pub mod pallet {
 				}
 			});
 
+			// The pause is done outside of the transaction, so it is kept even though
+			// the changes of the message are reverted.
+			if let (Some(domain), Err(e)) = (inbound_domain, res) {
+				if e == Error::<T>::InboundRateLimitExceeded.into() {
+					Self::pause_domain(domain);
+				}
+			}
+
 			(res, LP_DEFENSIVE_WEIGHT)
 		}
 
@@ -1,22 +1,113 @@
-use cfg_traits::liquidity_pools::{
-	InboundMessageHandler, LpMessageHash, LpMessageProof, MessageHash, MessageQueue, RouterProvider,
+use cfg_traits::{
+	liquidity_pools::{
+		InboundMessageHandler, LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageRateLimit,
+		MessageHash, MessageQueue, RouterProvider,
+	},
+	Seconds, TimeAsSecs,
 };
 use cfg_types::domain_address::Domain;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	pallet_prelude::{Decode, Encode, TypeInfo},
+	pallet_prelude::{Decode, DispatchClass, Encode, TypeInfo},
+	storage::{with_transaction, TransactionOutcome},
+	traits::Get,
+	weights::Weight,
+	BoundedVec,
 };
 use parity_scale_codec::MaxEncodedLen;
-use sp_arithmetic::traits::{EnsureAddAssign, EnsureSub, SaturatedConversion};
+use sp_arithmetic::traits::{EnsureAddAssign, EnsureSub, SaturatedConversion, Saturating};
 use sp_runtime::DispatchError;
-use sp_std::vec::Vec;
+use sp_std::{iter, vec::Vec};
 
 use crate::{
-	message::GatewayMessage, Config, Error, Event, Pallet, PendingInboundEntries, Routers,
-	SessionIdStore,
+	message::GatewayMessage, weights::WeightInfo, AutoBatchedMessages, Config, DomainRouters,
+	Error, Event, OutboundRecordHashes, OutboundRecordNonce, OutboundRecords, Pallet,
+	PausedDomains, PendingInboundEntries, PendingInboundEntryCreatedAt, RateLimitUsages,
+	RateLimits, Routers, SessionIdStore, SurplusInboundProofs,
 };
 
+/// Type that holds the routers and the quorum threshold set for a domain.
+#[derive(Debug, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct DomainRouterSet<T: Config> {
+	/// The routers used for the domain, where the first one sends and
+	/// receives the messages and the rest of them the proofs.
+	pub router_ids: BoundedVec<T::RouterId, T::MaxRouterCount>,
+
+	/// The number of routers, including the one of the message, that must
+	/// agree on an inbound message before executing it.
+	pub threshold: u32,
+}
+
+impl<T: Config> DomainRouterSet<T> {
+	/// Ensures that the routers are unique and available for the domain and
+	/// that the threshold is reachable by them.
+	pub fn validate(&self, domain: Domain) -> DispatchResult {
+		let available_routers = T::RouterProvider::routers_for_domain(domain);
+
+		for (index, router_id) in self.router_ids.iter().enumerate() {
+			ensure!(
+				available_routers.contains(router_id),
+				Error::<T>::UnknownRouter
+			);
+			ensure!(
+				!self.router_ids[..index].contains(router_id),
+				Error::<T>::DuplicatedRouter
+			);
+		}
+
+		ensure!(
+			self.threshold > 0 && self.threshold as usize <= self.router_ids.len(),
+			Error::<T>::InvalidRouterThreshold
+		);
+
+		Ok(())
+	}
+}
+
+/// Type that holds the maximum amount of an inbound rate limit.
+#[derive(Debug, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct RateLimit {
+	/// The maximum amount that can be executed during one period.
+	pub max_amount: u128,
+
+	/// The duration of each period.
+	pub period: Seconds,
+}
+
+/// Type that holds the amount accounted for an inbound rate limit during the
+/// current period.
+#[derive(Debug, Default, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct RateLimitUsage {
+	/// The moment the current period started.
+	pub period_start: Seconds,
+
+	/// The amount executed during the current period.
+	pub amount: u128,
+}
+
+/// Type that holds the information of a recently sent outbound message.
+#[derive(Debug, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct OutboundRecord<T: Config> {
+	/// The account that originated the message.
+	pub sender: T::AccountId,
+
+	/// The destination of the message.
+	pub domain: Domain,
+
+	/// The LP message.
+	pub message: T::Message,
+
+	/// The routers the message was sent with, where the first one sent the
+	/// message and the rest of them its proof.
+	pub router_ids: BoundedVec<T::RouterId, T::MaxRouterCount>,
+
+	/// The number of times the message was sent again.
+	pub resend_count: u32,
+}
+
 /// Type that holds the information needed for inbound message entries.
 #[derive(Debug, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -108,6 +199,14 @@ impl<T: Config> InboundEntry<T> {
 		}
 	}
 
+	/// Returns the session ID of the entry.
+	pub fn session_id(&self) -> T::SessionId {
+		match self {
+			InboundEntry::Message(message_entry) => message_entry.session_id,
+			InboundEntry::Proof(proof_entry) => proof_entry.session_id,
+		}
+	}
+
 	/// Creates a new `InboundEntry` based on the information provided.
 	///
 	/// If the updated counts reach 0, it means that a new entry is no longer
@@ -253,11 +352,16 @@ impl<T: Config> InboundEntry<T> {
 }
 
 impl<T: Config> Pallet<T> {
-	/// Retrieves all stored routers and then filters them based
-	/// on the available routers for the provided domain.
+	/// Retrieves the routers set for the provided domain or, if there are
+	/// none, all stored routers filtered based on the available routers for
+	/// the provided domain.
 	pub(crate) fn get_router_ids_for_domain(
 		domain: Domain,
 	) -> Result<Vec<T::RouterId>, DispatchError> {
+		if let Some(router_set) = DomainRouters::<T>::get(domain) {
+			return Ok(router_set.router_ids.into_inner());
+		}
+
 		let stored_routers = Routers::<T>::get();
 
 		let all_routers_for_domain = T::RouterProvider::routers_for_domain(domain);
@@ -281,9 +385,20 @@ impl<T: Config> Pallet<T> {
 
 	/// Calculates and returns the proof count required for processing one
 	/// inbound message.
+	///
+	/// It is derived from the threshold set for the domain, if any, or requires
+	/// a proof from each router otherwise.
 	pub(crate) fn get_expected_proof_count(
+		domain: Domain,
 		router_ids: &[T::RouterId],
 	) -> Result<u32, DispatchError> {
+		if let Some(router_set) = DomainRouters::<T>::get(domain) {
+			return router_set
+				.threshold
+				.ensure_sub(1)
+				.map_err(|_| Error::<T>::NotEnoughRoutersForDomain.into());
+		}
+
 		let expected_proof_count = router_ids
 			.len()
 			.ensure_sub(1)
@@ -304,18 +419,52 @@ impl<T: Config> Pallet<T> {
 				None => {
 					*storage_entry = Some(new_inbound_entry);
 
+					Self::track_entry_creation(message_hash, router_id);
+
 					Ok::<(), DispatchError>(())
 				}
 				Some(stored_inbound_entry) => {
+					if stored_inbound_entry.session_id() != new_inbound_entry.session_id() {
+						Self::track_entry_creation(message_hash, router_id);
+					}
+
 					stored_inbound_entry.pre_dispatch_update(new_inbound_entry)
 				}
 			}
 		})
 	}
 
+	/// Stores the current time as the creation moment of an inbound entry.
+	pub(crate) fn track_entry_creation(message_hash: MessageHash, router_id: &T::RouterId) {
+		PendingInboundEntryCreatedAt::<T>::insert(message_hash, router_id, T::Time::now());
+	}
+
+	/// Returns `true` if the entry belongs to a previous session or was created
+	/// more than `InboundEntryExpiry` ago.
+	///
+	/// Entries without a creation moment only expire with their session.
+	pub(crate) fn is_entry_expired(
+		message_hash: MessageHash,
+		router_id: &T::RouterId,
+		entry: &InboundEntry<T>,
+		session_id: T::SessionId,
+		now: Seconds,
+	) -> bool {
+		if entry.session_id() != session_id {
+			return true;
+		}
+
+		PendingInboundEntryCreatedAt::<T>::get(message_hash, router_id)
+			.map(|created_at| now >= created_at.saturating_add(T::InboundEntryExpiry::get()))
+			.unwrap_or(false)
+	}
+
 	/// Checks if the number of proofs required for executing one message
 	/// were received, and if so, decreases the counts accordingly and executes
 	/// the message.
+	///
+	/// Only the entries of the message router and of the first routers that
+	/// voted, up to the expected proof count, are decreased.
 	pub(crate) fn execute_if_requirements_are_met(
 		message_hash: MessageHash,
 		router_ids: &[T::RouterId],
@@ -324,37 +473,50 @@ impl<T: Config> Pallet<T> {
 		domain: Domain,
 	) -> DispatchResult {
 		let mut message = None;
-		let mut votes = 0;
+		let mut voters = Vec::new();
 
 		for router_id in router_ids {
 			match PendingInboundEntries::<T>::get(message_hash, router_id) {
-				// We expected one InboundEntry for each router, if that's not the case,
-				// we can return.
-				None => return Ok(()),
-				Some(stored_inbound_entry) => match stored_inbound_entry {
-					InboundEntry::Message(message_entry)
-						if message_entry.session_id == session_id =>
-					{
-						message = Some(message_entry.message)
-					}
-					InboundEntry::Proof(proof_entry)
-						if proof_entry.has_valid_vote_for_session(session_id) =>
-					{
-						votes.ensure_add_assign(1)?;
-					}
-					_ => {}
-				},
+				Some(InboundEntry::Message(message_entry))
+					if message_entry.session_id == session_id =>
+				{
+					message = Some((router_id.clone(), message_entry.message))
+				}
+				Some(InboundEntry::Proof(proof_entry))
+					if proof_entry.has_valid_vote_for_session(session_id) =>
+				{
+					voters.push(router_id.clone());
+				}
+				// Routers without a valid entry are not counted, the threshold
+				// determines whether the received votes are enough.
+				_ => {}
 			};
 		}
 
-		if votes < expected_proof_count {
+		if voters.len() < expected_proof_count.saturated_into::<usize>() {
 			return Ok(());
 		}
 
-		if let Some(msg) = message {
+		if let Some((message_router, msg)) = message {
+			Self::account_rate_limits(domain, &msg)?;
+
 			T::InboundMessageHandler::handle(domain, msg)?;
 
-			Self::execute_post_voting_dispatch(message_hash, router_ids, expected_proof_count)?;
+			let voting_routers = iter::once(message_router)
+				.chain(
+					voters
+						.into_iter()
+						.take(expected_proof_count.saturated_into()),
+				)
+				.collect::<Vec<_>>();
+
+			Self::execute_post_voting_dispatch(
+				message_hash,
+				&voting_routers,
+				expected_proof_count,
+			)?;
+
+			Self::prune_surplus_proofs(message_hash, router_ids, &voting_routers, session_id);
 
 			Self::deposit_event(Event::<T>::InboundMessageExecuted {
 				domain,
@@ -365,6 +527,47 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Adds the amounts of the message to the rate limits set for the domain,
+	/// starting a new period for those whose current period has finished.
+	///
+	/// Fails if any of the limits would be exceeded.
+	pub(crate) fn account_rate_limits(domain: Domain, message: &T::Message) -> DispatchResult {
+		for (key, amount) in message.rate_limited_amounts() {
+			let Some(limit) = RateLimits::<T>::get(domain, &key) else {
+				continue;
+			};
+
+			let now = T::Time::now();
+
+			RateLimitUsages::<T>::try_mutate(domain, &key, |usage| {
+				if now >= usage.period_start.saturating_add(limit.period) {
+					*usage = RateLimitUsage {
+						period_start: now,
+						amount: 0,
+					};
+				}
+
+				usage.amount = usage
+					.amount
+					.checked_add(amount)
+					.filter(|total| *total <= limit.max_amount)
+					.ok_or(Error::<T>::InboundRateLimitExceeded)?;
+
+				Ok::<(), DispatchError>(())
+			})?;
+		}
+
+		Ok(())
+	}
+
+	/// Stops the processing of inbound messages for the domain until it is
+	/// resumed.
+	pub(crate) fn pause_domain(domain: Domain) {
+		PausedDomains::<T>::insert(domain, ());
+
+		Self::deposit_event(Event::<T>::DomainPaused { domain });
+	}
+
 	/// Decreases the counts for inbound entries and removes them if the
 	/// counts reach 0.
 	pub(crate) fn execute_post_voting_dispatch(
@@ -387,6 +590,10 @@ impl<T: Config> Pallet<T> {
 							expected_proof_count,
 						)?;
 
+						if post_dispatch_entry.is_none() {
+							PendingInboundEntryCreatedAt::<T>::remove(message_hash, router_id);
+						}
+
 						*storage_entry = post_dispatch_entry;
 
 						Ok(())
@@ -398,6 +605,88 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Accounts for the proofs of the routers that did not vote for an
+	/// executed message, which happens when the domain threshold is lower
+	/// than the number of routers.
+	///
+	/// A proof already received from such a router is consumed, otherwise
+	/// the proof is expected later and discarded when received.
+	pub(crate) fn prune_surplus_proofs(
+		message_hash: MessageHash,
+		router_ids: &[T::RouterId],
+		voting_routers: &[T::RouterId],
+		session_id: T::SessionId,
+	) {
+		for router_id in router_ids.iter().filter(|id| !voting_routers.contains(id)) {
+			let consumed = PendingInboundEntries::<T>::mutate_exists(
+				message_hash,
+				router_id,
+				|storage_entry| match storage_entry {
+					Some(InboundEntry::Proof(proof_entry))
+						if proof_entry.has_valid_vote_for_session(session_id) =>
+					{
+						proof_entry.current_count.saturating_dec();
+
+						if proof_entry.current_count == 0 {
+							*storage_entry = None;
+							PendingInboundEntryCreatedAt::<T>::remove(message_hash, router_id);
+						}
+
+						true
+					}
+					_ => false,
+				},
+			);
+
+			if consumed {
+				continue;
+			}
+
+			SurplusInboundProofs::<T>::mutate(message_hash, router_id, |surplus| match surplus {
+				Some((proof_entry, _)) if proof_entry.session_id == session_id => {
+					proof_entry.current_count.saturating_inc();
+				}
+				_ => {
+					*surplus = Some((
+						ProofEntry {
+							session_id,
+							current_count: 1,
+						},
+						T::Time::now(),
+					))
+				}
+			});
+		}
+	}
+
+	/// Consumes one of the surplus proofs expected from the router for the
+	/// message, if any, returning whether the received proof must be
+	/// discarded.
+	pub(crate) fn discard_surplus_proof(
+		message_hash: MessageHash,
+		router_id: &T::RouterId,
+		session_id: T::SessionId,
+	) -> bool {
+		SurplusInboundProofs::<T>::mutate_exists(message_hash, router_id, |surplus| {
+			match surplus {
+				Some((proof_entry, _)) if proof_entry.has_valid_vote_for_session(session_id) => {
+					proof_entry.current_count.saturating_dec();
+
+					if proof_entry.current_count == 0 {
+						*surplus = None;
+					}
+
+					true
+				}
+				// Surplus proofs of previous sessions are no longer expected.
+				_ => {
+					*surplus = None;
+					false
+				}
+			}
+		})
+	}
+
 	/// Iterates over a batch of messages and checks if the requirements for
 	/// processing each message are met.
 	pub(crate) fn process_inbound_message(
@@ -405,15 +694,32 @@ impl<T: Config> Pallet<T> {
 		message: T::Message,
 		router_id: T::RouterId,
 	) -> DispatchResult {
+		ensure!(
+			!PausedDomains::<T>::contains_key(domain),
+			Error::<T>::DomainPaused
+		);
+
 		let router_ids = Self::get_router_ids_for_domain(domain)?;
 		let session_id = SessionIdStore::<T>::get();
-		let expected_proof_count = Self::get_expected_proof_count(&router_ids)?;
+		let expected_proof_count = Self::get_expected_proof_count(domain, &router_ids)?;
 		let message_hash = message.get_message_hash();
 		let inbound_entry: InboundEntry<T> =
 			InboundEntry::create(message.clone(), session_id, domain, expected_proof_count);
 
 		inbound_entry.validate(&router_ids, &router_id.clone())?;
 
+		if message.is_proof_message()
+			&& Self::discard_surplus_proof(message_hash, &router_id, session_id)
+		{
+			Self::deposit_event(Event::<T>::InboundProofDiscarded {
+				domain,
+				message_hash,
+				router_id,
+			});
+
+			return Ok(());
+		}
+
 		Self::upsert_pending_entry(message_hash, &router_id, inbound_entry)?;
 
 		Self::deposit_processing_event(domain, message, message_hash, router_id.clone());
@@ -450,14 +756,63 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Stores the message so it can be sent again, removing the oldest
+	/// stored message if the limit is reached.
+	///
+	/// Messages already stored are kept as they are.
+	pub(crate) fn record_outbound_message(
+		sender: T::AccountId,
+		destination: Domain,
+		message: T::Message,
+		router_ids: &[T::RouterId],
+	) {
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::record_outbound_message(),
+			DispatchClass::Mandatory,
+		);
+
+		let message_hash = message.get_message_hash();
+
+		if OutboundRecords::<T>::contains_key(message_hash) {
+			return;
+		}
+
+		let nonce = OutboundRecordNonce::<T>::mutate(|nonce| {
+			nonce.saturating_inc();
+			*nonce
+		});
+
+		OutboundRecordHashes::<T>::insert(nonce, message_hash);
+
+		if let Some(oldest_nonce) = nonce.checked_sub(T::MaxOutboundRecords::get().into()) {
+			if let Some(oldest_hash) = OutboundRecordHashes::<T>::take(oldest_nonce) {
+				OutboundRecords::<T>::remove(oldest_hash);
+			}
+		}
+
+		OutboundRecords::<T>::insert(
+			message_hash,
+			OutboundRecord {
+				sender,
+				domain: destination,
+				message,
+				router_ids: BoundedVec::truncate_from(router_ids.to_vec()),
+				resend_count: 0,
+			},
+		);
+	}
+
 	/// Retrieves the IDs of the routers set for a domain and queues the
 	/// message and proofs accordingly.
 	pub(crate) fn queue_outbound_message(
+		sender: T::AccountId,
 		destination: Domain,
 		message: T::Message,
 	) -> DispatchResult {
 		let router_ids = Self::get_router_ids_for_domain(destination)?;
 
+		Self::record_outbound_message(sender, destination, message.clone(), &router_ids);
+
 		let proof_message = message.to_proof_message();
 		let mut message_opt = Some(message);
 
@@ -481,4 +836,81 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Packs the message into the automatic batch of the sender for the
+	/// domain.
+	///
+	/// If the message can not be packed, because the batch is full, the
+	/// current batch is enqueued and the message starts a new one.
+	pub(crate) fn auto_batch_message(
+		sender: T::AccountId,
+		destination: Domain,
+		message: T::Message,
+	) -> DispatchResult {
+		// Fail early, instead of when the block is finalized.
+		Self::get_router_ids_for_domain(destination)?;
+
+		let full_batch = AutoBatchedMessages::<T>::mutate(destination, &sender, |batch| {
+			match batch {
+				Some(batch) => match batch.pack_with(message.clone()) {
+					Ok(()) => None,
+					Err(_) => Some(sp_std::mem::replace(batch, message)),
+				},
+				None => {
+					// The batch is enqueued when the block is finalized.
+					frame_system::Pallet::<T>::register_extra_weight_unchecked(
+						T::WeightInfo::queue_auto_batched_message(),
+						DispatchClass::Mandatory,
+					);
+
+					*batch = Some(message);
+					None
+				}
+			}
+		});
+
+		match full_batch {
+			Some(full_batch) => Self::queue_outbound_message(sender, destination, full_batch),
+			None => Ok(()),
+		}
+	}
+
+	/// Enqueues all the automatically packed batches.
+	///
+	/// A batch that can not be enqueued is kept, and enqueued again when the
+	/// next block is finalized.
+	pub(crate) fn queue_auto_batched_messages() {
+		for (domain, sender, message) in AutoBatchedMessages::<T>::iter().collect::<Vec<_>>() {
+			let res = with_transaction(|| {
+				let res = Self::queue_outbound_message(sender.clone(), domain, message.clone());
+
+				if res.is_ok() {
+					TransactionOutcome::Commit(res)
+				} else {
+					TransactionOutcome::Rollback(res)
+				}
+			});
+
+			match res {
+				Ok(()) => AutoBatchedMessages::<T>::remove(domain, &sender),
+				Err(error) => Self::deposit_event(Event::<T>::AutoBatchQueueFailed {
+					domain,
+					message,
+					error,
+				}),
+			}
+		}
+	}
+
+	/// Returns the weight of enqueuing the batches that could not be enqueued
+	/// in the previous block.
+	pub(crate) fn pending_auto_batches_weight() -> Weight {
+		let pending: u64 = AutoBatchedMessages::<T>::iter_keys()
+			.count()
+			.saturated_into();
+
+		T::DbWeight::get()
+			.reads(pending.saturating_add(1))
+			.saturating_add(T::WeightInfo::queue_auto_batched_message().saturating_mul(pending))
+	}
 }
@@ -2,8 +2,8 @@ use std::fmt::{Debug, Formatter};
 
 use cfg_mocks::pallet_mock_liquidity_pools;
 use cfg_traits::liquidity_pools::{
-	LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageRecovery, LpMessageSerializer,
-	MessageHash, RouterProvider,
+	LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageRateLimit, LpMessageRecovery,
+	LpMessageSerializer, MessageHash, RouterProvider,
 };
 use cfg_types::{
 	domain_address::{Domain, DomainAddress},
@@ -35,6 +35,8 @@ pub const MAX_PACKED_MESSAGES: usize = 10;
 
 pub const MESSAGE_HASH: [u8; 32] = [1; 32];
 
+pub const RATE_LIMIT_KEY: u8 = 1;
+
 #[derive(Eq, PartialEq, Clone, Encode, Decode, TypeInfo, Hash)]
 pub enum Message {
 	Simple,
@@ -42,6 +44,7 @@ pub enum Message {
 	Proof([u8; 32]),
 	InitiateMessageRecovery(([u8; 32], [u8; 32])),
 	DisputeMessageRecovery(([u8; 32], [u8; 32])),
+	Transfer(u128),
 }
 
 impl Debug for Message {
@@ -137,6 +140,18 @@ impl LpMessageRecovery for Message {
 	}
 }
 
+impl LpMessageRateLimit for Message {
+	type LimitKey = u8;
+
+	fn rate_limited_amounts(&self) -> Vec<(u8, u128)> {
+		match self {
+			Self::Pack(list) => list.iter().flat_map(|m| m.rate_limited_amounts()).collect(),
+			Self::Transfer(amount) => vec![(RATE_LIMIT_KEY, *amount)],
+			_ => vec![],
+		}
+	}
+}
+
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Hash)]
 pub struct RouterId(pub u32);
 
@@ -148,7 +163,7 @@ impl RouterProvider<Domain> for TestRouterProvider {
 	fn routers_for_domain(domain: Domain) -> Vec<Self::RouterId> {
 		match domain {
 			Domain::Centrifuge => vec![],
-			Domain::Evm(_) => vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3],
+			Domain::Evm(_) | Domain::NonEvm(_) => vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3],
 		}
 	}
 }
@@ -165,6 +180,7 @@ frame_support::construct_runtime!(
 		MockLiquidityPools: pallet_mock_liquidity_pools,
 		MockLiquidityPoolsGatewayQueue: cfg_mocks::queue::pallet,
 		MockMessageSender: cfg_mocks::router_message::pallet,
+		MockTime: cfg_mocks::pallet_mock_time,
 		LiquidityPoolsGateway: pallet_liquidity_pools_gateway,
 	}
 );
@@ -186,7 +202,12 @@ impl cfg_mocks::queue::pallet::Config for Runtime {
 	type Message = GatewayMessage<Message, RouterId>;
 }
 
+impl cfg_mocks::pallet_mock_time::Config for Runtime {
+	type Moment = u64;
+}
+
 impl cfg_mocks::router_message::pallet::Config for Runtime {
+	type Fees = u128;
 	type Message = Message;
 	type Middleware = RouterId;
 	type Origin = DomainAddress;
@@ -197,24 +218,37 @@ frame_support::parameter_types! {
 	pub const MaxIncomingMessageSize: u32 = 1024;
 	pub const LpAdminAccount: AccountId32 = LP_ADMIN_ACCOUNT;
 	pub const MaxRouterCount: u32 = 8;
+	pub const MaxOutboundRecords: u32 = 2;
+	pub const MaxOutboundResends: u32 = 1;
+	pub const InboundEntryExpiry: u64 = 100;
+	pub const MessageRecoveryDisputeWindow: u64 = 10;
 }
 
 impl pallet_liquidity_pools_gateway::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId32>;
+	type InboundEntryExpiry = InboundEntryExpiry;
 	type InboundMessageHandler = MockLiquidityPools;
 	type MaxIncomingMessageSize = MaxIncomingMessageSize;
+	type MaxOutboundRecords = MaxOutboundRecords;
+	type MaxOutboundResends = MaxOutboundResends;
 	type MaxRouterCount = MaxRouterCount;
 	type Message = Message;
 	type MessageQueue = MockLiquidityPoolsGatewayQueue;
+	type MessageRecoveryDisputeWindow = MessageRecoveryDisputeWindow;
 	type MessageSender = MockMessageSender;
+	type OutboundFees = u128;
+	type RateLimitKey = u8;
 	type RouterId = RouterId;
 	type RouterProvider = TestRouterProvider;
 	type RuntimeEvent = RuntimeEvent;
 	type Sender = Sender;
 	type SessionId = u32;
+	type Time = MockTime;
 	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	System::externalities()
+	let mut ext = System::externalities();
+	ext.execute_with(|| MockTime::mock_now(|| 0));
+	ext
 }
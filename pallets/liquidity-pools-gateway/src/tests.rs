@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use cfg_primitives::LP_DEFENSIVE_WEIGHT;
 use cfg_traits::liquidity_pools::{LpMessageHash, MessageProcessor, OutboundMessageHandler};
 use cfg_types::domain_address::*;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{Get, Hooks},
+};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use sp_arithmetic::ArithmeticError::{Overflow, Underflow};
@@ -11,6 +14,7 @@ use sp_core::{bounded::BoundedVec, crypto::AccountId32};
 use sp_runtime::{
 	DispatchError,
 	DispatchError::{Arithmetic, BadOrigin},
+	DispatchResult,
 };
 
 use super::{
@@ -18,7 +22,9 @@ use super::{
 	pallet::*,
 };
 use crate::{
-	message_processing::{InboundEntry, MessageEntry, ProofEntry},
+	message_processing::{
+		InboundEntry, MessageEntry, OutboundRecord, ProofEntry, RateLimit, RateLimitUsage,
+	},
 	GatewayMessage,
 };
 
@@ -29,8 +35,8 @@ mod utils {
 		[0u8; 32].into()
 	}
 
-	pub fn get_test_hook_bytes() -> [u8; 20] {
-		[10u8; 20]
+	pub fn get_test_hook_bytes() -> [u8; 32] {
+		[10u8; 32]
 	}
 
 	pub fn event_exists<E: Into<MockEvent>>(e: E) {
@@ -122,6 +128,113 @@ mod extrinsics {
 		}
 	}
 
+	mod set_domain_routers {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				let router_ids =
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap();
+
+				assert_ok!(LiquidityPoolsGateway::set_domain_routers(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					router_ids.clone(),
+					2,
+				));
+
+				let router_set = DomainRouters::<Runtime>::get(TEST_DOMAIN).unwrap();
+				assert_eq!(router_set.router_ids, router_ids);
+				assert_eq!(router_set.threshold, 2);
+				assert_eq!(SessionIdStore::<Runtime>::get(), 1);
+
+				event_exists(Event::<Runtime>::DomainRoutersSet {
+					domain: TEST_DOMAIN,
+					router_ids,
+					threshold: 2,
+					session_id: 1,
+				});
+
+				assert_ok!(LiquidityPoolsGateway::set_domain_routers(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					BoundedVec::default(),
+					0,
+				));
+
+				assert!(DomainRouters::<Runtime>::get(TEST_DOMAIN).is_none());
+				assert_eq!(SessionIdStore::<Runtime>::get(), 2);
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_routers(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap(),
+						1,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn domain_not_supported() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_routers(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap(),
+						1,
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
+
+		#[test]
+		fn invalid_router_set() {
+			new_test_ext().execute_with(|| {
+				let tests = vec![
+					(vec![RouterId(4)], 1, Error::<Runtime>::UnknownRouter),
+					(
+						vec![ROUTER_ID_1, ROUTER_ID_1],
+						1,
+						Error::<Runtime>::DuplicatedRouter,
+					),
+					(
+						vec![ROUTER_ID_1, ROUTER_ID_2],
+						0,
+						Error::<Runtime>::InvalidRouterThreshold,
+					),
+					(
+						vec![ROUTER_ID_1, ROUTER_ID_2],
+						3,
+						Error::<Runtime>::InvalidRouterThreshold,
+					),
+				];
+
+				for (router_ids, threshold, error) in tests {
+					assert_noop!(
+						LiquidityPoolsGateway::set_domain_routers(
+							RuntimeOrigin::root(),
+							TEST_DOMAIN,
+							BoundedVec::try_from(router_ids).unwrap(),
+							threshold,
+						),
+						error
+					);
+				}
+			});
+		}
+	}
+
 	mod set_domain_hook {
 		use super::*;
 
@@ -323,6 +436,7 @@ mod extrinsics {
 					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
 				);
 				SessionIdStore::<Runtime>::set(session_id);
+				ScheduledMessageRecoveries::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 0);
 
 				PendingInboundEntries::<Runtime>::insert(
 					MESSAGE_HASH,
@@ -358,6 +472,9 @@ mod extrinsics {
 
 				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1).is_none());
 				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_none());
+				assert!(
+					ScheduledMessageRecoveries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_none()
+				);
 			});
 		}
 
@@ -370,6 +487,7 @@ mod extrinsics {
 					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
 				);
 				SessionIdStore::<Runtime>::set(session_id);
+				ScheduledMessageRecoveries::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 0);
 
 				PendingInboundEntries::<Runtime>::insert(
 					MESSAGE_HASH,
@@ -474,6 +592,7 @@ mod extrinsics {
 					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
 				);
 				SessionIdStore::<Runtime>::set(session_id);
+				ScheduledMessageRecoveries::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 0);
 				PendingInboundEntries::<Runtime>::insert(
 					MESSAGE_HASH,
 					ROUTER_ID_2,
@@ -504,6 +623,7 @@ mod extrinsics {
 					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
 				);
 				SessionIdStore::<Runtime>::set(session_id);
+				ScheduledMessageRecoveries::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 0);
 				PendingInboundEntries::<Runtime>::insert(
 					MESSAGE_HASH,
 					ROUTER_ID_2,
@@ -526,190 +646,188 @@ mod extrinsics {
 				);
 			});
 		}
-	}
 
-	mod initiate_message_recovery {
-		use super::*;
+		#[test]
+		fn not_scheduled() {
+			new_test_ext().execute_with(|| {
+				Routers::<Runtime>::set(
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				);
+
+				assert_noop!(
+					LiquidityPoolsGateway::execute_message_recovery(
+						RuntimeOrigin::root(),
+						TEST_DOMAIN,
+						MESSAGE_HASH,
+						ROUTER_ID_2
+					),
+					Error::<Runtime>::MessageRecoveryNotScheduled
+				);
+			});
+		}
 
 		#[test]
-		fn success() {
+		fn dispute_window_not_elapsed() {
 			new_test_ext().execute_with(|| {
-				let recovery_router = [1u8; 32];
+				Routers::<Runtime>::set(
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				);
 
-				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+				assert_ok!(LiquidityPoolsGateway::schedule_message_recovery(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					MESSAGE_HASH,
+					ROUTER_ID_2
+				));
 
-				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
-					assert_eq!(mock_router_id, ROUTER_ID_1);
-					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
-					assert_eq!(
-						mock_message,
-						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
-					);
+				MockTime::mock_now(|| (MessageRecoveryDisputeWindow::get() - 1) * 1000);
 
-					Ok(())
-				});
+				assert_noop!(
+					LiquidityPoolsGateway::execute_message_recovery(
+						RuntimeOrigin::root(),
+						TEST_DOMAIN,
+						MESSAGE_HASH,
+						ROUTER_ID_2
+					),
+					Error::<Runtime>::MessageRecoveryDisputeWindowNotElapsed
+				);
 
-				assert_ok!(LiquidityPoolsGateway::initiate_message_recovery(
+				MockTime::mock_now(|| MessageRecoveryDisputeWindow::get() * 1000);
+
+				assert_ok!(LiquidityPoolsGateway::execute_message_recovery(
 					RuntimeOrigin::root(),
+					TEST_DOMAIN,
 					MESSAGE_HASH,
-					recovery_router,
-					ROUTER_ID_1,
+					ROUTER_ID_2
 				));
-
-				event_exists(Event::<Runtime>::MessageRecoveryInitiated {
-					domain: TEST_DOMAIN,
-					message_hash: MESSAGE_HASH,
-					recovery_router,
-					messaging_router: ROUTER_ID_1,
-				})
 			});
 		}
+	}
+
+	mod schedule_message_recovery {
+		use super::*;
 
 		#[test]
-		fn bad_origin() {
+		fn success() {
 			new_test_ext().execute_with(|| {
-				let recovery_router = [1u8; 32];
+				Routers::<Runtime>::set(
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				);
+				MockTime::mock_now(|| 5_000);
 
-				assert_noop!(
-					LiquidityPoolsGateway::initiate_message_recovery(
-						RuntimeOrigin::signed(AccountId32::new([0u8; 32])),
-						MESSAGE_HASH,
-						recovery_router,
-						ROUTER_ID_1,
-					),
-					BadOrigin
+				assert_ok!(LiquidityPoolsGateway::schedule_message_recovery(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					MESSAGE_HASH,
+					ROUTER_ID_2
+				));
+
+				let executable_at = 5 + MessageRecoveryDisputeWindow::get();
+
+				assert_eq!(
+					ScheduledMessageRecoveries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2),
+					Some(executable_at)
 				);
+
+				event_exists(Event::<Runtime>::MessageRecoveryScheduled {
+					domain: TEST_DOMAIN,
+					message_hash: MESSAGE_HASH,
+					router_id: ROUTER_ID_2,
+					executable_at,
+				});
 			});
 		}
 
 		#[test]
-		fn not_enough_routers_for_domain() {
+		fn bad_origin() {
 			new_test_ext().execute_with(|| {
-				let recovery_router = [1u8; 32];
-
 				assert_noop!(
-					LiquidityPoolsGateway::initiate_message_recovery(
-						RuntimeOrigin::root(),
+					LiquidityPoolsGateway::schedule_message_recovery(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
 						MESSAGE_HASH,
-						recovery_router,
-						ROUTER_ID_1,
+						ROUTER_ID_2
 					),
-					Error::<Runtime>::NotEnoughRoutersForDomain,
+					BadOrigin
 				);
 			});
 		}
 
 		#[test]
-		fn messaging_router_not_found() {
+		fn unknown_router() {
 			new_test_ext().execute_with(|| {
-				let recovery_router = [1u8; 32];
-
-				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
-
-				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
-					assert_eq!(mock_router_id, ROUTER_ID_1);
-					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
-					assert_eq!(
-						mock_message,
-						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
-					);
-
-					Ok(())
-				});
+				Routers::<Runtime>::set(
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				);
 
 				assert_noop!(
-					LiquidityPoolsGateway::initiate_message_recovery(
+					LiquidityPoolsGateway::schedule_message_recovery(
 						RuntimeOrigin::root(),
+						TEST_DOMAIN,
 						MESSAGE_HASH,
-						recovery_router,
-						RouterId(4),
+						ROUTER_ID_3
 					),
-					Error::<Runtime>::MessagingRouterNotFound,
+					Error::<Runtime>::UnknownRouter
 				);
 			});
 		}
 
 		#[test]
-		fn message_sender_error() {
+		fn already_scheduled() {
 			new_test_ext().execute_with(|| {
-				let recovery_router = [1u8; 32];
-
-				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
-
-				let err = DispatchError::Unavailable;
-
-				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
-					assert_eq!(mock_router_id, ROUTER_ID_1);
-					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
-					assert_eq!(
-						mock_message,
-						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
-					);
-
-					Err(err)
-				});
+				Routers::<Runtime>::set(
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				);
+				ScheduledMessageRecoveries::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 0);
 
 				assert_noop!(
-					LiquidityPoolsGateway::initiate_message_recovery(
+					LiquidityPoolsGateway::schedule_message_recovery(
 						RuntimeOrigin::root(),
+						TEST_DOMAIN,
 						MESSAGE_HASH,
-						recovery_router,
-						ROUTER_ID_1,
+						ROUTER_ID_2
 					),
-					err
+					Error::<Runtime>::MessageRecoveryAlreadyScheduled
 				);
 			});
 		}
 	}
 
-	mod dispute_message_recovery {
+	mod cancel_message_recovery {
 		use super::*;
 
 		#[test]
 		fn success() {
 			new_test_ext().execute_with(|| {
-				let recovery_router = [1u8; 32];
-
-				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
-
-				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
-					assert_eq!(mock_router_id, ROUTER_ID_1);
-					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
-					assert_eq!(
-						mock_message,
-						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
-					);
-
-					Ok(())
-				});
+				ScheduledMessageRecoveries::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 0);
 
-				assert_ok!(LiquidityPoolsGateway::dispute_message_recovery(
+				assert_ok!(LiquidityPoolsGateway::cancel_message_recovery(
 					RuntimeOrigin::root(),
 					MESSAGE_HASH,
-					recovery_router,
-					ROUTER_ID_1,
+					ROUTER_ID_2
 				));
 
-				event_exists(Event::<Runtime>::MessageRecoveryDisputed {
-					domain: TEST_DOMAIN,
+				assert!(
+					ScheduledMessageRecoveries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_none()
+				);
+
+				event_exists(Event::<Runtime>::MessageRecoveryCancelled {
 					message_hash: MESSAGE_HASH,
-					recovery_router,
-					messaging_router: ROUTER_ID_1,
-				})
+					router_id: ROUTER_ID_2,
+				});
 			});
 		}
 
 		#[test]
 		fn bad_origin() {
 			new_test_ext().execute_with(|| {
-				let recovery_router = [1u8; 32];
+				ScheduledMessageRecoveries::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 0);
 
 				assert_noop!(
-					LiquidityPoolsGateway::dispute_message_recovery(
-						RuntimeOrigin::signed(AccountId32::new([0u8; 32])),
+					LiquidityPoolsGateway::cancel_message_recovery(
+						RuntimeOrigin::signed(get_test_account_id()),
 						MESSAGE_HASH,
-						recovery_router,
-						ROUTER_ID_1,
+						ROUTER_ID_2
 					),
 					BadOrigin
 				);
@@ -717,12 +835,210 @@ mod extrinsics {
 		}
 
 		#[test]
-		fn not_enough_routers_for_domain() {
+		fn not_scheduled() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::cancel_message_recovery(
+						RuntimeOrigin::root(),
+						MESSAGE_HASH,
+						ROUTER_ID_2
+					),
+					Error::<Runtime>::MessageRecoveryNotScheduled
+				);
+			});
+		}
+	}
+
+	mod remove_expired_inbound_entries {
+		use super::*;
+
+		fn insert_entries(session_id: u32) {
+			PendingInboundEntries::<Runtime>::insert(
+				MESSAGE_HASH,
+				ROUTER_ID_1,
+				InboundEntry::Message(MessageEntry {
+					session_id,
+					domain: TEST_DOMAIN,
+					message: Message::Simple,
+					expected_proof_count: 1,
+				}),
+			);
+			PendingInboundEntryCreatedAt::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_1, 0);
+
+			PendingInboundEntries::<Runtime>::insert(
+				MESSAGE_HASH,
+				ROUTER_ID_2,
+				InboundEntry::Proof(ProofEntry {
+					session_id: TEST_SESSION_ID,
+					current_count: 1,
+				}),
+			);
+			PendingInboundEntryCreatedAt::<Runtime>::insert(MESSAGE_HASH, ROUTER_ID_2, 10);
+		}
+
+		#[test]
+		fn removes_entries_of_previous_sessions() {
+			new_test_ext().execute_with(|| {
+				SessionIdStore::<Runtime>::set(TEST_SESSION_ID);
+				insert_entries(TEST_SESSION_ID - 1);
+
+				assert_ok!(LiquidityPoolsGateway::remove_expired_inbound_entries(
+					RuntimeOrigin::signed(get_test_account_id()),
+					MESSAGE_HASH,
+				));
+
+				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1).is_none());
+				assert!(
+					PendingInboundEntryCreatedAt::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1)
+						.is_none()
+				);
+				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_some());
+
+				event_exists(Event::<Runtime>::InboundEntryRemoved {
+					message_hash: MESSAGE_HASH,
+					router_id: ROUTER_ID_1,
+				});
+			});
+		}
+
+		#[test]
+		fn removes_entries_older_than_expiry() {
+			new_test_ext().execute_with(|| {
+				SessionIdStore::<Runtime>::set(TEST_SESSION_ID);
+				insert_entries(TEST_SESSION_ID);
+
+				MockTime::mock_now(|| InboundEntryExpiry::get() * 1000);
+
+				assert_ok!(LiquidityPoolsGateway::remove_expired_inbound_entries(
+					RuntimeOrigin::signed(get_test_account_id()),
+					MESSAGE_HASH,
+				));
+
+				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1).is_none());
+				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_some());
+			});
+		}
+
+		#[test]
+		fn no_expired_entries() {
+			new_test_ext().execute_with(|| {
+				SessionIdStore::<Runtime>::set(TEST_SESSION_ID);
+				insert_entries(TEST_SESSION_ID);
+
+				MockTime::mock_now(|| (InboundEntryExpiry::get() - 1) * 1000);
+
+				assert_noop!(
+					LiquidityPoolsGateway::remove_expired_inbound_entries(
+						RuntimeOrigin::signed(get_test_account_id()),
+						MESSAGE_HASH,
+					),
+					Error::<Runtime>::NoExpiredInboundEntries
+				);
+			});
+		}
+
+		#[test]
+		fn creation_is_tracked_when_processing() {
+			new_test_ext().execute_with(|| {
+				Routers::<Runtime>::set(
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				);
+				SessionIdStore::<Runtime>::set(TEST_SESSION_ID);
+				MockTime::mock_now(|| 7_000);
+
+				let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Inbound {
+					domain: TEST_DOMAIN,
+					message: Message::Simple,
+					router_id: ROUTER_ID_1,
+				});
+				assert_ok!(res);
+
+				assert_eq!(
+					PendingInboundEntryCreatedAt::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1),
+					Some(7)
+				);
+
+				MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Inbound {
+					domain: TEST_DOMAIN,
+					message: Message::Proof(MESSAGE_HASH),
+					router_id: ROUTER_ID_2,
+				});
+				assert_ok!(res);
+
+				assert!(
+					PendingInboundEntryCreatedAt::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1)
+						.is_none()
+				);
+				assert!(
+					PendingInboundEntryCreatedAt::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2)
+						.is_none()
+				);
+			});
+		}
+	}
+	mod initiate_message_recovery {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				let recovery_router = [1u8; 32];
+
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+
+				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
+					assert_eq!(mock_router_id, ROUTER_ID_1);
+					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
+					assert_eq!(
+						mock_message,
+						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
+					);
+
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::initiate_message_recovery(
+					RuntimeOrigin::root(),
+					MESSAGE_HASH,
+					recovery_router,
+					ROUTER_ID_1,
+				));
+
+				event_exists(Event::<Runtime>::MessageRecoveryInitiated {
+					domain: TEST_DOMAIN,
+					message_hash: MESSAGE_HASH,
+					recovery_router,
+					messaging_router: ROUTER_ID_1,
+				})
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
 			new_test_ext().execute_with(|| {
 				let recovery_router = [1u8; 32];
 
 				assert_noop!(
-					LiquidityPoolsGateway::dispute_message_recovery(
+					LiquidityPoolsGateway::initiate_message_recovery(
+						RuntimeOrigin::signed(AccountId32::new([0u8; 32])),
+						MESSAGE_HASH,
+						recovery_router,
+						ROUTER_ID_1,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn not_enough_routers_for_domain() {
+			new_test_ext().execute_with(|| {
+				let recovery_router = [1u8; 32];
+
+				assert_noop!(
+					LiquidityPoolsGateway::initiate_message_recovery(
 						RuntimeOrigin::root(),
 						MESSAGE_HASH,
 						recovery_router,
@@ -745,14 +1061,14 @@ mod extrinsics {
 					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
 					assert_eq!(
 						mock_message,
-						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
+						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
 					Ok(())
 				});
 
 				assert_noop!(
-					LiquidityPoolsGateway::dispute_message_recovery(
+					LiquidityPoolsGateway::initiate_message_recovery(
 						RuntimeOrigin::root(),
 						MESSAGE_HASH,
 						recovery_router,
@@ -777,14 +1093,14 @@ mod extrinsics {
 					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
 					assert_eq!(
 						mock_message,
-						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
+						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
 					Err(err)
 				});
 
 				assert_noop!(
-					LiquidityPoolsGateway::dispute_message_recovery(
+					LiquidityPoolsGateway::initiate_message_recovery(
 						RuntimeOrigin::root(),
 						MESSAGE_HASH,
 						recovery_router,
@@ -795,104 +1111,847 @@ mod extrinsics {
 			});
 		}
 	}
-}
-
-mod implementations {
-	use super::*;
 
-	mod outbound_message_handler {
+	mod dispute_message_recovery {
 		use super::*;
 
 		#[test]
 		fn success() {
 			new_test_ext().execute_with(|| {
-				let domain = Domain::Evm(0);
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
-				let message_hash = msg.get_message_hash();
+				let recovery_router = [1u8; 32];
 
-				assert_ok!(LiquidityPoolsGateway::set_routers(
-					RuntimeOrigin::root(),
-					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
-				));
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
 
-				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
-					match mock_msg {
-						GatewayMessage::Inbound { .. } => {
-							assert!(false, "expected outbound message")
-						}
-						GatewayMessage::Outbound { message, .. } => match message {
-							Message::Proof(p) => {
-								assert_eq!(p, message_hash);
-							}
-							_ => {}
-						},
-					}
+				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
+					assert_eq!(mock_router_id, ROUTER_ID_1);
+					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
+					assert_eq!(
+						mock_message,
+						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
+					);
 
 					Ok(())
 				});
 
-				assert_ok!(LiquidityPoolsGateway::handle(sender, domain, msg));
-				assert_eq!(handler.times(), 3);
+				assert_ok!(LiquidityPoolsGateway::dispute_message_recovery(
+					RuntimeOrigin::root(),
+					MESSAGE_HASH,
+					recovery_router,
+					ROUTER_ID_1,
+				));
+
+				event_exists(Event::<Runtime>::MessageRecoveryDisputed {
+					domain: TEST_DOMAIN,
+					message_hash: MESSAGE_HASH,
+					recovery_router,
+					messaging_router: ROUTER_ID_1,
+				})
 			});
 		}
 
 		#[test]
-		fn domain_not_supported() {
+		fn bad_origin() {
 			new_test_ext().execute_with(|| {
-				let domain = Domain::Centrifuge;
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
+				let recovery_router = [1u8; 32];
+
+				assert_noop!(
+					LiquidityPoolsGateway::dispute_message_recovery(
+						RuntimeOrigin::signed(AccountId32::new([0u8; 32])),
+						MESSAGE_HASH,
+						recovery_router,
+						ROUTER_ID_1,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn not_enough_routers_for_domain() {
+			new_test_ext().execute_with(|| {
+				let recovery_router = [1u8; 32];
+
+				assert_noop!(
+					LiquidityPoolsGateway::dispute_message_recovery(
+						RuntimeOrigin::root(),
+						MESSAGE_HASH,
+						recovery_router,
+						ROUTER_ID_1,
+					),
+					Error::<Runtime>::NotEnoughRoutersForDomain,
+				);
+			});
+		}
+
+		#[test]
+		fn messaging_router_not_found() {
+			new_test_ext().execute_with(|| {
+				let recovery_router = [1u8; 32];
+
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+
+				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
+					assert_eq!(mock_router_id, ROUTER_ID_1);
+					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
+					assert_eq!(
+						mock_message,
+						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
+					);
+
+					Ok(())
+				});
+
+				assert_noop!(
+					LiquidityPoolsGateway::dispute_message_recovery(
+						RuntimeOrigin::root(),
+						MESSAGE_HASH,
+						recovery_router,
+						RouterId(4),
+					),
+					Error::<Runtime>::MessagingRouterNotFound,
+				);
+			});
+		}
+
+		#[test]
+		fn message_sender_error() {
+			new_test_ext().execute_with(|| {
+				let recovery_router = [1u8; 32];
+
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+
+				let err = DispatchError::Unavailable;
+
+				MockMessageSender::mock_send(move |mock_router_id, mock_sender, mock_message| {
+					assert_eq!(mock_router_id, ROUTER_ID_1);
+					assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
+					assert_eq!(
+						mock_message,
+						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
+					);
+
+					Err(err)
+				});
+
+				assert_noop!(
+					LiquidityPoolsGateway::dispute_message_recovery(
+						RuntimeOrigin::root(),
+						MESSAGE_HASH,
+						recovery_router,
+						ROUTER_ID_1,
+					),
+					err
+				);
+			});
+		}
+	}
+
+	mod resend_outbound_message {
+		use super::*;
+
+		const FEES: u128 = 10;
+
+		fn send_outbound_message() {
+			Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap());
+
+			MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+			assert_ok!(LiquidityPoolsGateway::handle(
+				get_test_account_id(),
+				TEST_DOMAIN,
+				Message::Simple
+			));
+		}
+
+		#[test]
+		fn success_with_fees() {
+			new_test_ext().execute_with(|| {
+				send_outbound_message();
+
+				assert_eq!(
+					OutboundRecords::<Runtime>::get(MESSAGE_HASH),
+					Some(OutboundRecord {
+						sender: get_test_account_id(),
+						domain: TEST_DOMAIN,
+						message: Message::Simple,
+						router_ids: BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+						resend_count: 0,
+					})
+				);
+
+				let handler = MockMessageSender::mock_send_with_fees(
+					|mock_router_id, mock_sender, mock_message, mock_fees| {
+						assert_eq!(mock_router_id, ROUTER_ID_2);
+						assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
+						assert_eq!(mock_message, Message::Proof(MESSAGE_HASH));
+						assert_eq!(mock_fees, FEES);
+
+						Ok(())
+					},
+				);
+
+				assert_ok!(LiquidityPoolsGateway::resend_outbound_message(
+					RuntimeOrigin::root(),
+					MESSAGE_HASH,
+					ROUTER_ID_2,
+					Some(FEES),
+				));
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(
+					OutboundRecords::<Runtime>::get(MESSAGE_HASH)
+						.unwrap()
+						.resend_count,
+					1
+				);
+
+				event_exists(Event::<Runtime>::OutboundMessageResent {
+					domain: TEST_DOMAIN,
+					message_hash: MESSAGE_HASH,
+					router_id: ROUTER_ID_2,
+				});
+			});
+		}
+
+		#[test]
+		fn success_without_fees() {
+			new_test_ext().execute_with(|| {
+				send_outbound_message();
+
+				let handler = MockMessageSender::mock_send(|mock_router_id, _, mock_message| {
+					assert_eq!(mock_router_id, ROUTER_ID_1);
+					assert_eq!(mock_message, Message::Simple);
+
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::resend_outbound_message(
+					RuntimeOrigin::root(),
+					MESSAGE_HASH,
+					ROUTER_ID_1,
+					None,
+				));
+
+				assert_eq!(handler.times(), 1);
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				send_outbound_message();
+
+				assert_noop!(
+					LiquidityPoolsGateway::resend_outbound_message(
+						RuntimeOrigin::signed(get_test_account_id()),
+						MESSAGE_HASH,
+						ROUTER_ID_1,
+						None,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn max_resends_reached() {
+			new_test_ext().execute_with(|| {
+				send_outbound_message();
+
+				MockMessageSender::mock_send(|_, _, _| Ok(()));
+
+				assert_ok!(LiquidityPoolsGateway::resend_outbound_message(
+					RuntimeOrigin::root(),
+					MESSAGE_HASH,
+					ROUTER_ID_1,
+					None,
+				));
+
+				assert_noop!(
+					LiquidityPoolsGateway::resend_outbound_message(
+						RuntimeOrigin::root(),
+						MESSAGE_HASH,
+						ROUTER_ID_1,
+						None,
+					),
+					Error::<Runtime>::MaxOutboundResendsReached
+				);
+			});
+		}
+
+		#[test]
+		fn outbound_record_not_found() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::resend_outbound_message(
+						RuntimeOrigin::root(),
+						MESSAGE_HASH,
+						ROUTER_ID_1,
+						None,
+					),
+					Error::<Runtime>::OutboundRecordNotFound
+				);
+			});
+		}
+
+		#[test]
+		fn unknown_router() {
+			new_test_ext().execute_with(|| {
+				send_outbound_message();
+
+				assert_noop!(
+					LiquidityPoolsGateway::resend_outbound_message(
+						RuntimeOrigin::root(),
+						MESSAGE_HASH,
+						ROUTER_ID_3,
+						None,
+					),
+					Error::<Runtime>::UnknownRouter
+				);
+			});
+		}
+
+		#[test]
+		fn oldest_record_is_removed() {
+			new_test_ext().execute_with(|| {
+				let old_hashes = [[2; 32], [3; 32]];
+
+				for (index, hash) in old_hashes.into_iter().enumerate() {
+					OutboundRecords::<Runtime>::insert(
+						hash,
+						OutboundRecord {
+							sender: get_test_account_id(),
+							domain: TEST_DOMAIN,
+							message: Message::Simple,
+							router_ids: BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap(),
+							resend_count: 0,
+						},
+					);
+					OutboundRecordHashes::<Runtime>::insert(index as u64 + 1, hash);
+				}
+				OutboundRecordNonce::<Runtime>::set(2);
+
+				send_outbound_message();
+
+				assert_eq!(OutboundRecordNonce::<Runtime>::get(), 3);
+				assert_eq!(OutboundRecordHashes::<Runtime>::get(1), None);
+				assert_eq!(OutboundRecordHashes::<Runtime>::get(3), Some(MESSAGE_HASH));
+				assert!(OutboundRecords::<Runtime>::get([2; 32]).is_none());
+				assert!(OutboundRecords::<Runtime>::get([3; 32]).is_some());
+				assert!(OutboundRecords::<Runtime>::get(MESSAGE_HASH).is_some());
+			});
+		}
+	}
+
+	mod set_rate_limit {
+		use super::*;
+
+		const LIMIT: RateLimit = RateLimit {
+			max_amount: 100,
+			period: 3600,
+		};
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_rate_limit(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					RATE_LIMIT_KEY,
+					Some(LIMIT),
+				));
+
+				assert_eq!(
+					RateLimits::<Runtime>::get(TEST_DOMAIN, RATE_LIMIT_KEY),
+					Some(LIMIT)
+				);
+
+				event_exists(Event::<Runtime>::RateLimitSet {
+					domain: TEST_DOMAIN,
+					key: RATE_LIMIT_KEY,
+					limit: Some(LIMIT),
+				});
+			});
+		}
+
+		#[test]
+		fn removes_limit_and_usage() {
+			new_test_ext().execute_with(|| {
+				RateLimits::<Runtime>::insert(TEST_DOMAIN, RATE_LIMIT_KEY, LIMIT);
+				RateLimitUsages::<Runtime>::insert(
+					TEST_DOMAIN,
+					RATE_LIMIT_KEY,
+					RateLimitUsage {
+						period_start: 1,
+						amount: 10,
+					},
+				);
+
+				assert_ok!(LiquidityPoolsGateway::set_rate_limit(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					RATE_LIMIT_KEY,
+					None,
+				));
+
+				assert!(RateLimits::<Runtime>::get(TEST_DOMAIN, RATE_LIMIT_KEY).is_none());
+				assert_eq!(
+					RateLimitUsages::<Runtime>::get(TEST_DOMAIN, RATE_LIMIT_KEY),
+					RateLimitUsage::default()
+				);
+
+				event_exists(Event::<Runtime>::RateLimitSet {
+					domain: TEST_DOMAIN,
+					key: RATE_LIMIT_KEY,
+					limit: None,
+				});
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_rate_limit(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						RATE_LIMIT_KEY,
+						Some(LIMIT),
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn unsupported_domain() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_rate_limit(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						RATE_LIMIT_KEY,
+						Some(LIMIT),
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
+	}
+
+	mod resume_domain {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				PausedDomains::<Runtime>::insert(TEST_DOMAIN, ());
+
+				assert_ok!(LiquidityPoolsGateway::resume_domain(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+				));
+
+				assert!(!PausedDomains::<Runtime>::contains_key(TEST_DOMAIN));
+
+				event_exists(Event::<Runtime>::DomainResumed {
+					domain: TEST_DOMAIN,
+				});
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				PausedDomains::<Runtime>::insert(TEST_DOMAIN, ());
+
+				assert_noop!(
+					LiquidityPoolsGateway::resume_domain(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn domain_not_paused() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::resume_domain(RuntimeOrigin::root(), TEST_DOMAIN),
+					Error::<Runtime>::DomainNotPaused
+				);
+			});
+		}
+	}
+
+	mod set_auto_batching {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_auto_batching(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					true,
+				));
+
+				assert!(AutoBatchingDomains::<Runtime>::contains_key(TEST_DOMAIN));
+
+				event_exists(Event::<Runtime>::AutoBatchingSet {
+					domain: TEST_DOMAIN,
+					enabled: true,
+				});
+
+				assert_ok!(LiquidityPoolsGateway::set_auto_batching(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					false,
+				));
+
+				assert!(!AutoBatchingDomains::<Runtime>::contains_key(TEST_DOMAIN));
+
+				event_exists(Event::<Runtime>::AutoBatchingSet {
+					domain: TEST_DOMAIN,
+					enabled: false,
+				});
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_auto_batching(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						true,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn unsupported_domain() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_auto_batching(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						true,
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
+	}
+}
+
+mod implementations {
+	use super::*;
+
+	mod outbound_message_handler {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
+				let message_hash = msg.get_message_hash();
+
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+				));
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
+					match mock_msg {
+						GatewayMessage::Inbound { .. } => {
+							assert!(false, "expected outbound message")
+						}
+						GatewayMessage::Outbound { message, .. } => match message {
+							Message::Proof(p) => {
+								assert_eq!(p, message_hash);
+							}
+							_ => {}
+						},
+					}
+
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::handle(sender, domain, msg));
+				assert_eq!(handler.times(), 3);
+			});
+		}
+
+		#[test]
+		fn domain_not_supported() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Centrifuge;
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
+
+				assert_noop!(
+					LiquidityPoolsGateway::handle(sender, domain, msg),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
+
+		#[test]
+		fn routers_not_found() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
+
+				assert_noop!(
+					LiquidityPoolsGateway::handle(sender, domain, msg),
+					Error::<Runtime>::NotEnoughRoutersForDomain
+				);
+			});
+		}
+
+		#[test]
+		fn message_queue_error() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
+
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+				));
+
+				let gateway_message = GatewayMessage::Outbound {
+					message: msg.clone(),
+					router_id: ROUTER_ID_1,
+				};
+
+				let err = DispatchError::Unavailable;
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
+					assert_eq!(mock_msg, gateway_message);
+
+					Err(err)
+				});
+
+				assert_noop!(LiquidityPoolsGateway::handle(sender, domain, msg), err);
+				assert_eq!(handler.times(), 1);
+			});
+		}
+
+		mod auto_batching {
+			use super::*;
+
+			const USER: AccountId32 = AccountId32::new([1; 32]);
+			const OTHER: AccountId32 = AccountId32::new([2; 32]);
+
+			fn setup() {
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				));
+
+				AutoBatchingDomains::<Runtime>::insert(TEST_DOMAIN, ());
+			}
+
+			#[test]
+			fn queued_at_block_end() {
+				new_test_ext().execute_with(|| {
+					setup();
+
+					let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+					(0..3).for_each(|_| {
+						assert_ok!(LiquidityPoolsGateway::handle(
+							USER,
+							TEST_DOMAIN,
+							Message::Simple
+						));
+					});
+
+					// Not batched, automatic batching is not enabled for EVM 2
+					assert_ok!(LiquidityPoolsGateway::handle(
+						USER,
+						Domain::Evm(2),
+						Message::Simple
+					));
+					assert_eq!(handler.times(), 2);
+
+					assert_eq!(
+						AutoBatchedMessages::<Runtime>::get(TEST_DOMAIN, USER),
+						Some(Message::Pack(vec![Message::Simple; 3]))
+					);
+
+					let batch_hash = Message::Pack(vec![Message::Simple; 3]).get_message_hash();
+					let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
+						match mock_msg {
+							GatewayMessage::Outbound {
+								message: Message::Pack(list),
+								router_id,
+							} => {
+								assert_eq!(router_id, ROUTER_ID_1);
+								assert_eq!(list, vec![Message::Simple; 3]);
+							}
+							GatewayMessage::Outbound {
+								message: Message::Proof(hash),
+								router_id,
+							} => {
+								assert_eq!(router_id, ROUTER_ID_2);
+								assert_eq!(hash, batch_hash);
+							}
+							_ => assert!(false, "expected outbound batch or proof"),
+						}
+
+						Ok(())
+					});
+
+					LiquidityPoolsGateway::on_finalize(1);
+
+					// One batch and one proof
+					assert_eq!(handler.times(), 2);
+					assert_eq!(AutoBatchedMessages::<Runtime>::iter().count(), 0);
+				});
+			}
+
+			#[test]
+			fn batched_per_sender() {
+				new_test_ext().execute_with(|| {
+					setup();
+
+					let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+					assert_ok!(LiquidityPoolsGateway::handle(
+						USER,
+						TEST_DOMAIN,
+						Message::Simple
+					));
+					assert_ok!(LiquidityPoolsGateway::handle(
+						OTHER,
+						TEST_DOMAIN,
+						Message::Simple
+					));
+					assert_eq!(handler.times(), 0);
+
+					LiquidityPoolsGateway::on_finalize(1);
+
+					// A message and a proof for each sender
+					assert_eq!(handler.times(), 4);
+				});
+			}
+
+			#[test]
+			fn full_batch_queued() {
+				new_test_ext().execute_with(|| {
+					setup();
+
+					let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+					(0..MAX_PACKED_MESSAGES + 1).for_each(|_| {
+						assert_ok!(LiquidityPoolsGateway::handle(
+							USER,
+							TEST_DOMAIN,
+							Message::Simple
+						));
+					});
+
+					// The full batch was queued, the last message started a new one
+					assert_eq!(handler.times(), 2);
+					assert_eq!(
+						AutoBatchedMessages::<Runtime>::get(TEST_DOMAIN, USER),
+						Some(Message::Simple)
+					);
+
+					LiquidityPoolsGateway::on_finalize(1);
+
+					assert_eq!(handler.times(), 4);
+				});
+			}
+
+			#[test]
+			fn explicit_batch_takes_precedence() {
+				new_test_ext().execute_with(|| {
+					setup();
+
+					let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+					assert_ok!(LiquidityPoolsGateway::start_batch_message(
+						RuntimeOrigin::signed(USER),
+						TEST_DOMAIN
+					));
+					assert_ok!(LiquidityPoolsGateway::handle(
+						USER,
+						TEST_DOMAIN,
+						Message::Simple
+					));
+
+					assert!(AutoBatchedMessages::<Runtime>::get(TEST_DOMAIN, USER).is_none());
+
+					LiquidityPoolsGateway::on_finalize(1);
+
+					assert_eq!(handler.times(), 0);
+				});
+			}
 
-				assert_noop!(
-					LiquidityPoolsGateway::handle(sender, domain, msg),
-					Error::<Runtime>::DomainNotSupported
-				);
-			});
-		}
+			#[test]
+			fn routers_not_found() {
+				new_test_ext().execute_with(|| {
+					AutoBatchingDomains::<Runtime>::insert(TEST_DOMAIN, ());
 
-		#[test]
-		fn routers_not_found() {
-			new_test_ext().execute_with(|| {
-				let domain = Domain::Evm(0);
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
+					assert_noop!(
+						LiquidityPoolsGateway::handle(USER, TEST_DOMAIN, Message::Simple),
+						Error::<Runtime>::NotEnoughRoutersForDomain
+					);
+				});
+			}
 
-				assert_noop!(
-					LiquidityPoolsGateway::handle(sender, domain, msg),
-					Error::<Runtime>::NotEnoughRoutersForDomain
-				);
-			});
-		}
+			#[test]
+			fn queue_error() {
+				new_test_ext().execute_with(|| {
+					setup();
 
-		#[test]
-		fn message_queue_error() {
-			new_test_ext().execute_with(|| {
-				let domain = Domain::Evm(0);
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
+					assert_ok!(LiquidityPoolsGateway::handle(
+						USER,
+						TEST_DOMAIN,
+						Message::Simple
+					));
 
-				assert_ok!(LiquidityPoolsGateway::set_routers(
-					RuntimeOrigin::root(),
-					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
-				));
+					let err = DispatchError::Unavailable;
+					let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |_| Err(err));
 
-				let gateway_message = GatewayMessage::Outbound {
-					message: msg.clone(),
-					router_id: ROUTER_ID_1,
-				};
+					LiquidityPoolsGateway::on_finalize(1);
 
-				let err = DispatchError::Unavailable;
+					assert_eq!(handler.times(), 1);
+					event_exists(Event::<Runtime>::AutoBatchQueueFailed {
+						domain: TEST_DOMAIN,
+						message: Message::Simple,
+						error: err,
+					});
 
-				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
-					assert_eq!(mock_msg, gateway_message);
+					// The batch is kept and enqueued in the next block.
+					assert_eq!(
+						AutoBatchedMessages::<Runtime>::get(TEST_DOMAIN, USER),
+						Some(Message::Simple)
+					);
 
-					Err(err)
-				});
+					let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
 
-				assert_noop!(LiquidityPoolsGateway::handle(sender, domain, msg), err);
-				assert_eq!(handler.times(), 1);
-			});
+					LiquidityPoolsGateway::on_initialize(2);
+					LiquidityPoolsGateway::on_finalize(2);
+
+					// Enqueued for both routers
+					assert_eq!(handler.times(), 2);
+					assert!(AutoBatchedMessages::<Runtime>::get(TEST_DOMAIN, USER).is_none());
+				});
+			}
 		}
 	}
 
@@ -3225,6 +4284,290 @@ mod implementations {
 			}
 		}
 
+		mod inbound_with_domain_threshold {
+			use super::*;
+
+			fn set_domain_routers(threshold: u32) {
+				assert_ok!(LiquidityPoolsGateway::set_domain_routers(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+					threshold,
+				));
+			}
+
+			fn process(router_id: RouterId, message: Message) {
+				let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Inbound {
+					domain: TEST_DOMAIN,
+					message,
+					router_id,
+				});
+				assert_ok!(res);
+			}
+
+			#[test]
+			fn executed_when_threshold_is_reached() {
+				new_test_ext().execute_with(|| {
+					set_domain_routers(2);
+
+					let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					process(ROUTER_ID_1, Message::Simple);
+					assert_eq!(handler.times(), 0);
+
+					process(ROUTER_ID_3, Message::Proof(MESSAGE_HASH));
+					assert_eq!(handler.times(), 1);
+
+					assert!(
+						PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1).is_none()
+					);
+					assert!(
+						PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_3).is_none()
+					);
+
+					assert!(
+						SurplusInboundProofs::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_some()
+					);
+
+					process(ROUTER_ID_2, Message::Proof(MESSAGE_HASH));
+					assert_eq!(handler.times(), 1);
+					assert!(
+						PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_none()
+					);
+					assert!(
+						SurplusInboundProofs::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_none()
+					);
+
+					event_exists(Event::<Runtime>::InboundProofDiscarded {
+						domain: TEST_DOMAIN,
+						message_hash: MESSAGE_HASH,
+						router_id: ROUTER_ID_2,
+					});
+				});
+			}
+
+			#[test]
+			fn surplus_proofs_received_before_execution_are_pruned() {
+				new_test_ext().execute_with(|| {
+					set_domain_routers(2);
+
+					let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					process(ROUTER_ID_2, Message::Proof(MESSAGE_HASH));
+					process(ROUTER_ID_3, Message::Proof(MESSAGE_HASH));
+					process(ROUTER_ID_1, Message::Simple);
+					assert_eq!(handler.times(), 1);
+
+					for router_id in [ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3] {
+						assert!(
+							PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, &router_id)
+								.is_none()
+						);
+						assert!(
+							SurplusInboundProofs::<Runtime>::get(MESSAGE_HASH, &router_id)
+								.is_none()
+						);
+					}
+				});
+			}
+
+			#[test]
+			fn expired_surplus_proofs_are_removed() {
+				new_test_ext().execute_with(|| {
+					set_domain_routers(2);
+
+					MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					process(ROUTER_ID_1, Message::Simple);
+					process(ROUTER_ID_3, Message::Proof(MESSAGE_HASH));
+
+					SessionIdStore::<Runtime>::set(TEST_SESSION_ID + 1);
+
+					assert_ok!(LiquidityPoolsGateway::remove_expired_inbound_entries(
+						RuntimeOrigin::signed(get_test_account_id()),
+						MESSAGE_HASH,
+					));
+
+					assert!(
+						SurplusInboundProofs::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_2).is_none()
+					);
+				});
+			}
+
+			#[test]
+			fn executed_without_proofs() {
+				new_test_ext().execute_with(|| {
+					set_domain_routers(1);
+
+					let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					process(ROUTER_ID_1, Message::Simple);
+					assert_eq!(handler.times(), 1);
+					assert!(
+						PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1).is_none()
+					);
+				});
+			}
+
+			#[test]
+			fn not_executed_below_threshold() {
+				new_test_ext().execute_with(|| {
+					set_domain_routers(3);
+
+					let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					process(ROUTER_ID_1, Message::Simple);
+					process(ROUTER_ID_2, Message::Proof(MESSAGE_HASH));
+					assert_eq!(handler.times(), 0);
+
+					process(ROUTER_ID_3, Message::Proof(MESSAGE_HASH));
+					assert_eq!(handler.times(), 1);
+				});
+			}
+		}
+
+		mod inbound_with_rate_limits {
+			use super::*;
+
+			const PERIOD: u64 = 3600;
+
+			fn setup(max_amount: u128) {
+				assert_ok!(LiquidityPoolsGateway::set_domain_routers(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap(),
+					1,
+				));
+
+				assert_ok!(LiquidityPoolsGateway::set_rate_limit(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					RATE_LIMIT_KEY,
+					Some(RateLimit {
+						max_amount,
+						period: PERIOD,
+					}),
+				));
+			}
+
+			fn set_time(secs: u64) {
+				MockTime::mock_now(move || secs * 1000);
+			}
+
+			fn process(message: Message) -> DispatchResult {
+				let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Inbound {
+					domain: TEST_DOMAIN,
+					message,
+					router_id: ROUTER_ID_1,
+				});
+				res
+			}
+
+			#[test]
+			fn executed_within_limit() {
+				new_test_ext().execute_with(|| {
+					setup(100);
+					set_time(10);
+
+					let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					assert_ok!(process(Message::Transfer(60)));
+					assert_ok!(process(Message::Pack(vec![
+						Message::Transfer(30),
+						Message::Transfer(10),
+					])));
+					assert_ok!(process(Message::Simple));
+
+					assert_eq!(handler.times(), 3);
+					assert_eq!(
+						RateLimitUsages::<Runtime>::get(TEST_DOMAIN, RATE_LIMIT_KEY),
+						RateLimitUsage {
+							period_start: 10,
+							amount: 100,
+						}
+					);
+				});
+			}
+
+			#[test]
+			fn limit_restored_after_period() {
+				new_test_ext().execute_with(|| {
+					setup(100);
+					MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					set_time(10);
+					assert_ok!(process(Message::Transfer(100)));
+
+					set_time(10 + PERIOD);
+					assert_ok!(process(Message::Transfer(100)));
+
+					assert_eq!(
+						RateLimitUsages::<Runtime>::get(TEST_DOMAIN, RATE_LIMIT_KEY),
+						RateLimitUsage {
+							period_start: 10 + PERIOD,
+							amount: 100,
+						}
+					);
+				});
+			}
+
+			#[test]
+			fn exceeding_limit_pauses_domain() {
+				new_test_ext().execute_with(|| {
+					setup(100);
+					set_time(10);
+
+					let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					assert_ok!(process(Message::Transfer(60)));
+					assert_eq!(
+						process(Message::Transfer(50)),
+						Err(Error::<Runtime>::InboundRateLimitExceeded.into())
+					);
+
+					assert_eq!(handler.times(), 1);
+					assert!(PausedDomains::<Runtime>::contains_key(TEST_DOMAIN));
+					assert!(
+						PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1).is_none()
+					);
+					assert_eq!(
+						RateLimitUsages::<Runtime>::get(TEST_DOMAIN, RATE_LIMIT_KEY).amount,
+						60
+					);
+
+					event_exists(Event::<Runtime>::DomainPaused {
+						domain: TEST_DOMAIN,
+					});
+				});
+			}
+
+			#[test]
+			fn paused_domain_until_resumed() {
+				new_test_ext().execute_with(|| {
+					setup(100);
+					set_time(10);
+
+					let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+					PausedDomains::<Runtime>::insert(TEST_DOMAIN, ());
+
+					assert_eq!(
+						process(Message::Simple),
+						Err(Error::<Runtime>::DomainPaused.into())
+					);
+					assert_eq!(handler.times(), 0);
+
+					assert_ok!(LiquidityPoolsGateway::resume_domain(
+						RuntimeOrigin::root(),
+						TEST_DOMAIN,
+					));
+
+					assert_ok!(process(Message::Simple));
+					assert_eq!(handler.times(), 1);
+				});
+			}
+		}
+
 		mod outbound {
 			use super::*;
 
@@ -3362,6 +4705,24 @@ mod implementations {
 				});
 			}
 
+			#[test]
+			fn with_domain_routers() {
+				new_test_ext().execute_with(|| {
+					let domain = TEST_DOMAIN_ADDRESS.domain();
+
+					Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+					assert_ok!(LiquidityPoolsGateway::set_domain_routers(
+						RuntimeOrigin::root(),
+						domain,
+						BoundedVec::try_from(vec![ROUTER_ID_3, ROUTER_ID_2]).unwrap(),
+						1,
+					));
+
+					let res = LiquidityPoolsGateway::get_router_ids_for_domain(domain).unwrap();
+					assert_eq!(res, vec![ROUTER_ID_3, ROUTER_ID_2]);
+				});
+			}
+
 			#[test]
 			fn not_enough_routers_for_domain() {
 				new_test_ext().execute_with(|| {
@@ -3401,17 +4762,38 @@ mod implementations {
 					];
 
 					for test in tests {
-						let res = LiquidityPoolsGateway::get_expected_proof_count(&test).unwrap();
+						let res =
+							LiquidityPoolsGateway::get_expected_proof_count(TEST_DOMAIN, &test)
+								.unwrap();
 
 						assert_eq!(res, (test.len() - 1) as u32);
 					}
 				});
 			}
 
+			#[test]
+			fn with_domain_threshold() {
+				new_test_ext().execute_with(|| {
+					let router_ids = vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3];
+
+					assert_ok!(LiquidityPoolsGateway::set_domain_routers(
+						RuntimeOrigin::root(),
+						TEST_DOMAIN,
+						BoundedVec::try_from(router_ids.clone()).unwrap(),
+						2,
+					));
+
+					let res =
+						LiquidityPoolsGateway::get_expected_proof_count(TEST_DOMAIN, &router_ids)
+							.unwrap();
+					assert_eq!(res, 1);
+				});
+			}
+
 			#[test]
 			fn not_enough_routers_for_domain() {
 				new_test_ext().execute_with(|| {
-					let res = LiquidityPoolsGateway::get_expected_proof_count(&vec![]);
+					let res = LiquidityPoolsGateway::get_expected_proof_count(TEST_DOMAIN, &vec![]);
 
 					assert_eq!(
 						res.err().unwrap(),
@@ -10,10 +10,12 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
+use cfg_primitives::LP_DEFENSIVE_WEIGHT;
 use frame_support::weights::{constants::RocksDbWeight, Weight};
 
 pub trait WeightInfo {
 	fn set_routers() -> Weight;
+	fn set_domain_routers() -> Weight;
 	fn add_relayer() -> Weight;
 	fn remove_relayer() -> Weight;
 	fn receive_message() -> Weight;
@@ -23,6 +25,15 @@ pub trait WeightInfo {
 	fn execute_message_recovery() -> Weight;
 	fn initiate_message_recovery() -> Weight;
 	fn dispute_message_recovery() -> Weight;
+	fn resend_outbound_message() -> Weight;
+	fn set_rate_limit() -> Weight;
+	fn resume_domain() -> Weight;
+	fn schedule_message_recovery() -> Weight;
+	fn cancel_message_recovery() -> Weight;
+	fn remove_expired_inbound_entries(n: u32) -> Weight;
+	fn set_auto_batching() -> Weight;
+	fn queue_auto_batched_message() -> Weight;
+	fn record_outbound_message() -> Weight;
 }
 
 // NOTE: We use temporary weights here. `execute_epoch` is by far our heaviest
@@ -42,6 +53,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
 
+	fn set_domain_routers() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one read and two writes for sure and possible one
+		//       read for `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
 	fn add_relayer() -> Weight {
 		// TODO: BENCHMARK CORRECTLY
 		//
@@ -146,4 +168,103 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
+
+	fn resend_outbound_message() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one read and one write for the record and possible
+		//       one read for `AdminOrigin`. The EVM call is accounted as in the
+		//       outbound message processing.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(LP_DEFENSIVE_WEIGHT)
+	}
+
+	fn set_rate_limit() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has two writes for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn resume_domain() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one read and one write for sure and possible one
+		//       read for `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn schedule_message_recovery() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has three reads and one write for sure and possible
+		//       one read for `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn cancel_message_recovery() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one read and one write for sure and possible one
+		//       read for `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn remove_expired_inbound_entries(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one read for the session and three reads and three
+		//       writes for each of the `n` routers that could have an entry or a
+		//       surplus proof
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+
+	fn set_auto_batching() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one write for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn queue_auto_batched_message() -> Weight {
+		// NOTE: Enqueuing the batch for each router costs the same as
+		//       enqueuing a message sent without batching.
+		LP_DEFENSIVE_WEIGHT
+	}
+
+	fn record_outbound_message() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has three reads for the record, the nonce and the
+		//       oldest hash, and five writes to store the record and remove the
+		//       oldest one
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
 }
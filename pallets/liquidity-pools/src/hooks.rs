@@ -12,7 +12,6 @@
 // GNU General Public License for more details.
 
 use cfg_traits::{investments::ForeignInvestmentHooks, liquidity_pools::OutboundMessageHandler};
-use cfg_types::domain_address::Domain;
 use frame_support::traits::{
 	fungibles::Mutate,
 	tokens::{Fortitude, Precision, Preservation},
@@ -36,8 +35,7 @@ impl<T: Config> ForeignInvestmentHooks<T::AccountId> for Pallet<T> {
 		fulfilled: Self::Amount,
 	) -> DispatchResult {
 		let currency = Pallet::<T>::try_get_general_index(currency_id)?;
-		let (chain_id, ..) = Pallet::<T>::try_get_wrapped_token(&currency_id)?;
-		let domain = Domain::Evm(chain_id);
+		let (domain, investor) = Pallet::<T>::foreign_investor(who, currency_id)?;
 
 		T::Tokens::burn_from(
 			currency_id,
@@ -50,7 +48,7 @@ impl<T: Config> ForeignInvestmentHooks<T::AccountId> for Pallet<T> {
 		let message = Message::FulfilledCancelDepositRequest {
 			pool_id: pool_id.into(),
 			tranche_id: tranche_id.into(),
-			investor: who.clone().into(),
+			investor,
 			currency,
 			currency_payout: amount_cancelled.into(),
 			fulfilled_invest_amount: fulfilled.into(),
@@ -67,8 +65,7 @@ impl<T: Config> ForeignInvestmentHooks<T::AccountId> for Pallet<T> {
 		tranche_tokens_payout: Self::TrancheAmount,
 	) -> DispatchResult {
 		let currency = Pallet::<T>::try_get_general_index(currency_id)?;
-		let (chain_id, ..) = Pallet::<T>::try_get_wrapped_token(&currency_id)?;
-		let domain = Domain::Evm(chain_id);
+		let (domain, investor) = Pallet::<T>::foreign_investor(who, currency_id)?;
 
 		T::Tokens::transfer(
 			(pool_id, tranche_id).into(),
@@ -81,7 +78,7 @@ impl<T: Config> ForeignInvestmentHooks<T::AccountId> for Pallet<T> {
 		let message = Message::FulfilledDepositRequest {
 			pool_id: pool_id.into(),
 			tranche_id: tranche_id.into(),
-			investor: who.clone().into(),
+			investor,
 			currency,
 			currency_payout: amount_collected.into(),
 			tranche_tokens_payout: tranche_tokens_payout.into(),
@@ -98,8 +95,7 @@ impl<T: Config> ForeignInvestmentHooks<T::AccountId> for Pallet<T> {
 		amount_payout: Self::Amount,
 	) -> DispatchResult {
 		let currency = Pallet::<T>::try_get_general_index(currency_id)?;
-		let (chain_id, ..) = Pallet::<T>::try_get_wrapped_token(&currency_id)?;
-		let domain = Domain::Evm(chain_id);
+		let (domain, investor) = Pallet::<T>::foreign_investor(who, currency_id)?;
 
 		T::Tokens::burn_from(
 			currency_id,
@@ -112,7 +108,7 @@ impl<T: Config> ForeignInvestmentHooks<T::AccountId> for Pallet<T> {
 		let message = Message::FulfilledRedeemRequest {
 			pool_id: pool_id.into(),
 			tranche_id: tranche_id.into(),
-			investor: who.clone().into(),
+			investor,
 			currency,
 			currency_payout: amount_payout.into(),
 			tranche_tokens_payout: tranche_tokens_collected.into(),
@@ -20,7 +20,9 @@ use frame_support::{
 use sp_core::Get;
 use sp_runtime::{traits::Zero, DispatchResult};
 
-use crate::{pallet::Error, Config, GeneralCurrencyIndexOf, Message, Pallet};
+use crate::{
+	pallet::Error, Config, ForeignInvestorAddress, GeneralCurrencyIndexOf, Message, Pallet,
+};
 
 impl<T: Config> Pallet<T> {
 	/// Executes a transfer from another domain exclusively for
@@ -206,7 +208,9 @@ impl<T: Config> Pallet<T> {
 		let message = Message::FulfilledCancelRedeemRequest {
 			pool_id: pool_id.into(),
 			tranche_id: tranche_id.into(),
-			investor: investor.clone().into(),
+			investor: ForeignInvestorAddress::<T>::get(&investor)
+				.map(|address| address.bytes())
+				.unwrap_or_else(|| investor.clone().into()),
 			currency: currency_u128,
 			tranche_tokens_payout: amount.into(),
 		};
@@ -101,7 +101,7 @@ mod gmpf {
 }
 
 mod message;
-pub use message::Message;
+pub use message::{Message, RateLimitKey};
 
 pub mod hooks;
 mod inbound;
@@ -125,6 +125,17 @@ pub mod pallet {
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// Stores the address of the investors from non-EVM domains.
+	///
+	/// Their local account is a hash of their address and can not be converted
+	/// back into it, which is required to send them the outcome of their
+	/// investments.
+	///
+	/// Lifetime: Indefinitely.
+	#[pallet::storage]
+	pub type ForeignInvestorAddress<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DomainAddress, OptionQuery>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config<AccountId = AccountId32> {
 		/// Weight information for extrinsics in this pallet.
@@ -249,7 +260,7 @@ pub mod pallet {
 				Sender = Self::AccountId,
 				Message = Message,
 				Destination = Domain,
-			> + GetByKey<Domain, Option<[u8; 20]>>;
+			> + GetByKey<Domain, Option<[u8; 32]>>;
 
 		/// The prefix for currencies added via the LiquidityPools feature.
 		#[pallet::constant]
@@ -410,13 +421,10 @@ pub mod pallet {
 			let token_name = vec_to_fixed_array(metadata.name);
 			let token_symbol = vec_to_fixed_array(metadata.symbol);
 
-			// Determine hook from EVM chain id and 20 byte hook stored in Gateway
+			// Determine hook from the domain and hook stored in Gateway
 			let hook_bytes = T::OutboundMessageHandler::get(&domain)
 				.ok_or(Error::<T>::DomainHookAddressNotFound)?;
-			let evm_chain_id = match domain {
-				Domain::Evm(id) => Ok(id),
-				_ => Err(Error::<T>::InvalidDomain),
-			}?;
+			let hook = Self::hook_for_domain(domain, hook_bytes)?;
 
 			// Send the message to the domain
 			T::OutboundMessageHandler::handle(
@@ -428,7 +436,7 @@ pub mod pallet {
 					decimals: metadata.decimals.saturated_into(),
 					token_name,
 					token_symbol,
-					hook: DomainAddress::Evm(evm_chain_id, hook_bytes.into()).bytes(),
+					hook,
 				},
 			)?;
 
@@ -939,7 +947,7 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			tranche_id: T::TrancheId,
 			domain: Domain,
-			hook: [u8; 20],
+			hook: [u8; 32],
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
@@ -960,10 +968,7 @@ pub mod pallet {
 				Error::<T>::NotPoolAdmin
 			);
 
-			let evm_chain_id = match domain {
-				Domain::Evm(id) => Ok(id),
-				_ => Err(Error::<T>::InvalidDomain),
-			}?;
+			let hook = Self::hook_for_domain(domain, hook)?;
 
 			T::OutboundMessageHandler::handle(
 				who,
@@ -971,7 +976,7 @@ pub mod pallet {
 				Message::UpdateTrancheHook {
 					pool_id: pool_id.into(),
 					tranche_id: tranche_id.into(),
-					hook: DomainAddress::Evm(evm_chain_id, hook.into()).bytes(),
+					hook,
 				},
 			)?;
 
@@ -998,7 +1003,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 
 			ensure!(
-				matches!(domain_address.domain(), Domain::Evm(_)),
+				domain_address.domain() != Domain::Centrifuge,
 				Error::<T>::InvalidDomain
 			);
 
@@ -1090,6 +1095,49 @@ pub mod pallet {
 			}
 		}
 
+		/// Returns the representation of a hook address in the given domain.
+		/// EVM hooks are read from the first 20 bytes, non-EVM hooks are kept
+		/// as they are.
+		pub fn hook_for_domain(domain: Domain, hook: [u8; 32]) -> Result<[u8; 32], DispatchError> {
+			ensure!(domain != Domain::Centrifuge, Error::<T>::InvalidDomain);
+
+			Ok(DomainAddress::new(domain, hook).bytes())
+		}
+
+		/// Returns the local account of an investor from another domain.
+		///
+		/// The address of non-EVM investors is stored to be able to address
+		/// them again when their investments are fulfilled.
+		pub fn investor_account(domain: Domain, investor: [u8; 32]) -> T::AccountId {
+			let address = DomainAddress::new(domain, investor);
+			let account = address.account();
+
+			if matches!(address, DomainAddress::NonEvm(..)) {
+				ForeignInvestorAddress::<T>::insert(&account, address);
+			}
+
+			account
+		}
+
+		/// Returns the domain and the address of the given investor in that
+		/// domain.
+		///
+		/// Investors without a stored address are EVM investors, whose domain
+		/// is given by the wrapped token of the currency and whose account
+		/// contains their address.
+		pub fn foreign_investor(
+			who: &T::AccountId,
+			currency_id: T::CurrencyId,
+		) -> Result<(Domain, [u8; 32]), DispatchError> {
+			if let Some(address) = ForeignInvestorAddress::<T>::get(who) {
+				return Ok((address.domain(), address.bytes()));
+			}
+
+			let (chain_id, ..) = Self::try_get_wrapped_token(&currency_id)?;
+
+			Ok((Domain::Evm(chain_id), who.clone().into()))
+		}
+
 		/// Ensures that the given pool and tranche exists and returns the
 		/// corresponding investment id.
 		pub fn derive_invest_id(
@@ -1221,7 +1269,7 @@ pub mod pallet {
 				} => Self::handle_deposit_request(
 					pool_id.into(),
 					tranche_id.into(),
-					DomainAddress::new(sender, investor).account(),
+					Self::investor_account(sender, investor),
 					currency.into(),
 					amount.into(),
 				),
@@ -1234,7 +1282,7 @@ pub mod pallet {
 				} => Self::handle_redeem_request(
 					pool_id.into(),
 					tranche_id.into(),
-					DomainAddress::new(sender, investor).account(),
+					Self::investor_account(sender, investor),
 					amount.into(),
 					currency.into(),
 					sender,
@@ -1247,7 +1295,7 @@ pub mod pallet {
 				} => Self::handle_cancel_deposit_request(
 					pool_id.into(),
 					tranche_id.into(),
-					DomainAddress::new(sender, investor).account(),
+					Self::investor_account(sender, investor),
 					currency.into(),
 				),
 				Message::CancelRedeemRequest {
@@ -1258,7 +1306,7 @@ pub mod pallet {
 				} => Self::handle_cancel_redeem_request(
 					pool_id.into(),
 					tranche_id.into(),
-					DomainAddress::new(sender, investor).account(),
+					Self::investor_account(sender, investor),
 					currency.into(),
 					sender,
 				),
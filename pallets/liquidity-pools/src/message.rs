@@ -7,8 +7,8 @@
 
 use cfg_traits::{
 	liquidity_pools::{
-		LpMessageBatch, LpMessageForwarded, LpMessageHash, LpMessageProof, LpMessageRecovery,
-		LpMessageSerializer, MessageHash,
+		LpMessageBatch, LpMessageForwarded, LpMessageHash, LpMessageProof, LpMessageRateLimit,
+		LpMessageRecovery, LpMessageSerializer, MessageHash,
 	},
 	Seconds,
 };
@@ -65,6 +65,7 @@ impl From<Domain> for SerializableDomain {
 		match domain {
 			Domain::Centrifuge => Self(0, 0),
 			Domain::Evm(chain_id) => Self(1, chain_id),
+			Domain::NonEvm(chain_id) => Self(2, chain_id),
 		}
 	}
 }
@@ -76,6 +77,7 @@ impl TryInto<Domain> for SerializableDomain {
 		match self.0 {
 			0 => Ok(Domain::Centrifuge),
 			1 => Ok(Domain::Evm(self.1)),
+			2 => Ok(Domain::NonEvm(self.1)),
 			_ => Err(DispatchError::Other("Unknown domain")),
 		}
 	}
@@ -678,6 +680,59 @@ impl LpMessageForwarded for Message {
 	}
 }
 
+/// Identifies the inbound messages accounted by a rate limit.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RateLimitKey {
+	/// Amount of the currency transferred by `TransferAssets` messages.
+	TransferAssets { currency: u128 },
+	/// Amount of tranche tokens of the pool transferred by
+	/// `TransferTrancheTokens` messages.
+	TransferTrancheTokens { pool_id: u64 },
+	/// Amount of currency of the pool requested by `DepositRequest` messages.
+	DepositRequest { pool_id: u64 },
+	/// Amount of tranche tokens of the pool requested by `RedeemRequest`
+	/// messages.
+	RedeemRequest { pool_id: u64 },
+}
+
+impl LpMessageRateLimit for Message {
+	type LimitKey = RateLimitKey;
+
+	fn rate_limited_amounts(&self) -> Vec<(RateLimitKey, u128)> {
+		match self {
+			Message::Batch(content) => content
+				.clone()
+				.into_iter()
+				.flat_map(|message| message.rate_limited_amounts())
+				.collect(),
+			Message::Forwarded { message, .. } => {
+				Message::from(message.clone()).rate_limited_amounts()
+			}
+			Message::TransferAssets {
+				currency, amount, ..
+			} => vec![(
+				RateLimitKey::TransferAssets {
+					currency: *currency,
+				},
+				*amount,
+			)],
+			Message::TransferTrancheTokens {
+				pool_id, amount, ..
+			} => vec![(
+				RateLimitKey::TransferTrancheTokens { pool_id: *pool_id },
+				*amount,
+			)],
+			Message::DepositRequest {
+				pool_id, amount, ..
+			} => vec![(RateLimitKey::DepositRequest { pool_id: *pool_id }, *amount)],
+			Message::RedeemRequest {
+				pool_id, amount, ..
+			} => vec![(RateLimitKey::RedeemRequest { pool_id: *pool_id }, *amount)],
+			_ => vec![],
+		}
+	}
+}
+
 /// A Liquidity Pool message for updating restrictions on foreign domains.
 #[derive(
 	Encode,
@@ -766,6 +821,11 @@ mod tests {
 			hex::encode(gmpf::to_vec(&SerializableDomain::from(Domain::Evm(43114))).unwrap()),
 			"01000000000000a86a"
 		);
+		// Non-EVM chain
+		assert_eq!(
+			hex::encode(gmpf::to_vec(&SerializableDomain::from(Domain::NonEvm(7))).unwrap()),
+			"020000000000000007"
+		);
 	}
 
 	#[test]
@@ -1143,6 +1203,39 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn rate_limited_amounts() {
+		let transfer = Message::TransferTrancheTokens {
+			pool_id: 1,
+			tranche_id: default_tranche_id(),
+			domain: Domain::Centrifuge.into(),
+			receiver: default_address_32(),
+			amount: AMOUNT,
+		};
+
+		let mut batch = Message::TransferAssets {
+			currency: TOKEN_ID,
+			receiver: default_address_32(),
+			amount: 2,
+		};
+		batch.pack_with(transfer.clone()).unwrap();
+		batch.pack_with(Message::AddPool { pool_id: 1 }).unwrap();
+
+		let forwarded = Message::Forwarded {
+			source_domain: Domain::Evm(1284).into(),
+			forwarding_contract: default_address_20().into(),
+			message: batch.try_into().unwrap(),
+		};
+
+		assert_eq!(
+			forwarded.rate_limited_amounts(),
+			vec![
+				(RateLimitKey::TransferAssets { currency: TOKEN_ID }, 2),
+				(RateLimitKey::TransferTrancheTokens { pool_id: 1 }, AMOUNT),
+			]
+		);
+	}
+
 	/// Verify the identity property of decode . encode on a Message value and
 	/// that it in fact encodes to and can be decoded from a given hex string.
 	fn test_encode_decode_identity(msg: Message, expected_hex: &str) {
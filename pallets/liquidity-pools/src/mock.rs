@@ -26,6 +26,9 @@ pub type Ratio = FixedU128;
 pub const CHAIN_ID: u64 = 1;
 pub const EVM_DOMAIN: Domain = Domain::Evm(CHAIN_ID);
 
+pub const NON_EVM_CHAIN_ID: u64 = 2;
+pub const NON_EVM_DOMAIN: Domain = Domain::NonEvm(NON_EVM_CHAIN_ID);
+
 pub const CONTRACT_ACCOUNT: H160 = H160::repeat_byte(1);
 pub const CONTRACT_DOMAIN_ADDRESS: DomainAddress = DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT);
 
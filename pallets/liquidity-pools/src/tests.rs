@@ -521,7 +521,7 @@ mod add_tranche {
 		AssetRegistry::mock_metadata(|_| Some(util::default_metadata()));
 		Gateway::mock_get(move |domain| {
 			assert_eq!(domain, &CONTRACT_DOMAIN_ADDRESS.domain());
-			Some(DOMAIN_HOOK_ADDRESS.into())
+			Some(DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes())
 		});
 		Gateway::mock_handle(move |sender, destination, msg| {
 			assert_eq!(sender, ALICE);
@@ -555,6 +555,29 @@ mod add_tranche {
 		})
 	}
 
+	#[test]
+	fn success_with_non_evm_domain() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			Gateway::mock_get(|domain| {
+				assert_eq!(domain, &NON_EVM_DOMAIN);
+				Some([10; 32])
+			});
+			Gateway::mock_handle(|_, destination, msg| {
+				assert_eq!(destination, NON_EVM_DOMAIN);
+				assert!(matches!(msg, Message::AddTranche { hook, .. } if hook == [10; 32]));
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::add_tranche(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				NON_EVM_DOMAIN,
+			));
+		})
+	}
+
 	mod erroring_out {
 		use super::*;
 
@@ -1895,7 +1918,7 @@ mod update_tranche_hook {
 				POOL_ID,
 				TRANCHE_ID,
 				EVM_DOMAIN,
-				DOMAIN_HOOK_ADDRESS.into()
+				DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes()
 			));
 		});
 	}
@@ -1914,7 +1937,7 @@ mod update_tranche_hook {
 						POOL_ID,
 						TRANCHE_ID,
 						EVM_DOMAIN,
-						DOMAIN_HOOK_ADDRESS.into()
+						DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes()
 					),
 					DispatchError::BadOrigin
 				);
@@ -1929,7 +1952,7 @@ mod update_tranche_hook {
 						POOL_ID,
 						TRANCHE_ID,
 						EVM_DOMAIN,
-						DOMAIN_HOOK_ADDRESS.into()
+						DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes()
 					),
 					DispatchError::BadOrigin
 				);
@@ -1948,7 +1971,7 @@ mod update_tranche_hook {
 						POOL_ID,
 						TRANCHE_ID,
 						EVM_DOMAIN,
-						DOMAIN_HOOK_ADDRESS.into()
+						DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes()
 					),
 					Error::<Runtime>::PoolNotFound
 				);
@@ -1967,7 +1990,7 @@ mod update_tranche_hook {
 						POOL_ID,
 						TRANCHE_ID,
 						EVM_DOMAIN,
-						DOMAIN_HOOK_ADDRESS.into()
+						DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes()
 					),
 					Error::<Runtime>::TrancheNotFound
 				);
@@ -1986,7 +2009,7 @@ mod update_tranche_hook {
 						POOL_ID,
 						TRANCHE_ID,
 						EVM_DOMAIN,
-						DOMAIN_HOOK_ADDRESS.into()
+						DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes()
 					),
 					Error::<Runtime>::NotPoolAdmin
 				);
@@ -2004,7 +2027,7 @@ mod update_tranche_hook {
 						POOL_ID,
 						TRANCHE_ID,
 						Domain::Centrifuge,
-						DOMAIN_HOOK_ADDRESS.into()
+						DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes()
 					),
 					Error::<Runtime>::InvalidDomain
 				);
@@ -1,4 +1,4 @@
-use cfg_traits::liquidity_pools::InboundMessageHandler;
+use cfg_traits::{investments::ForeignInvestmentHooks, liquidity_pools::InboundMessageHandler};
 use cfg_types::{
 	domain_address::DomainAddress,
 	permissions::{PermissionScope, PoolRole, Role},
@@ -8,7 +8,7 @@ use frame_support::{
 	traits::fungibles::{Inspect as _, Mutate as _},
 };
 
-use crate::{mock::*, Error, Message};
+use crate::{mock::*, Error, ForeignInvestorAddress, Message};
 
 #[test]
 fn receiving_invalid_message() {
@@ -791,3 +791,133 @@ mod handle_cancel_redeem_order {
 		}
 	}
 }
+
+mod non_evm_investor {
+	use super::*;
+
+	const INVESTOR: [u8; 32] = [3; 32];
+	const INVESTOR_ADDRESS: DomainAddress = DomainAddress::NonEvm(NON_EVM_CHAIN_ID, INVESTOR);
+
+	fn config_mocks() {
+		Pools::mock_pool_exists(|_| true);
+		Pools::mock_tranche_exists(|_, _| true);
+		AssetRegistry::mock_metadata(|_| Some(util::default_metadata()));
+		ForeignInvestment::mock_increase_foreign_investment(|who, _, _, _| {
+			assert_eq!(*who, INVESTOR_ADDRESS.account());
+			Ok(())
+		});
+		ForeignInvestment::mock_increase_foreign_redemption(|who, _, _, _| {
+			assert_eq!(*who, INVESTOR_ADDRESS.account());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn deposit_and_collect() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+
+			assert_ok!(LiquidityPools::handle(
+				NON_EVM_DOMAIN,
+				Message::DepositRequest {
+					pool_id: POOL_ID,
+					tranche_id: TRANCHE_ID,
+					investor: INVESTOR,
+					currency: util::currency_index(CURRENCY_ID),
+					amount: AMOUNT,
+				},
+			));
+
+			let local = INVESTOR_ADDRESS.account();
+			assert_eq!(
+				ForeignInvestorAddress::<Runtime>::get(&local),
+				Some(INVESTOR_ADDRESS)
+			);
+
+			Gateway::mock_handle(|sender, destination, msg| {
+				assert_eq!(sender, TreasuryAccount::get());
+				assert_eq!(destination, NON_EVM_DOMAIN);
+				assert_eq!(
+					msg,
+					Message::FulfilledDepositRequest {
+						pool_id: POOL_ID,
+						tranche_id: TRANCHE_ID,
+						investor: INVESTOR,
+						currency: util::currency_index(CURRENCY_ID),
+						currency_payout: AMOUNT,
+						tranche_tokens_payout: AMOUNT,
+					}
+				);
+				Ok(())
+			});
+
+			// Side effects of the investment collection
+			Tokens::mint_into(TRANCHE_CURRENCY, &local, AMOUNT).unwrap();
+
+			assert_ok!(LiquidityPools::fulfill_collect_investment(
+				&local,
+				INVESTMENT_ID,
+				CURRENCY_ID,
+				AMOUNT,
+				AMOUNT,
+			));
+
+			let destination = NON_EVM_DOMAIN.into_account();
+			assert_eq!(Tokens::balance(TRANCHE_CURRENCY, &local), 0);
+			assert_eq!(Tokens::balance(TRANCHE_CURRENCY, &destination), AMOUNT);
+		});
+	}
+
+	#[test]
+	fn redeem_and_collect() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+
+			Tokens::mint_into(TRANCHE_CURRENCY, &NON_EVM_DOMAIN.into_account(), AMOUNT).unwrap();
+
+			assert_ok!(LiquidityPools::handle(
+				NON_EVM_DOMAIN,
+				Message::RedeemRequest {
+					pool_id: POOL_ID,
+					tranche_id: TRANCHE_ID,
+					investor: INVESTOR,
+					currency: util::currency_index(CURRENCY_ID),
+					amount: AMOUNT,
+				},
+			));
+
+			let local = INVESTOR_ADDRESS.account();
+			assert_eq!(Tokens::balance(TRANCHE_CURRENCY, &local), AMOUNT);
+
+			Gateway::mock_handle(|sender, destination, msg| {
+				assert_eq!(sender, TreasuryAccount::get());
+				assert_eq!(destination, NON_EVM_DOMAIN);
+				assert_eq!(
+					msg,
+					Message::FulfilledRedeemRequest {
+						pool_id: POOL_ID,
+						tranche_id: TRANCHE_ID,
+						investor: INVESTOR,
+						currency: util::currency_index(CURRENCY_ID),
+						currency_payout: AMOUNT,
+						tranche_tokens_payout: AMOUNT,
+					}
+				);
+				Ok(())
+			});
+
+			// Side effects of the redemption collection
+			Tokens::mint_into(CURRENCY_ID, &local, AMOUNT).unwrap();
+
+			assert_ok!(LiquidityPools::fulfill_collect_redemption(
+				&local,
+				INVESTMENT_ID,
+				CURRENCY_ID,
+				AMOUNT,
+				AMOUNT,
+			));
+
+			assert_eq!(Tokens::balance(CURRENCY_ID, &local), 0);
+		});
+	}
+}
@@ -15,7 +15,7 @@ use cfg_primitives::CFG;
 use cfg_traits::{
 	benchmarking::FundedPoolBenchmarkHelper,
 	changes::ChangeGuard,
-	interest::{CompoundingSchedule, InterestAccrual, InterestRate},
+	interest::{CompoundingSchedule, DayCountConvention, InterestAccrual, InterestRate},
 	Permissions, PoolWriteOffPolicyMutate, TimeAsSecs, ValueProvider,
 };
 use cfg_types::{
@@ -23,14 +23,18 @@ use cfg_types::{
 	permissions::{PermissionScope, PoolRole, Role},
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::traits::tokens::nonfungibles::{Create, Mutate};
+use frame_support::{
+	traits::tokens::nonfungibles::{Create, Mutate},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use sp_arithmetic::FixedPointNumber;
 use sp_runtime::traits::{Bounded, Get, One, Zero};
+use sp_std::vec::Vec;
 
 use crate::{
 	entities::{
-		changes::{Change, LoanMutation},
+		changes::{Change, CollateralSubstitution, LoanMutation},
 		input::{PrincipalInput, RepaidInput},
 		loans::LoanInfo,
 		pricing::{
@@ -85,6 +89,7 @@ where
 	T::CollectionId: From<u16>,
 	T::ItemId: From<u16>,
 	T::PriceId: From<u32>,
+	T::PoolId: From<u32>,
 	T::Pool: FundedPoolBenchmarkHelper<
 		PoolId = T::PoolId,
 		AccountId = T::AccountId,
@@ -124,18 +129,21 @@ where
 	}
 
 	fn base_loan(item_id: T::ItemId) -> LoanInfo<T> {
-		let maturity_offset = 40 * 365 * 24 * 3600; // 40 years
+		// Just under 40 years of monthly payments, the longest schedule
+		// bounded by `MAX_CASHFLOW_PAYMENTS`
+		let maturity_offset = (39 * 365 + 330) * 24 * 3600;
 
 		LoanInfo {
 			schedule: RepaymentSchedule {
 				maturity: Maturity::fixed(T::Time::now() + maturity_offset),
-				interest_payments: InterestPayments::OnceAtMaturity,
-				pay_down_schedule: PayDownSchedule::None,
+				interest_payments: InterestPayments::Monthly(31),
+				pay_down_schedule: PayDownSchedule::Annuity,
 			},
-			collateral: (COLLECION_ID.into(), item_id),
+			collateral: BoundedVec::truncate_from(sp_std::vec![(COLLECION_ID.into(), item_id)]),
 			interest_rate: InterestRate::Fixed {
 				rate_per_year: T::Rate::saturating_from_rational(1, 5000),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			},
 			pricing: Pricing::Internal(InternalPricing {
 				collateral_value: COLLATERAL_VALUE.into(),
@@ -148,6 +156,7 @@ where
 					discount_rate: InterestRate::Fixed {
 						rate_per_year: T::Rate::saturating_from_rational(1, 5000),
 						compounding: CompoundingSchedule::Secondly,
+						day_count: DayCountConvention::Actual365,
 					},
 				}),
 			}),
@@ -199,8 +208,8 @@ where
 		.unwrap();
 	}
 
-	fn create_mutation() -> LoanMutation<T::Rate> {
-		LoanMutation::InterestPayments(InterestPayments::OnceAtMaturity)
+	fn create_mutation() -> LoanMutation<T::Rate, T::PriceId> {
+		LoanMutation::InterestPayments(InterestPayments::Monthly(1))
 	}
 
 	fn propose_mutation(pool_id: T::PoolId, loan_id: T::LoanId) -> T::Hash {
@@ -273,6 +282,104 @@ where
 		.unwrap()
 	}
 
+	/// Substitutes the collateral item of the loan created with `u16::MAX` by
+	/// as many new items as a loan can hold
+	fn create_substitution() -> CollateralSubstitution<T> {
+		let borrower = account::<T::AccountId>("borrower", 0, 0);
+
+		let added = (0..T::MaxCollateralsPerLoan::get())
+			.map(|i| {
+				let item_id = (u16::MAX - 1 - i as u16).into();
+				T::NonFungible::mint_into(&COLLECION_ID.into(), &item_id, &borrower).unwrap();
+				(COLLECION_ID.into(), item_id)
+			})
+			.collect::<sp_std::vec::Vec<_>>();
+
+		CollateralSubstitution {
+			removed: BoundedVec::truncate_from(sp_std::vec![(
+				COLLECION_ID.into(),
+				u16::MAX.into()
+			)]),
+			added: BoundedVec::truncate_from(added),
+			collateral_value: Some(COLLATERAL_VALUE.into()),
+		}
+	}
+
+	fn propose_substitution(pool_id: T::PoolId, loan_id: T::LoanId) -> T::Hash {
+		let loan_admin = account::<T::AccountId>("loan_admin", 0, 0);
+		let substitution = Self::create_substitution();
+
+		Pallet::<T>::propose_collateral_substitution(
+			RawOrigin::Signed(loan_admin).into(),
+			pool_id,
+			loan_id,
+			substitution.clone(),
+		)
+		.unwrap();
+
+		// We need to call noted again
+		// (that is idempotent for the same change and instant)
+		// to obtain the ChangeId used previously.
+		T::ChangeGuard::note(
+			pool_id,
+			Change::<T>::CollateralSubstitution(loan_id, substitution).into(),
+		)
+		.unwrap()
+	}
+
+	/// Creates as many pools as can participate in a syndicated loan
+	fn prepare_participant_pools() -> Vec<T::PoolId> {
+		let pool_admin = account("pool_admin", 0, 0);
+		let loan_admin = account::<T::AccountId>("loan_admin", 0, 0);
+
+		(1..=T::MaxParticipantsPerLoan::get())
+			.map(|i| {
+				let pool_id = i.into();
+				T::Pool::bench_create_funded_pool(pool_id, &pool_admin);
+				T::Permissions::add(
+					PermissionScope::Pool(pool_id),
+					loan_admin.clone(),
+					Role::PoolRole(PoolRole::LoanAdmin),
+				)
+				.unwrap();
+
+				pool_id
+			})
+			.collect()
+	}
+
+	fn create_participants(pool_ids: &[T::PoolId]) -> ParticipantsOf<T> {
+		// The pool of the loan keeps a share as big as the one of each participant
+		let share = T::Rate::saturating_from_rational(1, pool_ids.len() as u32 + 1);
+
+		BoundedVec::truncate_from(pool_ids.iter().map(|pool_id| (*pool_id, share)).collect())
+	}
+
+	fn propose_syndication(
+		pool_id: T::PoolId,
+		loan_id: T::LoanId,
+		participants: ParticipantsOf<T>,
+	) -> T::Hash {
+		let loan_admin = account::<T::AccountId>("loan_admin", 0, 0);
+
+		Pallet::<T>::propose_syndication(
+			RawOrigin::Signed(loan_admin).into(),
+			pool_id,
+			loan_id,
+			participants.clone(),
+		)
+		.unwrap();
+
+		// We need to call noted again
+		// (that is idempotent for the same change and instant)
+		// to obtain the ChangeId used previously.
+		T::ChangeGuard::note(
+			pool_id,
+			Change::<T>::Syndication(loan_id, participants).into(),
+		)
+		.unwrap()
+	}
+
 	fn set_policy(pool_id: T::PoolId) {
 		let change_id = Self::propose_policy(pool_id);
 
@@ -281,8 +388,8 @@ where
 			.unwrap();
 	}
 
-	fn expire_loan(pool_id: T::PoolId, loan_id: T::LoanId) {
-		Pallet::<T>::expire_action(pool_id, loan_id).unwrap();
+	fn overdue_loan(pool_id: T::PoolId, loan_id: T::LoanId) {
+		Pallet::<T>::overdue_action(pool_id, loan_id).unwrap();
 	}
 
 	fn initialize_active_state(n: u32) -> T::PoolId {
@@ -293,6 +400,7 @@ where
 			T::InterestAccrual::reference_rate(&InterestRate::Fixed {
 				rate_per_year: T::Rate::saturating_from_rational(i + 1, 5000),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			})
 			.unwrap();
 		}
@@ -314,6 +422,10 @@ where
 	fn max_active_loans() -> u32 {
 		T::MaxActiveLoansPerPool::get().min(10)
 	}
+
+	fn max_participations() -> u32 {
+		T::MaxParticipationsPerPool::get().min(Self::max_active_loans() - 1)
+	}
 }
 
 benchmarks! {
@@ -324,6 +436,7 @@ benchmarks! {
 		T::CollectionId: From<u16>,
 		T::ItemId: From<u16>,
 		T::PriceId: From<u32>,
+		T::PoolId: From<u32>,
 		T::Pool: FundedPoolBenchmarkHelper<PoolId = T::PoolId, AccountId = T::AccountId, Balance = T::Balance>,
 		T::Moment: Default,
 		T::PriceRegistry: ValueProvider<(u32, T::PoolId), T::PriceId, Value = PriceOf<T>>,
@@ -346,6 +459,9 @@ benchmarks! {
 		let pool_id = Helper::<T>::initialize_active_state(n);
 		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
 
+		// A further draw reschedules the payments of the outstanding principal
+		Helper::<T>::borrow_loan(pool_id, loan_id);
+
 	}: _(RawOrigin::Signed(borrower), pool_id, loan_id, PrincipalInput::Internal(10.into()))
 
 	repay {
@@ -372,7 +488,7 @@ benchmarks! {
 		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
 		Helper::<T>::borrow_loan(pool_id, loan_id);
 		Helper::<T>::set_policy(pool_id);
-		Helper::<T>::expire_loan(pool_id, loan_id);
+		Helper::<T>::overdue_loan(pool_id, loan_id);
 
 	}: _(RawOrigin::Signed(borrower), pool_id, loan_id)
 
@@ -481,7 +597,74 @@ benchmarks! {
 		let pool_id = Helper::<T>::initialize_active_state(n);
 		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
 
+		// A further draw reschedules the payments of the outstanding principal
+		Helper::<T>::borrow_loan(pool_id, loan_id);
+
 	}: _(RawOrigin::Signed(borrower), pool_id, loan_id, PrincipalInput::Internal(10.into()))
+
+	propose_collateral_substitution {
+		let n in 1..Helper::<T>::max_active_loans() - 1;
+
+		let loan_admin = account("loan_admin", 0, 0);
+		let pool_id = Helper::<T>::initialize_active_state(n);
+		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
+		Helper::<T>::borrow_loan(pool_id, loan_id);
+
+		let substitution = Helper::<T>::create_substitution();
+
+	}: _(RawOrigin::Signed(loan_admin), pool_id, loan_id, substitution)
+
+	apply_collateral_substitution {
+		let n in 1..Helper::<T>::max_active_loans() - 1;
+
+		let borrower = account("borrower", 0, 0);
+		let pool_id = Helper::<T>::initialize_active_state(n);
+		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
+		Helper::<T>::borrow_loan(pool_id, loan_id);
+
+		let change_id = Helper::<T>::propose_substitution(pool_id, loan_id);
+
+	}: _(RawOrigin::Signed(borrower), pool_id, change_id)
+
+	propose_syndication {
+		let loan_admin = account("loan_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+		let participants = Helper::<T>::create_participants(&Helper::<T>::prepare_participant_pools());
+		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
+
+	}: _(RawOrigin::Signed(loan_admin), pool_id, loan_id, participants)
+
+	apply_syndication {
+		let loan_admin = account("loan_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+		let participants = Helper::<T>::create_participants(&Helper::<T>::prepare_participant_pools());
+		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
+		let change_id = Helper::<T>::propose_syndication(pool_id, loan_id, participants);
+
+	}: _(RawOrigin::Signed(loan_admin), pool_id, change_id)
+
+	participation_valuation {
+		let n in 1..Helper::<T>::max_participations();
+
+		let any = account("any", 0, 0);
+		let loan_admin = account::<T::AccountId>("loan_admin", 0, 0);
+
+		// The participations are valued reading all the loans of the pool
+		let pool_id = Helper::<T>::initialize_active_state(Helper::<T>::max_active_loans() - n);
+		let participant_pool_id = Helper::<T>::prepare_participant_pools()[0];
+
+		for i in 0..n {
+			let loan_id = Helper::<T>::create_loan(pool_id, (u16::MAX - i as u16).into());
+			let participants = Helper::<T>::create_participants(&[participant_pool_id]);
+			let change_id = Helper::<T>::propose_syndication(pool_id, loan_id, participants);
+			Pallet::<T>::apply_syndication(RawOrigin::Signed(loan_admin.clone()).into(), pool_id, change_id).unwrap();
+			Helper::<T>::borrow_loan(pool_id, loan_id);
+		}
+
+	}: update_portfolio_valuation(RawOrigin::Signed(any), participant_pool_id)
+	verify {
+		assert!(Pallet::<T>::portfolio_valuation(participant_pool_id).value() > Zero::zero());
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -1,13 +1,15 @@
 use cfg_traits::{interest::InterestRate, Seconds};
-use frame_support::{pallet_prelude::RuntimeDebug, storage::bounded_vec::BoundedVec};
+use frame_support::{
+	pallet_prelude::RuntimeDebug, storage::bounded_vec::BoundedVec, RuntimeDebugNoBound,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 use crate::{
 	entities::input::{PrincipalInput, RepaidInput},
-	pallet::Config,
+	pallet::{CollateralOf, Config, ParticipantsOf},
 	types::{
-		cashflow::{InterestPayments, Maturity, PayDownSchedule},
+		cashflow::{InterestPayments, Maturity, PayDownSchedule, RepaymentSchedule},
 		policy::WriteOffRule,
 		valuation::ValuationMethod,
 	},
@@ -15,29 +17,149 @@ use crate::{
 
 /// Active loan mutation for internal pricing
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
-pub enum InternalMutation<Rate> {
-	ValuationMethod(ValuationMethod<Rate>),
+pub enum InternalMutation<Rate, PriceId> {
+	ValuationMethod(ValuationMethod<Rate, PriceId>),
 	ProbabilityOfDefault(Rate),
 	LossGivenDefault(Rate),
 	DiscountRate(InterestRate<Rate>),
 }
 
+/// Restructuring of an active loan with internal pricing
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct Restructuring<Rate> {
+	/// New repayment schedule of the loan
+	pub schedule: RepaymentSchedule,
+
+	/// Turns the outstanding interest into principal
+	pub capitalize_interest: bool,
+
+	/// Share of the outstanding principal that is forgiven, if any.
+	/// Applied after capitalizing the interest.
+	pub forgiveness: Option<Rate>,
+}
+
 /// Active loan mutation
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
-pub enum LoanMutation<Rate> {
+pub enum LoanMutation<Rate, PriceId> {
 	Maturity(Maturity),
 	MaturityExtension(Seconds),
 	InterestRate(InterestRate<Rate>),
 	InterestPayments(InterestPayments),
 	PayDownSchedule(PayDownSchedule),
-	Internal(InternalMutation<Rate>),
+	Internal(InternalMutation<Rate, PriceId>),
+	Restructure(Restructuring<Rate>),
+}
+
+/// Collateral substitution of an active loan
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebugNoBound, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CollateralSubstitution<T: Config> {
+	/// Collateral items given back to the borrower
+	pub removed: CollateralOf<T>,
+
+	/// Collateral items added by the borrower
+	pub added: CollateralOf<T>,
+
+	/// New value of the whole collateral, for loans with internal pricing.
+	/// If `None`, the collateral value is not modified.
+	pub collateral_value: Option<T::Balance>,
 }
 
 /// Change description
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 pub enum Change<T: Config> {
-	Loan(T::LoanId, LoanMutation<T::Rate>),
+	Loan(T::LoanId, LoanMutation<T::Rate, T::PriceId>),
 	Policy(BoundedVec<WriteOffRule<T::Rate>, T::MaxWriteOffPolicySize>),
 	TransferDebt(T::LoanId, T::LoanId, RepaidInput<T>, PrincipalInput<T>),
+	CollateralSubstitution(T::LoanId, CollateralSubstitution<T>),
+	Syndication(T::LoanId, ParticipantsOf<T>),
+}
+
+/// Changes before day count conventions and the new change kinds, for
+/// migration to v5
+pub mod v4 {
+	use cfg_traits::Seconds;
+	use frame_support::storage::bounded_vec::BoundedVec;
+	use parity_scale_codec::{Decode, Encode};
+
+	use crate::{
+		entities::{
+			input::{PrincipalInput, RepaidInput},
+			interest::v4::InterestRate,
+		},
+		pallet::Config,
+		types::{
+			cashflow::{InterestPayments, Maturity, PayDownSchedule},
+			policy::WriteOffRule,
+			valuation::v4::ValuationMethod,
+		},
+	};
+
+	#[derive(Encode, Decode)]
+	pub enum InternalMutation<Rate> {
+		ValuationMethod(ValuationMethod<Rate>),
+		ProbabilityOfDefault(Rate),
+		LossGivenDefault(Rate),
+		DiscountRate(InterestRate<Rate>),
+	}
+
+	impl<Rate> InternalMutation<Rate> {
+		pub fn migrate<PriceId>(self) -> super::InternalMutation<Rate, PriceId> {
+			match self {
+				Self::ValuationMethod(method) => {
+					super::InternalMutation::ValuationMethod(method.migrate())
+				}
+				Self::ProbabilityOfDefault(rate) => {
+					super::InternalMutation::ProbabilityOfDefault(rate)
+				}
+				Self::LossGivenDefault(rate) => super::InternalMutation::LossGivenDefault(rate),
+				Self::DiscountRate(rate) => super::InternalMutation::DiscountRate(rate.migrate()),
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub enum LoanMutation<Rate> {
+		Maturity(Maturity),
+		MaturityExtension(Seconds),
+		InterestRate(InterestRate<Rate>),
+		InterestPayments(InterestPayments),
+		PayDownSchedule(PayDownSchedule),
+		Internal(InternalMutation<Rate>),
+	}
+
+	impl<Rate> LoanMutation<Rate> {
+		pub fn migrate<PriceId>(self) -> super::LoanMutation<Rate, PriceId> {
+			match self {
+				Self::Maturity(maturity) => super::LoanMutation::Maturity(maturity),
+				Self::MaturityExtension(extension) => {
+					super::LoanMutation::MaturityExtension(extension)
+				}
+				Self::InterestRate(rate) => super::LoanMutation::InterestRate(rate.migrate()),
+				Self::InterestPayments(payments) => super::LoanMutation::InterestPayments(payments),
+				Self::PayDownSchedule(schedule) => super::LoanMutation::PayDownSchedule(schedule),
+				Self::Internal(mutation) => super::LoanMutation::Internal(mutation.migrate()),
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub enum Change<T: Config> {
+		Loan(T::LoanId, LoanMutation<T::Rate>),
+		Policy(BoundedVec<WriteOffRule<T::Rate>, T::MaxWriteOffPolicySize>),
+		TransferDebt(T::LoanId, T::LoanId, RepaidInput<T>, PrincipalInput<T>),
+	}
+
+	impl<T: Config> Change<T> {
+		pub fn migrate(self) -> super::Change<T> {
+			match self {
+				Self::Loan(loan_id, mutation) => super::Change::Loan(loan_id, mutation.migrate()),
+				Self::Policy(policy) => super::Change::Policy(policy),
+				Self::TransferDebt(from, to, repaid, principal) => {
+					super::Change::TransferDebt(from, to, repaid, principal)
+				}
+			}
+		}
+	}
 }
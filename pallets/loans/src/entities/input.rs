@@ -1,7 +1,7 @@
 use frame_support::{storage::bounded_btree_map::BoundedBTreeMap, RuntimeDebugNoBound};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::{ArithmeticError, DispatchError};
+use sp_runtime::{traits::Zero, ArithmeticError, DispatchError};
 
 use crate::{
 	entities::pricing::external::ExternalAmount,
@@ -54,6 +54,7 @@ impl<T: Config> RepaidInput<T> {
 			principal: self.principal.balance()?,
 			interest: self.interest,
 			unscheduled: self.unscheduled,
+			prepaid: Zero::zero(),
 		})
 	}
 }
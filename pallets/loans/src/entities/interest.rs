@@ -8,7 +8,10 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{traits::Zero, DispatchError, DispatchResult};
 
-use crate::pallet::Config;
+use crate::{
+	pallet::{Config, Error, InterestRateOf},
+	types::MutationError,
+};
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
@@ -17,7 +20,7 @@ pub struct ActiveInterestRate<T: Config> {
 	/// It the rate it has been penalized,
 	/// it contains the result of applying that penalty:
 	/// interest_rate = base_rate + penalty
-	interest_rate: InterestRate<T::Rate>,
+	interest_rate: InterestRateOf<T>,
 
 	/// Normalized accumulation of the interest rate.
 	/// Used to get the current interest
@@ -28,8 +31,8 @@ pub struct ActiveInterestRate<T: Config> {
 }
 
 impl<T: Config> ActiveInterestRate<T> {
-	pub fn activate(interest_rate: InterestRate<T::Rate>) -> Result<Self, DispatchError> {
-		T::InterestAccrual::reference_rate(&interest_rate)?;
+	pub fn activate(interest_rate: InterestRateOf<T>) -> Result<Self, DispatchError> {
+		T::InterestAccrual::reference_rate(&interest_rate.accrual_rate())?;
 		Ok(Self {
 			interest_rate,
 			normalized_acc: T::Balance::zero(),
@@ -37,8 +40,8 @@ impl<T: Config> ActiveInterestRate<T> {
 		})
 	}
 
-	pub fn deactivate(self) -> Result<InterestRate<T::Rate>, DispatchError> {
-		T::InterestAccrual::unreference_rate(&self.interest_rate)?;
+	pub fn deactivate(self) -> Result<InterestRateOf<T>, DispatchError> {
+		T::InterestAccrual::unreference_rate(&self.interest_rate.accrual_rate())?;
 		Ok(self.interest_rate)
 	}
 
@@ -46,29 +49,34 @@ impl<T: Config> ActiveInterestRate<T> {
 		!self.normalized_acc.is_zero()
 	}
 
-	pub fn rate(&self) -> &InterestRate<T::Rate> {
+	pub fn rate(&self) -> &InterestRateOf<T> {
 		&self.interest_rate
 	}
 
+	/// Interest rate currently accruing, with the reference rate resolved.
+	pub fn accrual_rate(&self) -> InterestRate<T::Rate> {
+		self.interest_rate.accrual_rate()
+	}
+
 	pub fn penalty(&self) -> T::Rate {
 		self.penalty
 	}
 
 	pub fn current_debt(&self) -> Result<T::Balance, DispatchError> {
 		let now = T::Time::now();
-		T::InterestAccrual::calculate_debt(&self.interest_rate, self.normalized_acc, now)
+		T::InterestAccrual::calculate_debt(&self.accrual_rate(), self.normalized_acc, now)
 	}
 
 	pub fn current_debt_cached<Rates>(&self, cache: &Rates) -> Result<T::Balance, DispatchError>
 	where
 		Rates: RateCollection<T::Rate, T::Balance, T::Balance>,
 	{
-		cache.current_debt(&self.interest_rate, self.normalized_acc)
+		cache.current_debt(&self.accrual_rate(), self.normalized_acc)
 	}
 
 	pub fn adjust_debt(&mut self, adjustment: Adjustment<T::Balance>) -> DispatchResult {
 		self.normalized_acc = T::InterestAccrual::adjust_normalized_debt(
-			&self.interest_rate,
+			&self.accrual_rate(),
 			self.normalized_acc,
 			adjustment,
 		)?;
@@ -81,25 +89,135 @@ impl<T: Config> ActiveInterestRate<T> {
 		self.update_rate(base_rate, new_penalty)
 	}
 
+	/// Sets a new base rate.
+	/// A floating rate keeps its reference and reference rate,
+	/// so only the spread, compounding and day count are modified.
 	pub fn set_base_rate(&mut self, base_rate: InterestRate<T::Rate>) -> DispatchResult {
+		let base_rate = match (&self.interest_rate, base_rate) {
+			(
+				InterestRate::Fixed { .. },
+				InterestRate::Fixed {
+					rate_per_year,
+					compounding,
+					day_count,
+				},
+			) => InterestRate::Fixed {
+				rate_per_year,
+				compounding,
+				day_count,
+			},
+			(
+				InterestRate::Floating {
+					reference,
+					reference_rate,
+					..
+				},
+				InterestRate::Floating {
+					spread,
+					compounding,
+					day_count,
+					..
+				},
+			) => InterestRate::Floating {
+				reference: *reference,
+				reference_rate: *reference_rate,
+				spread,
+				compounding,
+				day_count,
+			},
+			_ => Err(Error::<T>::from(MutationError::InterestRateKindMismatch))?,
+		};
+
 		self.update_rate(base_rate, self.penalty)
 	}
 
+	/// Sets the last known value of the reference of a floating rate.
+	/// Returns `true` if the rate was floating and its value changed.
+	pub fn set_reference_rate(&mut self, reference_rate: T::Rate) -> Result<bool, DispatchError> {
+		match &self.interest_rate {
+			InterestRate::Floating {
+				reference_rate: current,
+				..
+			} if *current != reference_rate => {
+				let base_rate = self
+					.interest_rate
+					.clone()
+					.ensure_sub(self.penalty)?
+					.with_reference_rate(reference_rate);
+
+				self.update_rate(base_rate, self.penalty)?;
+				Ok(true)
+			}
+			_ => Ok(false),
+		}
+	}
+
 	fn update_rate(
 		&mut self,
-		new_base_rate: InterestRate<T::Rate>,
+		new_base_rate: InterestRateOf<T>,
 		new_penalty: T::Rate,
 	) -> DispatchResult {
 		let new_rate = new_base_rate.ensure_add(new_penalty)?;
-		let old_rate = self.interest_rate.clone();
+		let old_rate = self.interest_rate.accrual_rate();
 
-		T::InterestAccrual::reference_rate(&new_rate)?;
+		T::InterestAccrual::reference_rate(&new_rate.accrual_rate())?;
 
-		self.normalized_acc =
-			T::InterestAccrual::renormalize_debt(&old_rate, &new_rate, self.normalized_acc)?;
+		self.normalized_acc = T::InterestAccrual::renormalize_debt(
+			&old_rate,
+			&new_rate.accrual_rate(),
+			self.normalized_acc,
+		)?;
 		self.interest_rate = new_rate;
 		self.penalty = new_penalty;
 
 		T::InterestAccrual::unreference_rate(&old_rate)
 	}
 }
+
+/// Interest rates before day count conventions, for migration to v5
+pub mod v4 {
+	use cfg_traits::interest::{CompoundingSchedule, DayCountConvention};
+	use parity_scale_codec::{Decode, Encode};
+
+	use crate::pallet::Config;
+
+	#[derive(Encode, Decode)]
+	pub enum InterestRate<Rate> {
+		Fixed {
+			rate_per_year: Rate,
+			compounding: CompoundingSchedule,
+		},
+	}
+
+	impl<Rate> InterestRate<Rate> {
+		pub fn migrate<Reference>(self) -> cfg_traits::interest::InterestRate<Rate, Reference> {
+			match self {
+				InterestRate::Fixed {
+					rate_per_year,
+					compounding,
+				} => cfg_traits::interest::InterestRate::Fixed {
+					rate_per_year,
+					compounding,
+					day_count: DayCountConvention::Actual365,
+				},
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct ActiveInterestRate<T: Config> {
+		interest_rate: InterestRate<T::Rate>,
+		normalized_acc: T::Balance,
+		penalty: T::Rate,
+	}
+
+	impl<T: Config> ActiveInterestRate<T> {
+		pub fn migrate(self) -> super::ActiveInterestRate<T> {
+			super::ActiveInterestRate {
+				interest_rate: self.interest_rate.migrate(),
+				normalized_acc: self.normalized_acc,
+				penalty: self.penalty,
+			}
+		}
+	}
+}
@@ -1,6 +1,7 @@
 use cfg_traits::{
 	self,
-	interest::{InterestAccrual, InterestRate, RateCollection},
+	data::DataRegistry,
+	interest::{InterestAccrual, RateCollection},
 	Seconds, TimeAsSecs,
 };
 use cfg_types::adjustments::Adjustment;
@@ -10,29 +11,30 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		BlockNumberProvider, EnsureAdd, EnsureAddAssign, EnsureFixedPointNumber, EnsureSub, Zero,
+		BlockNumberProvider, EnsureAdd, EnsureAddAssign, EnsureDiv, EnsureFixedPointNumber,
+		EnsureMul, EnsureSub, EnsureSubAssign, One, Zero,
 	},
 	DispatchError,
 };
-use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 
 use crate::{
 	entities::{
-		changes::LoanMutation,
+		changes::{CollateralSubstitution, LoanMutation, Restructuring},
 		input::{PrincipalInput, RepaidInput},
 		pricing::{
 			external::ExternalActivePricing, internal::InternalActivePricing, ActivePricing,
 			Pricing,
 		},
 	},
-	pallet::{AssetOf, Config, Error},
+	pallet::{AssetOf, CollateralOf, Config, Error},
 	types::{
-		cashflow::{CashflowPayment, RepaymentSchedule},
+		cashflow::{CashflowPayment, PaymentStatus, RepaymentSchedule},
 		policy::{WriteOffStatus, WriteOffTrigger},
 		BorrowLoanError, BorrowRestrictions, CloseLoanError, CreateLoanError, LoanRestrictions,
 		MutationError, RepaidAmount, RepayLoanError, RepayRestrictions,
 	},
-	PriceOf,
+	InterestRateOf, PriceOf,
 };
 
 /// Loan information.
@@ -44,11 +46,11 @@ pub struct LoanInfo<T: Config> {
 	/// Specify the repayments schedule of the loan
 	pub schedule: RepaymentSchedule,
 
-	/// Collateral used for this loan
-	pub collateral: AssetOf<T>,
+	/// Collateral items used for this loan
+	pub collateral: CollateralOf<T>,
 
 	/// Interest rate per year
-	pub interest_rate: InterestRate<T::Rate>,
+	pub interest_rate: InterestRateOf<T>,
 
 	/// Pricing properties for this loan
 	pub pricing: Pricing<T>,
@@ -58,18 +60,23 @@ pub struct LoanInfo<T: Config> {
 }
 
 impl<T: Config> LoanInfo<T> {
-	pub fn collateral(&self) -> AssetOf<T> {
-		self.collateral
+	pub fn collateral(&self) -> &CollateralOf<T> {
+		&self.collateral
 	}
 
 	/// Validates the loan information.
 	pub fn validate(&self, now: Seconds) -> DispatchResult {
+		ensure!(
+			is_valid_collateral::<T>(&self.collateral),
+			Error::<T>::from(CreateLoanError::InvalidCollateral)
+		);
+
 		match &self.pricing {
 			Pricing::Internal(pricing) => pricing.validate()?,
 			Pricing::External(pricing) => pricing.validate()?,
 		}
 
-		T::InterestAccrual::validate_rate(&self.interest_rate)?;
+		T::InterestAccrual::validate_rate(&self.interest_rate.accrual_rate())?;
 
 		ensure!(
 			self.schedule.is_valid(now)?,
@@ -144,8 +151,48 @@ pub struct ClosedLoan<T: Config> {
 }
 
 impl<T: Config> ClosedLoan<T> {
-	pub fn collateral(&self) -> AssetOf<T> {
-		self.info.collateral
+	pub fn collateral(&self) -> &CollateralOf<T> {
+		&self.info.collateral
+	}
+}
+
+/// A collateral is valid if it is not empty and has no repeated items
+fn is_valid_collateral<T: Config>(collateral: &[AssetOf<T>]) -> bool {
+	!collateral.is_empty()
+		&& collateral
+			.iter()
+			.enumerate()
+			.all(|(i, asset)| !collateral[..i].contains(asset))
+}
+
+/// Reference the payment statuses of an active loan are computed from.
+/// It is rebased on each borrow, so the scheduled payments cover the
+/// principal of every draw.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ScheduleBase<T: Config> {
+	/// Date the scheduled payments are generated from
+	since: Seconds,
+
+	/// Principal the scheduled payments are generated for
+	principal: T::Balance,
+
+	/// Amounts due before `since` that were not repaid,
+	/// expected at the date of the earliest of them
+	overdue: Option<CashflowPayment<T::Balance>>,
+
+	/// Amount repaid until `since`, already allocated to previous payments
+	repaid: RepaidAmount<T::Balance>,
+}
+
+impl<T: Config> ScheduleBase<T> {
+	fn new(since: Seconds, principal: T::Balance, repaid: RepaidAmount<T::Balance>) -> Self {
+		Self {
+			since,
+			principal,
+			overdue: None,
+			repaid,
+		}
 	}
 }
 
@@ -157,7 +204,7 @@ pub struct ActiveLoan<T: Config> {
 	schedule: RepaymentSchedule,
 
 	/// Collateral used for this loan
-	collateral: AssetOf<T>,
+	collateral: CollateralOf<T>,
 
 	/// Restrictions of this loan
 	restrictions: LoanRestrictions,
@@ -180,9 +227,15 @@ pub struct ActiveLoan<T: Config> {
 	/// Total repaid amount of this loan
 	total_repaid: RepaidAmount<T::Balance>,
 
+	/// Total principal forgiven by restructurings of this loan
+	total_forgiven: T::Balance,
+
 	/// Until this date all principal & interest
 	/// payments occurred as scheduled.
 	repayments_on_schedule_until: Seconds,
+
+	/// Reference of the scheduled payments
+	schedule_base: ScheduleBase<T>,
 }
 
 impl<T: Config> ActiveLoan<T> {
@@ -193,6 +246,10 @@ impl<T: Config> ActiveLoan<T> {
 		initial_amount: PrincipalInput<T>,
 		now: Seconds,
 	) -> Result<Self, DispatchError> {
+		if let Some(reference) = info.interest_rate.reference() {
+			T::PriceRegistry::register_id(reference, &pool_id)?;
+		}
+
 		Ok(ActiveLoan {
 			schedule: info.schedule,
 			collateral: info.collateral,
@@ -201,7 +258,7 @@ impl<T: Config> ActiveLoan<T> {
 			origination_date: now,
 			pricing: match info.pricing {
 				Pricing::Internal(inner) => ActivePricing::Internal(
-					InternalActivePricing::activate(inner, info.interest_rate)?,
+					InternalActivePricing::activate(inner, info.interest_rate, pool_id)?,
 				),
 				Pricing::External(inner) => {
 					ActivePricing::External(ExternalActivePricing::activate(
@@ -216,7 +273,9 @@ impl<T: Config> ActiveLoan<T> {
 			restrictions: info.restrictions,
 			total_borrowed: T::Balance::zero(),
 			total_repaid: RepaidAmount::default(),
+			total_forgiven: T::Balance::zero(),
 			repayments_on_schedule_until: now,
+			schedule_base: ScheduleBase::new(now, T::Balance::zero(), RepaidAmount::default()),
 		})
 	}
 
@@ -224,6 +283,10 @@ impl<T: Config> ActiveLoan<T> {
 		&self.borrower
 	}
 
+	pub fn collateral(&self) -> &CollateralOf<T> {
+		&self.collateral
+	}
+
 	pub fn origination_date(&self) -> Seconds {
 		self.origination_date
 	}
@@ -243,10 +306,60 @@ impl<T: Config> ActiveLoan<T> {
 		}
 	}
 
+	/// Oracle key of the reference rate of floating interest rates
+	pub fn reference_id(&self) -> Option<T::PriceId> {
+		self.pricing.interest().rate().reference().copied()
+	}
+
+	/// All oracle keys this loan reads from
+	pub fn price_ids(&self) -> Vec<T::PriceId> {
+		let mut price_ids = match &self.pricing {
+			ActivePricing::Internal(inner) => inner.price_ids().collect(),
+			ActivePricing::External(inner) => sp_std::vec![inner.price_id()],
+		};
+		price_ids.extend(self.reference_id());
+		price_ids
+	}
+
+	/// Updates the reference rate of a floating interest rate with the value
+	/// of its reference in `prices`, if any.
+	/// The oracle value is expected to have the precision of `T::Rate`,
+	/// and is truncated to the 4 decimals supported by interest rates.
+	/// Returns the new reference rate if it changed.
+	pub fn update_reference_rate(
+		&mut self,
+		prices: &BTreeMap<T::PriceId, PriceOf<T>>,
+	) -> Result<Option<T::Rate>, DispatchError> {
+		let Some((value, _)) = self.reference_id().and_then(|id| prices.get(&id)) else {
+			return Ok(None);
+		};
+
+		let four_decimals = T::Rate::saturating_from_integer(10000);
+		let reference_rate = T::Rate::saturating_from_rational(*value, T::Rate::DIV)
+			.ensure_mul(four_decimals)?
+			.trunc()
+			.ensure_div(four_decimals)?;
+
+		Ok(self
+			.pricing
+			.interest_mut()
+			.set_reference_rate(reference_rate)?
+			.then_some(reference_rate))
+	}
+
+	pub fn total_repaid(&self) -> &RepaidAmount<T::Balance> {
+		&self.total_repaid
+	}
+
+	pub fn total_forgiven(&self) -> T::Balance {
+		self.total_forgiven
+	}
+
 	pub fn principal(&self) -> Result<T::Balance, DispatchError> {
 		Ok(self
 			.total_borrowed
-			.ensure_sub(self.total_repaid.principal)?)
+			.ensure_sub(self.total_repaid.principal)?
+			.ensure_sub(self.total_forgiven)?)
 	}
 
 	pub fn expected_cashflows(&self) -> Result<Vec<CashflowPayment<T::Balance>>, DispatchError> {
@@ -257,10 +370,144 @@ impl<T: Config> ActiveLoan<T> {
 				ActivePricing::Internal(_) => self.principal()?,
 				ActivePricing::External(inner) => inner.outstanding_notional_principal()?,
 			},
-			self.pricing.interest().rate(),
+			&self.pricing.interest().accrual_rate(),
 		)
 	}
 
+	/// Payments scheduled since the schedule base, each one with the amount
+	/// repaid for it. The amount repaid since the base is allocated to the
+	/// payments in order, so a payment is only considered paid once all
+	/// previous payments are paid.
+	fn scheduled_payments(
+		&self,
+	) -> Result<Vec<(CashflowPayment<T::Balance>, T::Balance)>, DispatchError> {
+		let base = &self.schedule_base;
+		let mut repaid = self
+			.total_repaid
+			.effective()?
+			.ensure_sub(base.repaid.effective()?)?;
+
+		let payments = match self.maturity_date() {
+			Some(maturity) if maturity <= base.since => vec![CashflowPayment {
+				when: maturity,
+				principal: base.principal,
+				interest: T::Balance::zero(),
+			}],
+			_ => self.schedule.generate_cashflows(
+				base.since,
+				base.principal,
+				base.principal,
+				&self.pricing.interest().accrual_rate(),
+			)?,
+		};
+
+		base.overdue
+			.clone()
+			.into_iter()
+			.chain(payments)
+			.map(|payment| {
+				let expected = payment.principal.ensure_add(payment.interest)?;
+				let allocated = repaid.min(expected);
+				repaid.ensure_sub_assign(allocated)?;
+
+				Ok((payment, allocated))
+			})
+			.collect()
+	}
+
+	/// Amounts due until `now` and not repaid yet, expected at the date of
+	/// the earliest of them. Repayments cover the interest of each payment
+	/// before its principal.
+	fn overdue_payment(
+		&self,
+		now: Seconds,
+	) -> Result<Option<CashflowPayment<T::Balance>>, DispatchError> {
+		self.scheduled_payments()?
+			.into_iter()
+			.filter(|(payment, _)| payment.when <= now)
+			.try_fold(None, |overdue, (payment, repaid)| {
+				let interest = payment.interest.saturating_sub(repaid);
+				let principal = payment
+					.principal
+					.saturating_sub(repaid.saturating_sub(payment.interest));
+
+				if principal.is_zero() && interest.is_zero() {
+					return Ok(overdue);
+				}
+
+				Ok(Some(match overdue {
+					Some(CashflowPayment {
+						when,
+						principal: overdue_principal,
+						interest: overdue_interest,
+					}) => CashflowPayment {
+						when,
+						principal: overdue_principal.ensure_add(principal)?,
+						interest: overdue_interest.ensure_add(interest)?,
+					},
+					None => CashflowPayment {
+						when: payment.when,
+						principal,
+						interest,
+					},
+				}))
+			})
+	}
+
+	/// Principal the schedule expects to be repaid until `now` that has not
+	/// been repaid yet. Any principal repaid before, on schedule or ahead of
+	/// it, counts towards the scheduled payments.
+	pub fn outstanding_scheduled_principal(
+		&self,
+		now: Seconds,
+	) -> Result<T::Balance, DispatchError> {
+		let scheduled = self
+			.scheduled_payments()?
+			.iter()
+			.filter(|(payment, _)| payment.when <= now)
+			.try_fold(T::Balance::zero(), |due, (payment, _)| {
+				due.ensure_add(payment.principal)
+			})?;
+
+		let repaid = self
+			.total_repaid
+			.principal
+			.ensure_sub(self.schedule_base.repaid.principal)?;
+
+		Ok(scheduled.saturating_sub(repaid))
+	}
+
+	/// Status of each payment scheduled since the last borrow, preceded by
+	/// the amounts that were already overdue at that moment, if any.
+	pub fn payment_statuses(
+		&self,
+		now: Seconds,
+	) -> Result<Vec<(CashflowPayment<T::Balance>, PaymentStatus)>, DispatchError> {
+		self.scheduled_payments()?
+			.into_iter()
+			.map(|(payment, repaid)| {
+				let expected = payment.principal.ensure_add(payment.interest)?;
+
+				let status = if repaid >= expected {
+					PaymentStatus::Paid
+				} else if !repaid.is_zero() {
+					PaymentStatus::PartiallyPaid
+				} else if payment.when <= now {
+					PaymentStatus::Missed
+				} else {
+					PaymentStatus::Pending
+				};
+
+				Ok((payment, status))
+			})
+			.collect()
+	}
+
+	/// Date of the earliest scheduled payment not fully repaid on time
+	pub fn first_overdue_payment(&self, now: Seconds) -> Result<Option<Seconds>, DispatchError> {
+		Ok(self.overdue_payment(now)?.map(|payment| payment.when))
+	}
+
 	pub fn write_off_status(&self) -> WriteOffStatus<T::Rate> {
 		WriteOffStatus {
 			percentage: self.write_off_percentage,
@@ -290,6 +537,12 @@ impl<T: Config> ActiveLoan<T> {
 				}
 				ActivePricing::Internal(_) => Ok(false),
 			},
+			WriteOffTrigger::PaymentOverdue(overdue_secs) => {
+				match self.first_overdue_payment(now)? {
+					Some(when) => Ok(now >= when.ensure_add(*overdue_secs)?),
+					None => Ok(false),
+				}
+			}
 		}
 	}
 
@@ -297,7 +550,7 @@ impl<T: Config> ActiveLoan<T> {
 		let maturity_date = self.schedule.maturity.date();
 		let value = match &self.pricing {
 			ActivePricing::Internal(inner) => {
-				inner.present_value(self.origination_date, maturity_date)?
+				inner.present_value(pool_id, self.origination_date, &self.schedule)?
 			}
 			ActivePricing::External(inner) => inner.present_value(pool_id, maturity_date)?,
 		};
@@ -320,7 +573,7 @@ impl<T: Config> ActiveLoan<T> {
 		let maturity_date = self.schedule.maturity.date();
 		let value = match &self.pricing {
 			ActivePricing::Internal(inner) => {
-				inner.present_value_cached(rates, self.origination_date, maturity_date)?
+				inner.present_value_cached(rates, prices, self.origination_date, &self.schedule)?
 			}
 			ActivePricing::External(inner) => inner.present_value_cached(prices, maturity_date)?,
 		};
@@ -361,9 +614,8 @@ impl<T: Config> ActiveLoan<T> {
 			Error::<T>::from(BorrowLoanError::Restriction)
 		);
 
-		let now = T::Time::now();
 		ensure!(
-			self.schedule.maturity.is_valid(now),
+			self.schedule.maturity.is_valid(T::Time::now()),
 			Error::<T>::from(BorrowLoanError::MaturityDatePassed)
 		);
 
@@ -373,8 +625,25 @@ impl<T: Config> ActiveLoan<T> {
 	pub fn borrow(&mut self, amount: &PrincipalInput<T>, pool_id: T::PoolId) -> DispatchResult {
 		self.ensure_can_borrow(amount, pool_id)?;
 
+		// The payments of the new draw are scheduled together with the
+		// outstanding principal, carrying the amounts already overdue.
+		let now = T::Time::now();
+		let overdue = self.overdue_payment(now)?;
+
 		self.total_borrowed.ensure_add_assign(amount.balance()?)?;
 
+		let overdue_principal = overdue
+			.as_ref()
+			.map(|payment| payment.principal)
+			.unwrap_or_default();
+
+		self.schedule_base = ScheduleBase {
+			since: now,
+			principal: self.principal()?.saturating_sub(overdue_principal),
+			overdue,
+			repaid: self.total_repaid.clone(),
+		};
+
 		match &mut self.pricing {
 			ActivePricing::Internal(inner) => {
 				inner.adjust(Adjustment::Increase(amount.balance()?))?
@@ -436,15 +705,25 @@ impl<T: Config> ActiveLoan<T> {
 		Ok(amount)
 	}
 
+	/// Repays the loan, returning the amount repaid and the part of the
+	/// principal that was paid ahead of the schedule.
 	pub fn repay(
 		&mut self,
 		amount: RepaidInput<T>,
 		pool_id: T::PoolId,
-	) -> Result<RepaidInput<T>, DispatchError> {
+	) -> Result<(RepaidInput<T>, T::Balance), DispatchError> {
 		let amount = self.prepare_repayment(amount, pool_id)?;
 
-		self.total_repaid
-			.ensure_add_assign(&amount.repaid_amount()?)?;
+		let outstanding_scheduled = self.outstanding_scheduled_principal(T::Time::now())?;
+		let repaid = RepaidAmount {
+			prepaid: amount
+				.principal
+				.balance()?
+				.saturating_sub(outstanding_scheduled),
+			..amount.repaid_amount()?
+		};
+
+		self.total_repaid.ensure_add_assign(&repaid)?;
 
 		match &mut self.pricing {
 			ActivePricing::Internal(inner) => {
@@ -459,7 +738,7 @@ impl<T: Config> ActiveLoan<T> {
 
 		self.repayments_on_schedule_until = T::Time::now();
 
-		Ok(amount)
+		Ok((amount, repaid.prepaid))
 	}
 
 	pub fn write_off(&mut self, new_status: &WriteOffStatus<T::Rate>) -> DispatchResult {
@@ -484,9 +763,13 @@ impl<T: Config> ActiveLoan<T> {
 	pub fn close(self, pool_id: T::PoolId) -> Result<(ClosedLoan<T>, T::AccountId), DispatchError> {
 		self.ensure_can_close()?;
 
+		if let Some(reference) = self.reference_id() {
+			T::PriceRegistry::unregister_id(&reference, &pool_id)?;
+		}
+
 		let (pricing, interest_rate) = match self.pricing {
 			ActivePricing::Internal(inner) => {
-				let (pricing, interest_rate) = inner.deactivate()?;
+				let (pricing, interest_rate) = inner.deactivate(pool_id)?;
 				(Pricing::Internal(pricing), interest_rate)
 			}
 			ActivePricing::External(inner) => {
@@ -511,34 +794,171 @@ impl<T: Config> ActiveLoan<T> {
 		Ok((loan, self.borrower))
 	}
 
-	pub fn mutate_with(&mut self, mutation: LoanMutation<T::Rate>) -> DispatchResult {
+	pub fn mutate_with(
+		&mut self,
+		mutation: LoanMutation<T::Rate, T::PriceId>,
+		pool_id: T::PoolId,
+	) -> DispatchResult {
 		match mutation {
-			LoanMutation::Maturity(maturity) => self.schedule.maturity = maturity,
-			LoanMutation::MaturityExtension(extension) => self
-				.schedule
-				.maturity
-				.extends(extension)
-				.map_err(|_| Error::<T>::from(MutationError::MaturityExtendedTooMuch))?,
+			LoanMutation::Maturity(maturity) => {
+				self.schedule.maturity = maturity;
+				self.ensure_valid_schedule()?;
+			}
+			LoanMutation::MaturityExtension(extension) => {
+				self.schedule
+					.maturity
+					.extends(extension)
+					.map_err(|_| Error::<T>::from(MutationError::MaturityExtendedTooMuch))?;
+				self.ensure_valid_schedule()?;
+			}
 			LoanMutation::InterestRate(rate) => self.pricing.interest_mut().set_base_rate(rate)?,
-			LoanMutation::InterestPayments(payments) => self.schedule.interest_payments = payments,
-			LoanMutation::PayDownSchedule(schedule) => self.schedule.pay_down_schedule = schedule,
+			LoanMutation::InterestPayments(payments) => {
+				self.schedule.interest_payments = payments;
+				self.ensure_valid_schedule()?;
+			}
+			LoanMutation::PayDownSchedule(schedule) => {
+				self.schedule.pay_down_schedule = schedule;
+				self.ensure_valid_schedule()?;
+			}
 			LoanMutation::Internal(mutation) => match &mut self.pricing {
-				ActivePricing::Internal(inner) => inner.mutate_with(mutation)?,
+				ActivePricing::Internal(inner) => inner.mutate_with(mutation, pool_id)?,
 				ActivePricing::External(_) => {
 					Err(Error::<T>::from(MutationError::InternalPricingExpected))?
 				}
 			},
+			LoanMutation::Restructure(restructuring) => self.restructure(restructuring)?,
 		};
 
 		Ok(())
 	}
 
+	/// Capitalizes the interest and forgives principal as specified by the
+	/// restructuring, and replaces the repayment schedule.
+	/// Repayments are considered on schedule from now, under the new terms.
+	fn restructure(&mut self, restructuring: Restructuring<T::Rate>) -> DispatchResult {
+		let ActivePricing::Internal(inner) = &mut self.pricing else {
+			Err(Error::<T>::from(MutationError::InternalPricingExpected))?
+		};
+
+		if restructuring.capitalize_interest {
+			let principal = self
+				.total_borrowed
+				.ensure_sub(self.total_repaid.principal)?
+				.ensure_sub(self.total_forgiven)?;
+			let interest = inner.outstanding_interest(principal)?;
+			self.total_borrowed.ensure_add_assign(interest)?;
+		}
+
+		if let Some(forgiveness) = restructuring.forgiveness {
+			ensure!(
+				forgiveness <= One::one(),
+				Error::<T>::from(MutationError::InvalidForgiveness)
+			);
+
+			let principal = self
+				.total_borrowed
+				.ensure_sub(self.total_repaid.principal)?
+				.ensure_sub(self.total_forgiven)?;
+			let forgiven = forgiveness.ensure_mul_int(principal)?;
+			inner.adjust(Adjustment::Decrease(forgiven))?;
+			self.total_forgiven.ensure_add_assign(forgiven)?;
+		}
+
+		self.schedule = restructuring.schedule;
+		self.repayments_on_schedule_until = T::Time::now();
+		self.schedule_base =
+			ScheduleBase::new(T::Time::now(), self.principal()?, self.total_repaid.clone());
+		self.ensure_valid_schedule()
+	}
+
+	/// Current terms of the loan
+	pub fn terms(&self) -> Result<LoanTerms<T>, DispatchError> {
+		let principal = self.principal()?;
+		let interest = match &self.pricing {
+			ActivePricing::Internal(inner) => inner.outstanding_interest(principal)?,
+			ActivePricing::External(inner) => inner.outstanding_interest()?,
+		};
+
+		Ok(LoanTerms {
+			schedule: self.schedule.clone(),
+			principal,
+			interest,
+		})
+	}
+
+	/// Removes and adds the collateral items of the substitution,
+	/// updating the collateral value of internal pricing if given.
+	pub fn substitute_collateral(
+		&mut self,
+		substitution: &CollateralSubstitution<T>,
+	) -> DispatchResult {
+		let mut collateral = self.collateral.to_vec();
+		for asset in &substitution.removed {
+			let position = collateral
+				.iter()
+				.position(|current| current == asset)
+				.ok_or(Error::<T>::from(MutationError::CollateralNotFound))?;
+			collateral.remove(position);
+		}
+		collateral.extend(substitution.added.iter().copied());
+
+		ensure!(
+			is_valid_collateral::<T>(&collateral),
+			Error::<T>::from(MutationError::InvalidCollateral)
+		);
+
+		self.collateral = collateral
+			.try_into()
+			.map_err(|_| Error::<T>::from(MutationError::InvalidCollateral))?;
+
+		if let Some(value) = substitution.collateral_value {
+			match &mut self.pricing {
+				ActivePricing::Internal(inner) => {
+					inner.set_collateral_value(value, self.total_borrowed)?
+				}
+				ActivePricing::External(_) => {
+					Err(Error::<T>::from(MutationError::InternalPricingExpected))?
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	fn ensure_valid_schedule(&self) -> DispatchResult {
+		ensure!(
+			self.schedule
+				.is_valid_since(self.origination_date, T::Time::now())?,
+			Error::<T>::from(MutationError::InvalidRepaymentSchedule)
+		);
+
+		Ok(())
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
-	pub fn set_maturity(&mut self, duration: Seconds) {
-		self.schedule.maturity = crate::types::cashflow::Maturity::fixed(duration);
+	pub fn set_overdue_payment(&mut self, when: Seconds) {
+		self.schedule_base.overdue = Some(CashflowPayment {
+			when,
+			principal: T::Balance::zero(),
+			interest: T::Balance::one(),
+		});
 	}
 }
 
+/// Terms of an active loan, reported before and after a restructuring
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct LoanTerms<T: Config> {
+	/// Repayment schedule of the loan
+	pub schedule: RepaymentSchedule,
+
+	/// Outstanding principal of the loan
+	pub principal: T::Balance,
+
+	/// Outstanding interest of the loan
+	pub interest: T::Balance,
+}
+
 /// Data containing an active loan with extra computed.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
@@ -598,15 +1018,16 @@ impl<T: Config> TryFrom<(T::PoolId, ActiveLoan<T>)> for ActiveLoanInfo<T> {
 
 /// Adds `with_linear_pricing` to ExternalPricing struct for migration to v4
 pub mod v3 {
-	use cfg_traits::{interest::InterestRate, Seconds};
+	use cfg_traits::Seconds;
 	use parity_scale_codec::{Decode, Encode};
 
 	use crate::{
 		entities::{
+			interest::v4::InterestRate,
 			loans::BlockNumberFor,
 			pricing::external::v3::{ActivePricing, Pricing},
 		},
-		types::{cashflow::RepaymentSchedule, LoanRestrictions, RepaidAmount},
+		types::{cashflow::RepaymentSchedule, v4::RepaidAmount, LoanRestrictions},
 		AssetOf, Config,
 	};
 
@@ -625,8 +1046,8 @@ pub mod v3 {
 	}
 
 	impl<T: Config> ActiveLoan<T> {
-		pub fn migrate(self, with_linear_pricing: bool) -> super::ActiveLoan<T> {
-			super::ActiveLoan {
+		pub fn migrate(self, with_linear_pricing: bool) -> super::v4::ActiveLoan<T> {
+			super::v4::ActiveLoan {
 				schedule: self.schedule,
 				collateral: self.collateral,
 				restrictions: self.restrictions,
@@ -648,8 +1069,11 @@ pub mod v3 {
 	}
 
 	impl<T: Config> CreatedLoan<T> {
-		pub fn migrate(self, with_linear_pricing: bool) -> super::CreatedLoan<T> {
-			super::CreatedLoan::<T>::new(self.info.migrate(with_linear_pricing), self.borrower)
+		pub fn migrate(self, with_linear_pricing: bool) -> super::v4::CreatedLoan<T> {
+			super::v4::CreatedLoan {
+				info: self.info.migrate(with_linear_pricing),
+				borrower: self.borrower,
+			}
 		}
 	}
 
@@ -662,8 +1086,8 @@ pub mod v3 {
 	}
 
 	impl<T: Config> ClosedLoan<T> {
-		pub fn migrate(self, with_linear_pricing: bool) -> super::ClosedLoan<T> {
-			super::ClosedLoan::<T> {
+		pub fn migrate(self, with_linear_pricing: bool) -> super::v4::ClosedLoan<T> {
+			super::v4::ClosedLoan {
 				closed_at: self.closed_at,
 				info: self.info.migrate(with_linear_pricing),
 				total_borrowed: self.total_borrowed,
@@ -682,8 +1106,8 @@ pub mod v3 {
 	}
 
 	impl<T: Config> LoanInfo<T> {
-		pub fn migrate(self, with_linear_pricing: bool) -> super::LoanInfo<T> {
-			super::LoanInfo::<T> {
+		pub fn migrate(self, with_linear_pricing: bool) -> super::v4::LoanInfo<T> {
+			super::v4::LoanInfo {
 				pricing: self.pricing.migrate(with_linear_pricing),
 				schedule: self.schedule,
 				collateral: self.collateral,
@@ -693,3 +1117,113 @@ pub mod v3 {
 		}
 	}
 }
+
+/// Loans before day count conventions, multiple collateral items and
+/// prepaid principal tracking, for migration to v5
+pub mod v4 {
+	use cfg_traits::Seconds;
+	use frame_support::BoundedVec;
+	use parity_scale_codec::{Decode, Encode};
+	use sp_std::vec;
+
+	use crate::{
+		entities::{
+			interest::v4::InterestRate,
+			loans::BlockNumberFor,
+			pricing::v4::{ActivePricing, Pricing},
+		},
+		types::{cashflow::RepaymentSchedule, v4::RepaidAmount, LoanRestrictions},
+		AssetOf, Config,
+	};
+
+	#[derive(Encode, Decode)]
+	pub struct LoanInfo<T: Config> {
+		pub schedule: RepaymentSchedule,
+		pub collateral: AssetOf<T>,
+		pub interest_rate: InterestRate<T::Rate>,
+		pub pricing: Pricing<T>,
+		pub restrictions: LoanRestrictions,
+	}
+
+	impl<T: Config> LoanInfo<T> {
+		pub fn migrate(self) -> super::LoanInfo<T> {
+			super::LoanInfo {
+				schedule: self.schedule,
+				collateral: BoundedVec::truncate_from(vec![self.collateral]),
+				interest_rate: self.interest_rate.migrate(),
+				pricing: self.pricing.migrate(),
+				restrictions: self.restrictions,
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct CreatedLoan<T: Config> {
+		pub info: LoanInfo<T>,
+		pub borrower: T::AccountId,
+	}
+
+	impl<T: Config> CreatedLoan<T> {
+		pub fn migrate(self) -> super::CreatedLoan<T> {
+			super::CreatedLoan::new(self.info.migrate(), self.borrower)
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct ActiveLoan<T: Config> {
+		pub schedule: RepaymentSchedule,
+		pub collateral: AssetOf<T>,
+		pub restrictions: LoanRestrictions,
+		pub borrower: T::AccountId,
+		pub write_off_percentage: T::Rate,
+		pub origination_date: Seconds,
+		pub pricing: ActivePricing<T>,
+		pub total_borrowed: T::Balance,
+		pub total_repaid: RepaidAmount<T::Balance>,
+		pub repayments_on_schedule_until: Seconds,
+	}
+
+	impl<T: Config> ActiveLoan<T> {
+		/// Payments are scheduled from the origination date, as if all the
+		/// principal had been borrowed at once.
+		pub fn migrate(self) -> super::ActiveLoan<T> {
+			super::ActiveLoan {
+				schedule: self.schedule,
+				collateral: BoundedVec::truncate_from(vec![self.collateral]),
+				restrictions: self.restrictions,
+				borrower: self.borrower,
+				write_off_percentage: self.write_off_percentage,
+				origination_date: self.origination_date,
+				pricing: self.pricing.migrate(),
+				total_borrowed: self.total_borrowed,
+				total_repaid: self.total_repaid.migrate(),
+				total_forgiven: Default::default(),
+				repayments_on_schedule_until: self.repayments_on_schedule_until,
+				schedule_base: super::ScheduleBase::new(
+					self.origination_date,
+					self.total_borrowed,
+					Default::default(),
+				),
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct ClosedLoan<T: Config> {
+		pub closed_at: BlockNumberFor<T>,
+		pub info: LoanInfo<T>,
+		pub total_borrowed: T::Balance,
+		pub total_repaid: RepaidAmount<T::Balance>,
+	}
+
+	impl<T: Config> ClosedLoan<T> {
+		pub fn migrate(self) -> super::ClosedLoan<T> {
+			super::ClosedLoan {
+				closed_at: self.closed_at,
+				info: self.info.migrate(),
+				total_borrowed: self.total_borrowed,
+				total_repaid: self.total_repaid.migrate(),
+			}
+		}
+	}
+}
@@ -44,3 +44,46 @@ impl<T: Config> ActivePricing<T> {
 		}
 	}
 }
+
+/// Pricing before day count conventions, for migration to v5
+pub mod v4 {
+	use parity_scale_codec::{Decode, Encode};
+
+	use crate::{
+		entities::pricing::{
+			external::{v4::ExternalActivePricing, ExternalPricing},
+			internal::v4::{InternalActivePricing, InternalPricing},
+		},
+		pallet::Config,
+	};
+
+	#[derive(Encode, Decode)]
+	pub enum Pricing<T: Config> {
+		Internal(InternalPricing<T>),
+		External(ExternalPricing<T>),
+	}
+
+	impl<T: Config> Pricing<T> {
+		pub fn migrate(self) -> super::Pricing<T> {
+			match self {
+				Pricing::Internal(inner) => super::Pricing::Internal(inner.migrate()),
+				Pricing::External(inner) => super::Pricing::External(inner),
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub enum ActivePricing<T: Config> {
+		Internal(InternalActivePricing<T>),
+		External(ExternalActivePricing<T>),
+	}
+
+	impl<T: Config> ActivePricing<T> {
+		pub fn migrate(self) -> super::ActivePricing<T> {
+			match self {
+				ActivePricing::Internal(inner) => super::ActivePricing::Internal(inner.migrate()),
+				ActivePricing::External(inner) => super::ActivePricing::External(inner.migrate()),
+			}
+		}
+	}
+}
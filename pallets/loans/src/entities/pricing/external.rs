@@ -1,6 +1,4 @@
-use cfg_traits::{
-	self, data::DataRegistry, interest::InterestRate, IntoSeconds, Seconds, TimeAsSecs,
-};
+use cfg_traits::{self, data::DataRegistry, IntoSeconds, Seconds, TimeAsSecs};
 use cfg_types::adjustments::Adjustment;
 use frame_support::{self, ensure, pallet_prelude::RuntimeDebug, RuntimeDebugNoBound};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -13,7 +11,7 @@ use sp_std::{cmp::min, collections::btree_map::BTreeMap};
 
 use crate::{
 	entities::interest::ActiveInterestRate,
-	pallet::{Config, Error},
+	pallet::{Config, Error, InterestRateOf},
 	PriceOf,
 };
 
@@ -111,7 +109,7 @@ pub struct ExternalActivePricing<T: Config> {
 impl<T: Config> ExternalActivePricing<T> {
 	pub fn activate(
 		info: ExternalPricing<T>,
-		interest_rate: InterestRate<T::Rate>,
+		interest_rate: InterestRateOf<T>,
 		pool_id: T::PoolId,
 		amount: ExternalAmount<T>,
 		price_required: bool,
@@ -134,7 +132,7 @@ impl<T: Config> ExternalActivePricing<T> {
 	pub fn deactivate(
 		self,
 		pool_id: T::PoolId,
-	) -> Result<(ExternalPricing<T>, InterestRate<T::Rate>), DispatchError> {
+	) -> Result<(ExternalPricing<T>, InterestRateOf<T>), DispatchError> {
 		T::PriceRegistry::unregister_id(&self.info.price_id, &pool_id)?;
 		Ok((self.info, self.interest.deactivate()?))
 	}
@@ -334,24 +332,28 @@ pub mod v3 {
 
 	use crate::{
 		entities::{
-			interest::ActiveInterestRate,
-			pricing::{external::MaxBorrowAmount, internal, internal::InternalActivePricing},
+			interest::v4::ActiveInterestRate,
+			pricing::{
+				external::MaxBorrowAmount,
+				internal::v4::{InternalActivePricing, InternalPricing},
+				v4 as pricing_v4,
+			},
 		},
 		Config,
 	};
 
 	#[derive(Encode, Decode)]
 	pub enum Pricing<T: Config> {
-		Internal(internal::InternalPricing<T>),
+		Internal(InternalPricing<T>),
 		External(ExternalPricing<T>),
 	}
 
 	impl<T: Config> Pricing<T> {
-		pub fn migrate(self, with_linear_pricing: bool) -> crate::entities::pricing::Pricing<T> {
+		pub fn migrate(self, with_linear_pricing: bool) -> pricing_v4::Pricing<T> {
 			match self {
-				Pricing::Internal(i) => crate::entities::pricing::Pricing::Internal(i),
+				Pricing::Internal(i) => pricing_v4::Pricing::Internal(i),
 				Pricing::External(e) => {
-					crate::entities::pricing::Pricing::External(e.migrate(with_linear_pricing))
+					pricing_v4::Pricing::External(e.migrate(with_linear_pricing))
 				}
 			}
 		}
@@ -372,15 +374,12 @@ pub mod v3 {
 	}
 
 	impl<T: Config> ActivePricing<T> {
-		pub fn migrate(
-			self,
-			with_linear_pricing: bool,
-		) -> crate::entities::pricing::ActivePricing<T> {
+		pub fn migrate(self, with_linear_pricing: bool) -> pricing_v4::ActivePricing<T> {
 			match self {
-				ActivePricing::Internal(i) => crate::entities::pricing::ActivePricing::Internal(i),
-				ActivePricing::External(e) => crate::entities::pricing::ActivePricing::External(
-					e.migrate(with_linear_pricing),
-				),
+				ActivePricing::Internal(i) => pricing_v4::ActivePricing::Internal(i),
+				ActivePricing::External(e) => {
+					pricing_v4::ActivePricing::External(e.migrate(with_linear_pricing))
+				}
 			}
 		}
 	}
@@ -395,8 +394,8 @@ pub mod v3 {
 	}
 
 	impl<T: Config> ExternalActivePricing<T> {
-		pub fn migrate(self, with_linear_pricing: bool) -> super::ExternalActivePricing<T> {
-			super::ExternalActivePricing {
+		pub fn migrate(self, with_linear_pricing: bool) -> super::v4::ExternalActivePricing<T> {
+			super::v4::ExternalActivePricing {
 				info: self.info.migrate(with_linear_pricing),
 				outstanding_quantity: self.outstanding_quantity,
 				interest: self.interest,
@@ -418,3 +417,33 @@ pub mod v3 {
 		}
 	}
 }
+
+/// External pricing before day count conventions, for migration to v5
+pub mod v4 {
+	use cfg_traits::Seconds;
+	use parity_scale_codec::{Decode, Encode};
+
+	use super::ExternalPricing;
+	use crate::{entities::interest::v4::ActiveInterestRate, pallet::Config};
+
+	#[derive(Encode, Decode)]
+	pub struct ExternalActivePricing<T: Config> {
+		pub info: ExternalPricing<T>,
+		pub outstanding_quantity: T::Quantity,
+		pub interest: ActiveInterestRate<T>,
+		pub latest_settlement_price: T::Balance,
+		pub settlement_price_updated: Seconds,
+	}
+
+	impl<T: Config> ExternalActivePricing<T> {
+		pub fn migrate(self) -> super::ExternalActivePricing<T> {
+			super::ExternalActivePricing {
+				info: self.info,
+				outstanding_quantity: self.outstanding_quantity,
+				interest: self.interest.migrate(),
+				latest_settlement_price: self.latest_settlement_price,
+				settlement_price_updated: self.settlement_price_updated,
+			}
+		}
+	}
+}
@@ -1,7 +1,4 @@
-use cfg_traits::{
-	interest::{InterestRate, RateCollection},
-	Seconds, TimeAsSecs,
-};
+use cfg_traits::{data::DataRegistry, interest::RateCollection, Seconds, TimeAsSecs};
 use cfg_types::adjustments::Adjustment;
 use frame_support::{
 	ensure,
@@ -12,14 +9,16 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::Saturating;
 use sp_runtime::{
-	traits::{EnsureFixedPointNumber, EnsureSub},
-	DispatchError,
+	traits::{EnsureFixedPointNumber, EnsureSub, Zero},
+	DispatchError, FixedPointNumber,
 };
+use sp_std::collections::btree_map::BTreeMap;
 
 use crate::{
 	entities::{changes::InternalMutation, interest::ActiveInterestRate},
-	pallet::{Config, Error},
+	pallet::{Config, Error, InterestRateOf, PriceOf},
 	types::{
+		cashflow::RepaymentSchedule,
 		valuation::{DiscountedCashFlow, ValuationMethod},
 		CreateLoanError, MutationError,
 	},
@@ -39,11 +38,12 @@ pub enum MaxBorrowAmount<Rate> {
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 pub struct InternalPricing<T: Config> {
-	/// Value of the collateral used for this loan
+	/// Value of the collateral used for this loan,
+	/// summed over all its collateral items
 	pub collateral_value: T::Balance,
 
 	/// Valuation method of this loan
-	pub valuation_method: ValuationMethod<T::Rate>,
+	pub valuation_method: ValuationMethod<T::Rate, T::PriceId>,
 
 	/// How much can be borrowed
 	pub max_borrow_amount: MaxBorrowAmount<T::Rate>,
@@ -74,62 +74,128 @@ pub struct InternalActivePricing<T: Config> {
 impl<T: Config> InternalActivePricing<T> {
 	pub fn activate(
 		info: InternalPricing<T>,
-		interest_rate: InterestRate<T::Rate>,
+		interest_rate: InterestRateOf<T>,
+		pool_id: T::PoolId,
 	) -> Result<Self, DispatchError> {
+		for price_id in info.valuation_method.price_ids() {
+			T::PriceRegistry::register_id(price_id, &pool_id)?;
+		}
+
 		Ok(Self {
 			info,
 			interest: ActiveInterestRate::activate(interest_rate)?,
 		})
 	}
 
-	pub fn deactivate(self) -> Result<(InternalPricing<T>, InterestRate<T::Rate>), DispatchError> {
+	pub fn deactivate(
+		self,
+		pool_id: T::PoolId,
+	) -> Result<(InternalPricing<T>, InterestRateOf<T>), DispatchError> {
+		for price_id in self.info.valuation_method.price_ids() {
+			T::PriceRegistry::unregister_id(price_id, &pool_id)?;
+		}
+
 		Ok((self.info, self.interest.deactivate()?))
 	}
 
+	/// Oracle keys used by the valuation method
+	pub fn price_ids(&self) -> impl Iterator<Item = T::PriceId> + '_ {
+		self.info.valuation_method.price_ids().into_iter().copied()
+	}
+
 	fn compute_present_value(
 		&self,
 		debt: T::Balance,
 		origination_date: Seconds,
-		maturity_date: Option<Seconds>,
+		schedule: &RepaymentSchedule,
+		price_of: impl Fn(&T::PriceId) -> Option<T::Balance>,
 	) -> Result<T::Balance, DispatchError> {
+		// Until the oracle feeds every price, the loan is valued at its debt
+		if self
+			.price_ids()
+			.any(|price_id| price_of(&price_id).is_none())
+		{
+			return Ok(debt);
+		}
+
+		let price_of = |price_id: &T::PriceId| -> Result<T::Balance, DispatchError> {
+			Ok(price_of(price_id).ok_or(Error::<T>::PriceNeededForValuationMethod)?)
+		};
+
 		match &self.info.valuation_method {
 			ValuationMethod::DiscountedCashFlow(dcf) => {
-				let maturity_date =
-					maturity_date.ok_or(Error::<T>::MaturityDateNeededForValuationMethod)?;
+				let maturity_date = schedule
+					.maturity
+					.date()
+					.ok_or(Error::<T>::MaturityDateNeededForValuationMethod)?;
 
 				let now = T::Time::now();
-				Ok(dcf.compute_present_value(
+				dcf.compute_present_value(
 					debt,
 					now,
-					self.interest.rate(),
+					&self.interest.accrual_rate(),
+					schedule,
 					maturity_date,
 					origination_date,
-				)?)
+				)
 			}
+			ValuationMethod::MarkToModel(mtm) => {
+				let maturity_date = schedule
+					.maturity
+					.date()
+					.ok_or(Error::<T>::MaturityDateNeededForValuationMethod)?;
+
+				// The oracle gives the yields with the precision of `T::Rate`
+				let yield_of = |price_id: &T::PriceId| -> Result<T::Rate, DispatchError> {
+					Ok(T::Rate::saturating_from_rational(
+						price_of(price_id)?,
+						T::Rate::DIV,
+					))
+				};
+
+				let now = T::Time::now();
+				mtm.compute_present_value(
+					debt,
+					now,
+					&self.interest.accrual_rate(),
+					schedule,
+					maturity_date,
+					yield_of,
+				)
+			}
+			ValuationMethod::LowerOfCostOrMarket { price_id } => Ok(debt.min(price_of(price_id)?)),
 			ValuationMethod::OutstandingDebt | ValuationMethod::Cash => Ok(debt),
 		}
 	}
 
 	pub fn present_value(
 		&self,
+		pool_id: T::PoolId,
 		origination_date: Seconds,
-		maturity_date: Option<Seconds>,
+		schedule: &RepaymentSchedule,
 	) -> Result<T::Balance, DispatchError> {
 		let debt = self.interest.current_debt()?;
-		self.compute_present_value(debt, origination_date, maturity_date)
+		self.compute_present_value(debt, origination_date, schedule, |price_id| {
+			T::PriceRegistry::get(price_id, &pool_id)
+				.ok()
+				.map(|(price, _)| price)
+		})
 	}
 
 	pub fn present_value_cached<Rates>(
 		&self,
 		cache: &Rates,
+		prices: &BTreeMap<T::PriceId, PriceOf<T>>,
 		origination_date: Seconds,
-		maturity_date: Option<Seconds>,
+		schedule: &RepaymentSchedule,
 	) -> Result<T::Balance, DispatchError>
 	where
 		Rates: RateCollection<T::Rate, T::Balance, T::Balance>,
 	{
 		let debt = self.interest.current_debt_cached(cache)?;
-		self.compute_present_value(debt, origination_date, maturity_date)
+		self.compute_present_value(debt, origination_date, schedule, |price_id| {
+			prices.get(price_id).map(|(price, _)| *price)
+		})
 	}
 
 	pub fn outstanding_interest(
@@ -158,6 +224,28 @@ impl<T: Config> InternalActivePricing<T> {
 		self.interest.adjust_debt(adjustment)
 	}
 
+	pub fn set_collateral_value(
+		&mut self,
+		value: T::Balance,
+		total_borrowed: T::Balance,
+	) -> DispatchResult {
+		let (advance_rate, borrowed) = match self.info.max_borrow_amount {
+			MaxBorrowAmount::UpToTotalBorrowed { advance_rate } => (advance_rate, total_borrowed),
+			MaxBorrowAmount::UpToOutstandingDebt { advance_rate } => {
+				(advance_rate, self.interest.current_debt()?)
+			}
+		};
+
+		ensure!(
+			!value.is_zero() && advance_rate.ensure_mul_int(value)? >= borrowed,
+			Error::<T>::from(MutationError::InvalidCollateralValue)
+		);
+
+		self.info.collateral_value = value;
+
+		Ok(())
+	}
+
 	fn mut_dcf(&mut self) -> Result<&mut DiscountedCashFlow<T::Rate>, DispatchError> {
 		match &mut self.info.valuation_method {
 			ValuationMethod::DiscountedCashFlow(dcf) => Ok(dcf),
@@ -165,9 +253,21 @@ impl<T: Config> InternalActivePricing<T> {
 		}
 	}
 
-	pub fn mutate_with(&mut self, mutation: InternalMutation<T::Rate>) -> DispatchResult {
+	pub fn mutate_with(
+		&mut self,
+		mutation: InternalMutation<T::Rate, T::PriceId>,
+		pool_id: T::PoolId,
+	) -> DispatchResult {
 		match mutation {
-			InternalMutation::ValuationMethod(method) => self.info.valuation_method = method,
+			InternalMutation::ValuationMethod(method) => {
+				for price_id in self.info.valuation_method.price_ids() {
+					T::PriceRegistry::unregister_id(price_id, &pool_id)?;
+				}
+				for price_id in method.price_ids() {
+					T::PriceRegistry::register_id(price_id, &pool_id)?;
+				}
+				self.info.valuation_method = method;
+			}
 			InternalMutation::ProbabilityOfDefault(rate) => {
 				self.mut_dcf()?.probability_of_default = rate;
 			}
@@ -178,3 +278,46 @@ impl<T: Config> InternalActivePricing<T> {
 		self.info.validate()
 	}
 }
+
+/// Internal pricing before day count conventions, for migration to v5
+pub mod v4 {
+	use parity_scale_codec::{Decode, Encode};
+
+	use super::MaxBorrowAmount;
+	use crate::{
+		entities::interest::v4::ActiveInterestRate, pallet::Config,
+		types::valuation::v4::ValuationMethod,
+	};
+
+	#[derive(Encode, Decode)]
+	pub struct InternalPricing<T: Config> {
+		collateral_value: T::Balance,
+		valuation_method: ValuationMethod<T::Rate>,
+		max_borrow_amount: MaxBorrowAmount<T::Rate>,
+	}
+
+	impl<T: Config> InternalPricing<T> {
+		pub fn migrate(self) -> super::InternalPricing<T> {
+			super::InternalPricing {
+				collateral_value: self.collateral_value,
+				valuation_method: self.valuation_method.migrate(),
+				max_borrow_amount: self.max_borrow_amount,
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct InternalActivePricing<T: Config> {
+		info: InternalPricing<T>,
+		interest: ActiveInterestRate<T>,
+	}
+
+	impl<T: Config> InternalActivePricing<T> {
+		pub fn migrate(self) -> super::InternalActivePricing<T> {
+			super::InternalActivePricing {
+				info: self.info.migrate(),
+				interest: self.interest.migrate(),
+			}
+		}
+	}
+}
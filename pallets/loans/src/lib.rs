@@ -17,18 +17,22 @@
 //!
 //! The following actions are performed over a loan:
 //!
-//! | Extrinsics                          | Role      |
-//! |-------------------------------------|-----------|
-//! | [`Pallet::create()`]                | Borrower  |
-//! | [`Pallet::borrow()`]                | Borrower  |
-//! | [`Pallet::repay()`]                 | Borrower  |
-//! | [`Pallet::write_off()`]             |           |
-//! | [`Pallet::admin_write_off()`]       | LoanAdmin |
-//! | [`Pallet::propose_loan_mutation()`] | LoanAdmin |
-//! | [`Pallet::apply_loan_mutation()`]   |           |
-//! | [`Pallet::propose_transfer_debt()`] | Borrower  |
-//! | [`Pallet::apply_transfer_debt()`]   |           |
-//! | [`Pallet::close()`]                 | Borrower  |
+//! | Extrinsics                                    | Role      |
+//! |-----------------------------------------------|-----------|
+//! | [`Pallet::create()`]                          | Borrower  |
+//! | [`Pallet::borrow()`]                          | Borrower  |
+//! | [`Pallet::repay()`]                           | Borrower  |
+//! | [`Pallet::write_off()`]                       |           |
+//! | [`Pallet::admin_write_off()`]                 | LoanAdmin |
+//! | [`Pallet::propose_loan_mutation()`]           | LoanAdmin |
+//! | [`Pallet::apply_loan_mutation()`]             |           |
+//! | [`Pallet::propose_transfer_debt()`]           | Borrower  |
+//! | [`Pallet::apply_transfer_debt()`]             |           |
+//! | [`Pallet::propose_collateral_substitution()`] | LoanAdmin |
+//! | [`Pallet::apply_collateral_substitution()`]   | Borrower  |
+//! | [`Pallet::propose_syndication()`]             | LoanAdmin |
+//! | [`Pallet::apply_syndication()`]               | LoanAdmin |
+//! | [`Pallet::close()`]                           | Borrower  |
 //!
 //! The following actions are performed over an entire pool of loans:
 //!
@@ -57,6 +61,8 @@ pub mod types;
 /// Utility types for configure the pallet from a runtime
 pub mod util;
 
+pub mod migrations;
+
 mod weights;
 
 #[cfg(test)]
@@ -74,7 +80,7 @@ pub mod pallet {
 		self,
 		changes::ChangeGuard,
 		data::{DataCollection, DataRegistry},
-		interest::InterestAccrual,
+		interest::{InterestAccrual, InterestRate, RateCollection},
 		IntoSeconds, Permissions, PoolInspect, PoolNAV, PoolReserve, PoolWriteOffPolicyMutate,
 		Seconds, TimeAsSecs,
 	};
@@ -84,9 +90,9 @@ pub mod pallet {
 		portfolio::{self, InitialPortfolioValuation, PortfolioValuationUpdateType},
 	};
 	use entities::{
-		changes::{Change, LoanMutation},
+		changes::{Change, CollateralSubstitution, LoanMutation},
 		input::{PriceCollectionInput, PrincipalInput, RepaidInput},
-		loans::{self, ActiveLoan, ActiveLoanInfo, LoanInfo},
+		loans::{self, ActiveLoan, ActiveLoanInfo, LoanInfo, LoanTerms},
 	};
 	use frame_support::{
 		pallet_prelude::*,
@@ -101,25 +107,60 @@ pub mod pallet {
 	use scale_info::TypeInfo;
 	use sp_arithmetic::{FixedPointNumber, PerThing};
 	use sp_runtime::{
-		traits::{BadOrigin, EnsureAdd, EnsureAddAssign, EnsureInto, One, Zero},
+		traits::{
+			BadOrigin, EnsureAdd, EnsureAddAssign, EnsureFixedPointNumber, EnsureInto,
+			EnsureSubAssign, One, Zero,
+		},
 		ArithmeticError, FixedPointOperand, TransactionOutcome,
 	};
 	use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 	use types::{
 		self,
-		cashflow::CashflowPayment,
+		cashflow::{CashflowPayment, PaymentStatus},
 		policy::{self, WriteOffRule, WriteOffStatus},
-		BorrowLoanError, CloseLoanError, CreateLoanError, MutationError, RepayLoanError,
-		WrittenOffError,
+		BorrowLoanError, CloseLoanError, CreateLoanError, MutationError, Participation,
+		RepayLoanError, SyndicationError, WrittenOffError,
 	};
 
 	use super::*;
 
 	pub type PortfolioInfoOf<T> = Vec<(<T as Config>::LoanId, ActiveLoanInfo<T>)>;
 	pub type AssetOf<T> = (<T as Config>::CollectionId, <T as Config>::ItemId);
+	pub type CollateralOf<T> = BoundedVec<AssetOf<T>, <T as Config>::MaxCollateralsPerLoan>;
 	pub type PriceOf<T> = (<T as Config>::Balance, <T as Config>::Moment);
+	pub type InterestRateOf<T> = InterestRate<<T as Config>::Rate, <T as Config>::PriceId>;
+	pub type PortfolioValuationOf<T> = portfolio::PortfolioValuation<
+		<T as Config>::Balance,
+		<T as Config>::LoanId,
+		MaxPortfolioEntriesOf<T>,
+	>;
+	pub type ParticipantsOf<T> = BoundedVec<
+		(<T as Config>::PoolId, <T as Config>::Rate),
+		<T as Config>::MaxParticipantsPerLoan,
+	>;
+	pub type ParticipationOf<T> =
+		Participation<<T as Config>::PoolId, <T as Config>::LoanId, <T as Config>::Rate>;
+	pub type ParticipationsOf<T> =
+		BoundedVec<ParticipationOf<T>, <T as Config>::MaxParticipantsPerLoan>;
+	pub type PoolParticipationsOf<T> = BoundedVec<
+		(
+			<T as Config>::LoanId,
+			(<T as Config>::PoolId, <T as Config>::LoanId),
+		),
+		<T as Config>::MaxParticipationsPerPool,
+	>;
+
+	/// Max number of values in the portfolio of a pool: its active loans and
+	/// its participations in syndicated loans of other pools
+	pub struct MaxPortfolioEntriesOf<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> Get<u32> for MaxPortfolioEntriesOf<T> {
+		fn get() -> u32 {
+			T::MaxActiveLoansPerPool::get().saturating_add(T::MaxParticipationsPerPool::get())
+		}
+	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -222,6 +263,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxWriteOffPolicySize: Get<u32> + Parameter;
 
+		/// Max number of collateral items a loan can hold.
+		#[pallet::constant]
+		type MaxCollateralsPerLoan: Get<u32> + Parameter;
+
+		/// Max number of pools participating in a syndicated loan.
+		#[pallet::constant]
+		type MaxParticipantsPerLoan: Get<u32> + Parameter;
+
+		/// Max number of participations a pool can hold in syndicated loans of
+		/// other pools.
+		#[pallet::constant]
+		type MaxParticipationsPerPool: Get<u32>;
+
 		/// Information of runtime weights
 		type WeightInfo: WeightInfo;
 	}
@@ -287,11 +341,30 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::PoolId,
-		portfolio::PortfolioValuation<T::Balance, T::LoanId, T::MaxActiveLoansPerPool>,
+		PortfolioValuationOf<T>,
 		ValueQuery,
 		InitialPortfolioValuation<T::Time>,
 	>;
 
+	/// Stores the pools participating in each syndicated loan
+	#[pallet::storage]
+	pub type Syndications<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::LoanId,
+		ParticipationsOf<T>,
+		ValueQuery,
+	>;
+
+	/// Stores the participations of each pool in syndicated loans of other
+	/// pools, as the id identifying each participation in the portfolio of
+	/// the pool together with the syndicated loan it refers to.
+	#[pallet::storage]
+	pub type Participations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolParticipationsOf<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -312,6 +385,7 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
 			amount: RepaidInput<T>,
+			prepaid_principal: T::Balance,
 		},
 		/// A loan was written off
 		WrittenOff {
@@ -323,13 +397,13 @@ pub mod pallet {
 		Mutated {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
-			mutation: LoanMutation<T::Rate>,
+			mutation: LoanMutation<T::Rate, T::PriceId>,
 		},
 		/// A loan was closed
 		Closed {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
-			collateral: AssetOf<T>,
+			collateral: CollateralOf<T>,
 		},
 		/// The portfolio valuation for a pool was updated.
 		PortfolioValuationUpdated {
@@ -361,6 +435,32 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
 			amount: RepaidInput<T>,
+			prepaid_principal: T::Balance,
+		},
+		/// The reference rate of a floating interest rate was updated
+		ReferenceRateUpdated {
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			reference_rate: T::Rate,
+		},
+		/// An active loan was restructured
+		Restructured {
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			before: LoanTerms<T>,
+			after: LoanTerms<T>,
+		},
+		/// The collateral of an active loan was substituted
+		CollateralSubstituted {
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			substitution: CollateralSubstitution<T>,
+		},
+		/// A loan was syndicated across other pools
+		Syndicated {
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			participations: ParticipationsOf<T>,
 		},
 	}
 
@@ -409,6 +509,11 @@ pub mod pallet {
 		/// method needs one. Making valuation and maturity settings
 		/// incompatible.
 		MaturityDateNeededForValuationMethod,
+		/// Emits when the valuation method needs an oracle price that is not
+		/// available for the valuation.
+		PriceNeededForValuationMethod,
+		/// Emits when the loan can not be syndicated
+		SyndicationError(SyndicationError),
 	}
 
 	impl<T> From<CreateLoanError> for Error<T> {
@@ -447,6 +552,12 @@ pub mod pallet {
 		}
 	}
 
+	impl<T> From<SyndicationError> for Error<T> {
+		fn from(error: SyndicationError) -> Self {
+			Error::<T>::SyndicationError(error)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates a new loan against the collateral provided
@@ -462,13 +573,20 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			Self::ensure_role(pool_id, &who, PoolRole::Borrower)?;
-			Self::ensure_collateral_owner(&who, info.collateral())?;
+			for collateral in info.collateral() {
+				Self::ensure_collateral_owner(&who, *collateral)?;
+			}
 			Self::ensure_pool_exists(pool_id)?;
 
 			info.validate(T::Time::now())?;
 
-			let collateral = info.collateral();
-			T::NonFungible::transfer(&collateral.0, &collateral.1, &T::Pool::account_for(pool_id))?;
+			for collateral in info.collateral() {
+				T::NonFungible::transfer(
+					&collateral.0,
+					&collateral.1,
+					&T::Pool::account_for(pool_id),
+				)?;
+			}
 
 			let loan_id = Self::generate_loan_id(pool_id)?;
 			CreatedLoan::<T>::insert(pool_id, loan_id, loans::CreatedLoan::new(info.clone(), who));
@@ -485,6 +603,8 @@ pub mod pallet {
 		/// Transfers borrow amount to the borrower.
 		///
 		/// The origin must be the borrower of the loan.
+		/// For syndicated loans, each participant pool funds its share of
+		/// the amount from its own reserve.
 		/// The borrow action should fulfill the borrow restrictions configured
 		/// at [`types::LoanRestrictions`]. The `amount` will be transferred
 		/// from pool reserve to borrower. The portfolio valuation of the pool
@@ -501,7 +621,7 @@ pub mod pallet {
 
 			let _count = Self::borrow_action(&who, pool_id, loan_id, &amount, false)?;
 
-			T::Pool::withdraw(pool_id, who, amount.balance()?)?;
+			Self::withdraw_from_reserves(pool_id, loan_id, who, amount.balance()?)?;
 
 			Self::deposit_event(Event::<T>::Borrowed {
 				pool_id,
@@ -515,6 +635,8 @@ pub mod pallet {
 		/// Transfers amount borrowed to the pool reserve.
 		///
 		/// The origin must be the borrower of the loan.
+		/// For syndicated loans, the amount is distributed to the reserve of
+		/// each participant pool according to its share.
 		/// The repay action should fulfill the repay restrictions
 		/// configured at [`types::RepayRestrictions`].
 		/// If the repaying `amount` is more than current debt, only current
@@ -532,14 +654,16 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let (amount, _count) = Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
+			let ((amount, prepaid_principal), _count) =
+				Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
 
-			T::Pool::deposit(pool_id, who, amount.repaid_amount()?.total()?)?;
+			Self::deposit_into_reserves(pool_id, loan_id, who, amount.repaid_amount()?.total()?)?;
 
 			Self::deposit_event(Event::<T>::Repaid {
 				pool_id,
 				loan_id,
 				amount,
+				prepaid_principal,
 			});
 
 			Ok(())
@@ -640,14 +764,14 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
-			mutation: LoanMutation<T::Rate>,
+			mutation: LoanMutation<T::Rate, T::PriceId>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			Self::ensure_role(pool_id, &who, PoolRole::LoanAdmin)?;
 
 			let (mut loan, _count) = Self::get_active_loan(pool_id, loan_id)?;
 			transactional::with_transaction(|| {
-				let result = loan.mutate_with(mutation.clone());
+				let result = loan.mutate_with(mutation.clone(), pool_id);
 
 				// We do not want to apply the mutation,
 				// only check if there is no error in applying it
@@ -676,8 +800,14 @@ pub mod pallet {
 				Err(Error::<T>::UnrelatedChangeId)?
 			};
 
-			let (_, _count) = Self::update_active_loan(pool_id, loan_id, |loan| {
-				loan.mutate_with(mutation.clone())
+			let (terms, _count) = Self::update_active_loan(pool_id, loan_id, |loan| {
+				if !matches!(mutation, LoanMutation::Restructure(_)) {
+					return loan.mutate_with(mutation.clone(), pool_id).map(|_| None);
+				}
+
+				let before = loan.terms()?;
+				loan.mutate_with(mutation.clone(), pool_id)?;
+				Ok(Some((before, loan.terms()?)))
 			})?;
 
 			Self::deposit_event(Event::<T>::Mutated {
@@ -686,6 +816,15 @@ pub mod pallet {
 				mutation,
 			});
 
+			if let Some((before, after)) = terms {
+				Self::deposit_event(Event::<T>::Restructured {
+					pool_id,
+					loan_id,
+					before,
+					after,
+				});
+			}
+
 			Ok(())
 		}
 
@@ -713,10 +852,13 @@ pub mod pallet {
 
 			Self::ensure_loan_borrower(&who, &borrower)?;
 
-			let collateral = closed_loan.collateral();
-			T::NonFungible::transfer(&collateral.0, &collateral.1, &who)?;
+			let collateral = closed_loan.collateral().clone();
+			for (collection_id, item_id) in &collateral {
+				T::NonFungible::transfer(collection_id, item_id, &who)?;
+			}
 
 			ClosedLoan::<T>::insert(pool_id, loan_id, closed_loan);
+			Self::remove_syndication(pool_id, loan_id);
 
 			Self::deposit_event(Event::<T>::Closed {
 				pool_id,
@@ -768,10 +910,14 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Updates the porfolio valuation for the given pool
-		#[pallet::weight(T::WeightInfo::update_portfolio_valuation(
-			T::MaxActiveLoansPerPool::get()
-		))]
+		/// Updates the porfolio valuation for the given pool,
+		/// refreshing first the reference rates of its floating rate loans.
+		#[pallet::weight(
+			T::WeightInfo::update_portfolio_valuation(T::MaxActiveLoansPerPool::get())
+				.saturating_add(T::WeightInfo::participation_valuation(
+					T::MaxParticipationsPerPool::get()
+				))
+		)]
 		#[pallet::call_index(10)]
 		pub fn update_portfolio_valuation(
 			origin: OriginFor<T>,
@@ -780,12 +926,16 @@ pub mod pallet {
 			ensure_signed(origin)?;
 			Self::ensure_pool_exists(pool_id)?;
 
-			let (_, count) = Self::update_portfolio_valuation_for_pool(
-				pool_id,
-				PriceCollectionInput::FromRegistry,
-			)?;
+			let (_, count) = Self::update_rates_and_portfolio_valuation(pool_id)?;
+			let participations = Participations::<T>::decode_len(pool_id)
+				.unwrap_or_default()
+				.ensure_into()?;
 
-			Ok(Some(T::WeightInfo::update_portfolio_valuation(count)).into())
+			Ok(Some(
+				T::WeightInfo::update_portfolio_valuation(count)
+					.saturating_add(T::WeightInfo::participation_valuation(participations)),
+			)
+			.into())
 		}
 
 		/// Transfer debt from one loan to another loan,
@@ -915,12 +1065,170 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let (amount, _count) = Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
+			let ((amount, prepaid_principal), _count) =
+				Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
 
 			Self::deposit_event(Event::<T>::DebtDecreased {
 				pool_id,
 				loan_id,
 				amount,
+				prepaid_principal,
+			});
+
+			Ok(())
+		}
+
+		/// Propose a substitution of the collateral items of an active loan.
+		///
+		/// The origin must be a loan admin of the pool, who approves with this
+		/// proposal the collateral the loan will hold after the substitution.
+		#[pallet::weight(T::WeightInfo::propose_collateral_substitution(
+			T::MaxActiveLoansPerPool::get()
+		))]
+		#[pallet::call_index(15)]
+		pub fn propose_collateral_substitution(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			substitution: CollateralSubstitution<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::LoanAdmin)?;
+
+			let (mut loan, _count) = Self::get_active_loan(pool_id, loan_id)?;
+			loan.substitute_collateral(&substitution)?;
+
+			T::ChangeGuard::note(
+				pool_id,
+				Change::CollateralSubstitution(loan_id, substitution).into(),
+			)?;
+
+			Ok(())
+		}
+
+		/// Apply a proposed collateral substitution identified by a change id.
+		///
+		/// The origin must be the borrower of the loan and the owner of the
+		/// added collateral items, which are transferred to the pool.
+		/// The removed collateral items are given back to the borrower.
+		#[pallet::weight(T::WeightInfo::apply_collateral_substitution(
+			T::MaxActiveLoansPerPool::get()
+		))]
+		#[pallet::call_index(16)]
+		pub fn apply_collateral_substitution(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let Change::CollateralSubstitution(loan_id, substitution) =
+				Self::get_released_change(pool_id, change_id)?
+			else {
+				Err(Error::<T>::UnrelatedChangeId)?
+			};
+
+			let (_, _count) = Self::update_active_loan(pool_id, loan_id, |loan| {
+				Self::ensure_loan_borrower(&who, loan.borrower())?;
+
+				for collateral in &substitution.added {
+					Self::ensure_collateral_owner(&who, *collateral)?;
+					T::NonFungible::transfer(
+						&collateral.0,
+						&collateral.1,
+						&T::Pool::account_for(pool_id),
+					)?;
+				}
+
+				for (collection_id, item_id) in &substitution.removed {
+					T::NonFungible::transfer(collection_id, item_id, &who)?;
+				}
+
+				loan.substitute_collateral(&substitution)
+			})?;
+
+			Self::deposit_event(Event::<T>::CollateralSubstituted {
+				pool_id,
+				loan_id,
+				substitution,
+			});
+
+			Ok(())
+		}
+
+		/// Propose a syndication of a loan across other pools.
+		///
+		/// Each participant pool funds its share of the borrowed amounts,
+		/// receives its share of the repayments and values its share in its
+		/// own portfolio. The pool of the loan keeps the remaining share.
+		/// The loan can not have been borrowed from yet.
+		#[pallet::weight(T::WeightInfo::propose_syndication())]
+		#[pallet::call_index(17)]
+		pub fn propose_syndication(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			participants: ParticipantsOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::LoanAdmin)?;
+			Self::ensure_syndicable(pool_id, loan_id, &participants)?;
+
+			T::ChangeGuard::note(pool_id, Change::Syndication(loan_id, participants).into())?;
+
+			Ok(())
+		}
+
+		/// Apply a proposed syndication identified by a change id.
+		///
+		/// The origin must be a loan admin of every participant pool, who
+		/// approves with this call the participation of those pools.
+		#[pallet::weight(T::WeightInfo::apply_syndication())]
+		#[pallet::call_index(18)]
+		pub fn apply_syndication(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let Change::Syndication(loan_id, participants) =
+				Self::get_released_change(pool_id, change_id)?
+			else {
+				Err(Error::<T>::UnrelatedChangeId)?
+			};
+
+			Self::ensure_syndicable(pool_id, loan_id, &participants)?;
+
+			let participations = participants
+				.into_iter()
+				.map(|(participant_pool_id, share)| {
+					Self::ensure_role(participant_pool_id, &who, PoolRole::LoanAdmin)?;
+
+					let participation_id = Self::generate_loan_id(participant_pool_id)?;
+					Participations::<T>::try_mutate(participant_pool_id, |participations| {
+						participations
+							.try_push((participation_id, (pool_id, loan_id)))
+							.map_err(|_| {
+								Error::<T>::from(SyndicationError::MaxParticipationsReached)
+							})
+					})?;
+
+					Ok(Participation {
+						pool_id: participant_pool_id,
+						loan_id: participation_id,
+						share,
+					})
+				})
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			let participations = ParticipationsOf::<T>::truncate_from(participations);
+			Syndications::<T>::insert(pool_id, loan_id, participations.clone());
+
+			Self::deposit_event(Event::<T>::Syndicated {
+				pool_id,
+				loan_id,
+				participations,
 			});
 
 			Ok(())
@@ -966,7 +1274,7 @@ pub mod pallet {
 			loan_id: T::LoanId,
 			amount: &RepaidInput<T>,
 			permissionless: bool,
-		) -> Result<(RepaidInput<T>, u32), DispatchError> {
+		) -> Result<((RepaidInput<T>, T::Balance), u32), DispatchError> {
 			Self::update_active_loan(pool_id, loan_id, |loan| {
 				if !permissionless {
 					Self::ensure_loan_borrower(who, loan.borrower())?;
@@ -990,8 +1298,14 @@ pub mod pallet {
 				Error::<T>::TransferDebtToSameLoan
 			);
 
-			let repaid_amount =
-				Self::repay_action(who, pool_id, from_loan_id, &repaid_amount, permissionless)?.0;
+			ensure!(
+				!Syndications::<T>::contains_key(pool_id, from_loan_id)
+					&& !Syndications::<T>::contains_key(pool_id, to_loan_id),
+				Error::<T>::from(SyndicationError::DebtTransferNotAllowed)
+			);
+
+			let ((repaid_amount, _), _count) =
+				Self::repay_action(who, pool_id, from_loan_id, &repaid_amount, permissionless)?;
 
 			ensure!(
 				borrow_amount.balance()? == repaid_amount.repaid_amount()?.total()?,
@@ -1004,11 +1318,12 @@ pub mod pallet {
 			Ok((repaid_amount, count))
 		}
 
-		/// Set the maturity date of the loan to this instant.
+		/// Add an unpaid payment due at this instant to the schedule of the
+		/// loan, keeping the rest of the schedule.
 		#[cfg(feature = "runtime-benchmarks")]
-		pub fn expire_action(pool_id: T::PoolId, loan_id: T::LoanId) -> DispatchResult {
+		pub fn overdue_action(pool_id: T::PoolId, loan_id: T::LoanId) -> DispatchResult {
 			Self::update_active_loan(pool_id, loan_id, |loan| {
-				loan.set_maturity(T::Time::now());
+				loan.set_overdue_payment(T::Time::now());
 				Ok(())
 			})?;
 			Ok(())
@@ -1061,6 +1376,50 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn ensure_syndicable(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			participants: &ParticipantsOf<T>,
+		) -> DispatchResult {
+			ensure!(
+				CreatedLoan::<T>::contains_key(pool_id, loan_id),
+				Error::<T>::from(SyndicationError::LoanNotCreatedOrNotFound)
+			);
+			ensure!(
+				!Syndications::<T>::contains_key(pool_id, loan_id),
+				Error::<T>::from(SyndicationError::AlreadySyndicated)
+			);
+			ensure!(
+				!participants.is_empty(),
+				Error::<T>::from(SyndicationError::InvalidParticipant)
+			);
+
+			let mut total_share = T::Rate::zero();
+			for (index, (participant_pool_id, share)) in participants.iter().enumerate() {
+				Self::ensure_pool_exists(*participant_pool_id)?;
+				ensure!(
+					*participant_pool_id != pool_id
+						&& !participants[..index]
+							.iter()
+							.any(|(id, _)| id == participant_pool_id),
+					Error::<T>::from(SyndicationError::InvalidParticipant)
+				);
+				ensure!(
+					!share.is_zero(),
+					Error::<T>::from(SyndicationError::InvalidShare)
+				);
+
+				total_share.ensure_add_assign(*share)?;
+			}
+
+			ensure!(
+				total_share < T::Rate::one(),
+				Error::<T>::from(SyndicationError::InvalidShare)
+			);
+
+			Ok(())
+		}
+
 		fn generate_loan_id(pool_id: T::PoolId) -> Result<T::LoanId, ArithmeticError> {
 			LastLoanId::<T>::try_mutate(pool_id, |last_loan_id| {
 				last_loan_id.ensure_add_assign(One::one())?;
@@ -1093,7 +1452,7 @@ pub mod pallet {
 			let collection = T::PriceRegistry::collection(&pool_id)?;
 			Ok(ActiveLoans::<T>::get(pool_id)
 				.iter()
-				.filter_map(|(_, loan)| loan.price_id())
+				.flat_map(|(_, loan)| loan.price_ids())
 				.filter_map(|price_id| {
 					collection
 						.get(&price_id)
@@ -1103,23 +1462,52 @@ pub mod pallet {
 				.collect::<BTreeMap<_, _>>())
 		}
 
+		/// Values the active loans of a pool with the given prices.
+		/// Floating rates keep accruing with their last known reference rate,
+		/// which is only refreshed by [`Pallet::update_portfolio_valuation()`]
+		/// and [`PoolNAV::update_nav()`].
 		pub fn update_portfolio_valuation_for_pool(
 			pool_id: T::PoolId,
 			input_prices: PriceCollectionInput<T>,
 		) -> Result<(T::Balance, u32), DispatchError> {
-			let rates = T::InterestAccrual::rates();
 			let prices = match input_prices {
 				PriceCollectionInput::Empty => BTreeMap::default(),
 				PriceCollectionInput::Custom(prices) => prices.into(),
 				PriceCollectionInput::FromRegistry => Self::registered_prices(pool_id)?,
 			};
 
+			Self::update_portfolio_valuation_with_prices(pool_id, &prices)
+		}
+
+		/// Updates the reference rates of the floating rate loans of a pool
+		/// and then its portfolio valuation, both from the registered prices.
+		fn update_rates_and_portfolio_valuation(
+			pool_id: T::PoolId,
+		) -> Result<(T::Balance, u32), DispatchError> {
+			let prices = Self::registered_prices(pool_id)?;
+			Self::update_reference_rates(pool_id, &prices)?;
+			Self::update_portfolio_valuation_with_prices(pool_id, &prices)
+		}
+
+		fn update_portfolio_valuation_with_prices(
+			pool_id: T::PoolId,
+			prices: &BTreeMap<T::PriceId, PriceOf<T>>,
+		) -> Result<(T::Balance, u32), DispatchError> {
+			let rates = T::InterestAccrual::rates();
 			let loans = ActiveLoans::<T>::get(pool_id);
-			let values = loans
+			let mut values = loans
 				.iter()
-				.map(|(loan_id, loan)| Ok((*loan_id, loan.present_value_by(&rates, &prices)?)))
+				.map(|(loan_id, loan)| {
+					let present_value = loan.present_value_by(&rates, prices)?;
+					let (retained, _) =
+						Self::split_by_participation(pool_id, *loan_id, present_value)?;
+
+					Ok((*loan_id, retained))
+				})
 				.collect::<Result<Vec<_>, DispatchError>>()?;
+			values.extend(Self::participation_values(pool_id, &rates)?);
 
+			let count = values.len() as u32;
 			let portfolio = portfolio::PortfolioValuation::from_values(T::Time::now(), values)?;
 			let valuation = portfolio.value();
 			PortfolioValuation::<T>::insert(pool_id, portfolio);
@@ -1130,7 +1518,160 @@ pub mod pallet {
 				update_type: PortfolioValuationUpdateType::Exact,
 			});
 
-			Ok((valuation, loans.len() as u32))
+			Ok((valuation, count))
+		}
+
+		/// Values the participations of a pool in the active syndicated loans
+		/// of other pools.
+		/// The active loans and prices of each of those pools are read once.
+		fn participation_values<Rates>(
+			pool_id: T::PoolId,
+			rates: &Rates,
+		) -> Result<Vec<(T::LoanId, T::Balance)>, DispatchError>
+		where
+			Rates: RateCollection<T::Rate, T::Balance, T::Balance>,
+		{
+			let mut loan_pools = BTreeMap::new();
+			let mut values = Vec::new();
+			for (participation_id, (loan_pool_id, loan_id)) in Participations::<T>::get(pool_id) {
+				if !loan_pools.contains_key(&loan_pool_id) {
+					let loans = ActiveLoans::<T>::get(loan_pool_id);
+					let prices = Self::registered_prices(loan_pool_id)?;
+					loan_pools.insert(loan_pool_id, (loans, prices));
+				}
+				let (loans, prices) = &loan_pools[&loan_pool_id];
+
+				let Some((_, loan)) = loans.iter().find(|(id, _)| *id == loan_id) else {
+					// Not borrowed from yet, the participation has no value
+					continue;
+				};
+
+				let present_value = loan.present_value_by(rates, prices)?;
+				let (_, parts) =
+					Self::split_by_participation(loan_pool_id, loan_id, present_value)?;
+
+				values.extend(
+					parts
+						.into_iter()
+						.filter(|(participation, _)| participation.loan_id == participation_id)
+						.map(|(_, value)| (participation_id, value)),
+				);
+			}
+
+			Ok(values)
+		}
+
+		/// Splits an amount of a loan between the pool of the loan and the
+		/// pools participating in it, according to their shares.
+		/// The pool of the loan retains the remainder.
+		fn split_by_participation(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			amount: T::Balance,
+		) -> Result<(T::Balance, Vec<(ParticipationOf<T>, T::Balance)>), DispatchError> {
+			let mut retained = amount;
+			let parts = Syndications::<T>::get(pool_id, loan_id)
+				.into_iter()
+				.map(|participation| {
+					let part = participation.share.ensure_mul_int(amount)?;
+					retained.ensure_sub_assign(part)?;
+					Ok((participation, part))
+				})
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			Ok((retained, parts))
+		}
+
+		/// Updates the portfolio of each pool participating in a loan with
+		/// its share of the loan present value, using `f`.
+		/// Returns the share of the present value retained by the pool of the
+		/// loan.
+		fn value_participations(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			present_value: T::Balance,
+			f: impl Fn(&mut PortfolioValuationOf<T>, T::LoanId, T::Balance) -> DispatchResult,
+		) -> Result<T::Balance, DispatchError> {
+			let (retained, parts) = Self::split_by_participation(pool_id, loan_id, present_value)?;
+
+			for (participation, value) in parts {
+				PortfolioValuation::<T>::try_mutate(participation.pool_id, |portfolio| {
+					f(portfolio, participation.loan_id, value)?;
+
+					Self::deposit_event(Event::<T>::PortfolioValuationUpdated {
+						pool_id: participation.pool_id,
+						valuation: portfolio.value(),
+						update_type: PortfolioValuationUpdateType::Inexact,
+					});
+
+					Ok::<_, DispatchError>(())
+				})?;
+			}
+
+			Ok(retained)
+		}
+
+		/// Transfers an amount from the reserves of the pool of the loan and
+		/// the pools participating in it.
+		fn withdraw_from_reserves(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			to: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let (retained, parts) = Self::split_by_participation(pool_id, loan_id, amount)?;
+
+			for (participation, part) in parts {
+				T::Pool::withdraw(participation.pool_id, to.clone(), part)?;
+			}
+
+			T::Pool::withdraw(pool_id, to, retained)
+		}
+
+		/// Transfers an amount to the reserves of the pool of the loan and
+		/// the pools participating in it.
+		fn deposit_into_reserves(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			from: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let (retained, parts) = Self::split_by_participation(pool_id, loan_id, amount)?;
+
+			for (participation, part) in parts {
+				T::Pool::deposit(participation.pool_id, from.clone(), part)?;
+			}
+
+			T::Pool::deposit(pool_id, from, retained)
+		}
+
+		fn remove_syndication(pool_id: T::PoolId, loan_id: T::LoanId) {
+			for participation in Syndications::<T>::take(pool_id, loan_id) {
+				Participations::<T>::mutate(participation.pool_id, |participations| {
+					participations.retain(|(id, _)| *id != participation.loan_id)
+				});
+			}
+		}
+
+		/// Updates the reference rate of the floating rate loans of a pool
+		/// with the values of their references found in `prices`.
+		fn update_reference_rates(
+			pool_id: T::PoolId,
+			prices: &BTreeMap<T::PriceId, PriceOf<T>>,
+		) -> DispatchResult {
+			ActiveLoans::<T>::try_mutate(pool_id, |active_loans| {
+				for (loan_id, loan) in active_loans.iter_mut() {
+					if let Some(reference_rate) = loan.update_reference_rate(prices)? {
+						Self::deposit_event(Event::<T>::ReferenceRateUpdated {
+							pool_id,
+							loan_id: *loan_id,
+							reference_rate,
+						});
+					}
+				}
+
+				Ok(())
+			})
 		}
 
 		fn insert_active_loan(
@@ -1139,7 +1680,13 @@ pub mod pallet {
 			loan: ActiveLoan<T>,
 		) -> Result<u32, DispatchError> {
 			PortfolioValuation::<T>::try_mutate(pool_id, |portfolio| {
-				portfolio.insert_elem(loan_id, loan.present_value(pool_id)?)?;
+				let present_value = Self::value_participations(
+					pool_id,
+					loan_id,
+					loan.present_value(pool_id)?,
+					|participant, id, value| participant.insert_elem(id, value),
+				)?;
+				portfolio.insert_elem(loan_id, present_value)?;
 
 				Self::deposit_event(Event::<T>::PortfolioValuationUpdated {
 					pool_id,
@@ -1174,7 +1721,13 @@ pub mod pallet {
 
 					let result = f(loan)?;
 
-					portfolio.update_elem(loan_id, loan.present_value(pool_id)?)?;
+					let present_value = Self::value_participations(
+						pool_id,
+						loan_id,
+						loan.present_value(pool_id)?,
+						|participant, id, value| participant.update_elem(id, value),
+					)?;
+					portfolio.update_elem(loan_id, present_value)?;
 
 					Self::deposit_event(Event::<T>::PortfolioValuationUpdated {
 						pool_id,
@@ -1212,6 +1765,13 @@ pub mod pallet {
 					portfolio.remove_elem(loan_id)
 				})?;
 
+				Self::value_participations(
+					pool_id,
+					loan_id,
+					Zero::zero(),
+					|participant, id, _| participant.remove_elem(id),
+				)?;
+
 				Ok((
 					active_loans.swap_remove(index).1,
 					active_loans.len().ensure_into()?,
@@ -1263,6 +1823,17 @@ pub mod pallet {
 				.map(|(_, loan)| loan.expected_cashflows())
 				.ok_or(Error::<T>::LoanNotActiveOrNotFound)?
 		}
+
+		pub fn payment_statuses(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+		) -> Result<Vec<(CashflowPayment<T::Balance>, PaymentStatus)>, DispatchError> {
+			ActiveLoans::<T>::get(pool_id)
+				.into_iter()
+				.find(|(id, _)| *id == loan_id)
+				.map(|(_, loan)| loan.payment_statuses(T::Time::now()))
+				.ok_or(Error::<T>::LoanNotActiveOrNotFound)?
+		}
 	}
 
 	// TODO: This implementation can be cleaned once #908 be solved
@@ -1277,8 +1848,7 @@ pub mod pallet {
 		}
 
 		fn update_nav(pool_id: T::PoolId) -> Result<T::Balance, DispatchError> {
-			Self::update_portfolio_valuation_for_pool(pool_id, PriceCollectionInput::FromRegistry)
-				.map(|portfolio| portfolio.0)
+			Self::update_rates_and_portfolio_valuation(pool_id).map(|portfolio| portfolio.0)
 		}
 
 		fn initialise(_: OriginFor<T>, _: T::PoolId, _: T::ItemId) -> DispatchResult {
@@ -1298,9 +1868,11 @@ pub mod pallet {
 		fn worst_case_policy() -> Self::Policy {
 			use crate::pallet::policy::WriteOffTrigger;
 
+			// Checking for overdue payments regenerates the payment schedule,
+			// the most expensive trigger to evaluate.
 			vec![
 				WriteOffRule::new(
-					[WriteOffTrigger::PrincipalOverdue(0)],
+					[WriteOffTrigger::PaymentOverdue(0)],
 					T::Rate::zero(),
 					T::Rate::zero(),
 				);
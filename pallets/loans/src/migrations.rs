@@ -0,0 +1,91 @@
+// Copyright 2023 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+#[cfg(feature = "try-runtime")]
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{Get, OnRuntimeUpgrade},
+	BoundedVec,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{
+	entities::loans::v4,
+	pallet::{ActiveLoans, ClosedLoan, Config, CreatedLoan},
+};
+
+/// Adds the day count convention to the interest rates of all loans.
+/// All rates until v4 count the actual time over a year of 365 days.
+/// The single collateral item of each loan becomes its collateral set.
+/// The repaid amounts start tracking the principal prepaid from now on.
+/// The payment statuses of active loans are computed from their origination.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: u64 = 0;
+
+		CreatedLoan::<T>::translate_values::<v4::CreatedLoan<T>, _>(|loan| {
+			count += 1;
+			Some(loan.migrate())
+		});
+
+		ActiveLoans::<T>::translate_values::<Vec<(T::LoanId, v4::ActiveLoan<T>)>, _>(|loans| {
+			count += 1;
+			Some(BoundedVec::truncate_from(
+				loans
+					.into_iter()
+					.map(|(loan_id, loan)| (loan_id, loan.migrate()))
+					.collect(),
+			))
+		});
+
+		ClosedLoan::<T>::translate_values::<v4::ClosedLoan<T>, _>(|loan| {
+			count += 1;
+			Some(loan.migrate())
+		});
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let created = CreatedLoan::<T>::iter_keys().count() as u64;
+		let active = ActiveLoans::<T>::iter_keys().count() as u64;
+		let closed = ClosedLoan::<T>::iter_keys().count() as u64;
+
+		Ok((created, active, closed).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(pre_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let (created, active, closed): (u64, u64, u64) = Decode::decode(&mut pre_state.as_slice())
+			.map_err(|_| "Loans: pre_upgrade provides a valid state")?;
+
+		frame_support::ensure!(
+			CreatedLoan::<T>::iter_values().count() as u64 == created,
+			"Loans: Mismatching number of created loans after migration"
+		);
+		frame_support::ensure!(
+			ActiveLoans::<T>::iter_values().count() as u64 == active,
+			"Loans: Mismatching number of pools with active loans after migration"
+		);
+		frame_support::ensure!(
+			ClosedLoan::<T>::iter_values().count() as u64 == closed,
+			"Loans: Mismatching number of closed loans after migration"
+		);
+
+		Ok(())
+	}
+}
@@ -151,6 +151,49 @@ fn with_maturity_passed() {
 	});
 }
 
+#[test]
+fn with_payment_overdue() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			schedule: RepaymentSchedule {
+				interest_payments: InterestPayments::Monthly(1),
+				..util::base_internal_loan().schedule
+			},
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let first_payment = util::get_loan(loan_id).expected_cashflows().unwrap()[0].clone();
+		advance_time(Duration::from_secs(first_payment.when) - now());
+
+		config_mocks(COLLATERAL_VALUE / 4);
+		assert_ok!(Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(COLLATERAL_VALUE / 4)
+		));
+
+		// The missed payment is carried, and the rest of the schedule
+		// expects the principal of both draws
+		let loan = util::get_loan(loan_id);
+		let statuses = loan.payment_statuses(now().as_secs()).unwrap();
+
+		assert_eq!(statuses[0], (first_payment.clone(), PaymentStatus::Missed));
+		assert!(statuses[1..]
+			.iter()
+			.all(|(_, status)| *status == PaymentStatus::Pending));
+		assert_eq!(
+			statuses.last().unwrap().0.principal,
+			COLLATERAL_VALUE / 2 + COLLATERAL_VALUE / 4
+		);
+		assert_eq!(
+			loan.first_overdue_payment(now().as_secs()),
+			Ok(Some(first_payment.when))
+		);
+	});
+}
+
 #[test]
 fn with_wrong_internal_pricing() {
 	new_test_ext().execute_with(|| {
@@ -342,6 +385,42 @@ fn with_unregister_price_id_and_oracle_not_required() {
 	});
 }
 
+#[test]
+fn with_missing_price_internal_pricing() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			pricing: Pricing::Internal(InternalPricing {
+				valuation_method: ValuationMethod::LowerOfCostOrMarket {
+					price_id: REGISTER_PRICE_ID,
+				},
+				..util::base_internal_pricing()
+			}),
+			..util::base_internal_loan()
+		});
+
+		config_mocks(COLLATERAL_VALUE);
+		MockPrices::mock_get(|_, _| Err(PRICE_ID_NO_FOUND));
+
+		assert_ok!(Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(COLLATERAL_VALUE)
+		));
+
+		// Without an oracle price, the loan is valued at its debt
+		assert_eq!(
+			util::current_loan_debt(loan_id),
+			util::current_loan_pv(loan_id)
+		);
+
+		// Once the oracle sets a value, the market price applies
+		MockPrices::mock_get(|_, _| Ok((PRICE_VALUE, BLOCK_TIME_MS)));
+
+		assert_eq!(PRICE_VALUE, util::current_loan_pv(loan_id));
+	});
+}
+
 #[test]
 fn with_wrong_big_amount_external_pricing() {
 	new_test_ext().execute_with(|| {
@@ -690,4 +769,55 @@ mod cashflow {
 			);
 		});
 	}
+
+	#[test]
+	fn computed_correctly_with_monthly_payments() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(LoanInfo {
+				schedule: RepaymentSchedule {
+					interest_payments: InterestPayments::Monthly(1),
+					..util::base_internal_loan().schedule
+				},
+				..util::base_internal_loan()
+			});
+
+			config_mocks(COLLATERAL_VALUE / 2);
+			assert_ok!(Loans::borrow(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				loan_id,
+				PrincipalInput::Internal(COLLATERAL_VALUE / 2)
+			));
+
+			let loan = util::get_loan(loan_id);
+			let cashflow = loan.expected_cashflows().unwrap();
+
+			// One payment the first day of each month until maturity,
+			// plus the payment at maturity, 1 year later.
+			assert_eq!(cashflow.len(), 13);
+			assert_eq!(cashflow.last().unwrap().when, loan.maturity_date().unwrap());
+			assert!(cashflow.windows(2).all(|w| w[0].when < w[1].when));
+
+			// Principal is paid only at maturity
+			assert!(cashflow[..12].iter().all(|payment| payment.principal == 0));
+			assert_eq!(cashflow[12].principal, COLLATERAL_VALUE / 2);
+
+			// All interest is distributed among the payments
+			let principal = COLLATERAL_VALUE / 2;
+			let acc_interest_rate_per_year = checked_pow(
+				util::default_interest_rate().per_sec().unwrap(),
+				SECONDS_PER_YEAR as usize,
+			)
+			.unwrap();
+			let interest = acc_interest_rate_per_year.saturating_mul_int(principal) - principal;
+
+			assert_eq!(
+				cashflow
+					.iter()
+					.map(|payment| payment.interest)
+					.sum::<Balance>(),
+				interest
+			);
+		});
+	}
 }
@@ -134,3 +134,27 @@ fn just_created() {
 		assert_eq!(Uniques::owner(ASSET_AA.0, ASSET_AA.1).unwrap(), BORROWER);
 	});
 }
+
+#[test]
+fn with_multiple_collateral_items() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			collateral: BoundedVec::truncate_from(vec![ASSET_AA, ASSET_BA]),
+			..util::base_internal_loan()
+		});
+
+		assert_eq!(
+			Uniques::owner(ASSET_BA.0, ASSET_BA.1).unwrap(),
+			POOL_A_ACCOUNT
+		);
+
+		assert_ok!(Loans::close(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id
+		));
+
+		assert_eq!(Uniques::owner(ASSET_AA.0, ASSET_AA.1).unwrap(), BORROWER);
+		assert_eq!(Uniques::owner(ASSET_BA.0, ASSET_BA.1).unwrap(), BORROWER);
+	});
+}
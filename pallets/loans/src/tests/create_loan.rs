@@ -55,7 +55,7 @@ fn with_wrong_assets() {
 		config_mocks(POOL_A);
 
 		let loan = LoanInfo {
-			collateral: NO_ASSET,
+			collateral: util::collateral(NO_ASSET),
 			..util::base_internal_loan()
 		};
 		assert_noop!(
@@ -64,7 +64,7 @@ fn with_wrong_assets() {
 		);
 
 		let loan = LoanInfo {
-			collateral: ASSET_AB,
+			collateral: util::collateral(ASSET_AB),
 			..util::base_internal_loan()
 		};
 		assert_noop!(
@@ -117,6 +117,7 @@ fn with_wrong_valuation() {
 					discount_rate: InterestRate::Fixed {
 						rate_per_year: Rate::from_float(1.1), // Too high
 						compounding: CompoundingSchedule::Secondly,
+						day_count: DayCountConvention::Actual365,
 					},
 				}),
 				..util::base_internal_pricing()
@@ -131,6 +132,37 @@ fn with_wrong_valuation() {
 	});
 }
 
+#[test]
+fn with_unsorted_yield_curve() {
+	new_test_ext().execute_with(|| {
+		config_mocks(POOL_A);
+
+		let loan = LoanInfo {
+			pricing: Pricing::Internal(InternalPricing {
+				valuation_method: ValuationMethod::MarkToModel(MarkToModel {
+					yield_curve: BoundedVec::truncate_from(vec![
+						YieldCurvePoint {
+							tenor: YEAR.as_secs(),
+							price_id: REGISTER_PRICE_ID,
+						},
+						YieldCurvePoint {
+							tenor: (YEAR / 2).as_secs(),
+							price_id: UNREGISTER_PRICE_ID,
+						},
+					]),
+				}),
+				..util::base_internal_pricing()
+			}),
+			..util::base_internal_loan()
+		};
+
+		assert_noop!(
+			Loans::create(RuntimeOrigin::signed(BORROWER), POOL_A, loan),
+			Error::<Runtime>::from(CreateLoanError::InvalidValuationMethod)
+		);
+	});
+}
+
 #[test]
 fn with_wrong_interest_rate() {
 	new_test_ext().execute_with(|| {
@@ -140,6 +172,7 @@ fn with_wrong_interest_rate() {
 			interest_rate: InterestRate::Fixed {
 				rate_per_year: Rate::from_float(3.0),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			}, // Too high
 			..util::base_internal_loan()
 		};
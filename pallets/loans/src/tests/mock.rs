@@ -112,6 +112,11 @@ frame_support::parameter_types! {
 	pub const MaxActiveLoansPerPool: u32 = 5;
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxWriteOffPolicySize: u32 = 4;
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxCollateralsPerLoan: u32 = 3;
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxParticipantsPerLoan: u32 = 2;
+	pub const MaxParticipationsPerPool: u32 = 2;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
@@ -198,6 +203,9 @@ impl pallet_loans::Config for Runtime {
 	type ItemId = ItemId;
 	type LoanId = LoanId;
 	type MaxActiveLoansPerPool = MaxActiveLoansPerPool;
+	type MaxCollateralsPerLoan = MaxCollateralsPerLoan;
+	type MaxParticipantsPerLoan = MaxParticipantsPerLoan;
+	type MaxParticipationsPerPool = MaxParticipationsPerPool;
 	type MaxWriteOffPolicySize = MaxWriteOffPolicySize;
 	type Moment = Millis;
 	type NonFungible = Uniques;
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use cfg_mocks::pallet_mock_data::util::MockDataCollection;
 use cfg_primitives::{SECONDS_PER_DAY, SECONDS_PER_YEAR};
-use cfg_traits::interest::{CompoundingSchedule, InterestRate};
+use cfg_traits::interest::{CompoundingSchedule, DayCountConvention, InterestRate};
 use cfg_types::permissions::{PermissionScope, PoolRole, Role};
 use frame_support::{assert_noop, assert_ok, storage::bounded_vec::BoundedVec};
 use sp_runtime::{
@@ -12,9 +12,9 @@ use sp_runtime::{
 
 use super::{
 	entities::{
-		changes::{Change, InternalMutation, LoanMutation},
-		input::{PrincipalInput, RepaidInput},
-		loans::{ActiveLoan, ActiveLoanInfo, LoanInfo},
+		changes::{Change, CollateralSubstitution, InternalMutation, LoanMutation, Restructuring},
+		input::{PriceCollectionInput, PrincipalInput, RepaidInput},
+		loans::{ActiveLoan, ActiveLoanInfo, LoanInfo, LoanTerms},
 		pricing::{
 			external::{
 				ExternalActivePricing, ExternalAmount, ExternalPricing,
@@ -24,13 +24,16 @@ use super::{
 			ActivePricing, Pricing,
 		},
 	},
-	pallet::{ActiveLoans, CreatedLoan, Error, Event, LastLoanId, PortfolioValuation},
+	pallet::{
+		ActiveLoans, CollateralOf, CreatedLoan, Error, Event, InterestRateOf, LastLoanId,
+		ParticipantsOf, Participations, PortfolioValuation, Syndications,
+	},
 	types::{
-		cashflow::{InterestPayments, Maturity, PayDownSchedule, RepaymentSchedule},
+		cashflow::{InterestPayments, Maturity, PayDownSchedule, PaymentStatus, RepaymentSchedule},
 		policy::{WriteOffRule, WriteOffStatus, WriteOffTrigger},
-		valuation::{DiscountedCashFlow, ValuationMethod},
+		valuation::{DiscountedCashFlow, MarkToModel, ValuationMethod, YieldCurvePoint},
 		BorrowLoanError, BorrowRestrictions, CloseLoanError, CreateLoanError, LoanRestrictions,
-		MutationError, RepayLoanError, RepayRestrictions, WrittenOffError,
+		MutationError, RepayLoanError, RepayRestrictions, SyndicationError, WrittenOffError,
 	},
 };
 
@@ -44,6 +47,8 @@ mod mutate_loan;
 mod policy;
 mod portfolio_valuation;
 mod repay_loan;
+mod substitute_collateral;
+mod syndication;
 mod transfer_debt;
 mod util;
 mod write_off_loan;
@@ -1,9 +1,9 @@
 use super::*;
 
-const DEFAULT_MUTATION: LoanMutation<Rate> =
+const DEFAULT_MUTATION: LoanMutation<Rate, PriceId> =
 	LoanMutation::InterestPayments(InterestPayments::OnceAtMaturity);
 
-fn config_mocks(loan_id: LoanId, loan_mutation: &LoanMutation<Rate>) {
+fn config_mocks(loan_id: LoanId, loan_mutation: &LoanMutation<Rate, PriceId>) {
 	MockPermissions::mock_has(|scope, who, role| {
 		matches!(scope, PermissionScope::Pool(id) if id == POOL_A)
 			&& matches!(role, Role::PoolRole(PoolRole::LoanAdmin))
@@ -162,6 +162,166 @@ mod wrong_mutation {
 		});
 	}
 
+	#[test]
+	fn with_maturity_beyond_max_payments() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(LoanInfo {
+				schedule: RepaymentSchedule {
+					maturity: Maturity::Fixed {
+						date: (now() + YEAR).as_secs(),
+						extension: (YEAR * 40).as_secs(),
+					},
+					interest_payments: InterestPayments::Monthly(1),
+					pay_down_schedule: PayDownSchedule::None,
+				},
+				..util::base_internal_loan()
+			});
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			// More monthly payments than `MAX_CASHFLOW_PAYMENTS`
+			let mutations = vec![
+				LoanMutation::Maturity(Maturity::fixed((now() + YEAR * 41).as_secs())),
+				LoanMutation::MaturityExtension((YEAR * 40).as_secs()),
+			];
+
+			for mutation in mutations {
+				config_mocks(loan_id, &mutation);
+				assert_noop!(
+					Loans::propose_loan_mutation(
+						RuntimeOrigin::signed(LOAN_ADMIN),
+						POOL_A,
+						loan_id,
+						mutation,
+					),
+					Error::<Runtime>::MutationError(MutationError::InvalidRepaymentSchedule)
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn with_maturity_none() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(LoanInfo {
+				schedule: RepaymentSchedule {
+					interest_payments: InterestPayments::Monthly(1),
+					..util::base_internal_loan().schedule
+				},
+				..util::base_internal_loan()
+			});
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			// Periodic payments require a maturity date
+			let mutation = LoanMutation::Maturity(Maturity::None);
+
+			config_mocks(loan_id, &mutation);
+			assert_noop!(
+				Loans::propose_loan_mutation(
+					RuntimeOrigin::signed(LOAN_ADMIN),
+					POOL_A,
+					loan_id,
+					mutation,
+				),
+				Error::<Runtime>::MutationError(MutationError::InvalidRepaymentSchedule)
+			);
+		});
+	}
+
+	#[test]
+	fn with_interest_payments() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			// Payment day out of the month
+			let mutation = LoanMutation::InterestPayments(InterestPayments::Monthly(32));
+
+			config_mocks(loan_id, &mutation);
+			assert_noop!(
+				Loans::propose_loan_mutation(
+					RuntimeOrigin::signed(LOAN_ADMIN),
+					POOL_A,
+					loan_id,
+					mutation,
+				),
+				Error::<Runtime>::MutationError(MutationError::InvalidRepaymentSchedule)
+			);
+		});
+	}
+
+	#[test]
+	fn with_pay_down_schedule() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			// Paying down requires periodic interest payments
+			let mutation = LoanMutation::PayDownSchedule(PayDownSchedule::Linear);
+
+			config_mocks(loan_id, &mutation);
+			assert_noop!(
+				Loans::propose_loan_mutation(
+					RuntimeOrigin::signed(LOAN_ADMIN),
+					POOL_A,
+					loan_id,
+					mutation,
+				),
+				Error::<Runtime>::MutationError(MutationError::InvalidRepaymentSchedule)
+			);
+		});
+	}
+
+	#[test]
+	fn with_restructure() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_external_loan());
+			util::borrow_loan(loan_id, PrincipalInput::External(ExternalAmount::empty()));
+
+			let mutation = LoanMutation::Restructure(Restructuring {
+				schedule: util::base_external_loan().schedule,
+				capitalize_interest: true,
+				forgiveness: None,
+			});
+
+			config_mocks(loan_id, &mutation);
+			assert_noop!(
+				Loans::propose_loan_mutation(
+					RuntimeOrigin::signed(LOAN_ADMIN),
+					POOL_A,
+					loan_id,
+					mutation,
+				),
+				Error::<Runtime>::MutationError(MutationError::InternalPricingExpected)
+			);
+		});
+	}
+
+	#[test]
+	fn with_forgiveness() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+			// More than the whole principal
+			let mutation = LoanMutation::Restructure(Restructuring {
+				schedule: util::base_internal_loan().schedule,
+				capitalize_interest: false,
+				forgiveness: Some(Rate::from_float(1.5)),
+			});
+
+			config_mocks(loan_id, &mutation);
+			assert_noop!(
+				Loans::propose_loan_mutation(
+					RuntimeOrigin::signed(LOAN_ADMIN),
+					POOL_A,
+					loan_id,
+					mutation,
+				),
+				Error::<Runtime>::MutationError(MutationError::InvalidForgiveness)
+			);
+		});
+	}
+
 	#[test]
 	fn with_interest_rate() {
 		new_test_ext().execute_with(|| {
@@ -172,6 +332,7 @@ mod wrong_mutation {
 			let mutation = LoanMutation::InterestRate(InterestRate::Fixed {
 				rate_per_year: Rate::from_float(3.0),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			});
 
 			config_mocks(loan_id, &mutation);
@@ -220,6 +381,7 @@ fn with_successful_mutation_application() {
 			interest_rate: InterestRate::Fixed {
 				rate_per_year: Rate::from_float(0.1),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			},
 			pricing: Pricing::Internal(InternalPricing {
 				valuation_method: ValuationMethod::DiscountedCashFlow(DiscountedCashFlow {
@@ -228,6 +390,7 @@ fn with_successful_mutation_application() {
 					discount_rate: InterestRate::Fixed {
 						rate_per_year: Rate::from_float(0.1),
 						compounding: CompoundingSchedule::Secondly,
+						day_count: DayCountConvention::Actual365,
 					},
 				}),
 				..util::base_internal_pricing()
@@ -239,8 +402,8 @@ fn with_successful_mutation_application() {
 		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
 
 		let mutations = vec![
-			// LoanMutation::InterestPayments(..), No changes, only one variant
-			// LoanMutation::PayDownSchedule(..), No changes, only one variant
+			// LoanMutation::InterestPayments(..), No changes in the PV
+			// LoanMutation::PayDownSchedule(..), No changes in the PV
 			LoanMutation::Maturity(Maturity::Fixed {
 				date: (now() + YEAR * 2).as_secs(),
 				extension: (YEAR * 2).as_secs(),
@@ -249,6 +412,7 @@ fn with_successful_mutation_application() {
 			LoanMutation::InterestRate(InterestRate::Fixed {
 				rate_per_year: Rate::from_float(0.5),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			}),
 			LoanMutation::Internal(InternalMutation::ProbabilityOfDefault(Rate::from_float(
 				0.5,
@@ -257,6 +421,7 @@ fn with_successful_mutation_application() {
 			LoanMutation::Internal(InternalMutation::DiscountRate(InterestRate::Fixed {
 				rate_per_year: Rate::from_float(0.5),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			})),
 			LoanMutation::Internal(InternalMutation::ValuationMethod(
 				ValuationMethod::OutstandingDebt,
@@ -298,3 +463,64 @@ fn with_successful_mutation_application() {
 		}
 	});
 }
+
+#[test]
+fn with_successful_restructuring() {
+	new_test_ext().execute_with(|| {
+		let loan = util::base_internal_loan();
+		let old_schedule = loan.schedule.clone();
+
+		let loan_id = util::create_loan(loan);
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		advance_time(YEAR / 2);
+
+		let new_schedule = RepaymentSchedule {
+			maturity: Maturity::fixed((now() + YEAR).as_secs()),
+			interest_payments: InterestPayments::OnceAtMaturity,
+			pay_down_schedule: PayDownSchedule::None,
+		};
+		let mutation = LoanMutation::Restructure(Restructuring {
+			schedule: new_schedule.clone(),
+			capitalize_interest: true,
+			forgiveness: Some(Rate::from_float(0.1)),
+		});
+
+		config_mocks(loan_id, &mutation);
+		assert_ok!(Loans::propose_loan_mutation(
+			RuntimeOrigin::signed(LOAN_ADMIN),
+			POOL_A,
+			loan_id,
+			mutation,
+		));
+
+		let debt = util::current_loan_debt(loan_id);
+		assert_ok!(Loans::apply_loan_mutation(
+			RuntimeOrigin::signed(LOAN_ADMIN),
+			POOL_A,
+			CHANGE_ID,
+		));
+
+		// The interest is capitalized and then a 10% of the principal forgiven
+		let principal = debt - Rate::from_float(0.1).saturating_mul_int(debt);
+		let after = util::get_loan(loan_id).terms().unwrap();
+		assert_eq!(after.schedule, new_schedule);
+		assert_eq!(after.principal, principal);
+
+		// The forgiven principal is not taken as repaid nor as never borrowed
+		let loan = util::get_loan(loan_id);
+		assert_eq!(loan.total_forgiven(), debt - principal);
+		assert_eq!(loan.total_repaid().principal, 0);
+
+		System::assert_has_event(RuntimeEvent::Loans(Event::Restructured {
+			pool_id: POOL_A,
+			loan_id,
+			before: LoanTerms {
+				schedule: old_schedule,
+				principal: COLLATERAL_VALUE,
+				interest: debt - COLLATERAL_VALUE,
+			},
+			after,
+		}));
+	});
+}
@@ -173,6 +173,63 @@ fn with_price_outdated() {
 	});
 }
 
+#[test]
+fn with_payment_overdue() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			schedule: RepaymentSchedule {
+				interest_payments: InterestPayments::Monthly(1),
+				..util::base_internal_loan().schedule
+			},
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		let policy: BoundedVec<_, _> = vec![WriteOffRule::new(
+			[WriteOffTrigger::PaymentOverdue(10)],
+			Rate::from_float(POLICY_PERCENTAGE),
+			Rate::from_float(POLICY_PENALTY),
+		)]
+		.try_into()
+		.unwrap();
+
+		config_mocks(POOL_A, &policy.clone());
+		assert_ok!(Loans::propose_write_off_policy(
+			RuntimeOrigin::signed(POOL_ADMIN),
+			POOL_A,
+			policy,
+		));
+		assert_ok!(Loans::apply_write_off_policy(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			CHANGE_ID
+		));
+
+		// The first interest payment is not paid, far before maturity
+		let first_payment = util::get_loan(loan_id).expected_cashflows().unwrap()[0].clone();
+		advance_time(Duration::from_secs(first_payment.when + 9) - now());
+		assert_noop!(
+			Loans::write_off(RuntimeOrigin::signed(ANY), POOL_A, loan_id),
+			Error::<Runtime>::NoValidWriteOffRule
+		);
+
+		advance_time(Duration::from_secs(1));
+		assert_ok!(Loans::write_off(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			loan_id
+		));
+
+		assert_eq!(
+			util::get_loan(loan_id).write_off_status(),
+			WriteOffStatus {
+				percentage: Rate::from_float(POLICY_PERCENTAGE),
+				penalty: Rate::from_float(POLICY_PENALTY),
+			}
+		);
+	});
+}
+
 #[test]
 fn with_success() {
 	new_test_ext().execute_with(|| {
@@ -55,7 +55,7 @@ fn without_active_loans() {
 	new_test_ext().execute_with(|| {
 		util::create_loan(util::base_external_loan());
 		util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 
@@ -75,7 +75,7 @@ fn with_active_loans() {
 		util::borrow_loan(loan_1, PrincipalInput::External(amount.clone()));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 		util::borrow_loan(loan_2, PrincipalInput::Internal(COLLATERAL_VALUE));
@@ -103,7 +103,7 @@ fn with_active_written_off_loans() {
 		util::borrow_loan(loan_1, PrincipalInput::External(amount));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 		util::borrow_loan(loan_2, PrincipalInput::Internal(COLLATERAL_VALUE));
@@ -128,7 +128,7 @@ fn filled_and_cleaned() {
 		util::borrow_loan(loan_1, PrincipalInput::External(amount.clone()));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 		util::borrow_loan(loan_2, PrincipalInput::Internal(COLLATERAL_VALUE));
@@ -278,7 +278,7 @@ fn internal_dcf_with_no_maturity() {
 		internal.schedule.maturity = Maturity::None;
 
 		let loan_id = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..internal
 		});
 
@@ -303,7 +303,7 @@ fn internal_oustanding_debt_with_no_maturity() {
 		internal.schedule.maturity = Maturity::None;
 
 		let loan_id = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..internal
 		});
 		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
@@ -329,3 +329,195 @@ fn internal_oustanding_debt_with_no_maturity() {
 		expected_portfolio(0);
 	});
 }
+
+#[test]
+fn with_floating_rate_loans() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			interest_rate: InterestRate::Floating {
+				reference: REGISTER_PRICE_ID,
+				reference_rate: Rate::saturating_from_rational(1, 100),
+				spread: Rate::saturating_from_rational(2, 100),
+				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
+			},
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+		MockPrices::mock_collection(|pool_id| {
+			assert_eq!(*pool_id, POOL_A);
+			Ok(MockDataCollection::new(|id| match *id {
+				REGISTER_PRICE_ID => Ok((
+					Rate::saturating_from_rational(3, 100).into_inner(),
+					BLOCK_TIME_MS,
+				)),
+				_ => Err(PRICE_ID_NO_FOUND),
+			}))
+		});
+
+		update_portfolio();
+
+		System::assert_has_event(RuntimeEvent::Loans(Event::ReferenceRateUpdated {
+			pool_id: POOL_A,
+			loan_id,
+			reference_rate: Rate::saturating_from_rational(3, 100),
+		}));
+		assert_eq!(
+			util::get_loan(loan_id)
+				.pricing()
+				.interest()
+				.rate()
+				.per_year(),
+			Rate::saturating_from_rational(5, 100)
+		);
+
+		advance_time(YEAR / 2);
+
+		assert_eq!(
+			util::current_debt_for(util::interest_for(0.05, YEAR / 2), COLLATERAL_VALUE),
+			util::current_loan_debt(loan_id)
+		);
+
+		update_portfolio();
+		expected_portfolio(util::current_loan_pv(loan_id));
+	});
+}
+
+#[test]
+fn with_mark_to_model_loans() {
+	const YIELD_6M: PriceId = 1;
+	const YIELD_18M: PriceId = 2;
+
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			pricing: Pricing::Internal(InternalPricing {
+				valuation_method: ValuationMethod::MarkToModel(MarkToModel {
+					yield_curve: BoundedVec::truncate_from(vec![
+						YieldCurvePoint {
+							tenor: (YEAR / 2).as_secs(),
+							price_id: YIELD_6M,
+						},
+						YieldCurvePoint {
+							tenor: (YEAR + YEAR / 2).as_secs(),
+							price_id: YIELD_18M,
+						},
+					]),
+				}),
+				..util::base_internal_pricing()
+			}),
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+		MockPrices::mock_collection(|pool_id| {
+			assert_eq!(*pool_id, POOL_A);
+			Ok(MockDataCollection::new(|id| match *id {
+				YIELD_6M => Ok((
+					Rate::saturating_from_rational(4, 100).into_inner(),
+					BLOCK_TIME_MS,
+				)),
+				YIELD_18M => Ok((
+					Rate::saturating_from_rational(6, 100).into_inner(),
+					BLOCK_TIME_MS,
+				)),
+				_ => Err(PRICE_ID_NO_FOUND),
+			}))
+		});
+
+		update_portfolio();
+
+		// The only cash flow happens at maturity, in one year,
+		// where the curve interpolates a yield of 5%
+		let cashflows = util::get_loan(loan_id).expected_cashflows().unwrap();
+		let cashflow = cashflows[0].principal + cashflows[0].interest;
+		let discount_rate: InterestRate<Rate> = InterestRate::Fixed {
+			rate_per_year: Rate::saturating_from_rational(5, 100),
+			compounding: CompoundingSchedule::Secondly,
+			day_count: DayCountConvention::Actual365,
+		};
+		let discount = Rate::one()
+			/ discount_rate
+				.accumulated_rate(now().as_secs(), (now() + YEAR).as_secs())
+				.unwrap();
+
+		expected_portfolio(discount.saturating_mul_int(cashflow));
+	});
+}
+
+#[test]
+fn with_lower_of_cost_or_market_loans() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			pricing: Pricing::Internal(InternalPricing {
+				valuation_method: ValuationMethod::LowerOfCostOrMarket {
+					price_id: REGISTER_PRICE_ID,
+				},
+				..util::base_internal_pricing()
+			}),
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		config_mocks();
+		update_portfolio();
+
+		// The market value is below the debt
+		expected_portfolio(PRICE_VALUE);
+		assert_eq!(util::current_loan_pv(loan_id), PRICE_VALUE);
+
+		MockPrices::mock_collection(|_| {
+			Ok(MockDataCollection::new(|_| {
+				Ok((COLLATERAL_VALUE * 2, BLOCK_TIME_MS))
+			}))
+		});
+		update_portfolio();
+
+		// The debt is below the market value
+		expected_portfolio(util::current_loan_debt(loan_id));
+	});
+}
+
+#[test]
+fn with_floating_rate_loans_valued_without_refreshing_rates() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			interest_rate: InterestRate::Floating {
+				reference: REGISTER_PRICE_ID,
+				reference_rate: Rate::saturating_from_rational(1, 100),
+				spread: Rate::saturating_from_rational(2, 100),
+				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
+			},
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		MockPrices::mock_collection(|pool_id| {
+			assert_eq!(*pool_id, POOL_A);
+			Ok(MockDataCollection::new(|id| match *id {
+				REGISTER_PRICE_ID => Ok((
+					Rate::saturating_from_rational(3, 100).into_inner(),
+					BLOCK_TIME_MS,
+				)),
+				_ => Err(PRICE_ID_NO_FOUND),
+			}))
+		});
+
+		assert_ok!(Loans::update_portfolio_valuation_for_pool(
+			POOL_A,
+			PriceCollectionInput::FromRegistry
+		));
+
+		assert_eq!(
+			util::get_loan(loan_id)
+				.pricing()
+				.interest()
+				.rate()
+				.per_year(),
+			Rate::saturating_from_rational(3, 100)
+		);
+	});
+}
@@ -186,10 +186,172 @@ fn with_success_half_amount() {
 				interest: 0,
 				..amount
 			},
+			prepaid_principal: COLLATERAL_VALUE / 2,
 		}));
 	});
 }
 
+#[test]
+fn with_principal_prepaid_ahead_of_schedule() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			schedule: RepaymentSchedule {
+				interest_payments: InterestPayments::Monthly(1),
+				pay_down_schedule: PayDownSchedule::Linear,
+				..util::base_internal_loan().schedule
+			},
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let first_payment = util::get_loan(loan_id).expected_cashflows().unwrap()[0].clone();
+		advance_time(Duration::from_secs(first_payment.when) - now());
+
+		// Repays the scheduled principal and the same amount in advance
+		let amount = RepaidInput {
+			principal: PrincipalInput::Internal(first_payment.principal * 2),
+			interest: 0,
+			unscheduled: 0,
+		};
+
+		config_mocks(first_payment.principal * 2);
+		assert_ok!(Loans::repay(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			amount.clone()
+		));
+
+		System::assert_last_event(RuntimeEvent::Loans(Event::Repaid {
+			pool_id: POOL_A,
+			loan_id: loan_id,
+			amount,
+			prepaid_principal: first_payment.principal,
+		}));
+
+		assert_eq!(
+			util::get_loan(loan_id).total_repaid().prepaid,
+			first_payment.principal
+		);
+	});
+}
+
+#[test]
+fn with_earlier_repayments_covering_the_schedule() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			schedule: RepaymentSchedule {
+				interest_payments: InterestPayments::Monthly(1),
+				pay_down_schedule: PayDownSchedule::Linear,
+				..util::base_internal_loan().schedule
+			},
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let first_payment = util::get_loan(loan_id).expected_cashflows().unwrap()[0].clone();
+		let amount = RepaidInput {
+			principal: PrincipalInput::Internal(first_payment.principal),
+			interest: 0,
+			unscheduled: 0,
+		};
+
+		// Nothing is due yet, so the whole repayment is ahead of the schedule
+		config_mocks(first_payment.principal);
+		assert_ok!(Loans::repay(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			amount.clone()
+		));
+
+		// The first payment was already covered by the previous repayment
+		advance_time(Duration::from_secs(first_payment.when) - now());
+		assert_ok!(Loans::repay(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			amount.clone()
+		));
+
+		System::assert_last_event(RuntimeEvent::Loans(Event::Repaid {
+			pool_id: POOL_A,
+			loan_id: loan_id,
+			amount,
+			prepaid_principal: first_payment.principal,
+		}));
+
+		assert_eq!(
+			util::get_loan(loan_id).total_repaid().prepaid,
+			first_payment.principal * 2
+		);
+	});
+}
+
+#[test]
+fn with_payment_statuses_tracked() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(LoanInfo {
+			schedule: RepaymentSchedule {
+				interest_payments: InterestPayments::Monthly(1),
+				..util::base_internal_loan().schedule
+			},
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let statuses = || {
+			util::get_loan(loan_id)
+				.payment_statuses(now().as_secs())
+				.unwrap()
+				.into_iter()
+				.map(|(_, status)| status)
+				.collect::<Vec<_>>()
+		};
+
+		let cashflow = util::get_loan(loan_id).expected_cashflows().unwrap();
+		assert!(statuses().iter().all(|s| *s == PaymentStatus::Pending));
+
+		// Only half of the first payment is repaid
+		advance_time(Duration::from_secs(cashflow[0].when) - now());
+		let amount = RepaidInput {
+			principal: PrincipalInput::Internal(0),
+			interest: cashflow[0].interest / 2,
+			unscheduled: 0,
+		};
+		config_mocks(amount.interest);
+		assert_ok!(Loans::repay(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			amount
+		));
+		assert_eq!(statuses()[0], PaymentStatus::PartiallyPaid);
+		assert_eq!(statuses()[1], PaymentStatus::Pending);
+
+		// The second payment date passes without any repayment
+		advance_time(Duration::from_secs(cashflow[1].when) - now());
+		assert_eq!(statuses()[1], PaymentStatus::Missed);
+
+		// Both payments are covered
+		let remaining = cashflow[0].interest - cashflow[0].interest / 2 + cashflow[1].interest;
+		let amount = RepaidInput {
+			principal: PrincipalInput::Internal(remaining),
+			interest: 0,
+			unscheduled: 0,
+		};
+		config_mocks(remaining);
+		assert_ok!(Loans::repay(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			amount
+		));
+		assert_eq!(statuses()[..2], [PaymentStatus::Paid, PaymentStatus::Paid]);
+		assert_eq!(statuses()[2], PaymentStatus::Pending);
+	});
+}
+
 #[test]
 fn with_success_total_amount() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,312 @@
+use super::*;
+
+fn substitution(
+	removed: Vec<Asset>,
+	added: Vec<Asset>,
+	collateral_value: Option<Balance>,
+) -> CollateralSubstitution<Runtime> {
+	CollateralSubstitution {
+		removed: BoundedVec::truncate_from(removed),
+		added: BoundedVec::truncate_from(added),
+		collateral_value,
+	}
+}
+
+fn config_mocks(loan_id: LoanId, substitution: &CollateralSubstitution<Runtime>) {
+	MockPermissions::mock_has(|scope, who, role| {
+		matches!(scope, PermissionScope::Pool(id) if id == POOL_A)
+			&& matches!(role, Role::PoolRole(PoolRole::LoanAdmin))
+			&& who == LOAN_ADMIN
+	});
+
+	MockChangeGuard::mock_note({
+		let substitution = substitution.clone();
+		move |pool_id, change| {
+			assert_eq!(pool_id, POOL_A);
+			assert_eq!(
+				change,
+				Change::CollateralSubstitution(loan_id, substitution.clone())
+			);
+			Ok(CHANGE_ID)
+		}
+	});
+
+	MockChangeGuard::mock_released({
+		let substitution = substitution.clone();
+		move |pool_id, change_id| {
+			assert_eq!(pool_id, POOL_A);
+			assert_eq!(change_id, CHANGE_ID);
+			Ok(Change::CollateralSubstitution(
+				loan_id,
+				substitution.clone(),
+			))
+		}
+	});
+
+	MockPools::mock_account_for(|_| POOL_A_ACCOUNT);
+}
+
+#[test]
+fn with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+		let substitution = substitution(vec![ASSET_AA], vec![ASSET_BA], None);
+		config_mocks(loan_id, &substitution);
+
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				loan_id,
+				substitution
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn without_active_loan() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+
+		let substitution = substitution(vec![ASSET_AA], vec![ASSET_BA], None);
+		config_mocks(loan_id, &substitution);
+
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				substitution
+			),
+			Error::<Runtime>::LoanNotActiveOrNotFound
+		);
+	});
+}
+
+#[test]
+fn with_wrong_collateral() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+		let not_held = substitution(vec![ASSET_BA], vec![ASSET_BB], None);
+		config_mocks(loan_id, &not_held);
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				not_held
+			),
+			Error::<Runtime>::from(MutationError::CollateralNotFound)
+		);
+
+		let empty = substitution(vec![ASSET_AA], vec![], None);
+		config_mocks(loan_id, &empty);
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				empty
+			),
+			Error::<Runtime>::from(MutationError::InvalidCollateral)
+		);
+
+		let repeated = substitution(vec![], vec![ASSET_AA], None);
+		config_mocks(loan_id, &repeated);
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				repeated
+			),
+			Error::<Runtime>::from(MutationError::InvalidCollateral)
+		);
+
+		let too_many = substitution(vec![], vec![ASSET_BA, ASSET_BB, ASSET_BC], None);
+		config_mocks(loan_id, &too_many);
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				too_many
+			),
+			Error::<Runtime>::from(MutationError::InvalidCollateral)
+		);
+	});
+}
+
+#[test]
+fn with_collateral_value_for_external_pricing() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_external_loan());
+		let amount = ExternalAmount::new(QUANTITY, PRICE_VALUE);
+		util::borrow_loan(loan_id, PrincipalInput::External(amount));
+
+		let substitution = substitution(vec![ASSET_AA], vec![ASSET_BA], Some(COLLATERAL_VALUE));
+		config_mocks(loan_id, &substitution);
+
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				substitution
+			),
+			Error::<Runtime>::from(MutationError::InternalPricingExpected)
+		);
+	});
+}
+
+#[test]
+fn with_invalid_collateral_value() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let zero = substitution(vec![ASSET_AA], vec![ASSET_BA], Some(0));
+		config_mocks(loan_id, &zero);
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				zero
+			),
+			Error::<Runtime>::from(MutationError::InvalidCollateralValue)
+		);
+
+		// Less than what was already borrowed against the collateral
+		let uncovered = substitution(vec![ASSET_AA], vec![ASSET_BA], Some(COLLATERAL_VALUE / 4));
+		config_mocks(loan_id, &uncovered);
+		assert_noop!(
+			Loans::propose_collateral_substitution(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				uncovered
+			),
+			Error::<Runtime>::from(MutationError::InvalidCollateralValue)
+		);
+
+		let covered = substitution(vec![ASSET_AA], vec![ASSET_BA], Some(COLLATERAL_VALUE / 2));
+		config_mocks(loan_id, &covered);
+		assert_ok!(Loans::propose_collateral_substitution(
+			RuntimeOrigin::signed(LOAN_ADMIN),
+			POOL_A,
+			loan_id,
+			covered
+		));
+	});
+}
+
+#[test]
+fn with_wrong_borrower() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+		let substitution = substitution(vec![], vec![ASSET_BC], None);
+		config_mocks(loan_id, &substitution);
+
+		assert_ok!(Loans::propose_collateral_substitution(
+			RuntimeOrigin::signed(LOAN_ADMIN),
+			POOL_A,
+			loan_id,
+			substitution
+		));
+
+		assert_noop!(
+			Loans::apply_collateral_substitution(
+				RuntimeOrigin::signed(OTHER_BORROWER),
+				POOL_A,
+				CHANGE_ID
+			),
+			Error::<Runtime>::NotLoanBorrower
+		);
+	});
+}
+
+#[test]
+fn with_added_collateral_not_owned() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+		let substitution = substitution(vec![], vec![ASSET_BC], None);
+		config_mocks(loan_id, &substitution);
+
+		assert_noop!(
+			Loans::apply_collateral_substitution(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				CHANGE_ID
+			),
+			Error::<Runtime>::NotNFTOwner
+		);
+	});
+}
+
+#[test]
+fn with_successful_substitution() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		let substitution = substitution(
+			vec![ASSET_AA],
+			vec![ASSET_BA, ASSET_BB],
+			Some(COLLATERAL_VALUE * 2),
+		);
+		config_mocks(loan_id, &substitution);
+
+		assert_ok!(Loans::propose_collateral_substitution(
+			RuntimeOrigin::signed(LOAN_ADMIN),
+			POOL_A,
+			loan_id,
+			substitution.clone()
+		));
+
+		assert_ok!(Loans::apply_collateral_substitution(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			CHANGE_ID
+		));
+
+		System::assert_last_event(RuntimeEvent::Loans(Event::CollateralSubstituted {
+			pool_id: POOL_A,
+			loan_id,
+			substitution,
+		}));
+
+		assert_eq!(Uniques::owner(ASSET_AA.0, ASSET_AA.1).unwrap(), BORROWER);
+		assert_eq!(
+			Uniques::owner(ASSET_BA.0, ASSET_BA.1).unwrap(),
+			POOL_A_ACCOUNT
+		);
+		assert_eq!(
+			Uniques::owner(ASSET_BB.0, ASSET_BB.1).unwrap(),
+			POOL_A_ACCOUNT
+		);
+
+		let loan = util::get_loan(loan_id);
+		assert_eq!(loan.collateral().to_vec(), vec![ASSET_BA, ASSET_BB]);
+		match loan.pricing() {
+			ActivePricing::Internal(inner) => {
+				// The whole collateral value can now be borrowed
+				assert_eq!(
+					inner.max_borrow_amount(COLLATERAL_VALUE),
+					Ok(COLLATERAL_VALUE)
+				);
+			}
+			ActivePricing::External(_) => unreachable!(),
+		}
+	});
+}
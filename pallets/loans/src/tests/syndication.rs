@@ -0,0 +1,315 @@
+use super::*;
+
+const SHARE: f64 = 0.25;
+
+fn participants(participants: Vec<(PoolId, f64)>) -> ParticipantsOf<Runtime> {
+	BoundedVec::truncate_from(
+		participants
+			.into_iter()
+			.map(|(pool_id, share)| (pool_id, Rate::from_float(share)))
+			.collect(),
+	)
+}
+
+fn config_mocks(loan_id: LoanId, participants: &ParticipantsOf<Runtime>) {
+	MockPermissions::mock_has(|scope, who, role| {
+		matches!(scope, PermissionScope::Pool(id) if id == POOL_A || id == POOL_B)
+			&& matches!(role, Role::PoolRole(PoolRole::LoanAdmin))
+			&& who == LOAN_ADMIN
+	});
+
+	MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A || pool_id == POOL_B);
+
+	MockChangeGuard::mock_note({
+		let participants = participants.clone();
+		move |pool_id, change| {
+			assert_eq!(pool_id, POOL_A);
+			assert_eq!(change, Change::Syndication(loan_id, participants.clone()));
+			Ok(CHANGE_ID)
+		}
+	});
+
+	MockChangeGuard::mock_released({
+		let participants = participants.clone();
+		move |pool_id, change_id| {
+			assert_eq!(pool_id, POOL_A);
+			assert_eq!(change_id, CHANGE_ID);
+			Ok(Change::Syndication(loan_id, participants.clone()))
+		}
+	});
+}
+
+fn syndicate(loan_id: LoanId) -> LoanId {
+	let participants = participants(vec![(POOL_B, SHARE)]);
+	config_mocks(loan_id, &participants);
+
+	assert_ok!(Loans::propose_syndication(
+		RuntimeOrigin::signed(LOAN_ADMIN),
+		POOL_A,
+		loan_id,
+		participants
+	));
+
+	assert_ok!(Loans::apply_syndication(
+		RuntimeOrigin::signed(LOAN_ADMIN),
+		POOL_A,
+		CHANGE_ID
+	));
+
+	Syndications::<Runtime>::get(POOL_A, loan_id)[0].loan_id
+}
+
+fn expected_portfolio(pool_id: PoolId, loan_id: LoanId, value: Balance) {
+	let portfolio = PortfolioValuation::<Runtime>::get(pool_id);
+	assert_eq!(portfolio.value(), value);
+	assert_eq!(portfolio.value_of(loan_id), Some(value));
+}
+
+#[test]
+fn with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+
+		let participants = participants(vec![(POOL_B, SHARE)]);
+		config_mocks(loan_id, &participants);
+
+		assert_noop!(
+			Loans::propose_syndication(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				loan_id,
+				participants.clone()
+			),
+			BadOrigin
+		);
+
+		assert_ok!(Loans::propose_syndication(
+			RuntimeOrigin::signed(LOAN_ADMIN),
+			POOL_A,
+			loan_id,
+			participants
+		));
+
+		MockPermissions::mock_has(|scope, who, _| {
+			matches!(scope, PermissionScope::Pool(id) if id == POOL_A) && who == LOAN_ADMIN
+		});
+
+		assert_noop!(
+			Loans::apply_syndication(RuntimeOrigin::signed(LOAN_ADMIN), POOL_A, CHANGE_ID),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn with_wrong_participants() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+
+		let wrong_participants = [
+			(vec![], SyndicationError::InvalidParticipant),
+			(vec![(POOL_A, SHARE)], SyndicationError::InvalidParticipant),
+			(
+				vec![(POOL_B, SHARE), (POOL_B, SHARE)],
+				SyndicationError::InvalidParticipant,
+			),
+			(vec![(POOL_B, 0.0)], SyndicationError::InvalidShare),
+			(vec![(POOL_B, 1.0)], SyndicationError::InvalidShare),
+		];
+
+		for (wrong_participants, error) in wrong_participants {
+			let wrong_participants = participants(wrong_participants);
+			config_mocks(loan_id, &wrong_participants);
+
+			assert_noop!(
+				Loans::propose_syndication(
+					RuntimeOrigin::signed(LOAN_ADMIN),
+					POOL_A,
+					loan_id,
+					wrong_participants
+				),
+				Error::<Runtime>::from(error)
+			);
+		}
+	});
+}
+
+#[test]
+fn with_active_loan() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let participants = participants(vec![(POOL_B, SHARE)]);
+		config_mocks(loan_id, &participants);
+
+		assert_noop!(
+			Loans::propose_syndication(
+				RuntimeOrigin::signed(LOAN_ADMIN),
+				POOL_A,
+				loan_id,
+				participants
+			),
+			Error::<Runtime>::from(SyndicationError::LoanNotCreatedOrNotFound)
+		);
+	});
+}
+
+#[test]
+fn with_already_syndicated_loan() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		syndicate(loan_id);
+
+		assert_noop!(
+			Loans::apply_syndication(RuntimeOrigin::signed(LOAN_ADMIN), POOL_A, CHANGE_ID),
+			Error::<Runtime>::from(SyndicationError::AlreadySyndicated)
+		);
+	});
+}
+
+#[test]
+fn with_max_participations_reached() {
+	new_test_ext().execute_with(|| {
+		for asset in [ASSET_AA, ASSET_BA] {
+			let loan_id = util::create_loan(LoanInfo {
+				collateral: util::collateral(asset),
+				..util::base_internal_loan()
+			});
+			syndicate(loan_id);
+		}
+
+		let loan_id = util::create_loan(LoanInfo {
+			collateral: util::collateral(ASSET_BB),
+			..util::base_internal_loan()
+		});
+		let participants = participants(vec![(POOL_B, SHARE)]);
+		config_mocks(loan_id, &participants);
+
+		assert_noop!(
+			Loans::apply_syndication(RuntimeOrigin::signed(LOAN_ADMIN), POOL_A, CHANGE_ID),
+			Error::<Runtime>::from(SyndicationError::MaxParticipationsReached)
+		);
+	});
+}
+
+#[test]
+fn with_successful_syndication() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		let participation_id = syndicate(loan_id);
+
+		assert_eq!(participation_id, LastLoanId::<Runtime>::get(POOL_B));
+		assert_eq!(
+			Participations::<Runtime>::get(POOL_B).to_vec(),
+			vec![(participation_id, (POOL_A, loan_id))]
+		);
+
+		let amount = COLLATERAL_VALUE / 2;
+		let share = Rate::from_float(SHARE).saturating_mul_int(amount);
+
+		MockPools::mock_withdraw(move |pool_id, to, withdrawn| {
+			assert_eq!(to, BORROWER);
+			match pool_id {
+				POOL_A => assert_eq!(withdrawn, amount - share),
+				POOL_B => assert_eq!(withdrawn, share),
+				_ => unreachable!(),
+			}
+			Ok(())
+		});
+		assert_ok!(Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(amount)
+		));
+
+		expected_portfolio(POOL_A, loan_id, amount - share);
+		expected_portfolio(POOL_B, participation_id, share);
+
+		MockPools::mock_deposit(move |pool_id, from, deposited| {
+			assert_eq!(from, BORROWER);
+			match pool_id {
+				POOL_A => assert_eq!(deposited, amount - share),
+				POOL_B => assert_eq!(deposited, share),
+				_ => unreachable!(),
+			}
+			Ok(())
+		});
+		assert_ok!(Loans::repay(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			RepaidInput {
+				principal: PrincipalInput::Internal(amount),
+				interest: 0,
+				unscheduled: 0,
+			}
+		));
+
+		expected_portfolio(POOL_A, loan_id, 0);
+		expected_portfolio(POOL_B, participation_id, 0);
+
+		util::close_loan(loan_id);
+
+		assert_eq!(
+			PortfolioValuation::<Runtime>::get(POOL_B).value_of(participation_id),
+			None
+		);
+		assert_eq!(Syndications::<Runtime>::get(POOL_A, loan_id).len(), 0);
+		assert_eq!(Participations::<Runtime>::get(POOL_B).len(), 0);
+	});
+}
+
+#[test]
+fn with_participation_valuation() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		let participation_id = syndicate(loan_id);
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		advance_time(YEAR / 2);
+
+		MockPools::mock_pool_exists(|_| true);
+		MockPrices::mock_collection(|_| Ok(MockDataCollection::new(|_| Err(PRICE_ID_NO_FOUND))));
+		assert_ok!(Loans::update_portfolio_valuation(
+			RuntimeOrigin::signed(ANY),
+			POOL_B
+		));
+
+		let present_value = util::current_loan_pv(loan_id);
+		let share = Rate::from_float(SHARE).saturating_mul_int(present_value);
+
+		expected_portfolio(POOL_B, participation_id, share);
+	});
+}
+
+#[test]
+fn with_transfer_debt() {
+	new_test_ext().execute_with(|| {
+		let loan_1 = util::create_loan(util::base_internal_loan());
+		syndicate(loan_1);
+		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let loan_2 = util::create_loan(LoanInfo {
+			collateral: util::collateral(ASSET_BA),
+			..util::base_internal_loan()
+		});
+
+		assert_noop!(
+			Loans::propose_transfer_debt(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				loan_1,
+				loan_2,
+				RepaidInput {
+					principal: PrincipalInput::Internal(COLLATERAL_VALUE / 2),
+					interest: 0,
+					unscheduled: 0,
+				},
+				PrincipalInput::Internal(COLLATERAL_VALUE / 2)
+			),
+			Error::<Runtime>::from(SyndicationError::DebtTransferNotAllowed)
+		);
+	});
+}
@@ -51,7 +51,7 @@ fn with_wrong_borrower() {
 		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 
@@ -78,7 +78,7 @@ fn with_wrong_borrower_for_repaid_loan() {
 	new_test_ext().execute_with(|| {
 		let loan_1 = util::create_loan_by(
 			LoanInfo {
-				collateral: ASSET_BC,
+				collateral: util::collateral(ASSET_BC),
 				..util::base_internal_loan()
 			},
 			OTHER_BORROWER,
@@ -113,7 +113,7 @@ fn with_wrong_borrower_for_borrowed_loan() {
 
 		let loan_2 = util::create_loan_by(
 			LoanInfo {
-				collateral: ASSET_BC,
+				collateral: util::collateral(ASSET_BC),
 				..util::base_internal_loan()
 			},
 			OTHER_BORROWER,
@@ -208,7 +208,7 @@ fn with_mismatch_internal_internal_amounts() {
 		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 
@@ -238,7 +238,7 @@ fn with_mismatch_external_internal_amounts() {
 		util::borrow_loan(loan_1, PrincipalInput::External(amount));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 
@@ -274,7 +274,7 @@ fn with_mismatch_internal_external_amounts() {
 		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_external_loan()
 		});
 
@@ -306,7 +306,7 @@ fn with_mismatch_external_external_amounts() {
 		util::borrow_loan(loan_1, PrincipalInput::External(amount));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_external_loan()
 		});
 
@@ -355,7 +355,7 @@ fn with_success_internals() {
 		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 
@@ -406,7 +406,7 @@ fn with_success_externals() {
 		util::borrow_loan(loan_1, PrincipalInput::External(amount));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_external_loan()
 		});
 
@@ -448,7 +448,7 @@ fn with_transfer_roundtrip() {
 		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
 
 		let loan_2 = util::create_loan(LoanInfo {
-			collateral: ASSET_BA,
+			collateral: util::collateral(ASSET_BA),
 			..util::base_internal_loan()
 		});
 
@@ -80,6 +80,10 @@ pub fn set_up_policy(percentage: f64, penalty: f64) {
 	MockPools::mock_pool_exists(|_| panic!("no pool_exists() mock"));
 }
 
+pub fn collateral(asset: Asset) -> CollateralOf<Runtime> {
+	BoundedVec::truncate_from(vec![asset])
+}
+
 pub fn base_internal_pricing() -> InternalPricing<Runtime> {
 	InternalPricing {
 		collateral_value: COLLATERAL_VALUE,
@@ -98,6 +102,7 @@ pub fn dcf_internal_pricing() -> InternalPricing<Runtime> {
 			discount_rate: InterestRate::Fixed {
 				rate_per_year: Rate::from_float(DEFAULT_DISCOUNT_RATE),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			},
 		}),
 	}
@@ -110,10 +115,11 @@ pub fn dcf_internal_loan() -> LoanInfo<Runtime> {
 	}
 }
 
-pub fn default_interest_rate() -> InterestRate<Rate> {
+pub fn default_interest_rate() -> InterestRateOf<Runtime> {
 	InterestRate::Fixed {
 		rate_per_year: Rate::from_float(DEFAULT_INTEREST_RATE),
 		compounding: CompoundingSchedule::Secondly,
+		day_count: DayCountConvention::Actual365,
 	}
 }
 
@@ -128,7 +134,7 @@ pub fn base_internal_loan() -> LoanInfo<Runtime> {
 			pay_down_schedule: PayDownSchedule::None,
 		},
 		interest_rate: default_interest_rate(),
-		collateral: ASSET_AA,
+		collateral: util::collateral(ASSET_AA),
 		pricing: Pricing::Internal(base_internal_pricing()),
 		restrictions: LoanRestrictions {
 			borrows: BorrowRestrictions::NotWrittenOff,
@@ -155,7 +161,7 @@ pub fn base_external_loan() -> LoanInfo<Runtime> {
 			pay_down_schedule: PayDownSchedule::None,
 		},
 		interest_rate: default_interest_rate(),
-		collateral: ASSET_AA,
+		collateral: util::collateral(ASSET_AA),
 		pricing: Pricing::External(base_external_pricing()),
 		restrictions: LoanRestrictions {
 			borrows: BorrowRestrictions::NotWrittenOff,
@@ -12,18 +12,24 @@
 // GNU General Public License for more details.
 
 use cfg_traits::{interest::InterestRate, Seconds};
-use frame_support::pallet_prelude::RuntimeDebug;
+use chrono::{DateTime, Datelike, Months, NaiveDate};
+use frame_support::{pallet_prelude::RuntimeDebug, traits::tokens};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		ensure_pow, EnsureAdd, EnsureAddAssign, EnsureFixedPointNumber, EnsureInto, EnsureSub,
+		EnsureAdd, EnsureAddAssign, EnsureDiv, EnsureFixedPointNumber, EnsureInto, EnsureSub,
 		EnsureSubAssign,
 	},
 	DispatchError, FixedPointNumber, FixedPointOperand, FixedU128,
 };
 use sp_std::{vec, vec::Vec};
 
+/// Maximum number of payments a cashflow can contain.
+/// It bounds the computation of the cashflow, i.e: 40 years of monthly
+/// payments.
+pub const MAX_CASHFLOW_PAYMENTS: usize = 480;
+
 /// Specify the expected repayments date
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 pub enum Maturity {
@@ -76,6 +82,37 @@ impl Maturity {
 pub enum InterestPayments {
 	/// All interest is expected to be paid at the maturity date
 	OnceAtMaturity,
+
+	/// Interest is expected to be paid every month.
+	/// The associated value corresponds to the payment day in the month, from
+	/// 1 to 31. In months with fewer days, the last day of the month is used.
+	Monthly(u8),
+
+	/// Interest is expected to be paid every three months.
+	/// The associated value corresponds to the payment day in the month.
+	Quarterly(u8),
+
+	/// Interest is expected to be paid every six months.
+	/// The associated value corresponds to the payment day in the month.
+	SemiAnnually(u8),
+
+	/// Interest is expected to be paid every year.
+	/// The associated value corresponds to the payment day in the month.
+	Annually(u8),
+}
+
+impl InterestPayments {
+	/// Returns the months between payments and the payment day in the month,
+	/// or `None` if interest is not paid periodically.
+	pub fn periodicity(&self) -> Option<(u32, u8)> {
+		match self {
+			InterestPayments::OnceAtMaturity => None,
+			InterestPayments::Monthly(day) => Some((1, *day)),
+			InterestPayments::Quarterly(day) => Some((3, *day)),
+			InterestPayments::SemiAnnually(day) => Some((6, *day)),
+			InterestPayments::Annually(day) => Some((12, *day)),
+		}
+	}
 }
 
 /// Specify the paydown schedules of the loan
@@ -83,6 +120,14 @@ pub enum InterestPayments {
 pub enum PayDownSchedule {
 	/// No restrictions on how the paydown should be done.
 	None,
+
+	/// The principal is paid down in equal parts on each interest payment
+	/// date.
+	Linear,
+
+	/// The principal is paid down so that each interest payment date expects
+	/// the same amount, principal plus interest.
+	Annuity,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
@@ -92,6 +137,22 @@ pub struct CashflowPayment<Balance> {
 	pub interest: Balance,
 }
 
+/// Repayment status of a scheduled cashflow payment
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub enum PaymentStatus {
+	/// The payment was fully repaid
+	Paid,
+
+	/// The payment was repaid only in part
+	PartiallyPaid,
+
+	/// The payment date has passed without any repayment
+	Missed,
+
+	/// The payment date has not been reached yet
+	Pending,
+}
+
 /// Specify the repayment schedule of the loan
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 pub struct RepaymentSchedule {
@@ -108,13 +169,76 @@ pub struct RepaymentSchedule {
 
 impl RepaymentSchedule {
 	pub fn is_valid(&self, now: Seconds) -> Result<bool, DispatchError> {
-		let valid = match self.interest_payments {
-			InterestPayments::OnceAtMaturity => true,
+		self.is_valid_since(now, now)
+	}
+
+	/// Checks the schedule of a loan originated at `origination_date`, whose
+	/// cashflows are generated from that date on.
+	pub fn is_valid_since(
+		&self,
+		origination_date: Seconds,
+		now: Seconds,
+	) -> Result<bool, DispatchError> {
+		let valid = match self.interest_payments.periodicity() {
+			// Paying down the principal requires periodic payments
+			None => self.pay_down_schedule == PayDownSchedule::None,
+			Some((_, day)) => match self.maturity.date() {
+				// The payment dates are only computed to check that the amount of
+				// payments is bounded
+				Some(maturity) if (1..=31).contains(&day) && maturity > now => {
+					self.payment_dates(origination_date, maturity).is_ok()
+				}
+				_ => false,
+			},
 		};
 
 		Ok(valid && self.maturity.is_valid(now))
 	}
 
+	/// Returns the dates where a payment is expected, in increasing order.
+	/// The last date always corresponds to the maturity date.
+	fn payment_dates(
+		&self,
+		origination_date: Seconds,
+		maturity: Seconds,
+	) -> Result<Vec<Seconds>, DispatchError> {
+		let Some((months, day)) = self.interest_payments.periodicity() else {
+			return Ok(vec![maturity]);
+		};
+
+		// Payment dates are computed backwards from the maturity month,
+		// so they do not depend on the origination date.
+		let maturity_month = date::first_day_of_month(date::from_seconds(maturity)?)?;
+
+		let mut dates = vec![maturity];
+		for period in 0.. {
+			let month = maturity_month
+				.checked_sub_months(Months::new(period * months))
+				.ok_or(DispatchError::Other("Invalid payment month"))?;
+
+			let when = date::into_seconds(date::with_day_or_last(month, day.into())?)?;
+
+			if when >= maturity {
+				continue;
+			}
+
+			if when <= origination_date {
+				break;
+			}
+
+			if dates.len() >= MAX_CASHFLOW_PAYMENTS {
+				return Err(DispatchError::Other(
+					"Exceeded max number of cashflow payments",
+				));
+			}
+
+			dates.push(when);
+		}
+
+		dates.reverse();
+		Ok(dates)
+	}
+
 	pub fn generate_cashflows<Balance, Rate>(
 		&self,
 		origination_date: Seconds,
@@ -123,37 +247,130 @@ impl RepaymentSchedule {
 		interest_rate: &InterestRate<Rate>,
 	) -> Result<Vec<CashflowPayment<Balance>>, DispatchError>
 	where
-		Balance: FixedPointOperand + EnsureAdd + EnsureSub,
+		Balance: tokens::Balance + FixedPointOperand,
 		Rate: FixedPointNumber,
 	{
 		let Some(maturity) = self.maturity.date() else {
 			return Ok(Vec::new());
 		};
 
-		let timeflow = match &self.interest_payments {
-			InterestPayments::OnceAtMaturity => vec![(maturity, 1)],
+		if self.pay_down_schedule == PayDownSchedule::None {
+			return self.bullet_cashflows(
+				origination_date,
+				maturity,
+				principal,
+				principal_base,
+				interest_rate,
+			);
+		}
+
+		// Accumulated interest rate for each period between payments
+		let mut last_date = origination_date;
+		let periods = self
+			.payment_dates(origination_date, maturity)?
+			.into_iter()
+			.map(|when| {
+				let acc_rate = interest_rate.accumulated_rate(last_date, when)?;
+				last_date = when;
+
+				Ok((when, acc_rate))
+			})
+			.collect::<Result<Vec<(Seconds, Rate)>, DispatchError>>()?;
+
+		// Principal expected in each payment for linear schedules,
+		// or principal plus interest for annuities.
+		let count = periods.len();
+		let paydown = match self.pay_down_schedule {
+			PayDownSchedule::None => None,
+			PayDownSchedule::Linear => {
+				let payments: u32 = count.ensure_into()?;
+				Some(principal.ensure_div(payments.into())?)
+			}
+			PayDownSchedule::Annuity => Some(annuity_payment(principal, &periods)?),
 		};
 
-		let total_weight = timeflow
-			.iter()
-			.map(|(_, weight)| weight)
-			.try_fold(0, |a, b| a.ensure_add(*b))?;
+		// Each payment expects the interest accrued by the outstanding principal
+		// during its period. The last payment expects the remaining principal.
+		let mut outstanding = principal;
+		periods
+			.into_iter()
+			.enumerate()
+			.map(|(index, (when, acc_rate))| {
+				let interest = acc_rate
+					.ensure_mul_int(outstanding)?
+					.ensure_sub(outstanding)?;
+
+				if index + 1 == count {
+					return Ok(CashflowPayment {
+						when,
+						principal: outstanding,
+						interest: interest.ensure_add(principal)?.ensure_sub(principal_base)?,
+					});
+				}
 
-		let lifetime = maturity.ensure_sub(origination_date)?.ensure_into()?;
-		let interest_rate_per_lifetime = ensure_pow(interest_rate.per_sec()?, lifetime)?;
-		let interest_at_maturity = interest_rate_per_lifetime
+				let scheduled_principal = match (&self.pay_down_schedule, paydown) {
+					(PayDownSchedule::Linear, Some(payment)) => payment,
+					(PayDownSchedule::Annuity, Some(payment)) => payment.saturating_sub(interest),
+					_ => Balance::zero(),
+				}
+				.min(outstanding);
+
+				outstanding.ensure_sub_assign(scheduled_principal)?;
+
+				Ok(CashflowPayment {
+					when,
+					principal: scheduled_principal,
+					interest,
+				})
+			})
+			.collect()
+	}
+
+	/// Cashflow of a loan whose principal is paid at maturity.
+	/// The interest accrued until maturity is distributed among the payments
+	/// proportionally to the period each one covers.
+	fn bullet_cashflows<Balance, Rate>(
+		&self,
+		origination_date: Seconds,
+		maturity: Seconds,
+		principal: Balance,
+		principal_base: Balance,
+		interest_rate: &InterestRate<Rate>,
+	) -> Result<Vec<CashflowPayment<Balance>>, DispatchError>
+	where
+		Balance: tokens::Balance + FixedPointOperand,
+		Rate: FixedPointNumber,
+	{
+		let lifetime = maturity.ensure_sub(origination_date)?;
+		let interest_at_maturity = interest_rate
+			.accumulated_rate(origination_date, maturity)?
 			.ensure_mul_int(principal)?
 			.ensure_sub(principal_base)?;
 
-		timeflow
+		// Each payment accrues the interest of the period since the previous
+		// payment. The last payment gets the remaining interest and the whole
+		// principal.
+		let mut last_date = origination_date;
+		let mut remaining_interest = interest_at_maturity;
+
+		self.payment_dates(origination_date, maturity)?
 			.into_iter()
-			.map(|(date, weight)| {
-				let proportion = FixedU128::ensure_from_rational(weight, total_weight)?;
-				let principal = proportion.ensure_mul_int(principal)?;
-				let interest = proportion.ensure_mul_int(interest_at_maturity)?;
+			.map(|when| {
+				let (principal, interest) = if when == maturity {
+					(principal, remaining_interest)
+				} else {
+					let period = when.ensure_sub(last_date)?;
+					let proportion = FixedU128::ensure_from_rational(period, lifetime)?;
+					let interest = proportion.ensure_mul_int(interest_at_maturity)?;
+					remaining_interest.ensure_sub_assign(interest)?;
+
+					(Balance::zero(), interest)
+				};
+
+				last_date = when;
 
 				Ok(CashflowPayment {
-					when: date,
+					when,
 					principal,
 					interest,
 				})
@@ -170,7 +387,7 @@ impl RepaymentSchedule {
 		until: Seconds,
 	) -> Result<Balance, DispatchError>
 	where
-		Balance: FixedPointOperand + EnsureAdd + EnsureSub,
+		Balance: tokens::Balance + FixedPointOperand,
 		Rate: FixedPointNumber,
 	{
 		let cashflow =
@@ -186,9 +403,69 @@ impl RepaymentSchedule {
 	}
 }
 
+/// Returns the level payment that pays off the principal and its interest
+/// in the given periods: `principal / sum(discount factor of each payment)`
+fn annuity_payment<Balance, Rate>(
+	principal: Balance,
+	periods: &[(Seconds, Rate)],
+) -> Result<Balance, DispatchError>
+where
+	Balance: FixedPointOperand,
+	Rate: FixedPointNumber,
+{
+	let mut discount = Rate::one();
+	let mut total_discount = Rate::zero();
+	for (_, acc_rate) in periods {
+		discount = discount.ensure_div(*acc_rate)?;
+		total_discount.ensure_add_assign(discount)?;
+	}
+
+	Ok(Rate::one()
+		.ensure_div(total_discount)?
+		.ensure_mul_int(principal)?)
+}
+
+mod date {
+	use super::*;
+
+	pub fn from_seconds(date_in_seconds: Seconds) -> Result<NaiveDate, DispatchError> {
+		Ok(DateTime::from_timestamp(date_in_seconds.ensure_into()?, 0)
+			.ok_or(DispatchError::Other("Invalid date in seconds"))?
+			.date_naive())
+	}
+
+	/// Returns the last second of the day
+	pub fn into_seconds(date: NaiveDate) -> Result<Seconds, DispatchError> {
+		Ok(date
+			.and_hms_opt(23, 59, 59)
+			.ok_or(DispatchError::Other("Invalid h/m/s"))?
+			.and_utc()
+			.timestamp()
+			.ensure_into()?)
+	}
+
+	pub fn first_day_of_month(date: NaiveDate) -> Result<NaiveDate, DispatchError> {
+		date.with_day(1)
+			.ok_or(DispatchError::Other("Invalid first day of month"))
+	}
+
+	/// Returns the date in the same month with the given day,
+	/// or the last day of the month if the month has fewer days.
+	pub fn with_day_or_last(date: NaiveDate, day: u32) -> Result<NaiveDate, DispatchError> {
+		let last_day = first_day_of_month(date)?
+			.checked_add_months(Months::new(1))
+			.and_then(|next_month| next_month.pred_opt())
+			.ok_or(DispatchError::Other("Invalid last day of month"))?
+			.day();
+
+		date.with_day(day.min(last_day))
+			.ok_or(DispatchError::Other("Invalid day of month"))
+	}
+}
+
 #[cfg(test)]
 pub mod tests {
-	use cfg_traits::interest::CompoundingSchedule;
+	use cfg_traits::interest::{CompoundingSchedule, DayCountConvention};
 	use chrono::NaiveDate;
 
 	use super::*;
@@ -241,6 +518,7 @@ pub mod tests {
 					&InterestRate::Fixed {
 						rate_per_year: Rate::from_float(0.12),
 						compounding: CompoundingSchedule::Secondly,
+						day_count: DayCountConvention::Actual365,
 					}
 				)
 				.unwrap()
@@ -251,4 +529,244 @@ pub mod tests {
 			)
 		}
 	}
+
+	mod monthly {
+		use super::*;
+
+		fn schedule(interest_payments: InterestPayments) -> RepaymentSchedule {
+			RepaymentSchedule {
+				maturity: Maturity::fixed(last_secs_from_ymd(2022, 7, 1)),
+				interest_payments,
+				pay_down_schedule: PayDownSchedule::None,
+			}
+		}
+
+		#[test]
+		fn payment_dates() {
+			assert_eq!(
+				schedule(InterestPayments::Monthly(1))
+					.generate_cashflows(
+						last_secs_from_ymd(2022, 4, 16),
+						25000u128,
+						25000u128,
+						&InterestRate::Fixed {
+							rate_per_year: Rate::from_float(0.12),
+							compounding: CompoundingSchedule::Secondly,
+							day_count: DayCountConvention::Actual365,
+						}
+					)
+					.unwrap()
+					.into_iter()
+					.map(|payment| payment.when)
+					.collect::<Vec<_>>(),
+				vec![
+					last_secs_from_ymd(2022, 5, 1),
+					last_secs_from_ymd(2022, 6, 1),
+					last_secs_from_ymd(2022, 7, 1),
+				]
+			)
+		}
+
+		#[test]
+		fn day_adjusted_to_month() {
+			assert_eq!(
+				RepaymentSchedule {
+					maturity: Maturity::fixed(last_secs_from_ymd(2022, 4, 30)),
+					interest_payments: InterestPayments::Monthly(31),
+					pay_down_schedule: PayDownSchedule::None,
+				}
+				.payment_dates(
+					last_secs_from_ymd(2022, 1, 31),
+					last_secs_from_ymd(2022, 4, 30)
+				)
+				.unwrap(),
+				vec![
+					last_secs_from_ymd(2022, 2, 28),
+					last_secs_from_ymd(2022, 3, 31),
+					last_secs_from_ymd(2022, 4, 30),
+				]
+			)
+		}
+
+		#[test]
+		fn correct_amounts() {
+			// The interest of each payment is proportional to the period it covers.
+			// The principal is paid in the last payment.
+			assert_eq!(
+				schedule(InterestPayments::Monthly(1))
+					.generate_cashflows(
+						last_secs_from_ymd(2022, 4, 16),
+						25000u128,
+						25000u128,
+						&InterestRate::Fixed {
+							rate_per_year: Rate::from_float(0.12),
+							compounding: CompoundingSchedule::Secondly,
+							day_count: DayCountConvention::Actual365,
+						}
+					)
+					.unwrap()
+					.into_iter()
+					.map(|payment| (payment.principal, payment.interest))
+					.collect::<Vec<_>>(),
+				vec![(0, 124), (0, 257), (25000, 251)]
+			)
+		}
+
+		#[test]
+		fn too_many_payments() {
+			let schedule = RepaymentSchedule {
+				maturity: Maturity::fixed(last_secs_from_ymd(2100, 1, 1)),
+				interest_payments: InterestPayments::Monthly(1),
+				pay_down_schedule: PayDownSchedule::None,
+			};
+
+			assert_eq!(schedule.is_valid(last_secs_from_ymd(2022, 1, 1)), Ok(false));
+		}
+
+		#[test]
+		fn invalid_day() {
+			assert_eq!(
+				schedule(InterestPayments::Monthly(32)).is_valid(last_secs_from_ymd(2022, 1, 1)),
+				Ok(false)
+			);
+			assert_eq!(
+				schedule(InterestPayments::Monthly(0)).is_valid(last_secs_from_ymd(2022, 1, 1)),
+				Ok(false)
+			);
+		}
+	}
+
+	mod periodic {
+		use super::*;
+
+		#[test]
+		fn quarterly_dates_from_maturity() {
+			assert_eq!(
+				RepaymentSchedule {
+					maturity: Maturity::fixed(last_secs_from_ymd(2023, 1, 15)),
+					interest_payments: InterestPayments::Quarterly(15),
+					pay_down_schedule: PayDownSchedule::None,
+				}
+				.payment_dates(
+					last_secs_from_ymd(2022, 3, 1),
+					last_secs_from_ymd(2023, 1, 15)
+				)
+				.unwrap(),
+				vec![
+					last_secs_from_ymd(2022, 4, 15),
+					last_secs_from_ymd(2022, 7, 15),
+					last_secs_from_ymd(2022, 10, 15),
+					last_secs_from_ymd(2023, 1, 15),
+				]
+			)
+		}
+
+		#[test]
+		fn semi_annually_and_annually() {
+			let origination = last_secs_from_ymd(2022, 1, 1);
+			let maturity = last_secs_from_ymd(2024, 1, 1);
+
+			let dates = |interest_payments| {
+				RepaymentSchedule {
+					maturity: Maturity::fixed(maturity),
+					interest_payments,
+					pay_down_schedule: PayDownSchedule::None,
+				}
+				.payment_dates(origination, maturity)
+				.unwrap()
+			};
+
+			assert_eq!(
+				dates(InterestPayments::SemiAnnually(1)),
+				vec![
+					last_secs_from_ymd(2022, 7, 1),
+					last_secs_from_ymd(2023, 1, 1),
+					last_secs_from_ymd(2023, 7, 1),
+					last_secs_from_ymd(2024, 1, 1),
+				]
+			);
+
+			assert_eq!(
+				dates(InterestPayments::Annually(1)),
+				vec![
+					last_secs_from_ymd(2023, 1, 1),
+					last_secs_from_ymd(2024, 1, 1)
+				]
+			);
+		}
+	}
+
+	mod pay_down {
+		use super::*;
+
+		fn cashflow(pay_down_schedule: PayDownSchedule) -> Vec<(Seconds, u128, u128)> {
+			RepaymentSchedule {
+				maturity: Maturity::fixed(last_secs_from_ymd(2023, 1, 1)),
+				interest_payments: InterestPayments::Monthly(1),
+				pay_down_schedule,
+			}
+			.generate_cashflows(
+				last_secs_from_ymd(2022, 1, 1),
+				12000u128,
+				12000u128,
+				&InterestRate::Fixed {
+					rate_per_year: Rate::from_float(0.12),
+					compounding: CompoundingSchedule::Secondly,
+					day_count: DayCountConvention::Actual365,
+				},
+			)
+			.unwrap()
+			.into_iter()
+			.map(|payment| (payment.when, payment.principal, payment.interest))
+			.collect()
+		}
+
+		#[test]
+		fn linear() {
+			let cashflow = cashflow(PayDownSchedule::Linear);
+
+			assert_eq!(cashflow.len(), 12);
+			assert!(cashflow.iter().all(|(_, principal, _)| *principal == 1000));
+
+			// Interest decreases with the outstanding principal
+			assert_eq!(
+				cashflow
+					.iter()
+					.map(|(_, _, interest)| *interest)
+					.collect::<Vec<_>>(),
+				vec![122, 101, 102, 89, 81, 69, 61, 51, 39, 30, 19, 10]
+			);
+		}
+
+		#[test]
+		fn annuity() {
+			let cashflow = cashflow(PayDownSchedule::Annuity);
+
+			assert_eq!(cashflow.len(), 12);
+			assert_eq!(
+				cashflow
+					.iter()
+					.map(|(_, principal, _)| *principal)
+					.sum::<u128>(),
+				12000
+			);
+
+			// Level payments, the last one absorbs the rounding
+			assert!(cashflow[..11]
+				.iter()
+				.all(|(_, principal, interest)| principal + interest == 1066));
+			assert_eq!(cashflow[11].1 + cashflow[11].2, 1063);
+		}
+
+		#[test]
+		fn requires_periodic_payments() {
+			let schedule = RepaymentSchedule {
+				maturity: Maturity::fixed(last_secs_from_ymd(2023, 1, 1)),
+				interest_payments: InterestPayments::OnceAtMaturity,
+				pay_down_schedule: PayDownSchedule::Linear,
+			};
+
+			assert_eq!(schedule.is_valid(last_secs_from_ymd(2022, 1, 1)), Ok(false));
+		}
+	}
 }
@@ -33,6 +33,8 @@ pub enum CreateLoanError {
 	InvalidBorrowRestriction,
 	/// Emits when a repay restriction is incorrect
 	InvalidRepayRestriction,
+	/// Emits when the collateral is empty or contains repeated items
+	InvalidCollateral,
 }
 
 /// Error related to loan borrowing
@@ -82,6 +84,41 @@ pub enum MutationError {
 	InternalPricingExpected,
 	/// Maturity extensions exceed max extension allowed.
 	MaturityExtendedTooMuch,
+	/// Emits when the mutated repayment schedule is incorrectly specified
+	InvalidRepaymentSchedule,
+	/// Emits when a fixed interest rate is mutated into a floating one or
+	/// vice versa
+	InterestRateKindMismatch,
+	/// Emits when a substitution removes a collateral item the loan does
+	/// not hold
+	CollateralNotFound,
+	/// Emits when the substituted collateral is empty, contains repeated
+	/// items or exceeds the max collateral items per loan
+	InvalidCollateral,
+	/// Emits when the forgiven share of a restructuring is greater than one
+	InvalidForgiveness,
+	/// Emits when the substituted collateral value is zero or does not cover
+	/// the amount already borrowed against the collateral
+	InvalidCollateralValue,
+}
+
+/// Error related to loan syndications
+#[derive(Encode, Decode, TypeInfo, PalletError)]
+pub enum SyndicationError {
+	/// Emits when the loan is not found or has already been borrowed from
+	LoanNotCreatedOrNotFound,
+	/// Emits when the loan already has participant pools
+	AlreadySyndicated,
+	/// Emits when there are no participants, or a participant is the pool
+	/// of the loan or appears more than once
+	InvalidParticipant,
+	/// Emits when a share is zero or the shares sum the whole loan
+	InvalidShare,
+	/// Emits when debt is transferred from or to a syndicated loan
+	DebtTransferNotAllowed,
+	/// Emits when a participant pool already holds the max number of
+	/// participations
+	MaxParticipationsReached,
 }
 
 /// Specify how offer a loan can be borrowed
@@ -118,11 +155,26 @@ pub struct LoanRestrictions {
 	pub repayments: RepayRestrictions,
 }
 
+/// Share of a syndicated loan held by a participant pool
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Participation<PoolId, LoanId, Rate> {
+	/// Pool participating in the loan
+	pub pool_id: PoolId,
+
+	/// Identifies the share in the portfolio of the participant pool
+	pub loan_id: LoanId,
+
+	/// Share of the loan funded and held by the participant pool
+	pub share: Rate,
+}
+
 #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 pub struct RepaidAmount<Balance> {
 	pub principal: Balance,
 	pub interest: Balance,
 	pub unscheduled: Balance,
+	/// Part of `principal` repaid ahead of the repayment schedule
+	pub prepaid: Balance,
 }
 
 impl<Balance: EnsureAdd + Copy> RepaidAmount<Balance> {
@@ -139,6 +191,33 @@ impl<Balance: EnsureAdd + Copy> RepaidAmount<Balance> {
 	pub fn ensure_add_assign(&mut self, other: &Self) -> Result<(), ArithmeticError> {
 		self.principal.ensure_add_assign(other.principal)?;
 		self.interest.ensure_add_assign(other.interest)?;
-		self.unscheduled.ensure_add_assign(other.unscheduled)
+		self.unscheduled.ensure_add_assign(other.unscheduled)?;
+		self.prepaid.ensure_add_assign(other.prepaid)
+	}
+}
+
+/// Repaid amounts before tracking the prepaid principal, for migration to v5
+pub mod v4 {
+	use parity_scale_codec::{Decode, Encode};
+	use sp_runtime::traits::Zero;
+
+	#[derive(Encode, Decode)]
+	pub struct RepaidAmount<Balance> {
+		principal: Balance,
+		interest: Balance,
+		unscheduled: Balance,
+	}
+
+	impl<Balance: Zero> RepaidAmount<Balance> {
+		/// The principal repaid until v4 is not classified, so none of it is
+		/// considered prepaid.
+		pub fn migrate(self) -> super::RepaidAmount<Balance> {
+			super::RepaidAmount {
+				principal: self.principal,
+				interest: self.interest,
+				unscheduled: self.unscheduled,
+				prepaid: Zero::zero(),
+			}
+		}
 	}
 }
@@ -42,6 +42,10 @@ pub enum WriteOffTrigger {
 
 	/// Seconds since the oracle valuation was last updated
 	PriceOutdated(Seconds),
+
+	/// Seconds after a scheduled payment date has passed without the payment
+	/// being fully repaid
+	PaymentOverdue(Seconds),
 }
 
 /// Wrapper type to identify equality berween kinds of triggers,
@@ -58,6 +62,9 @@ impl PartialEq for UniqueWriteOffTrigger {
 			WriteOffTrigger::PriceOutdated(_) => {
 				matches!(other.0, WriteOffTrigger::PriceOutdated(_))
 			}
+			WriteOffTrigger::PaymentOverdue(_) => {
+				matches!(other.0, WriteOffTrigger::PaymentOverdue(_))
+			}
 		}
 	}
 }
@@ -11,19 +11,27 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_primitives::SECONDS_PER_YEAR;
-use cfg_traits::{interest::InterestRate, Seconds};
+use cfg_traits::{
+	interest::{CompoundingSchedule, InterestRate},
+	Seconds,
+};
 use frame_support::{
 	pallet_prelude::RuntimeDebug,
-	traits::tokens::{self},
+	storage::bounded_vec::BoundedVec,
+	traits::{
+		tokens::{self},
+		ConstU32,
+	},
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::checked_pow;
 use sp_runtime::{
-	traits::{EnsureDiv, EnsureFixedPointNumber, EnsureInto, EnsureMul, EnsureSub, One},
-	ArithmeticError, FixedPointNumber, FixedPointOperand,
+	traits::{EnsureAdd, EnsureDiv, EnsureFixedPointNumber, EnsureMul, EnsureSub, One},
+	ArithmeticError, DispatchError, FixedPointNumber, FixedPointOperand,
 };
+use sp_std::vec::Vec;
+
+use crate::types::cashflow::RepaymentSchedule;
 
 /// Discounted cash flow values
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
@@ -57,9 +65,10 @@ impl<Rate: FixedPointNumber> DiscountedCashFlow<Rate> {
 		debt: Balance,
 		when: Seconds,
 		interest_rate: &InterestRate<Rate>,
+		schedule: &RepaymentSchedule,
 		maturity_date: Seconds,
 		origination_date: Seconds,
-	) -> Result<Balance, ArithmeticError> {
+	) -> Result<Balance, DispatchError> {
 		// If the loan is overdue, there are no future cash flows to discount,
 		// hence we use the outstanding debt as the value.
 		if when > maturity_date {
@@ -67,41 +76,157 @@ impl<Rate: FixedPointNumber> DiscountedCashFlow<Rate> {
 		}
 
 		// Calculate the expected loss over the term of the loan
-		let tel = Rate::saturating_from_rational(
-			maturity_date.ensure_sub(origination_date)?,
-			SECONDS_PER_YEAR,
-		)
-		.ensure_mul(self.probability_of_default)?
-		.ensure_mul(self.loss_given_default)?
-		.min(One::one());
+		let tel = interest_rate
+			.day_count()
+			.year_fraction::<Rate>(origination_date, maturity_date)?
+			.ensure_mul(self.probability_of_default)?
+			.ensure_mul(self.loss_given_default)?
+			.min(One::one());
 
 		let tel_inv = Rate::one().ensure_sub(tel)?;
 
-		// Calculate the risk-adjusted expected cash flows
-		let exp = maturity_date.ensure_sub(when)?.ensure_into()?;
+		// Calculate the expected cash flows of the current debt
+		let cashflows = schedule.generate_cashflows(when, debt, debt, interest_rate)?;
 
-		// TODO: Simplify this once #1231 is merged allowing to extract only the
-		// acc_rate from InterestAccrual
-		let interest_rate_per_sec = interest_rate.per_sec()?;
-		let acc_rate = checked_pow(interest_rate_per_sec, exp).ok_or(ArithmeticError::Overflow)?;
-		let ecf = acc_rate.ensure_mul_int(debt)?;
-		let ra_ecf = tel_inv.ensure_mul_int(ecf)?;
+		let mut present_value = Balance::zero();
+		let mut last_date = when;
+		let mut rate = Rate::one();
+		for payment in cashflows {
+			// Risk-adjusted expected cash flow
+			let ecf = payment.principal.ensure_add(payment.interest)?;
+			let ra_ecf = tel_inv.ensure_mul_int(ecf)?;
 
-		// Discount the risk-adjusted expected cash flows
+			// Discount the risk-adjusted expected cash flow
+			// TODO: use InterestAccrual for this once #1231 is merged
+			// This would immply that discount_rate should be register/unregister.
+			rate = rate.ensure_mul(
+				self.discount_rate
+					.accumulated_rate(last_date, payment.when)?,
+			)?;
+			let d = Rate::one().ensure_div(rate)?;
 
-		// TODO: use InterestAccrual for this once #1231 is merged
-		// This would immply that discount_rate should be register/unregister.
-		let discount_rate_per_sec = self.discount_rate.per_sec()?;
-		let rate = checked_pow(discount_rate_per_sec, exp).ok_or(ArithmeticError::Overflow)?;
-		let d = Rate::one().ensure_div(rate)?;
+			present_value.ensure_add_assign(d.ensure_mul_int(ra_ecf)?)?;
+			last_date = payment.when;
+		}
 
-		d.ensure_mul_int(ra_ecf)
+		Ok(present_value)
 	}
 }
 
+/// Maximum number of points a yield curve can have
+pub type MaxYieldCurvePoints = ConstU32<16>;
+
+/// Point of a yield curve
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct YieldCurvePoint<PriceId> {
+	/// Time until the cash flow, from the valuation date.
+	pub tenor: Seconds,
+
+	/// Oracle key of the yearly yield for this tenor.
+	pub price_id: PriceId,
+}
+
+/// Mark-to-model values
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct MarkToModel<PriceId> {
+	/// Yield curve used to discount future cash flows, sorted by tenor.
+	pub yield_curve: BoundedVec<YieldCurvePoint<PriceId>, MaxYieldCurvePoints>,
+}
+
+impl<PriceId> MarkToModel<PriceId> {
+	pub fn is_valid(&self) -> bool {
+		!self.yield_curve.is_empty()
+			&& self
+				.yield_curve
+				.windows(2)
+				.all(|points| points[0].tenor < points[1].tenor)
+	}
+
+	pub fn price_ids(&self) -> impl Iterator<Item = &PriceId> {
+		self.yield_curve.iter().map(|point| &point.price_id)
+	}
+
+	/// Discounts each expected cash flow with the yield of the curve at its
+	/// tenor. `yield_of` gives the yearly yield of each point of the curve.
+	pub fn compute_present_value<Rate, Balance>(
+		&self,
+		debt: Balance,
+		when: Seconds,
+		interest_rate: &InterestRate<Rate>,
+		schedule: &RepaymentSchedule,
+		maturity_date: Seconds,
+		yield_of: impl Fn(&PriceId) -> Result<Rate, DispatchError>,
+	) -> Result<Balance, DispatchError>
+	where
+		Rate: FixedPointNumber,
+		Balance: tokens::Balance + FixedPointOperand,
+	{
+		// Same as discounted cash flows, overdue loans are valued by their debt
+		if when > maturity_date {
+			return Ok(debt);
+		}
+
+		let curve = self
+			.yield_curve
+			.iter()
+			.map(|point| Ok((point.tenor, yield_of(&point.price_id)?)))
+			.collect::<Result<Vec<(Seconds, Rate)>, DispatchError>>()?;
+
+		let cashflows = schedule.generate_cashflows(when, debt, debt, interest_rate)?;
+
+		let mut present_value = Balance::zero();
+		for payment in cashflows {
+			let ecf = payment.principal.ensure_add(payment.interest)?;
+
+			let discount_rate: InterestRate<Rate> = InterestRate::Fixed {
+				rate_per_year: interpolate(&curve, payment.when.ensure_sub(when)?)?,
+				compounding: CompoundingSchedule::Secondly,
+				day_count: interest_rate.day_count(),
+			};
+			let d = Rate::one().ensure_div(discount_rate.accumulated_rate(when, payment.when)?)?;
+
+			present_value.ensure_add_assign(d.ensure_mul_int(ecf)?)?;
+		}
+
+		Ok(present_value)
+	}
+}
+
+/// Linear interpolation of a sorted non-empty curve at `tenor`.
+/// Values out of the curve take the value of the nearest point.
+fn interpolate<Rate: FixedPointNumber>(
+	curve: &[(Seconds, Rate)],
+	tenor: Seconds,
+) -> Result<Rate, DispatchError> {
+	let (Some(first), Some(last)) = (curve.first(), curve.last()) else {
+		return Err(DispatchError::Other("Empty yield curve"));
+	};
+
+	if tenor <= first.0 {
+		return Ok(first.1);
+	}
+
+	let Some(points) = curve.windows(2).find(|points| tenor <= points[1].0) else {
+		return Ok(last.1);
+	};
+
+	let ((from_tenor, from_rate), (to_tenor, to_rate)) = (points[0], points[1]);
+	let fraction = Rate::checked_from_rational(
+		tenor.ensure_sub(from_tenor)?,
+		to_tenor.ensure_sub(from_tenor)?,
+	)
+	.ok_or(ArithmeticError::Overflow)?;
+
+	Ok(if to_rate >= from_rate {
+		from_rate.ensure_add(to_rate.ensure_sub(from_rate)?.ensure_mul(fraction)?)?
+	} else {
+		from_rate.ensure_sub(from_rate.ensure_sub(to_rate)?.ensure_mul(fraction)?)?
+	})
+}
+
 /// Defines the valuation method of a loan
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
-pub enum ValuationMethod<Rate> {
+pub enum ValuationMethod<Rate, PriceId> {
 	/// Dicounted cash flow valuation
 	DiscountedCashFlow(DiscountedCashFlow<Rate>),
 	/// Outstanding debt valuation
@@ -110,16 +235,74 @@ pub enum ValuationMethod<Rate> {
 	/// that the given loan is i.e. an account
 	/// holding cash
 	Cash,
+	/// Expected cash flows discounted with a yield curve from the oracle
+	MarkToModel(MarkToModel<PriceId>),
+	/// The lowest value between the outstanding debt
+	/// and the market value given by the oracle
+	LowerOfCostOrMarket { price_id: PriceId },
 }
 
-impl<Rate> ValuationMethod<Rate>
+impl<Rate, PriceId> ValuationMethod<Rate, PriceId>
 where
 	Rate: FixedPointNumber,
 {
 	pub fn is_valid(&self) -> bool {
 		match self {
 			ValuationMethod::DiscountedCashFlow(dcf) => dcf.discount_rate.per_year() <= One::one(),
-			ValuationMethod::OutstandingDebt | ValuationMethod::Cash => true,
+			ValuationMethod::MarkToModel(mtm) => mtm.is_valid(),
+			ValuationMethod::OutstandingDebt
+			| ValuationMethod::Cash
+			| ValuationMethod::LowerOfCostOrMarket { .. } => true,
+		}
+	}
+}
+
+impl<Rate, PriceId> ValuationMethod<Rate, PriceId> {
+	/// Oracle keys this valuation method reads from
+	pub fn price_ids(&self) -> Vec<&PriceId> {
+		match self {
+			ValuationMethod::MarkToModel(mtm) => mtm.price_ids().collect(),
+			ValuationMethod::LowerOfCostOrMarket { price_id } => sp_std::vec![price_id],
+			ValuationMethod::DiscountedCashFlow(_)
+			| ValuationMethod::OutstandingDebt
+			| ValuationMethod::Cash => Vec::new(),
+		}
+	}
+}
+
+/// Valuation methods before day count conventions, for migration to v5
+pub mod v4 {
+	use parity_scale_codec::{Decode, Encode};
+
+	use crate::entities::interest::v4::InterestRate;
+
+	#[derive(Encode, Decode)]
+	pub struct DiscountedCashFlow<Rate> {
+		probability_of_default: Rate,
+		loss_given_default: Rate,
+		discount_rate: InterestRate<Rate>,
+	}
+
+	#[derive(Encode, Decode)]
+	pub enum ValuationMethod<Rate> {
+		DiscountedCashFlow(DiscountedCashFlow<Rate>),
+		OutstandingDebt,
+		Cash,
+	}
+
+	impl<Rate> ValuationMethod<Rate> {
+		pub fn migrate<PriceId>(self) -> super::ValuationMethod<Rate, PriceId> {
+			match self {
+				ValuationMethod::DiscountedCashFlow(dcf) => {
+					super::ValuationMethod::DiscountedCashFlow(super::DiscountedCashFlow {
+						probability_of_default: dcf.probability_of_default,
+						loss_given_default: dcf.loss_given_default,
+						discount_rate: dcf.discount_rate.migrate(),
+					})
+				}
+				ValuationMethod::OutstandingDebt => super::ValuationMethod::OutstandingDebt,
+				ValuationMethod::Cash => super::ValuationMethod::Cash,
+			}
 		}
 	}
 }
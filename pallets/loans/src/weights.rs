@@ -28,6 +28,11 @@ pub trait WeightInfo {
 	fn propose_transfer_debt(n: u32) -> Weight;
 	fn apply_transfer_debt(n: u32) -> Weight;
 	fn increase_debt(n: u32) -> Weight;
+	fn propose_collateral_substitution(n: u32) -> Weight;
+	fn apply_collateral_substitution(n: u32) -> Weight;
+	fn propose_syndication() -> Weight;
+	fn apply_syndication() -> Weight;
+	fn participation_valuation(n: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -86,4 +91,24 @@ impl WeightInfo for () {
 	fn increase_debt(_: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn propose_collateral_substitution(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_collateral_substitution(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn propose_syndication() -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_syndication() -> Weight {
+		Weight::zero()
+	}
+
+	fn participation_valuation(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
@@ -83,7 +83,8 @@ benchmarks! {
 			PoolChanges = PoolChanges<
 				<T as pallet_pool_system::Config>::Rate,
 				<T as pallet_pool_system::Config>::StringLimit,
-				<T as pallet_pool_system::Config>::MaxTranches
+				<T as pallet_pool_system::Config>::MaxTranches,
+				<T as pallet_pool_system::Config>::Balance
 			>,
 			PoolFeeInput = (PoolFeeBucket, <<T as pallet_pool_system::Config>::PoolFees as PoolFeesBenchmarkHelper>::PoolFeeInfo),
 		>,
@@ -139,6 +140,8 @@ benchmarks! {
 			min_epoch_time: Change::NewValue(SECS_PER_DAY),
 			max_nav_age: Change::NewValue(SECS_PER_HOUR),
 			tranche_metadata: Change::NoChange,
+			tranche_limits: Change::NoChange,
+			tranche_terms: Change::NoChange,
 		};
 	}: update(RawOrigin::Signed(admin), POOL, changes.clone())
 	verify {
@@ -164,6 +167,8 @@ benchmarks! {
 			min_epoch_time: Change::NewValue(SECS_PER_DAY),
 			max_nav_age: Change::NewValue(SECS_PER_HOUR),
 			tranche_metadata: Change::NewValue(build_update_tranche_token_metadata::<T>()),
+			tranche_limits: Change::NoChange,
+			tranche_terms: Change::NoChange,
 		};
 	}: update(RawOrigin::Signed(admin), POOL, changes)
 	verify {
@@ -191,6 +196,8 @@ benchmarks! {
 			min_epoch_time: Change::NewValue(SECS_PER_DAY),
 			max_nav_age: Change::NewValue(SECS_PER_HOUR),
 			tranche_metadata: Change::NewValue(build_update_tranche_token_metadata::<T>()),
+			tranche_limits: Change::NoChange,
+			tranche_terms: Change::NoChange,
 		};
 
 		// Invest so we can redeem later
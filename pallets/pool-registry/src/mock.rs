@@ -39,9 +39,14 @@ use frame_support::{
 use frame_system::EnsureSigned;
 use orml_traits::{asset_registry::AssetMetadata, parameter_type_with_key};
 use pallet_pool_system::{
-	pool_types::{PoolChanges, PoolDetails, ScheduledUpdateDetails},
+	pool_types::{
+		changes::{Change as PoolSystemChange, PoolChangeProposal},
+		PoolChanges, PoolDetails, ScheduledUpdateDetails,
+	},
 	tranches::TrancheInput,
 };
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{ConstU128, Zero},
@@ -94,6 +99,8 @@ parameter_types! {
 
 	pub const MinUpdateDelay: u64 = 0; // for testing purposes
 	pub const ChallengeTime: BlockNumber = 0;
+	pub const EpochSolverSteps: u32 = 0;
+	pub const MaxMaturedRedemptions: u32 = 10;
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = 1;
 	pub const DefaultMaxNAVAge: u64 = 24 * 60 * 60;
@@ -142,7 +149,9 @@ impl pallet_pool_system::Config for Test {
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
 	type DefaultMinEpochTime = DefaultMinEpochTime;
 	type EpochId = PoolEpochId;
+	type EpochSolverSteps = EpochSolverSteps;
 	type Investments = Investments;
+	type MaxMaturedRedemptions = MaxMaturedRedemptions;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
@@ -159,7 +168,7 @@ impl pallet_pool_system::Config for Test {
 	type PoolFeesNAV = PoolFees;
 	type PoolId = PoolId;
 	type Rate = Rate;
-	type RuntimeChange = pallet_pool_system::pool_types::changes::PoolChangeProposal;
+	type RuntimeChange = RuntimeChange;
 	type RuntimeEvent = RuntimeEvent;
 	type StringLimit = StringLimit;
 	type Time = Timestamp;
@@ -232,6 +241,7 @@ where
 		<T as pallet_pool_system::Config>::Rate,
 		<T as pallet_pool_system::Config>::StringLimit,
 		<T as pallet_pool_system::Config>::MaxTranches,
+		<T as pallet_pool_system::Config>::Balance,
 	>;
 	type PoolFeeInput = (
 		PoolFeeBucket,
@@ -395,6 +405,28 @@ frame_support::construct_runtime!(
 	}
 );
 
+/// Pool system changes are the only changes noted in this mock.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RuntimeChange(PoolSystemChange);
+
+impl From<PoolSystemChange> for RuntimeChange {
+	fn from(change: PoolSystemChange) -> Self {
+		RuntimeChange(change)
+	}
+}
+
+impl From<RuntimeChange> for PoolSystemChange {
+	fn from(change: RuntimeChange) -> Self {
+		change.0
+	}
+}
+
+impl From<RuntimeChange> for PoolChangeProposal {
+	fn from(_: RuntimeChange) -> Self {
+		PoolChangeProposal::new([])
+	}
+}
+
 pub struct PoolCurrency;
 impl Contains<CurrencyId> for PoolCurrency {
 	fn contains(id: &CurrencyId) -> bool {
@@ -419,7 +451,7 @@ impl PoolUpdateGuard for UpdateGuard {
 		u64,
 		MaxTranches,
 	>;
-	type ScheduledUpdateDetails = ScheduledUpdateDetails<Rate, StringLimit, MaxTranches>;
+	type ScheduledUpdateDetails = ScheduledUpdateDetails<Rate, StringLimit, MaxTranches, Balance>;
 
 	fn released(
 		pool: &Self::PoolDetails,
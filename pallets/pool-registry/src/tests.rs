@@ -44,6 +44,8 @@ fn update_pool() {
 				tranche_metadata: Change::NoChange,
 				min_epoch_time: Change::NewValue(10),
 				max_nav_age: Change::NoChange,
+				tranche_limits: Change::NoChange,
+				tranche_terms: Change::NoChange,
 			};
 
 			assert_ok!(PoolRegistry::update(
@@ -197,6 +197,106 @@ benchmarks! {
 		assert_eq!(get_pool::<T>().epoch.current, 2);
 		assert!(Pallet::<T>::epoch_targets(POOL).is_none());
 	}
+
+	solve_epoch {
+		let n in 1..T::MaxTranches::get(); // number of tranches
+
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(n, 0, admin.clone())?;
+		T::AssetsUnderManagementNAV::initialise(RawOrigin::Signed(admin.clone()).into(), POOL, 0.into())?;
+		unrestrict_epoch_close::<T>();
+
+		// Orders above the max reserve in every tranche make the solver bisect
+		// the fulfillment of each of them
+		let investment = MAX_RESERVE * 2;
+		for index in 0..n {
+			let tranche: TrancheIndex = index.into();
+			let investor = create_investor::<T>(index, tranche, None)?;
+			let origin = RawOrigin::Signed(investor.clone()).into();
+			pallet_investments::Pallet::<T>::update_invest_order(origin, (POOL, get_tranche_id::<T>(tranche)), investment)?;
+		}
+
+		let admin_origin = RawOrigin::Signed(admin.clone()).into();
+		Pallet::<T>::close_epoch(admin_origin, POOL)?;
+		let pool = get_pool::<T>();
+		let epoch = EpochExecution::<T>::get(POOL).unwrap();
+	}: {
+		Pallet::<T>::solve_epoch(&pool, &epoch)?;
+	}
+	verify {
+		assert_eq!(get_pool::<T>().epoch.last_executed, 0);
+	}
+
+	propose_status_change {
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+	}: propose_status_change(RawOrigin::Signed(admin), POOL, PoolStatus::Paused)
+	verify {
+		assert!(NotedChange::<T>::iter_prefix(POOL).next().is_some());
+	}
+
+	apply_status_change {
+		let n in 1..T::MaxTranches::get(); // number of tranches
+
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(n, 0, admin.clone())?;
+		T::AssetsUnderManagementNAV::initialise(RawOrigin::Signed(admin.clone()).into(), POOL, 0.into())?;
+
+		// Closing a pool is the most expensive status change
+		Pool::<T>::mutate(POOL, |pool| pool.as_mut().unwrap().status = PoolStatus::WindDown);
+		Pallet::<T>::propose_status_change(RawOrigin::Signed(admin.clone()).into(), POOL, PoolStatus::Closed)?;
+		let (change_id, _) = NotedChange::<T>::iter_prefix(POOL).next().unwrap();
+
+		// Closing requires an epoch to be closed after the proposal
+		Pool::<T>::mutate(POOL, |pool| {
+			pool.as_mut().unwrap().epoch.last_closed = T::Time::now().saturating_add(1)
+		});
+	}: apply_status_change(RawOrigin::Signed(admin), POOL, change_id)
+	verify {
+		assert_eq!(get_pool::<T>().status, PoolStatus::Closed);
+	}
+
+	redeem_matured {
+		let n in 1..T::MaxMaturedRedemptions::get(); // number of investors
+
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+		let tranche_id = get_tranche_id::<T>(TRANCHE);
+		TrancheTerms::<T>::insert(POOL, tranche_id, tranches::TrancheTerms {
+			lock_up: None,
+			maturity: Some(T::Time::now()),
+		});
+
+		let investors = (0..n)
+			.map(|id| create_investor::<T>(id, TRANCHE, Some(MINT_AMOUNT)))
+			.collect::<Result<Vec<_>, _>>()?;
+	}: redeem_matured(RawOrigin::Signed(admin), POOL, tranche_id, BoundedVec::truncate_from(investors))
+	verify {
+		let investor = account::<T::AccountId>("investor", n - 1, 0);
+		assert_eq!(T::Tokens::balance(CurrencyId::Tranche(POOL, tranche_id), &investor), 0);
+	}
+
+	allocate_losses {
+		let n in 1..T::MaxTranches::get(); // number of tranches
+
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(n, 0, admin.clone())?;
+		T::AssetsUnderManagementNAV::initialise(RawOrigin::Signed(admin.clone()).into(), POOL, 0.into())?;
+		unrestrict_epoch_close::<T>();
+
+		// Issued tranche tokens make every tranche absorb its share of the losses
+		for index in 0..n {
+			create_investor::<T>(index, index.into(), Some(MINT_AMOUNT))?;
+		}
+	}: allocate_losses(RawOrigin::Signed(admin), POOL)
+	verify {
+		assert!(TrancheLosses::<T>::get(POOL, get_tranche_id::<T>(TRANCHE)).price.is_some());
+	}
 }
 
 pub fn prepare_asset_registry<T: Config>()
@@ -325,13 +425,13 @@ where
 }
 
 pub fn update_pool<T: Config<PoolId = u64>>(
-	changes: PoolChanges<T::Rate, T::StringLimit, T::MaxTranches>,
+	changes: PoolChanges<T::Rate, T::StringLimit, T::MaxTranches, T::Balance>,
 ) -> Result<UpdateState, DispatchError> {
 	Pallet::<T>::update(POOL, changes)
 }
 
 pub fn get_scheduled_update<T: Config<PoolId = u64>>(
-) -> ScheduledUpdateDetails<T::Rate, T::StringLimit, T::MaxTranches> {
+) -> ScheduledUpdateDetails<T::Rate, T::StringLimit, T::MaxTranches, T::Balance> {
 	Pallet::<T>::scheduled_update(POOL).unwrap()
 }
 
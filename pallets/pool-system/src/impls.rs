@@ -231,6 +231,8 @@ impl<T: Config> PoolMutate<T::AccountId, T::PoolId> for Pallet<T> {
 		if changes.min_epoch_time == Change::NoChange
 			&& changes.max_nav_age == Change::NoChange
 			&& changes.tranches == Change::NoChange
+			&& changes.tranche_limits == Change::NoChange
+			&& changes.tranche_terms == Change::NoChange
 		{
 			// If there's an existing update, we remove it
 			// If not, this transaction is a no-op
@@ -262,8 +264,27 @@ impl<T: Config> PoolMutate<T::AccountId, T::PoolId> for Pallet<T> {
 			Self::is_valid_tranche_change(Some(&pool.tranches), tranches)?;
 		}
 
+		let num_tranches = match &changes.tranches {
+			Change::NewValue(tranches) => tranches.len(),
+			Change::NoChange => pool.tranches.num_tranches(),
+		};
+
+		if let Change::NewValue(limits) = &changes.tranche_limits {
+			ensure!(
+				limits.len() == num_tranches,
+				Error::<T>::InvalidTrancheLimits
+			);
+		}
+
 		let now = T::Time::now();
 
+		if let Change::NewValue(terms) = &changes.tranche_terms {
+			ensure!(
+				terms.len() == num_tranches && terms.iter().all(|terms| !terms.is_matured(now)),
+				Error::<T>::InvalidTrancheTerms
+			);
+		}
+
 		let update = ScheduledUpdateDetails {
 			changes: changes.clone(),
 			submitted_at: now,
@@ -388,6 +409,58 @@ impl<T: Config> InvestmentAccountant<T::AccountId> for Pallet<T> {
 		)
 		.map(|_| ())
 	}
+
+	fn ensure_investments_allowed(id: Self::InvestmentId) -> Result<(), Self::Error> {
+		let details = Pool::<T>::get(id.of_pool()).ok_or(Error::<T>::NoSuchPool)?;
+		ensure!(
+			details.status.allows_investments(),
+			Error::<T>::InvestmentsNotAllowed
+		);
+
+		ensure!(
+			!TrancheTerms::<T>::get(id.of_pool(), id.of_tranche()).is_matured(T::Time::now()),
+			Error::<T>::TrancheMatured
+		);
+
+		Ok(())
+	}
+
+	fn ensure_redemptions_allowed(
+		id: Self::InvestmentId,
+		who: &T::AccountId,
+	) -> Result<(), Self::Error> {
+		let details = Pool::<T>::get(id.of_pool()).ok_or(Error::<T>::NoSuchPool)?;
+		ensure!(
+			details.status.allows_redemptions(),
+			Error::<T>::RedemptionsNotAllowed
+		);
+
+		Self::ensure_unlocked(id, who)
+	}
+
+	fn note_deposit(id: Self::InvestmentId, who: &T::AccountId) -> Result<(), Self::Error> {
+		if TrancheTerms::<T>::get(id.of_pool(), id.of_tranche())
+			.lock_up
+			.is_some()
+		{
+			LastDeposit::<T>::insert(id, who, T::Time::now());
+		}
+
+		Ok(())
+	}
+
+	fn ensure_min_investment(
+		id: Self::InvestmentId,
+		amount: Self::Amount,
+	) -> Result<(), Self::Error> {
+		if let Some(min_investment) =
+			TrancheLimits::<T>::get(id.of_pool(), id.of_tranche()).min_investment
+		{
+			ensure!(amount >= min_investment, Error::<T>::InvestmentBelowMinimum);
+		}
+
+		Ok(())
+	}
 }
 
 impl<T: Config> ChangeGuard for Pallet<T> {
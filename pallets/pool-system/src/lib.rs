@@ -36,8 +36,9 @@ use orml_traits::{
 pub use pallet::*;
 use parity_scale_codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use pool_types::{
-	changes::{NotedPoolChange, PoolChangeProposal},
-	PoolChanges, PoolDepositInfo, PoolDetails, PoolEssence, PoolLocator, ScheduledUpdateDetails,
+	changes::{Change as PoolSystemChange, NotedPoolChange, PoolChangeProposal},
+	PoolChanges, PoolDepositInfo, PoolDetails, PoolEssence, PoolLocator, PoolStatus,
+	ScheduledUpdateDetails,
 };
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
@@ -54,8 +55,8 @@ use sp_runtime::{
 };
 use sp_std::{cmp::Ordering, vec::Vec};
 use tranches::{
-	EpochExecutionTranche, EpochExecutionTranches, Tranche, TrancheSolution, TrancheType,
-	TrancheUpdate, Tranches,
+	EpochExecutionTranche, EpochExecutionTranches, Tranche, TrancheLoc, TrancheSolution,
+	TrancheType, TrancheUpdate, Tranches,
 };
 pub use weights::*;
 
@@ -63,10 +64,12 @@ pub use weights::*;
 pub mod benchmarking;
 mod impls;
 
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 pub mod pool_types;
 mod solution;
+mod solver;
 #[cfg(test)]
 mod tests;
 pub mod tranches;
@@ -143,10 +146,22 @@ type ScheduledUpdateDetailsOf<T> = ScheduledUpdateDetails<
 	<T as Config>::Rate,
 	<T as Config>::StringLimit,
 	<T as Config>::MaxTranches,
+	<T as Config>::Balance,
+>;
+
+pub type PoolChangesOf<T> = PoolChanges<
+	<T as Config>::Rate,
+	<T as Config>::StringLimit,
+	<T as Config>::MaxTranches,
+	<T as Config>::Balance,
 >;
 
-pub type PoolChangesOf<T> =
-	PoolChanges<<T as Config>::Rate, <T as Config>::StringLimit, <T as Config>::MaxTranches>;
+/// Type alias for `struct TrancheLimits`
+pub type TrancheLimitsOf<T> = tranches::TrancheLimits<<T as Config>::Balance>;
+
+/// Type alias for `struct TrancheLosses`
+pub type TrancheLossesOf<T> =
+	tranches::TrancheLosses<<T as Config>::Balance, <T as Config>::BalanceRatio>;
 
 pub type PoolEssenceOf<T> = PoolEssence<
 	<T as Config>::CurrencyId,
@@ -172,8 +187,9 @@ impl Default for Release {
 #[frame_support::pallet]
 pub mod pallet {
 	use cfg_traits::{
+		changes::ChangeGuard,
 		fee::{PoolFeeBucket, PoolFeesInspect, PoolFeesMutate},
-		investments::{OrderManager, TrancheCurrency as TrancheCurrencyT},
+		investments::{Investment, OrderManager, TrancheCurrency as TrancheCurrencyT},
 		EpochTransitionHook, PoolUpdateGuard,
 	};
 	use cfg_types::{
@@ -270,7 +286,13 @@ pub mod pallet {
 
 		type CurrencyId: Parameter + Copy + MaxEncodedLen;
 
-		type RuntimeChange: Parameter + Member + MaxEncodedLen + TypeInfo + Into<PoolChangeProposal>;
+		type RuntimeChange: Parameter
+			+ Member
+			+ MaxEncodedLen
+			+ TypeInfo
+			+ Into<PoolChangeProposal>
+			+ From<PoolSystemChange>
+			+ TryInto<PoolSystemChange>;
 
 		type PoolCurrency: Contains<Self::CurrencyId>;
 
@@ -314,11 +336,17 @@ pub mod pallet {
 			+ TypeInfo;
 
 		type Investments: OrderManager<
-			Error = DispatchError,
-			InvestmentId = Self::TrancheCurrency,
-			Orders = TotalOrder<Self::Balance>,
-			Fulfillment = FulfillmentWithPrice<Self::BalanceRatio>,
-		>;
+				Error = DispatchError,
+				InvestmentId = Self::TrancheCurrency,
+				Orders = TotalOrder<Self::Balance>,
+				Fulfillment = FulfillmentWithPrice<Self::BalanceRatio>,
+			> + Investment<
+				Self::AccountId,
+				Amount = Self::Balance,
+				TrancheAmount = Self::Balance,
+				Error = DispatchError,
+				InvestmentId = Self::TrancheCurrency,
+			>;
 
 		type Time: TimeAsSecs;
 
@@ -344,6 +372,16 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChallengeTime: Get<BlockNumberFor<Self>>;
 
+		/// Number of bisection steps the on-chain epoch solver takes per
+		/// order. Zero disables the solver.
+		#[pallet::constant]
+		type EpochSolverSteps: Get<u32>;
+
+		/// Max number of investors whose tranche tokens can be put up for
+		/// redemption at once when a tranche has matured
+		#[pallet::constant]
+		type MaxMaturedRedemptions: Get<u32>;
+
 		/// Pool parameter defaults
 		#[pallet::constant]
 		type DefaultMinEpochTime: Get<Seconds>;
@@ -383,7 +421,7 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -422,6 +460,56 @@ pub mod pallet {
 		NotedPoolChange<T::RuntimeChange>,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn tranche_limits)]
+	pub type TrancheLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		TrancheLimitsOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tranche_terms)]
+	pub type TrancheTerms<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		tranches::TrancheTerms,
+		ValueQuery,
+	>;
+
+	/// The moment of the latest deposit of an investor into a tranche with
+	/// a lock-up period.
+	#[pallet::storage]
+	pub type LastDeposit<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::TrancheCurrency,
+		Blake2_128Concat,
+		T::AccountId,
+		Seconds,
+	>;
+
+	/// The losses absorbed by each tranche, allocated whenever an epoch is
+	/// closed.
+	#[pallet::storage]
+	#[pallet::getter(fn tranche_losses)]
+	pub type TrancheLosses<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		TrancheLossesOf<T>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -478,6 +566,39 @@ pub mod pallet {
 			nav_fees: T::Balance,
 			reserve: T::Balance,
 		},
+		/// The status of a pool was updated.
+		StatusUpdated {
+			pool_id: T::PoolId,
+			status: PoolStatus,
+		},
+		/// The tranche tokens of an investor in a matured tranche were put
+		/// up for redemption.
+		MaturedRedemptionOrdered {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			investor: T::AccountId,
+			amount: T::Balance,
+		},
+		/// A loss of the pool was allocated to a tranche.
+		LossAllocated {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			amount: T::Balance,
+			outstanding: T::Balance,
+		},
+		/// A tranche recovered from a previously allocated loss.
+		LossRecovered {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			amount: T::Balance,
+			outstanding: T::Balance,
+		},
+		/// A tranche senior to the residual tranche started absorbing
+		/// losses.
+		TrancheImpaired {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+		},
 	}
 
 	#[pallet::error]
@@ -556,6 +677,44 @@ pub mod pallet {
 		ChangeNotFound,
 		/// The external change was found for is not ready yet to be released.
 		ChangeNotReady,
+		/// The released change is not a pool system change.
+		ChangeNotPoolSystem,
+		/// The pool can not change from its current status to the requested
+		/// one
+		InvalidStatusTransition,
+		/// The pool can not be closed while tranche tokens are issued
+		TranchesNotRedeemed,
+		/// The pool can not be closed while its reserve is not empty
+		ReserveNotEmpty,
+		/// The pool can not be closed while its assets are not repaid
+		AssetsNotRepaid,
+		/// The pool can not be closed while invest or redeem orders are
+		/// outstanding
+		OrdersOutstanding,
+		/// The status of the pool does not allow new investments
+		InvestmentsNotAllowed,
+		/// The status of the pool does not allow new redemptions
+		RedemptionsNotAllowed,
+		/// The status of the pool does not allow borrowing from the reserve
+		BorrowsNotAllowed,
+		/// The status of the pool does not allow closing epochs
+		EpochClosingNotAllowed,
+		/// The tranche limits do not match the tranches of the pool
+		InvalidTrancheLimits,
+		/// The solution would fulfill investments beyond the limits of a
+		/// tranche
+		TrancheLimitExceeded,
+		/// The invest order is below the minimum investment of the tranche
+		InvestmentBelowMinimum,
+		/// The tranche terms do not match the tranches of the pool or have a
+		/// maturity in the past
+		InvalidTrancheTerms,
+		/// The investor's tranche tokens are still locked up
+		TokensLocked,
+		/// The tranche has matured and accepts no further investments
+		TrancheMatured,
+		/// The tranche has not matured yet
+		TrancheNotMatured,
 	}
 
 	#[pallet::call]
@@ -610,9 +769,25 @@ pub mod pallet {
 		/// submission period, partial executions can be submitted
 		/// to be scored, and the best-scoring solution will
 		/// eventually be executed. See `submit_solution`.
+		///
+		/// When entering the submission period, the on-chain solver
+		/// computes a healthy partial execution. If it finds one, the
+		/// solution is submitted right away and the challenge period
+		/// starts. See `solve_epoch`.
+		///
+		/// The losses of the pool are allocated to its tranches on
+		/// every close, see `TrancheLosses`. Closing an epoch which would
+		/// wipe out a tranche fails, the losses leading to it can still be
+		/// allocated with `allocate_losses`.
 		#[pallet::weight(T::WeightInfo::close_epoch_no_orders(T::MaxTranches::get(), T::PoolFees::get_max_fees_per_bucket())
-                             .max(T::WeightInfo::close_epoch_no_execution(T::MaxTranches::get(), T::PoolFees::get_max_fees_per_bucket()))
-                             .max(T::WeightInfo::close_epoch_execute(T::MaxTranches::get(), T::PoolFees::get_max_fees_per_bucket())))]
+                             .max(T::WeightInfo::close_epoch_no_execution(T::MaxTranches::get(), T::PoolFees::get_max_fees_per_bucket())
+                                 .saturating_add(T::WeightInfo::solve_epoch(T::MaxTranches::get()))
+                                 .saturating_add(T::WeightInfo::submit_solution(T::MaxTranches::get(), T::PoolFees::get_max_fees_per_bucket())))
+                             .max(T::WeightInfo::close_epoch_execute(T::MaxTranches::get(), T::PoolFees::get_max_fees_per_bucket()))
+                             .saturating_add(T::DbWeight::get().reads_writes(
+                                 T::MaxTranches::get().saturating_mul(3).into(),
+                                 T::MaxTranches::get().into(),
+                             )))]
 		#[transactional]
 		#[pallet::call_index(1)]
 		pub fn close_epoch(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResultWithPostInfo {
@@ -620,6 +795,10 @@ pub mod pallet {
 
 			Pool::<T>::try_mutate(pool_id, |pool| {
 				let pool = pool.as_mut().ok_or(Error::<T>::NoSuchPool)?;
+				ensure!(
+					pool.status.allows_epoch_closing(),
+					Error::<T>::EpochClosingNotAllowed
+				);
 				ensure!(
 					!EpochExecution::<T>::contains_key(pool_id),
 					Error::<T>::InSubmissionPeriod
@@ -684,13 +863,37 @@ pub mod pallet {
 					Error::<T>::WipedOut
 				);
 
+				let losses = Self::calculate_losses(
+					pool_id,
+					&pool.tranches,
+					nav_total,
+					&epoch_tranche_prices,
+				)?;
+				Self::store_losses(pool_id, losses)?;
+
 				Self::deposit_event(Event::EpochClosed {
 					pool_id,
 					epoch_id: submission_period_epoch,
 				});
 
+				// Matured tranches are redeemed with priority and take no investments
+				let matured: Vec<bool> = pool
+					.tranches
+					.residual_top_slice()
+					.iter()
+					.map(|tranche| {
+						TrancheTerms::<T>::get(pool_id, tranche.currency.of_tranche())
+							.is_matured(now)
+					})
+					.collect();
+
 				// Get the orders
-				let orders = Self::summarize_orders(&pool.tranches, &epoch_tranche_prices)?;
+				let orders = Self::summarize_orders(
+					&pool.tranches,
+					&epoch_tranche_prices,
+					&matured,
+					pool.status.allows_investments(),
+				)?;
 				if orders.all_are_zero() {
 					T::OnEpochTransition::on_execution_pre_fulfillments(pool_id)?;
 
@@ -713,22 +916,33 @@ pub mod pallet {
 						epoch_id: submission_period_epoch,
 					});
 
-					return Ok(Some(T::WeightInfo::close_epoch_no_orders(
-						pool.tranches
-							.num_tranches()
-							.try_into()
-							.expect("MaxTranches is u32. qed."),
-						T::PoolFees::get_pool_fee_bucket_count(pool_id, PoolFeeBucket::Top),
-					))
+					let num_tranches = pool
+						.tranches
+						.num_tranches()
+						.try_into()
+						.expect("MaxTranches is u32. qed.");
+					return Ok(Some(
+						T::WeightInfo::close_epoch_no_orders(
+							num_tranches,
+							T::PoolFees::get_pool_fee_bucket_count(pool_id, PoolFeeBucket::Top),
+						)
+						.saturating_add(Self::losses_weight(num_tranches)),
+					)
 					.into());
 				}
 
+				let num_tranches: u32 = pool
+					.tranches
+					.num_tranches()
+					.try_into()
+					.expect("MaxTranches is u32. qed.");
 				let epoch_tranches: Vec<EpochExecutionTrancheOf<T>> =
 					pool.tranches.combine_with_residual_top(
 						epoch_tranche_prices
 							.iter()
+							.zip(&matured)
 							.zip(orders.invest_redeem_residual_top()),
-						|tranche, (price, (invest, redeem))| {
+						|tranche, ((price, matured), (invest, redeem))| {
 							let epoch_tranche = EpochExecutionTranche {
 								currency: tranche.currency,
 								supply: tranche.balance()?,
@@ -736,6 +950,7 @@ pub mod pallet {
 								invest,
 								redeem,
 								seniority: tranche.seniority,
+								matured: *matured,
 								min_risk_buffer: tranche.min_risk_buffer(),
 								_phantom: Default::default(),
 							};
@@ -768,13 +983,13 @@ pub mod pallet {
 						pool_id,
 						epoch_id: submission_period_epoch,
 					});
-					Ok(Some(T::WeightInfo::close_epoch_execute(
-						pool.tranches
-							.num_tranches()
-							.try_into()
-							.expect("MaxTranches is u32. qed."),
-						T::PoolFees::get_pool_fee_bucket_count(pool_id, PoolFeeBucket::Top),
-					))
+					Ok(Some(
+						T::WeightInfo::close_epoch_execute(
+							num_tranches,
+							T::PoolFees::get_pool_fee_bucket_count(pool_id, PoolFeeBucket::Top),
+						)
+						.saturating_add(Self::losses_weight(num_tranches)),
+					)
 					.into())
 				} else {
 					// Any new submission needs to improve on the existing state (which is defined
@@ -788,17 +1003,38 @@ pub mod pallet {
 
 					let existing_state_solution =
 						Self::score_solution(pool, &epoch, &no_execution_solution)?;
-					epoch.best_submission = Some(existing_state_solution);
+
+					let num_fees =
+						T::PoolFees::get_pool_fee_bucket_count(pool_id, PoolFeeBucket::Top);
+					let mut weight =
+						T::WeightInfo::close_epoch_no_execution(num_tranches, num_fees)
+							.saturating_add(T::WeightInfo::solve_epoch(num_tranches))
+							.saturating_add(Self::losses_weight(num_tranches));
+
+					// A failing solver must not prevent the epoch from closing
+					match Self::solve_epoch(pool, &epoch).ok().flatten() {
+						Some(solution) if solution > existing_state_solution => {
+							epoch.best_submission = Some(solution.clone());
+							epoch.challenge_period_end =
+								Some(Self::current_block().saturating_add(T::ChallengeTime::get()));
+
+							Self::deposit_event(Event::SolutionSubmitted {
+								pool_id,
+								epoch_id: submission_period_epoch,
+								solution,
+							});
+
+							weight = weight.saturating_add(T::WeightInfo::submit_solution(
+								num_tranches,
+								num_fees,
+							));
+						}
+						_ => epoch.best_submission = Some(existing_state_solution),
+					}
+
 					EpochExecution::<T>::insert(pool_id, epoch);
 
-					Ok(Some(T::WeightInfo::close_epoch_no_execution(
-						pool.tranches
-							.num_tranches()
-							.try_into()
-							.expect("MaxTranches is u32. qed."),
-						T::PoolFees::get_pool_fee_bucket_count(pool_id, PoolFeeBucket::Top),
-					))
-					.into())
+					Ok(Some(weight).into())
 				}
 			})
 		}
@@ -944,6 +1180,172 @@ pub mod pallet {
 				.into())
 			})
 		}
+
+		/// Propose to move a pool to a new status.
+		///
+		/// The change is noted in the change guard and can be applied
+		/// with `apply_status_change` once released.
+		///
+		/// The caller must have the `PoolAdmin` role.
+		#[pallet::weight(T::WeightInfo::propose_status_change())]
+		#[pallet::call_index(4)]
+		pub fn propose_status_change(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			status: PoolStatus,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who,
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				BadOrigin
+			);
+
+			let pool = Pool::<T>::get(pool_id).ok_or(Error::<T>::NoSuchPool)?;
+			ensure!(
+				pool.status.can_transition_to(&status),
+				Error::<T>::InvalidStatusTransition
+			);
+
+			<Self as ChangeGuard>::note(pool_id, PoolSystemChange::Status(status).into())?;
+
+			Ok(())
+		}
+
+		/// Apply a status change released by the change guard.
+		///
+		/// Closing a pool requires all of its tranche tokens to be
+		/// redeemed. Once closed, the pool deposit is returned and its
+		/// scheduled update is removed.
+		#[pallet::weight(T::WeightInfo::apply_status_change(T::MaxTranches::get()))]
+		#[pallet::call_index(5)]
+		pub fn apply_status_change(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let PoolSystemChange::Status(status) =
+				<Self as ChangeGuard>::released(pool_id, change_id)?
+					.try_into()
+					.map_err(|_| Error::<T>::ChangeNotPoolSystem)?;
+
+			Pool::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::NoSuchPool)?;
+				ensure!(
+					pool.status.can_transition_to(&status),
+					Error::<T>::InvalidStatusTransition
+				);
+
+				if status == PoolStatus::Closed {
+					Self::do_close_pool(pool_id, pool)?;
+				}
+
+				pool.status = status;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::StatusUpdated { pool_id, status });
+
+			Ok(())
+		}
+
+		/// Put all tranche tokens of the given investors in a matured
+		/// tranche up for redemption.
+		///
+		/// Can be called by anyone once the tranche has reached its
+		/// maturity. Investors without tranche tokens are skipped. The
+		/// redemptions of matured tranches are fulfilled with priority when
+		/// executing an epoch.
+		#[pallet::weight(T::WeightInfo::redeem_matured(
+			investors.len().try_into().unwrap_or(u32::MAX)
+		))]
+		#[pallet::call_index(6)]
+		pub fn redeem_matured(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			investors: BoundedVec<T::AccountId, T::MaxMaturedRedemptions>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let pool = Pool::<T>::get(pool_id).ok_or(Error::<T>::NoSuchPool)?;
+			let tranche_currency = pool
+				.tranches
+				.tranche_currency(TrancheLoc::Id(tranche_id))
+				.ok_or(Error::<T>::InvalidTrancheId)?;
+
+			ensure!(
+				TrancheTerms::<T>::get(pool_id, tranche_id).is_matured(T::Time::now()),
+				Error::<T>::TrancheNotMatured
+			);
+
+			for investor in investors {
+				let amount = T::Tokens::balance(tranche_currency.into(), &investor);
+				if amount.is_zero() {
+					continue;
+				}
+
+				let redemption = T::Investments::redemption(&investor, tranche_currency)?;
+				T::Investments::update_redemption(
+					&investor,
+					tranche_currency,
+					redemption.ensure_add(amount)?,
+				)?;
+
+				Self::deposit_event(Event::MaturedRedemptionOrdered {
+					pool_id,
+					tranche_id,
+					investor,
+					amount,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Allocate the current losses of a pool to its tranches without
+		/// closing an epoch.
+		///
+		/// Can be called by anyone. Allows recording the losses of a pool
+		/// whose epoch can not be closed because it would wipe out a
+		/// tranche. See `TrancheLosses`.
+		#[pallet::weight(T::WeightInfo::allocate_losses(T::MaxTranches::get()))]
+		#[pallet::call_index(7)]
+		pub fn allocate_losses(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut pool = Pool::<T>::get(pool_id).ok_or(Error::<T>::NoSuchPool)?;
+			let now = T::Time::now();
+
+			let (nav_aum, aum_last_updated) =
+				T::AssetsUnderManagementNAV::nav(pool_id).ok_or(Error::<T>::NoNAV)?;
+			ensure!(
+				now.saturating_sub(aum_last_updated) <= pool.parameters.max_nav_age,
+				Error::<T>::NAVTooOld
+			);
+			let (nav_fees, fees_last_updated) =
+				T::PoolFeesNAV::nav(pool_id).ok_or(Error::<T>::NoNAV)?;
+			ensure!(
+				now.saturating_sub(fees_last_updated) <= pool.parameters.max_nav_age,
+				Error::<T>::NAVTooOld
+			);
+			let nav_total = Nav::new(nav_aum, nav_fees)
+				.total(pool.reserve.total)
+				.unwrap_or(T::Balance::default());
+
+			let prices = pool
+				.tranches
+				.calculate_prices::<T::BalanceRatio, T::Tokens, _>(nav_total, now)?;
+			let losses = Self::calculate_losses(pool_id, &pool.tranches, nav_total, &prices)?;
+
+			Self::store_losses(pool_id, losses)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -951,29 +1353,177 @@ pub mod pallet {
 			<frame_system::Pallet<T>>::block_number()
 		}
 
+		/// Ensures that the tranche tokens of `who` are not locked up anymore.
+		///
+		/// Tokens are locked up for the lock-up period of the tranche after
+		/// the latest deposit of `who`, and can neither be redeemed nor
+		/// transferred in the meantime. The lock-up ends with the maturity of
+		/// the tranche.
+		pub fn ensure_unlocked(id: T::TrancheCurrency, who: &T::AccountId) -> DispatchResult {
+			let now = T::Time::now();
+			let terms = TrancheTerms::<T>::get(id.of_pool(), id.of_tranche());
+			if let (Some(lock_up), false) = (terms.lock_up, terms.is_matured(now)) {
+				if let Some(deposited_at) = LastDeposit::<T>::get(id, who) {
+					ensure!(
+						now >= deposited_at.saturating_add(lock_up),
+						Error::<T>::TokensLocked
+					);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Allocates the losses of a pool to its tranches, following the
+		/// waterfall from the most senior to the residual tranche.
+		///
+		/// A non-residual tranche has a claim on its balance, and absorbs
+		/// the part of it which is not covered by the assets left after
+		/// paying out all tranches senior to it. The residual tranche has
+		/// no such claim, as it owns whatever is left. It absorbs any decline
+		/// of the value of its tokens since the latest allocation instead,
+		/// and recovers with any rise until its outstanding loss is
+		/// recovered. Both measures are amounts of the pool currency, see
+		/// `TrancheLosses`.
+		///
+		/// Returns the updated ledger of each tranche, ordered from
+		/// residual to top tranche.
+		fn calculate_losses(
+			pool_id: T::PoolId,
+			tranches: &TranchesOf<T>,
+			total_assets: T::Balance,
+			prices: &[T::BalanceRatio],
+		) -> Result<Vec<(T::TrancheId, TrancheLossesOf<T>)>, DispatchError> {
+			let mut remaining_assets = total_assets;
+			let mut ledger = tranches
+				.residual_top_slice()
+				.iter()
+				.zip(prices)
+				.rev()
+				.map(|(tranche, price)| {
+					let tranche_id = tranche.currency.of_tranche();
+					let mut losses = TrancheLosses::<T>::get(pool_id, tranche_id);
+					let total_issuance = T::Tokens::total_issuance(tranche.currency.into());
+
+					let outstanding = if total_issuance.is_zero() {
+						Zero::zero()
+					} else if tranche.tranche_type == TrancheType::Residual {
+						match losses.price {
+							Some(last_price) if *price < last_price => {
+								losses.outstanding.ensure_add(
+									last_price
+										.ensure_sub(*price)?
+										.ensure_mul_int(total_issuance)?,
+								)?
+							}
+							Some(last_price) => losses.outstanding.saturating_sub(
+								price
+									.ensure_sub(last_price)?
+									.ensure_mul_int(total_issuance)?,
+							),
+							None => losses.outstanding,
+						}
+					} else {
+						let balance = tranche.balance()?;
+						let covered = balance.min(remaining_assets);
+						remaining_assets = remaining_assets.ensure_sub(covered)?;
+						balance.ensure_sub(covered)?
+					};
+
+					losses.update(outstanding)?;
+					losses.price = Some(*price);
+
+					Ok((tranche_id, losses))
+				})
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			ledger.reverse();
+			Ok(ledger)
+		}
+
+		/// Writes the ledger computed by `calculate_losses` and emits an
+		/// event for each allocated or recovered loss.
+		fn store_losses(
+			pool_id: T::PoolId,
+			ledger: Vec<(T::TrancheId, TrancheLossesOf<T>)>,
+		) -> DispatchResult {
+			for (index, (tranche_id, losses)) in ledger.into_iter().enumerate() {
+				let previous = TrancheLosses::<T>::get(pool_id, tranche_id).outstanding;
+
+				if losses.outstanding > previous {
+					Self::deposit_event(Event::LossAllocated {
+						pool_id,
+						tranche_id,
+						amount: losses.outstanding.ensure_sub(previous)?,
+						outstanding: losses.outstanding,
+					});
+
+					// Only the first tranche is the residual one
+					if index > 0 && previous.is_zero() {
+						Self::deposit_event(Event::TrancheImpaired {
+							pool_id,
+							tranche_id,
+						});
+					}
+				} else if losses.outstanding < previous {
+					Self::deposit_event(Event::LossRecovered {
+						pool_id,
+						tranche_id,
+						amount: previous.ensure_sub(losses.outstanding)?,
+						outstanding: losses.outstanding,
+					});
+				}
+
+				TrancheLosses::<T>::insert(pool_id, tranche_id, losses);
+			}
+
+			Ok(())
+		}
+
+		/// Weight of the storage accesses of `calculate_losses` and
+		/// `store_losses` for a pool with `num_tranches` tranches.
+		fn losses_weight(num_tranches: u32) -> Weight {
+			T::DbWeight::get()
+				.reads_writes(num_tranches.saturating_mul(3).into(), num_tranches.into())
+		}
+
+		/// Summarizes the orders of all tranches. Invest orders are not
+		/// executed when the pool does not allow investments or the tranche
+		/// has matured, and count as zero.
 		fn summarize_orders(
 			tranches: &TranchesOf<T>,
 			prices: &[T::BalanceRatio],
+			matured: &[bool],
+			allows_investments: bool,
 		) -> Result<SummarizedOrders<T::Balance>, DispatchError> {
 			let mut acc_invest_orders = T::Balance::zero();
 			let mut acc_redeem_orders = T::Balance::zero();
 			let mut invest_orders = Vec::with_capacity(tranches.num_tranches());
 			let mut redeem_orders = Vec::with_capacity(tranches.num_tranches());
 
-			tranches.combine_with_residual_top(prices, |tranche, price| {
-				let invest_order = T::Investments::process_invest_orders(tranche.currency)?;
-				acc_invest_orders.ensure_add_assign(invest_order.amount)?;
-				invest_orders.push(invest_order.amount);
+			tranches.combine_with_residual_top(
+				prices.iter().zip(matured),
+				|tranche, (price, matured)| {
+					let invest_order = T::Investments::process_invest_orders(tranche.currency)?;
+					let invest_amount = if allows_investments && !matured {
+						invest_order.amount
+					} else {
+						T::Balance::zero()
+					};
+					acc_invest_orders.ensure_add_assign(invest_amount)?;
+					invest_orders.push(invest_amount);
 
-				// Redeem order is denominated in the `TrancheCurrency`. Hence, we need to
-				// convert them into `PoolCurrency` denomination
-				let redeem_order = T::Investments::process_redeem_orders(tranche.currency)?;
-				let redeem_amount_in_pool_currency = price.ensure_mul_int(redeem_order.amount)?;
-				acc_redeem_orders.ensure_add_assign(redeem_amount_in_pool_currency)?;
-				redeem_orders.push(redeem_amount_in_pool_currency);
+					// Redeem order is denominated in the `TrancheCurrency`. Hence, we need to
+					// convert them into `PoolCurrency` denomination
+					let redeem_order = T::Investments::process_redeem_orders(tranche.currency)?;
+					let redeem_amount_in_pool_currency =
+						price.ensure_mul_int(redeem_order.amount)?;
+					acc_redeem_orders.ensure_add_assign(redeem_amount_in_pool_currency)?;
+					redeem_orders.push(redeem_amount_in_pool_currency);
 
-				Ok(())
-			})?;
+					Ok(())
+				},
+			)?;
 
 			Ok(SummarizedOrders {
 				acc_invest_orders,
@@ -1045,6 +1595,8 @@ pub mod pallet {
 				.checked_sub(&acc_redeem)
 				.ok_or(Error::<T>::InsufficientCurrency)?;
 
+			Self::ensure_tranche_limits(epoch, solution)?;
+
 			Self::validate_pool_constraints(
 				PoolState::Healthy,
 				new_reserve,
@@ -1054,6 +1606,56 @@ pub mod pallet {
 			)
 		}
 
+		/// Ensures that the investments fulfilled by a solution do not
+		/// exceed the maximum supply or value of their tranches.
+		fn ensure_tranche_limits(
+			epoch: &EpochExecutionInfoOf<T>,
+			solution: &[TrancheSolution],
+		) -> DispatchResult {
+			for (tranche, tranche_solution) in
+				epoch.tranches.residual_top_slice().iter().zip(solution)
+			{
+				let invest = tranche_solution
+					.invest_fulfillment
+					.mul_floor(tranche.invest);
+				if invest.is_zero() {
+					continue;
+				}
+
+				let limits = TrancheLimits::<T>::get(
+					tranche.currency.of_pool(),
+					tranche.currency.of_tranche(),
+				);
+				let redeem = tranche_solution
+					.redeem_fulfillment
+					.mul_floor(tranche.redeem);
+
+				if let Some(max_value) = limits.max_value {
+					let value = tranche.supply.ensure_add(invest)?.saturating_sub(redeem);
+					ensure!(value <= max_value, Error::<T>::TrancheLimitExceeded);
+				}
+
+				if let Some(max_supply) = limits.max_supply {
+					let inverse_price = tranche
+						.price
+						.reciprocal()
+						.ok_or(Error::<T>::InvalidSolution)?;
+					let to_units = |amount: T::Balance| {
+						inverse_price
+							.checked_mul_int(amount)
+							.ok_or(ArithmeticError::Overflow)
+					};
+
+					let supply = T::Tokens::total_issuance(tranche.currency.into())
+						.ensure_add(to_units(invest)?)?
+						.saturating_sub(to_units(redeem)?);
+					ensure!(supply <= max_supply, Error::<T>::TrancheLimitExceeded);
+				}
+			}
+
+			Ok(())
+		}
+
 		/// Validates if the maximal reserve of a pool is exceeded or it
 		/// any of the risk buffers falls below its minium.
 		///
@@ -1143,6 +1745,28 @@ pub mod pallet {
 					}
 				}
 
+				if let Change::NewValue(limits) = &changes.tranche_limits {
+					for (tranche_id, tranche_limits) in pool
+						.tranches
+						.ids_residual_top()
+						.into_iter()
+						.zip(limits.iter())
+					{
+						TrancheLimits::<T>::insert(pool_id, tranche_id, tranche_limits);
+					}
+				}
+
+				if let Change::NewValue(terms) = &changes.tranche_terms {
+					for (tranche_id, tranche_terms) in pool
+						.tranches
+						.ids_residual_top()
+						.into_iter()
+						.zip(terms.iter())
+					{
+						TrancheTerms::<T>::insert(pool_id, tranche_id, tranche_terms);
+					}
+				}
+
 				Self::deposit_event(Event::Updated {
 					id: *pool_id,
 					old: old_pool,
@@ -1219,10 +1843,18 @@ pub mod pallet {
 			pool.reserve.deposit_from_epoch(&epoch.tranches, solution)?;
 
 			for (tranche, solution) in epoch.tranches.residual_top_slice().iter().zip(solution) {
+				// Invest orders not taken into account when closing the epoch stay
+				// unfulfilled, and can be cancelled by the investors.
+				let invest_fulfillment = if tranche.invest.is_zero() {
+					Perquintill::zero()
+				} else {
+					solution.invest_fulfillment
+				};
+
 				T::Investments::invest_fulfillment(
 					tranche.currency,
 					FulfillmentWithPrice {
-						of_amount: solution.invest_fulfillment,
+						of_amount: invest_fulfillment,
 						price: tranche.price,
 					},
 				)?;
@@ -1348,6 +1980,8 @@ pub mod pallet {
 			let pool_account = PoolLocator { pool_id }.into_account_truncating();
 			Pool::<T>::try_mutate(pool_id, |pool| {
 				let pool = pool.as_mut().ok_or(Error::<T>::NoSuchPool)?;
+				ensure!(pool.status.allows_borrows(), Error::<T>::BorrowsNotAllowed);
+
 				let now = T::Time::now();
 
 				pool.reserve.total = pool
@@ -1395,6 +2029,54 @@ pub mod pallet {
 			})
 		}
 
+		/// Ensures that all tranches of the pool are redeemed, returning the
+		/// pool deposit and removing the data of the pool that is no longer
+		/// needed.
+		///
+		/// NOTE: The pool details are kept, so the pool id and its tranche
+		/// tokens can not be registered again.
+		fn do_close_pool(pool_id: T::PoolId, pool: &PoolDetailsOf<T>) -> DispatchResult {
+			ensure!(
+				pool.tranches
+					.residual_top_slice()
+					.iter()
+					.all(|tranche| T::Tokens::total_issuance(tranche.currency.into()).is_zero()),
+				Error::<T>::TranchesNotRedeemed
+			);
+			ensure!(pool.reserve.total.is_zero(), Error::<T>::ReserveNotEmpty);
+			ensure!(
+				T::AssetsUnderManagementNAV::nav(pool_id)
+					.map_or(true, |(nav_aum, _)| nav_aum.is_zero()),
+				Error::<T>::AssetsNotRepaid
+			);
+			ensure!(
+				pool.tranches.residual_top_slice().iter().all(|tranche| {
+					T::Investments::invest_orders(tranche.currency)
+						.amount
+						.is_zero() && T::Investments::redeem_orders(tranche.currency)
+						.amount
+						.is_zero()
+				}),
+				Error::<T>::OrdersOutstanding
+			);
+
+			if let Some(PoolDepositInfo { depositor, deposit }) = PoolDeposit::<T>::take(pool_id) {
+				T::Currency::unreserve(&depositor, deposit);
+				AccountDeposit::<T>::mutate_exists(&depositor, |total_deposit| {
+					*total_deposit = total_deposit
+						.map(|total| total.saturating_sub(deposit))
+						.filter(|total| !total.is_zero());
+				});
+			}
+
+			ScheduledUpdate::<T>::remove(pool_id);
+			let _ = TrancheLimits::<T>::clear_prefix(pool_id, T::MaxTranches::get(), None);
+			let _ = TrancheTerms::<T>::clear_prefix(pool_id, T::MaxTranches::get(), None);
+			let _ = TrancheLosses::<T>::clear_prefix(pool_id, T::MaxTranches::get(), None);
+
+			Ok(())
+		}
+
 		pub(crate) fn take_deposit(depositor: T::AccountId, pool: T::PoolId) -> DispatchResult {
 			let deposit = T::PoolDeposit::get();
 			T::Currency::reserve(&depositor, deposit)?;
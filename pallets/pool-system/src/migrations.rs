@@ -0,0 +1,100 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+#[cfg(feature = "try-runtime")]
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{Get, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+#[cfg(feature = "try-runtime")]
+use orml_traits::Change;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{
+	pallet::{Config, EpochExecution, ScheduledUpdate},
+	pool_types::v2,
+	solution::v2 as solution_v2,
+};
+
+/// Adds the tranche limits and terms to the scheduled pool updates, and the
+/// matured flag to the tranches of the epochs in submission period.
+/// No update scheduled until v2 changes the tranche limits or terms, and no
+/// tranche of an epoch closed until v2 is considered matured.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: u64 = 0;
+
+		ScheduledUpdate::<T>::translate_values::<
+			v2::ScheduledUpdateDetails<T::Rate, T::StringLimit, T::MaxTranches>,
+			_,
+		>(|update| {
+			count += 1;
+			Some(update.migrate())
+		});
+
+		EpochExecution::<T>::translate_values::<
+			solution_v2::EpochExecutionInfo<
+				T::Balance,
+				T::BalanceRatio,
+				T::EpochId,
+				T::TrancheWeight,
+				BlockNumberFor<T>,
+				T::TrancheCurrency,
+				T::MaxTranches,
+			>,
+			_,
+		>(|epoch| {
+			count += 1;
+			Some(epoch.migrate())
+		});
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let scheduled = ScheduledUpdate::<T>::iter_keys().count() as u64;
+		let executing = EpochExecution::<T>::iter_keys().count() as u64;
+
+		Ok((scheduled, executing).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(pre_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let (scheduled, executing): (u64, u64) = Decode::decode(&mut pre_state.as_slice())
+			.map_err(|_| "PoolSystem: pre_upgrade provides a valid state")?;
+
+		frame_support::ensure!(
+			ScheduledUpdate::<T>::iter_values().count() as u64 == scheduled,
+			"PoolSystem: Mismatching number of scheduled updates after migration"
+		);
+		frame_support::ensure!(
+			ScheduledUpdate::<T>::iter_values().all(|update| {
+				matches!(update.changes.tranche_limits, Change::NoChange)
+					&& matches!(update.changes.tranche_terms, Change::NoChange)
+			}),
+			"PoolSystem: Migrated scheduled updates must not change tranche limits or terms"
+		);
+		frame_support::ensure!(
+			EpochExecution::<T>::iter_values().count() as u64 == executing,
+			"PoolSystem: Mismatching number of epoch executions after migration"
+		);
+
+		Ok(())
+	}
+}
@@ -34,15 +34,19 @@ use frame_system::{EnsureSigned, EnsureSignedBy};
 use orml_traits::{asset_registry::AssetMetadata, parameter_type_with_key};
 use pallet_pool_fees::PoolFeeInfoOf;
 use pallet_restricted_tokens::TransferDetails;
-use parity_scale_codec::Encode;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_arithmetic::FixedPointNumber;
 use sp_core::{ConstU128, H256};
-use sp_runtime::{traits::Zero, BuildStorage};
+use sp_runtime::{traits::Zero, BuildStorage, DispatchError, RuntimeDebug};
 use sp_std::marker::PhantomData;
 
 use crate::{
 	self as pallet_pool_system,
-	pool_types::{changes::PoolChangeProposal, PoolDetails, ScheduledUpdateDetails},
+	pool_types::{
+		changes::{Change as PoolSystemChange, PoolChangeProposal},
+		PoolDetails, ScheduledUpdateDetails,
+	},
 	Config, DispatchResult,
 };
 
@@ -367,6 +371,8 @@ parameter_types! {
 
 	pub const MinUpdateDelay: u64 = 0; // no delay
 	pub const ChallengeTime: BlockNumber = 0;
+	pub static EpochSolverSteps: u32 = 0;
+	pub const MaxMaturedRedemptions: u32 = 10;
 
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = 1;
@@ -410,7 +416,9 @@ impl Config for Runtime {
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
 	type DefaultMinEpochTime = DefaultMinEpochTime;
 	type EpochId = PoolEpochId;
+	type EpochSolverSteps = EpochSolverSteps;
 	type Investments = Investments;
+	type MaxMaturedRedemptions = MaxMaturedRedemptions;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
@@ -427,7 +435,7 @@ impl Config for Runtime {
 	type PoolFeesNAV = PoolFees;
 	type PoolId = PoolId;
 	type Rate = Rate;
-	type RuntimeChange = PoolChangeProposal;
+	type RuntimeChange = RuntimeChange;
 	type RuntimeEvent = RuntimeEvent;
 	type StringLimit = StringLimit;
 	type Time = Timestamp;
@@ -439,6 +447,46 @@ impl Config for Runtime {
 	type WeightInfo = ();
 }
 
+/// Either a raw proposal, used to test the requirements of the change guard,
+/// or a pool system change.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RuntimeChange {
+	Proposal(PoolChangeProposal),
+	PoolSystem(PoolSystemChange),
+}
+
+impl From<PoolChangeProposal> for RuntimeChange {
+	fn from(proposal: PoolChangeProposal) -> Self {
+		RuntimeChange::Proposal(proposal)
+	}
+}
+
+impl From<PoolSystemChange> for RuntimeChange {
+	fn from(change: PoolSystemChange) -> Self {
+		RuntimeChange::PoolSystem(change)
+	}
+}
+
+impl TryFrom<RuntimeChange> for PoolSystemChange {
+	type Error = DispatchError;
+
+	fn try_from(change: RuntimeChange) -> Result<Self, DispatchError> {
+		match change {
+			RuntimeChange::PoolSystem(change) => Ok(change),
+			_ => Err(DispatchError::Other("Expected a pool system change")),
+		}
+	}
+}
+
+impl From<RuntimeChange> for PoolChangeProposal {
+	fn from(change: RuntimeChange) -> Self {
+		match change {
+			RuntimeChange::Proposal(proposal) => proposal,
+			RuntimeChange::PoolSystem(_) => PoolChangeProposal::new([]),
+		}
+	}
+}
+
 pub struct PoolCurrency;
 impl Contains<CurrencyId> for PoolCurrency {
 	fn contains(id: &CurrencyId) -> bool {
@@ -463,7 +511,7 @@ impl PoolUpdateGuard for UpdateGuard {
 		u64,
 		MaxTranches,
 	>;
-	type ScheduledUpdateDetails = ScheduledUpdateDetails<Rate, StringLimit, MaxTranches>;
+	type ScheduledUpdateDetails = ScheduledUpdateDetails<Rate, StringLimit, MaxTranches, Balance>;
 
 	fn released(
 		pool: &Self::PoolDetails,
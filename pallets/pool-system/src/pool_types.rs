@@ -30,7 +30,8 @@ use sp_runtime::{
 use sp_std::{cmp::PartialEq, vec::Vec};
 
 use crate::tranches::{
-	EpochExecutionTranches, TrancheEssence, TrancheInput, TrancheSolution, TrancheUpdate, Tranches,
+	EpochExecutionTranches, TrancheEssence, TrancheInput, TrancheLimits, TrancheSolution,
+	TrancheTerms, TrancheUpdate, Tranches,
 };
 
 // The TypeId impl we derive pool-accounts from
@@ -89,12 +90,12 @@ where
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct ScheduledUpdateDetails<Rate, StringLimit, MaxTranches>
+pub struct ScheduledUpdateDetails<Rate, StringLimit, MaxTranches, Balance>
 where
 	StringLimit: Get<u32>,
 	MaxTranches: Get<u32>,
 {
-	pub changes: PoolChanges<Rate, StringLimit, MaxTranches>,
+	pub changes: PoolChanges<Rate, StringLimit, MaxTranches, Balance>,
 	pub submitted_at: Seconds,
 }
 
@@ -136,9 +137,49 @@ pub struct PoolDetails<
 	pub reserve: ReserveDetails<Balance>,
 }
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum PoolStatus {
+	/// The pool accepts new orders and its reserve can be borrowed from.
 	Open,
+	/// No new invest or redeem orders are accepted and no epochs can be
+	/// closed.
+	Paused,
+	/// Only new redeem orders are accepted and the reserve can not be
+	/// borrowed from.
+	WindDown,
+	/// All tranches were redeemed and the pool was retired.
+	Closed,
+}
+
+impl PoolStatus {
+	/// Returns whether the pool can change from this status to the `next`
+	/// one.
+	pub fn can_transition_to(&self, next: &PoolStatus) -> bool {
+		matches!(
+			(self, next),
+			(PoolStatus::Open, PoolStatus::Paused)
+				| (PoolStatus::Paused, PoolStatus::Open)
+				| (PoolStatus::Open, PoolStatus::WindDown)
+				| (PoolStatus::Paused, PoolStatus::WindDown)
+				| (PoolStatus::WindDown, PoolStatus::Closed)
+		)
+	}
+
+	pub fn allows_investments(&self) -> bool {
+		matches!(self, PoolStatus::Open)
+	}
+
+	pub fn allows_redemptions(&self) -> bool {
+		matches!(self, PoolStatus::Open | PoolStatus::WindDown)
+	}
+
+	pub fn allows_borrows(&self) -> bool {
+		matches!(self, PoolStatus::Open | PoolStatus::Paused)
+	}
+
+	pub fn allows_epoch_closing(&self) -> bool {
+		matches!(self, PoolStatus::Open | PoolStatus::WindDown)
+	}
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -150,7 +191,7 @@ pub struct PoolParameters {
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct PoolChanges<Rate, StringLimit, MaxTranches>
+pub struct PoolChanges<Rate, StringLimit, MaxTranches, Balance>
 where
 	StringLimit: Get<u32>,
 	MaxTranches: Get<u32>,
@@ -159,6 +200,10 @@ where
 	pub tranche_metadata: Change<BoundedVec<TrancheMetadata<StringLimit>, MaxTranches>>,
 	pub min_epoch_time: Change<Seconds>,
 	pub max_nav_age: Change<Seconds>,
+	/// The limits of each tranche, ordered from residual to top tranche
+	pub tranche_limits: Change<BoundedVec<TrancheLimits<Balance>, MaxTranches>>,
+	/// The terms of each tranche, ordered from residual to top tranche
+	pub tranche_terms: Change<BoundedVec<TrancheTerms, MaxTranches>>,
 }
 
 /// Information about the deposit that has been taken to create a pool
@@ -413,6 +458,14 @@ pub mod changes {
 		}
 	}
 
+	/// Changes of the pool system which must be released by the change
+	/// guard before being applied.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Change {
+		/// Moves the pool to a new status.
+		Status(PoolStatus),
+	}
+
 	/// A PoolChangeProposal with extra information about when it was noted.
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct NotedPoolChange<ChangeProposal: Into<PoolChangeProposal>> {
@@ -420,3 +473,69 @@ pub mod changes {
 		pub change: ChangeProposal,
 	}
 }
+
+pub mod v2 {
+	use cfg_traits::Seconds;
+	use cfg_types::pools::TrancheMetadata;
+	use frame_support::{traits::Get, BoundedVec};
+	use orml_traits::Change;
+	use parity_scale_codec::{Decode, Encode};
+
+	use crate::tranches::TrancheUpdate;
+
+	#[derive(Encode, Decode)]
+	pub struct PoolChanges<Rate, StringLimit, MaxTranches>
+	where
+		StringLimit: Get<u32>,
+		MaxTranches: Get<u32>,
+	{
+		tranches: Change<BoundedVec<TrancheUpdate<Rate>, MaxTranches>>,
+		tranche_metadata: Change<BoundedVec<TrancheMetadata<StringLimit>, MaxTranches>>,
+		min_epoch_time: Change<Seconds>,
+		max_nav_age: Change<Seconds>,
+	}
+
+	impl<Rate, StringLimit, MaxTranches> PoolChanges<Rate, StringLimit, MaxTranches>
+	where
+		StringLimit: Get<u32>,
+		MaxTranches: Get<u32>,
+	{
+		pub fn migrate<Balance>(
+			self,
+		) -> super::PoolChanges<Rate, StringLimit, MaxTranches, Balance> {
+			super::PoolChanges {
+				tranches: self.tranches,
+				tranche_metadata: self.tranche_metadata,
+				min_epoch_time: self.min_epoch_time,
+				max_nav_age: self.max_nav_age,
+				tranche_limits: Change::NoChange,
+				tranche_terms: Change::NoChange,
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct ScheduledUpdateDetails<Rate, StringLimit, MaxTranches>
+	where
+		StringLimit: Get<u32>,
+		MaxTranches: Get<u32>,
+	{
+		changes: PoolChanges<Rate, StringLimit, MaxTranches>,
+		submitted_at: Seconds,
+	}
+
+	impl<Rate, StringLimit, MaxTranches> ScheduledUpdateDetails<Rate, StringLimit, MaxTranches>
+	where
+		StringLimit: Get<u32>,
+		MaxTranches: Get<u32>,
+	{
+		pub fn migrate<Balance>(
+			self,
+		) -> super::ScheduledUpdateDetails<Rate, StringLimit, MaxTranches, Balance> {
+			super::ScheduledUpdateDetails {
+				changes: self.changes.migrate(),
+				submitted_at: self.submitted_at,
+			}
+		}
+	}
+}
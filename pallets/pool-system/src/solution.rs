@@ -499,6 +499,71 @@ where
 	Ok((acc_invest, acc_redeem, risk_buffers))
 }
 
+/// Epoch execution info before the matured flag of its tranches, for
+/// migration to v3
+pub mod v2 {
+	use frame_support::traits::Get;
+	use parity_scale_codec::{Decode, Encode};
+
+	use super::{EpochSolution, Nav};
+	use crate::tranches::v2::EpochExecutionTranches;
+
+	#[derive(Encode, Decode)]
+	pub struct EpochExecutionInfo<
+		Balance,
+		BalanceRatio,
+		EpochId,
+		Weight,
+		BlockNumber,
+		TrancheCurrency,
+		MaxTranches,
+	>
+	where
+		MaxTranches: Get<u32>,
+	{
+		epoch: EpochId,
+		nav: Nav<Balance>,
+		tranches:
+			EpochExecutionTranches<Balance, BalanceRatio, Weight, TrancheCurrency, MaxTranches>,
+		best_submission: Option<EpochSolution<Balance, MaxTranches>>,
+		challenge_period_end: Option<BlockNumber>,
+	}
+
+	impl<Balance, BalanceRatio, EpochId, Weight, BlockNumber, TrancheCurrency, MaxTranches>
+		EpochExecutionInfo<
+			Balance,
+			BalanceRatio,
+			EpochId,
+			Weight,
+			BlockNumber,
+			TrancheCurrency,
+			MaxTranches,
+		>
+	where
+		MaxTranches: Get<u32>,
+	{
+		pub fn migrate(
+			self,
+		) -> super::EpochExecutionInfo<
+			Balance,
+			BalanceRatio,
+			EpochId,
+			Weight,
+			BlockNumber,
+			TrancheCurrency,
+			MaxTranches,
+		> {
+			super::EpochExecutionInfo {
+				epoch: self.epoch,
+				nav: self.nav,
+				tranches: self.tranches.migrate(),
+				best_submission: self.best_submission,
+				challenge_period_end: self.challenge_period_end,
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -0,0 +1,149 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use super::*;
+
+/// A single order bucket of an epoch, i.e. the investments or the
+/// redemptions of one tranche.
+#[derive(Clone, Copy)]
+enum Order {
+	Invest(usize),
+	Redeem(usize),
+}
+
+impl<T: Config> Pallet<T> {
+	/// Computes a healthy partial solution for an epoch in submission
+	/// period.
+	///
+	/// The solver is greedy and follows the same priority as the scoring
+	/// weights of `EpochExecutionTranches::calculate_weights()`:
+	/// redemptions of matured tranches first, then the remaining redemptions
+	/// from senior to junior, then investments from junior to senior. Each
+	/// order is fulfilled as much as possible without bringing the pool into
+	/// an unhealthy state, searching the fulfillment with at
+	/// most `T::EpochSolverSteps` bisection steps.
+	///
+	/// Returns `None` if the solver is disabled, if not executing any
+	/// order already leaves the pool unhealthy, or if no order can be
+	/// fulfilled at all.
+	pub fn solve_epoch(
+		pool: &PoolDetailsOf<T>,
+		epoch: &EpochExecutionInfoOf<T>,
+	) -> Result<Option<EpochSolution<T::Balance, T::MaxTranches>>, DispatchError> {
+		let steps = T::EpochSolverSteps::get();
+		if steps == 0 {
+			return Ok(None);
+		}
+
+		let num_tranches = epoch.tranches.num_tranches();
+		let mut solution = sp_std::vec![
+			TrancheSolution {
+				invest_fulfillment: Perquintill::zero(),
+				redeem_fulfillment: Perquintill::zero(),
+			};
+			num_tranches
+		];
+
+		if !Self::is_healthy_solution(pool, epoch, &solution) {
+			return Ok(None);
+		}
+
+		let mut by_seniority = (0..num_tranches).collect::<Vec<_>>();
+		by_seniority.sort_by_key(|index| {
+			epoch
+				.tranches
+				.residual_top_slice()
+				.get(*index)
+				.map(|tranche| (tranche.matured, tranche.seniority))
+				.unwrap_or_default()
+		});
+
+		let orders = by_seniority
+			.iter()
+			.rev()
+			.copied()
+			.map(Order::Redeem)
+			.chain(by_seniority.iter().copied().map(Order::Invest));
+
+		for order in orders {
+			let amount = epoch
+				.tranches
+				.residual_top_slice()
+				.get(Self::order_index(order))
+				.map(|tranche| match order {
+					Order::Invest(_) => tranche.invest,
+					Order::Redeem(_) => tranche.redeem,
+				})
+				.unwrap_or_default();
+
+			if amount.is_zero() {
+				continue;
+			}
+
+			Self::set_fulfillment(&mut solution, order, Perquintill::one());
+			if Self::is_healthy_solution(pool, epoch, &solution) {
+				continue;
+			}
+
+			// The fulfillments of all other orders are fixed, so the healthy
+			// fulfillments of this order form an interval containing zero.
+			let mut healthy = 0u64;
+			let mut unhealthy = Perquintill::one().deconstruct();
+			for _ in 0..steps {
+				let candidate = healthy + (unhealthy - healthy) / 2;
+				if candidate == healthy {
+					break;
+				}
+
+				Self::set_fulfillment(&mut solution, order, Perquintill::from_parts(candidate));
+				if Self::is_healthy_solution(pool, epoch, &solution) {
+					healthy = candidate;
+				} else {
+					unhealthy = candidate;
+				}
+			}
+
+			Self::set_fulfillment(&mut solution, order, Perquintill::from_parts(healthy));
+		}
+
+		if solution.iter().all(|tranche| {
+			tranche.invest_fulfillment.is_zero() && tranche.redeem_fulfillment.is_zero()
+		}) {
+			return Ok(None);
+		}
+
+		Self::score_solution(pool, epoch, &solution).map(Some)
+	}
+
+	fn is_healthy_solution(
+		pool: &PoolDetailsOf<T>,
+		epoch: &EpochExecutionInfoOf<T>,
+		solution: &[TrancheSolution],
+	) -> bool {
+		Self::inspect_solution(pool, epoch, solution)
+			.map(|state| state == PoolState::Healthy)
+			.unwrap_or(false)
+	}
+
+	fn order_index(order: Order) -> usize {
+		match order {
+			Order::Invest(index) | Order::Redeem(index) => index,
+		}
+	}
+
+	fn set_fulfillment(solution: &mut [TrancheSolution], order: Order, fulfillment: Perquintill) {
+		match order {
+			Order::Invest(index) => solution[index].invest_fulfillment = fulfillment,
+			Order::Redeem(index) => solution[index].redeem_fulfillment = fulfillment,
+		}
+	}
+}
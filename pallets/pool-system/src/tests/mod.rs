@@ -614,6 +614,8 @@ fn epoch() {
 				min_epoch_time: Change::NewValue(30 * 60),
 				max_nav_age: Change::NewValue(0),
 				tranche_metadata: Change::NoChange,
+				tranche_limits: Change::NoChange,
+				tranche_terms: Change::NoChange,
 			}
 		));
 
@@ -982,6 +984,102 @@ fn submission_period() {
 	});
 }
 
+#[test]
+fn solver_submits_healthy_solution() {
+	new_test_ext().execute_with(|| {
+		let pool_owner = DEFAULT_POOL_OWNER;
+		let pool_owner_origin = RuntimeOrigin::signed(pool_owner);
+		EpochSolverSteps::set(64);
+
+		let senior_interest_rate = Rate::saturating_from_rational(10u128, 100u128)
+			/ Rate::saturating_from_integer(SECONDS_PER_YEAR)
+			+ One::one();
+		assert_ok!(PoolSystem::create(
+			pool_owner.clone(),
+			pool_owner.clone(),
+			0,
+			vec![
+				TrancheInput {
+					tranche_type: TrancheType::Residual,
+					seniority: None,
+					metadata: TrancheMetadata {
+						token_name: BoundedVec::default(),
+						token_symbol: BoundedVec::default(),
+					}
+				},
+				TrancheInput {
+					tranche_type: TrancheType::NonResidual {
+						interest_rate_per_sec: senior_interest_rate,
+						min_risk_buffer: Perquintill::from_percent(10),
+					},
+					seniority: None,
+					metadata: TrancheMetadata {
+						token_name: BoundedVec::default(),
+						token_symbol: BoundedVec::default(),
+					}
+				},
+			],
+			AUSD_CURRENCY_ID,
+			600 * CURRENCY,
+			vec![],
+		));
+		assert_ok!(Investments::update_invest_order(
+			RuntimeOrigin::signed(0),
+			(0, JuniorTrancheId::get()),
+			500 * CURRENCY
+		));
+		assert_ok!(Investments::update_invest_order(
+			RuntimeOrigin::signed(1),
+			(0, SeniorTrancheId::get()),
+			500 * CURRENCY
+		));
+
+		crate::Pool::<Runtime>::try_mutate(0, |maybe_pool| -> Result<(), ()> {
+			maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+			maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+			Ok(())
+		})
+		.unwrap();
+
+		// Executing all orders would exceed the max reserve
+		assert_ok!(PoolSystem::close_epoch(pool_owner_origin.clone(), 0));
+
+		let epoch = <pallet::EpochExecution<mock::Runtime>>::try_get(0).unwrap();
+		let best_submission = epoch.best_submission.unwrap();
+		assert!(best_submission.healthy());
+		assert!(epoch.challenge_period_end.is_some());
+
+		// Junior investments take precedence, senior investments fill up the
+		// remaining reserve
+		let solution = best_submission.solution();
+		assert_eq!(solution[0].invest_fulfillment, Perquintill::one());
+		assert!(solution[1].invest_fulfillment <= Perquintill::from_percent(20));
+		assert!(solution[1].invest_fulfillment >= Perquintill::from_percent(19));
+
+		// Only better solutions can replace the solver's one
+		assert_err!(
+			PoolSystem::submit_solution(
+				pool_owner_origin.clone(),
+				0,
+				vec![
+					TrancheSolution {
+						invest_fulfillment: Perquintill::one(),
+						redeem_fulfillment: Perquintill::zero(),
+					},
+					TrancheSolution {
+						invest_fulfillment: Perquintill::from_percent(10),
+						redeem_fulfillment: Perquintill::zero(),
+					}
+				]
+			),
+			Error::<Runtime>::NotNewBestSubmission
+		);
+
+		assert_ok!(PoolSystem::execute_epoch(pool_owner_origin, 0));
+		assert!(<pallet::EpochExecution<mock::Runtime>>::get(0).is_none());
+	});
+}
+
 #[test]
 fn execute_info_removed_after_epoch_execute() {
 	new_test_ext().execute_with(|| {
@@ -1125,6 +1223,8 @@ fn pool_updates_should_be_constrained() {
 					min_epoch_time: Change::NewValue(0),
 					max_nav_age: Change::NewValue(realistic_max_nav_age),
 					tranche_metadata: Change::NoChange,
+					tranche_limits: Change::NoChange,
+					tranche_terms: Change::NoChange,
 				}
 			),
 			Error::<Runtime>::PoolParameterBoundViolated
@@ -1137,6 +1237,8 @@ fn pool_updates_should_be_constrained() {
 					min_epoch_time: Change::NewValue(realistic_min_epoch_time),
 					max_nav_age: Change::NewValue(7 * 24 * 60 * 60),
 					tranche_metadata: Change::NoChange,
+					tranche_limits: Change::NoChange,
+					tranche_terms: Change::NoChange,
 				}
 			),
 			Error::<Runtime>::PoolParameterBoundViolated
@@ -1155,6 +1257,8 @@ fn pool_updates_should_be_constrained() {
 				min_epoch_time: Change::NewValue(realistic_min_epoch_time),
 				max_nav_age: Change::NewValue(realistic_max_nav_age),
 				tranche_metadata: Change::NoChange,
+				tranche_limits: Change::NoChange,
+				tranche_terms: Change::NoChange,
 			}
 		));
 
@@ -2563,18 +2667,18 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([]);
-			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(1)]);
-			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_3 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_3 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			// Same change but different moment so overwrites
 			util::advance_secs(1);
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_4 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_4 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_eq!(change_id_4, change_id_3);
 
@@ -2589,10 +2693,10 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_eq!(change_id_1, change_id_2)
 		});
@@ -2604,14 +2708,14 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.clone()).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.clone().into()).unwrap();
 
 			assert_eq!(
 				System::events().last().unwrap().event,
 				RuntimeEvent::PoolSystem(Event::ProposedChange {
 					pool_id: DEFAULT_POOL_ID,
 					change_id,
-					change,
+					change: change.into(),
 				})
 			);
 		});
@@ -2629,7 +2733,7 @@ mod changes {
 			);
 
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.clone()).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			// ChangeId not found in the pool
 			assert_noop!(
@@ -2652,7 +2756,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_ok!(PoolSystem::released(DEFAULT_POOL_ID, change_id));
 		});
@@ -2664,7 +2768,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			// Starts submitted period
 			util::default_pool::close_epoch();
@@ -2687,7 +2791,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(23)]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			util::advance_secs(22);
 
@@ -2708,7 +2812,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::NextEpoch]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_noop!(
 				PoolSystem::released(DEFAULT_POOL_ID, change_id),
@@ -2730,7 +2834,7 @@ mod changes {
 	fn requirement_next_epoch_no_pool() {
 		new_test_ext().execute_with(|| {
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_err!(
 				PoolSystem::released(DEFAULT_POOL_ID, change_id),
@@ -2745,7 +2849,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::BlockedByLockedRedemptions]);
-			let _change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let _change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			/* TODO: 1407
 			assert_noop!(
@@ -2771,7 +2875,7 @@ mod changes {
 				Requirement::DelayTime(3),
 			]);
 
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			util::advance_secs(4);
 
@@ -2783,6 +2887,789 @@ mod changes {
 	}
 }
 
+mod tranche_limits {
+	use sp_runtime::DispatchResult;
+
+	use super::*;
+	use crate::tranches::TrancheLimits;
+
+	fn junior_limits() -> TrancheLimits<Balance> {
+		TrancheLimits {
+			max_supply: None,
+			max_value: Some(400 * CURRENCY),
+			min_investment: Some(100 * CURRENCY),
+		}
+	}
+
+	fn update_limits(limits: Vec<TrancheLimits<Balance>>) -> DispatchResult {
+		PoolSystem::update(
+			DEFAULT_POOL_ID,
+			PoolChanges {
+				tranches: Change::NoChange,
+				tranche_metadata: Change::NoChange,
+				min_epoch_time: Change::NoChange,
+				max_nav_age: Change::NoChange,
+				tranche_limits: Change::NewValue(BoundedVec::truncate_from(limits)),
+				tranche_terms: Change::NoChange,
+			},
+		)
+		.map(|_| ())
+	}
+
+	#[test]
+	fn update() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			assert_noop!(
+				update_limits(vec![junior_limits()]),
+				Error::<Runtime>::InvalidTrancheLimits
+			);
+
+			assert_ok!(update_limits(vec![
+				junior_limits(),
+				TrancheLimits::default()
+			]));
+			assert_eq!(
+				PoolSystem::tranche_limits(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				junior_limits()
+			);
+		});
+	}
+
+	#[test]
+	fn min_investment() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+			assert_ok!(update_limits(vec![
+				junior_limits(),
+				TrancheLimits::default()
+			]));
+
+			assert_noop!(
+				Investments::update_invest_order(
+					RuntimeOrigin::signed(0),
+					(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+					50 * CURRENCY
+				),
+				Error::<Runtime>::InvestmentBelowMinimum
+			);
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				100 * CURRENCY
+			));
+
+			// Decreasing an order below the minimum is possible
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				50 * CURRENCY
+			));
+
+			// Orders placed through other pallets are checked as well
+			assert_noop!(
+				<Investments as cfg_traits::investments::Investment<_>>::update_investment(
+					&0,
+					(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+					60 * CURRENCY
+				),
+				Error::<Runtime>::InvestmentBelowMinimum
+			);
+
+			// Cancelling an order is always possible
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				0
+			));
+		});
+	}
+
+	#[test]
+	fn solution_respects_max_value() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+			assert_ok!(update_limits(vec![
+				junior_limits(),
+				TrancheLimits::default()
+			]));
+
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				500 * CURRENCY
+			));
+
+			Pool::<Runtime>::try_mutate(DEFAULT_POOL_ID, |maybe_pool| -> Result<(), ()> {
+				maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+				maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+				Ok(())
+			})
+			.unwrap();
+
+			// Fulfilling all investments would exceed the cap
+			assert_ok!(PoolSystem::close_epoch(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+			assert!(EpochExecution::<Runtime>::contains_key(DEFAULT_POOL_ID));
+
+			assert_noop!(
+				PoolSystem::submit_solution(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					vec![
+						TrancheSolution {
+							invest_fulfillment: Perquintill::one(),
+							redeem_fulfillment: Perquintill::zero(),
+						},
+						TrancheSolution {
+							invest_fulfillment: Perquintill::zero(),
+							redeem_fulfillment: Perquintill::zero(),
+						}
+					]
+				),
+				Error::<Runtime>::InvalidSolution
+			);
+
+			assert_ok!(PoolSystem::submit_solution(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID,
+				vec![
+					TrancheSolution {
+						invest_fulfillment: Perquintill::from_percent(80),
+						redeem_fulfillment: Perquintill::zero(),
+					},
+					TrancheSolution {
+						invest_fulfillment: Perquintill::zero(),
+						redeem_fulfillment: Perquintill::zero(),
+					}
+				]
+			));
+		});
+	}
+}
+
+mod tranche_terms {
+	use cfg_traits::{investments::InvestmentAccountant, Seconds};
+	use frame_support::traits::UnixTime;
+	use orml_traits::MultiCurrency;
+	use sp_runtime::DispatchResult;
+
+	use super::*;
+	use crate::{tranches::TrancheTerms, Event};
+
+	const LOCK_UP: Seconds = 100;
+	const MATURITY: Seconds = 1_000;
+
+	fn junior_terms() -> TrancheTerms {
+		TrancheTerms {
+			lock_up: Some(LOCK_UP),
+			maturity: Some(<Timestamp as UnixTime>::now().as_secs() + MATURITY),
+		}
+	}
+
+	fn update_terms(terms: Vec<TrancheTerms>) -> DispatchResult {
+		PoolSystem::update(
+			DEFAULT_POOL_ID,
+			PoolChanges {
+				tranches: Change::NoChange,
+				tranche_metadata: Change::NoChange,
+				min_epoch_time: Change::NoChange,
+				max_nav_age: Change::NoChange,
+				tranche_limits: Change::NoChange,
+				tranche_terms: Change::NewValue(BoundedVec::truncate_from(terms)),
+			},
+		)
+		.map(|_| ())
+	}
+
+	#[test]
+	fn update() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			assert_noop!(
+				update_terms(vec![junior_terms()]),
+				Error::<Runtime>::InvalidTrancheTerms
+			);
+			assert_noop!(
+				update_terms(vec![
+					TrancheTerms {
+						lock_up: None,
+						maturity: Some(<Timestamp as UnixTime>::now().as_secs() - 1),
+					},
+					TrancheTerms::default()
+				]),
+				Error::<Runtime>::InvalidTrancheTerms
+			);
+
+			assert_ok!(update_terms(vec![junior_terms(), TrancheTerms::default()]));
+			assert_eq!(
+				PoolSystem::tranche_terms(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				junior_terms()
+			);
+		});
+	}
+
+	#[test]
+	fn lock_up() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+			assert_ok!(update_terms(vec![junior_terms(), TrancheTerms::default()]));
+
+			let tranche_id = (DEFAULT_POOL_ID, JuniorTrancheId::get());
+			assert_ok!(OrmlTokens::deposit(
+				CurrencyId::Tranche(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				&0,
+				100 * CURRENCY
+			));
+			assert_ok!(
+				<PoolSystem as InvestmentAccountant<AccountId>>::note_deposit(tranche_id, &0)
+			);
+
+			assert_noop!(
+				Investments::update_redeem_order(
+					RuntimeOrigin::signed(0),
+					tranche_id,
+					100 * CURRENCY
+				),
+				Error::<Runtime>::TokensLocked
+			);
+			assert_noop!(
+				PoolSystem::ensure_unlocked(tranche_id, &0),
+				Error::<Runtime>::TokensLocked
+			);
+			assert_ok!(PoolSystem::ensure_unlocked(tranche_id, &1));
+
+			util::advance_secs(LOCK_UP);
+			assert_ok!(PoolSystem::ensure_unlocked(tranche_id, &0));
+			assert_ok!(Investments::update_redeem_order(
+				RuntimeOrigin::signed(0),
+				tranche_id,
+				100 * CURRENCY
+			));
+		});
+	}
+
+	#[test]
+	fn maturity() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+			assert_ok!(update_terms(vec![junior_terms(), TrancheTerms::default()]));
+
+			let tranche_id = (DEFAULT_POOL_ID, JuniorTrancheId::get());
+			assert_ok!(OrmlTokens::deposit(
+				CurrencyId::Tranche(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				&0,
+				100 * CURRENCY
+			));
+			assert_ok!(OrmlTokens::deposit(
+				CurrencyId::Tranche(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				&1,
+				50 * CURRENCY
+			));
+
+			assert_noop!(
+				PoolSystem::redeem_matured(
+					RuntimeOrigin::signed(1),
+					DEFAULT_POOL_ID,
+					JuniorTrancheId::get(),
+					BoundedVec::truncate_from(vec![0])
+				),
+				Error::<Runtime>::TrancheNotMatured
+			);
+
+			util::advance_secs(MATURITY);
+
+			assert_noop!(
+				Investments::update_invest_order(
+					RuntimeOrigin::signed(0),
+					tranche_id,
+					100 * CURRENCY
+				),
+				Error::<Runtime>::TrancheMatured
+			);
+			// Investors without tranche tokens are skipped
+			assert_ok!(PoolSystem::redeem_matured(
+				RuntimeOrigin::signed(1),
+				DEFAULT_POOL_ID,
+				JuniorTrancheId::get(),
+				BoundedVec::truncate_from(vec![0, 2, 1])
+			));
+			assert_eq!(
+				System::events()
+					.into_iter()
+					.filter_map(|record| match record.event {
+						RuntimeEvent::PoolSystem(
+							event @ Event::MaturedRedemptionOrdered { .. },
+						) => {
+							Some(event)
+						}
+						_ => None,
+					})
+					.collect::<Vec<_>>(),
+				vec![
+					Event::MaturedRedemptionOrdered {
+						pool_id: DEFAULT_POOL_ID,
+						tranche_id: JuniorTrancheId::get(),
+						investor: 0,
+						amount: 100 * CURRENCY,
+					},
+					Event::MaturedRedemptionOrdered {
+						pool_id: DEFAULT_POOL_ID,
+						tranche_id: JuniorTrancheId::get(),
+						investor: 1,
+						amount: 50 * CURRENCY,
+					}
+				]
+			);
+			for (investor, amount) in [(0, 100 * CURRENCY), (1, 50 * CURRENCY)] {
+				assert_eq!(
+					pallet_investments::RedeemOrders::<Runtime>::get(investor, tranche_id)
+						.unwrap()
+						.amount(),
+					amount
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn close_epoch_with_matured_tranche() {
+		new_test_ext().execute_with(|| {
+			EpochSolverSteps::set(64);
+			util::default_pool::create();
+			Pool::<Runtime>::try_mutate(DEFAULT_POOL_ID, |maybe_pool| -> Result<(), ()> {
+				maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+				maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+				Ok(())
+			})
+			.unwrap();
+
+			invest_close_and_collect(
+				DEFAULT_POOL_ID,
+				vec![
+					(0, JuniorTrancheId::get(), 100 * CURRENCY),
+					(1, SeniorTrancheId::get(), 200 * CURRENCY),
+				],
+			);
+
+			let junior_id = (DEFAULT_POOL_ID, JuniorTrancheId::get());
+			let senior_id = (DEFAULT_POOL_ID, SeniorTrancheId::get());
+			assert_ok!(update_terms(vec![
+				TrancheTerms::default(),
+				TrancheTerms {
+					lock_up: None,
+					maturity: Some(<Timestamp as UnixTime>::now().as_secs() + MATURITY),
+				}
+			]));
+
+			// Pending invest order placed before the senior tranche matured
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(2),
+				senior_id,
+				50 * CURRENCY
+			));
+
+			util::advance_secs(MATURITY);
+
+			assert_ok!(Investments::update_redeem_order(
+				RuntimeOrigin::signed(0),
+				junior_id,
+				100 * CURRENCY
+			));
+			assert_ok!(Investments::update_redeem_order(
+				RuntimeOrigin::signed(1),
+				senior_id,
+				100 * CURRENCY
+			));
+
+			// Redeeming everything would leave no junior tranche behind
+			assert_ok!(PoolSystem::close_epoch(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+
+			// Seniorities are kept, and the matured tranche takes no investments
+			let epoch = EpochExecution::<Runtime>::get(DEFAULT_POOL_ID).unwrap();
+			assert_eq!(
+				epoch
+					.tranches
+					.residual_top_slice()
+					.iter()
+					.map(|tranche| (tranche.seniority, tranche.matured, tranche.invest))
+					.collect::<Vec<_>>(),
+				vec![(0, false, 0), (1, true, 0)]
+			);
+
+			// The matured redemption is fulfilled first, the junior redemption
+			// takes what the risk buffer leaves
+			let best_submission = epoch.best_submission.unwrap();
+			assert!(best_submission.healthy());
+			let solution = best_submission.solution();
+			assert_eq!(solution[1].redeem_fulfillment, Perquintill::one());
+			assert!(solution[0].redeem_fulfillment >= Perquintill::from_percent(66));
+			assert!(solution[0].redeem_fulfillment <= Perquintill::from_percent(67));
+
+			assert_ok!(PoolSystem::execute_epoch(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+
+			// The invest order of the matured tranche stays unfulfilled
+			assert_ok!(Investments::collect_investments(
+				RuntimeOrigin::signed(2),
+				senior_id
+			));
+			assert_eq!(
+				pallet_investments::InvestOrders::<Runtime>::get(2, senior_id)
+					.unwrap()
+					.amount(),
+				50 * CURRENCY
+			);
+		});
+	}
+}
+
+mod tranche_losses {
+	use super::*;
+	use crate::{Event, TrancheLosses};
+
+	fn loss_events() -> Vec<Event<Runtime>> {
+		System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::PoolSystem(
+					event @ (Event::LossAllocated { .. }
+					| Event::LossRecovered { .. }
+					| Event::TrancheImpaired { .. }),
+				) => Some(event),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn wipe_out_and_recovery() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+			Pool::<Runtime>::try_mutate(DEFAULT_POOL_ID, |maybe_pool| -> Result<(), ()> {
+				maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+				maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+				Ok(())
+			})
+			.unwrap();
+
+			invest_close_and_collect(
+				DEFAULT_POOL_ID,
+				vec![
+					(0, JuniorTrancheId::get(), 100 * CURRENCY),
+					(1, SeniorTrancheId::get(), 100 * CURRENCY),
+				],
+			);
+			assert!(loss_events().is_empty());
+
+			// The pool loses more than the junior tranche holds
+			assert_ok!(test_borrow(
+				DEFAULT_POOL_OWNER,
+				DEFAULT_POOL_ID,
+				150 * CURRENCY
+			));
+			test_nav_down(DEFAULT_POOL_ID, 120 * CURRENCY);
+
+			let epoch = Pool::<Runtime>::get(DEFAULT_POOL_ID).unwrap().epoch.current;
+			assert_noop!(
+				PoolSystem::close_epoch(RuntimeOrigin::signed(DEFAULT_POOL_OWNER), DEFAULT_POOL_ID),
+				Error::<Runtime>::WipedOut
+			);
+
+			// The losses are still recorded without closing the epoch
+			assert_ok!(PoolSystem::allocate_losses(
+				RuntimeOrigin::signed(2),
+				DEFAULT_POOL_ID
+			));
+			let pool = Pool::<Runtime>::get(DEFAULT_POOL_ID).unwrap();
+			assert_eq!(pool.epoch.current, epoch);
+			assert_eq!(pool.status, PoolStatus::Open);
+			assert_eq!(
+				loss_events(),
+				vec![
+					Event::LossAllocated {
+						pool_id: DEFAULT_POOL_ID,
+						tranche_id: JuniorTrancheId::get(),
+						amount: 100 * CURRENCY,
+						outstanding: 100 * CURRENCY,
+					},
+					Event::LossAllocated {
+						pool_id: DEFAULT_POOL_ID,
+						tranche_id: SeniorTrancheId::get(),
+						amount: 20 * CURRENCY,
+						outstanding: 20 * CURRENCY,
+					},
+					Event::TrancheImpaired {
+						pool_id: DEFAULT_POOL_ID,
+						tranche_id: SeniorTrancheId::get(),
+					},
+				]
+			);
+
+			// The pool recovers its losses
+			System::reset_events();
+			test_nav_up(DEFAULT_POOL_ID, 120 * CURRENCY);
+
+			assert_ok!(PoolSystem::close_epoch(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+			assert_eq!(
+				Pool::<Runtime>::get(DEFAULT_POOL_ID).unwrap().epoch.current,
+				epoch + 1
+			);
+			assert_eq!(
+				loss_events(),
+				vec![
+					Event::LossRecovered {
+						pool_id: DEFAULT_POOL_ID,
+						tranche_id: JuniorTrancheId::get(),
+						amount: 100 * CURRENCY,
+						outstanding: 0,
+					},
+					Event::LossRecovered {
+						pool_id: DEFAULT_POOL_ID,
+						tranche_id: SeniorTrancheId::get(),
+						amount: 20 * CURRENCY,
+						outstanding: 0,
+					},
+				]
+			);
+
+			let senior_losses =
+				TrancheLosses::<Runtime>::get(DEFAULT_POOL_ID, SeniorTrancheId::get());
+			assert_eq!(senior_losses.outstanding, 0);
+			assert_eq!(senior_losses.allocated, 20 * CURRENCY);
+			assert_eq!(senior_losses.recovered, 20 * CURRENCY);
+		});
+	}
+}
+
+mod status {
+	use orml_traits::MultiCurrency;
+	use sp_runtime::{DispatchError, DispatchResult};
+
+	use super::*;
+	use crate::Event;
+
+	fn propose_and_apply(status: PoolStatus) -> DispatchResult {
+		PoolSystem::propose_status_change(
+			RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+			DEFAULT_POOL_ID,
+			status,
+		)?;
+
+		let change_id = System::events()
+			.into_iter()
+			.rev()
+			.find_map(|record| match record.event {
+				RuntimeEvent::PoolSystem(Event::ProposedChange { change_id, .. }) => {
+					Some(change_id)
+				}
+				_ => None,
+			})
+			.unwrap();
+
+		PoolSystem::apply_status_change(
+			RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+			DEFAULT_POOL_ID,
+			change_id,
+		)
+	}
+
+	fn status() -> PoolStatus {
+		Pool::<Runtime>::get(DEFAULT_POOL_ID).unwrap().status
+	}
+
+	#[test]
+	fn only_admin_can_propose() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			assert_noop!(
+				PoolSystem::propose_status_change(
+					RuntimeOrigin::signed(0),
+					DEFAULT_POOL_ID,
+					PoolStatus::Paused,
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_transition() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			assert_noop!(
+				PoolSystem::propose_status_change(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					PoolStatus::Closed,
+				),
+				Error::<Runtime>::InvalidStatusTransition
+			);
+		});
+	}
+
+	#[test]
+	fn paused_pool_freezes_epochs() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			assert_ok!(propose_and_apply(PoolStatus::Paused));
+			assert_eq!(status(), PoolStatus::Paused);
+			assert_eq!(
+				System::events().last().unwrap().event,
+				RuntimeEvent::PoolSystem(Event::StatusUpdated {
+					pool_id: DEFAULT_POOL_ID,
+					status: PoolStatus::Paused,
+				})
+			);
+
+			assert_noop!(
+				PoolSystem::close_epoch(RuntimeOrigin::signed(DEFAULT_POOL_OWNER), DEFAULT_POOL_ID),
+				Error::<Runtime>::EpochClosingNotAllowed
+			);
+
+			assert_ok!(propose_and_apply(PoolStatus::Open));
+			assert_eq!(status(), PoolStatus::Open);
+		});
+	}
+
+	#[test]
+	fn wind_down_blocks_borrows() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			assert_ok!(propose_and_apply(PoolStatus::WindDown));
+
+			assert_err!(
+				test_borrow(DEFAULT_POOL_OWNER, DEFAULT_POOL_ID, 1),
+				Error::<Runtime>::BorrowsNotAllowed
+			);
+			assert_noop!(
+				PoolSystem::propose_status_change(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					PoolStatus::Open,
+				),
+				Error::<Runtime>::InvalidStatusTransition
+			);
+		});
+	}
+
+	#[test]
+	fn close_requires_redeemed_tranches() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			let tranche_currency = CurrencyId::Tranche(DEFAULT_POOL_ID, JuniorTrancheId::get());
+			assert_ok!(OrmlTokens::deposit(tranche_currency, &0, 1));
+
+			assert_ok!(propose_and_apply(PoolStatus::WindDown));
+			assert_err!(
+				propose_and_apply(PoolStatus::Closed),
+				Error::<Runtime>::TranchesNotRedeemed
+			);
+
+			assert_ok!(OrmlTokens::withdraw(tranche_currency, &0, 1));
+			assert_ok!(propose_and_apply(PoolStatus::Closed));
+
+			assert_eq!(status(), PoolStatus::Closed);
+			assert!(crate::PoolDeposit::<Runtime>::get(DEFAULT_POOL_ID).is_none());
+			assert!(crate::AccountDeposit::<Runtime>::get(DEFAULT_POOL_OWNER).is_zero());
+		});
+	}
+
+	#[test]
+	fn close_requires_empty_reserve() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			assert_ok!(propose_and_apply(PoolStatus::WindDown));
+
+			Pool::<Runtime>::mutate(DEFAULT_POOL_ID, |pool| {
+				pool.as_mut().unwrap().reserve.total = 1;
+			});
+
+			assert_err!(
+				propose_and_apply(PoolStatus::Closed),
+				Error::<Runtime>::ReserveNotEmpty
+			);
+
+			Pool::<Runtime>::mutate(DEFAULT_POOL_ID, |pool| {
+				pool.as_mut().unwrap().reserve.total = 0;
+			});
+
+			assert_ok!(propose_and_apply(PoolStatus::Closed));
+		});
+	}
+
+	#[test]
+	fn wind_down_does_not_execute_invest_orders() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			let investment_id = (DEFAULT_POOL_ID, SeniorTrancheId::get());
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				investment_id,
+				500 * CURRENCY,
+			));
+
+			assert_ok!(propose_and_apply(PoolStatus::WindDown));
+
+			Pool::<Runtime>::mutate(DEFAULT_POOL_ID, |pool| {
+				let pool = pool.as_mut().unwrap();
+				pool.parameters.min_epoch_time = 0;
+				pool.parameters.max_nav_age = u64::MAX;
+			});
+
+			assert_ok!(PoolSystem::close_epoch(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+
+			// The invest order is not executed
+			assert!(crate::EpochExecution::<Runtime>::get(DEFAULT_POOL_ID).is_none());
+			assert!(Pool::<Runtime>::get(DEFAULT_POOL_ID)
+				.unwrap()
+				.reserve
+				.total
+				.is_zero());
+
+			assert_err!(
+				propose_and_apply(PoolStatus::Closed),
+				Error::<Runtime>::OrdersOutstanding
+			);
+
+			// The investor can still cancel the order
+			assert_ok!(Investments::collect_investments(
+				RuntimeOrigin::signed(0),
+				investment_id,
+			));
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				investment_id,
+				0,
+			));
+
+			assert_ok!(propose_and_apply(PoolStatus::Closed));
+		});
+	}
+}
+
 mod pool_fees {
 	use cfg_types::pools::{PoolFeeAmount, PoolFeeType};
 	use frame_support::traits::fungibles::Inspect;
@@ -52,6 +52,79 @@ pub struct TrancheUpdate<Rate> {
 	pub seniority: Option<Seniority>,
 }
 
+/// Limits on the size of a tranche and on the investments into it
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TrancheLimits<Balance> {
+	/// Maximum amount of tranche tokens in circulation
+	pub max_supply: Option<Balance>,
+	/// Maximum value of the tranche, denominated in the pool currency
+	pub max_value: Option<Balance>,
+	/// Minimum amount of an invest order, denominated in the pool currency
+	pub min_investment: Option<Balance>,
+}
+
+/// Terms of a fixed-term tranche
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TrancheTerms {
+	/// Time after their latest deposit during which investors can not redeem
+	pub lock_up: Option<Seconds>,
+	/// Moment from which all tranche tokens are redeemed with priority
+	pub maturity: Option<Seconds>,
+}
+
+impl TrancheTerms {
+	pub fn is_matured(&self, now: Seconds) -> bool {
+		self.maturity.map_or(false, |maturity| now >= maturity)
+	}
+}
+
+/// Ledger of the losses absorbed by a tranche, in the pool currency.
+///
+/// For a non-residual tranche, the outstanding loss is the part of its
+/// balance not covered by the assets of the pool. The residual tranche has no
+/// balance to claim, so its outstanding loss is the decline of the value of
+/// its tokens, accumulated over allocations. Both coincide with the value the
+/// investors of the tranche lost.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TrancheLosses<Balance, BalanceRatio> {
+	/// Loss the tranche currently absorbs
+	pub outstanding: Balance,
+	/// Sum of all losses allocated to the tranche
+	pub allocated: Balance,
+	/// Sum of all losses the tranche recovered from
+	pub recovered: Balance,
+	/// Token price of the tranche at the latest allocation
+	pub price: Option<BalanceRatio>,
+}
+
+impl<Balance, BalanceRatio> TrancheLosses<Balance, BalanceRatio>
+where
+	Balance: BaseArithmetic + Copy,
+{
+	/// Sets the loss the tranche currently absorbs and accounts the
+	/// difference to the previous one as allocated or recovered.
+	pub fn update(&mut self, outstanding: Balance) -> Result<(), DispatchError> {
+		if outstanding > self.outstanding {
+			self.allocated = self
+				.allocated
+				.ensure_add(outstanding.ensure_sub(self.outstanding)?)?;
+		} else {
+			self.recovered = self
+				.recovered
+				.ensure_add(self.outstanding.ensure_sub(outstanding)?)?;
+		}
+		self.outstanding = outstanding;
+
+		Ok(())
+	}
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum TrancheLoc<TrancheId> {
@@ -984,6 +1057,8 @@ pub struct EpochExecutionTranche<Balance, BalanceRatio, Weight, TrancheCurrency>
 	pub redeem: Balance,
 	pub min_risk_buffer: Perquintill,
 	pub seniority: Seniority,
+	/// Matured tranches are redeemed before all other tranches
+	pub matured: bool,
 
 	pub _phantom: PhantomData<Weight>,
 }
@@ -1276,6 +1351,7 @@ where
 		let redeem_starts = 10u128.checked_pow(n_tranches).unwrap_or(u128::MAX);
 
 		// The desired order priority is:
+		// - Matured redemptions
 		// - Senior redemptions
 		// - Junior redemptions
 		// - Junior investments
@@ -1283,9 +1359,17 @@ where
 		// We ensure this by having a higher base weight for redemptions,
 		// increasing the redemption weights by seniority,
 		// and decreasing the investment weight by seniority.
+		// Matured tranches share the redemption weight right above the most
+		// senior possible tranche.
 		self.residual_top_slice()
 			.iter()
 			.map(|tranche| {
+				let redeem_exponent = if tranche.matured {
+					n_tranches.saturating_add(1)
+				} else {
+					tranche.seniority.saturating_add(1)
+				};
+
 				(
 					10u128
 						.checked_pow(
@@ -1296,11 +1380,7 @@ where
 						.unwrap_or(u128::MAX)
 						.into(),
 					redeem_starts
-						.checked_mul(
-							10u128
-								.checked_pow(tranche.seniority.saturating_add(1))
-								.unwrap_or(u128::MAX),
-						)
+						.checked_mul(10u128.checked_pow(redeem_exponent).unwrap_or(u128::MAX))
 						.unwrap_or(u128::MAX)
 						.into(),
 				)
@@ -1455,6 +1535,84 @@ fn finalize_combine<R, T, W>(
 	}
 }
 
+/// Epoch execution tranches before the matured flag, for migration to v3
+pub mod v2 {
+	use frame_support::{traits::Get, BoundedVec};
+	use parity_scale_codec::{Decode, Encode};
+	use sp_runtime::Perquintill;
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	use super::Seniority;
+
+	#[derive(Encode, Decode)]
+	pub struct EpochExecutionTranche<Balance, BalanceRatio, Weight, TrancheCurrency> {
+		currency: TrancheCurrency,
+		supply: Balance,
+		price: BalanceRatio,
+		invest: Balance,
+		redeem: Balance,
+		min_risk_buffer: Perquintill,
+		seniority: Seniority,
+		_phantom: PhantomData<Weight>,
+	}
+
+	impl<Balance, BalanceRatio, Weight, TrancheCurrency>
+		EpochExecutionTranche<Balance, BalanceRatio, Weight, TrancheCurrency>
+	{
+		pub fn migrate(
+			self,
+		) -> super::EpochExecutionTranche<Balance, BalanceRatio, Weight, TrancheCurrency> {
+			super::EpochExecutionTranche {
+				currency: self.currency,
+				supply: self.supply,
+				price: self.price,
+				invest: self.invest,
+				redeem: self.redeem,
+				min_risk_buffer: self.min_risk_buffer,
+				seniority: self.seniority,
+				matured: false,
+				_phantom: self._phantom,
+			}
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct EpochExecutionTranches<Balance, BalanceRatio, Weight, TrancheCurrency, MaxTranches>
+	where
+		MaxTranches: Get<u32>,
+	{
+		tranches: BoundedVec<
+			EpochExecutionTranche<Balance, BalanceRatio, Weight, TrancheCurrency>,
+			MaxTranches,
+		>,
+	}
+
+	impl<Balance, BalanceRatio, Weight, TrancheCurrency, MaxTranches>
+		EpochExecutionTranches<Balance, BalanceRatio, Weight, TrancheCurrency, MaxTranches>
+	where
+		MaxTranches: Get<u32>,
+	{
+		pub fn migrate(
+			self,
+		) -> super::EpochExecutionTranches<
+			Balance,
+			BalanceRatio,
+			Weight,
+			TrancheCurrency,
+			MaxTranches,
+		> {
+			super::EpochExecutionTranches {
+				tranches: BoundedVec::truncate_from(
+					self.tranches
+						.into_iter()
+						.map(EpochExecutionTranche::migrate)
+						.collect::<Vec<_>>(),
+				),
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 pub mod test {
 	use cfg_primitives::{Balance, PoolId, TrancheId, TrancheWeight};
@@ -1545,6 +1703,7 @@ pub mod test {
 				redeem: 0,
 				min_risk_buffer: Default::default(),
 				seniority: 0,
+				matured: false,
 				_phantom: Default::default(),
 			}
 		}
@@ -4041,6 +4200,23 @@ pub mod test {
 				]
 			);
 
+			// A matured junior tranche redeems before the most senior tranche
+			let mut e_e_tranches = default_epoch_tranches();
+			e_e_tranches
+				.combine_with_mut_residual_top([true, false, false], |e, matured| {
+					e.matured = matured;
+					Ok(())
+				})
+				.unwrap();
+			assert_eq!(
+				e_e_tranches.calculate_weights(),
+				vec![
+					(TrancheWeight::from(1000), TrancheWeight::from(10000000)),
+					(TrancheWeight::from(100), TrancheWeight::from(100000)),
+					(TrancheWeight::from(10), TrancheWeight::from(1000000))
+				]
+			);
+
 			let mut e_e_tranches = default_epoch_tranches();
 
 			e_e_tranches
@@ -20,6 +20,11 @@ pub trait WeightInfo {
 	fn close_epoch_execute(n: u32, m: u32) -> Weight;
 	fn submit_solution(n: u32, m: u32) -> Weight;
 	fn execute_epoch(n: u32, m: u32) -> Weight;
+	fn propose_status_change() -> Weight;
+	fn apply_status_change(n: u32) -> Weight;
+	fn solve_epoch(n: u32) -> Weight;
+	fn redeem_matured(n: u32) -> Weight;
+	fn allocate_losses(n: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -46,4 +51,24 @@ impl WeightInfo for () {
 	fn execute_epoch(_: u32, _: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn propose_status_change() -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_status_change(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn solve_epoch(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn redeem_matured(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn allocate_losses(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
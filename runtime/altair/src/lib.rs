@@ -29,12 +29,14 @@ use cfg_primitives::{
 	},
 	LPGatewayQueueMessageNonce, LPGatewaySessionId,
 };
-use cfg_traits::{investments::OrderManager, Millis, PoolUpdateGuard, Seconds};
+use cfg_traits::{
+	investments::OrderManager, liquidity_pools::MessageHash, Millis, PoolUpdateGuard, Seconds,
+};
 use cfg_types::{
 	domain_address::DomainAddress,
 	fee_keys::{Fee, FeeKey},
 	fixed_point::{Quantity, Rate, Ratio},
-	investments::InvestmentPortfolio,
+	investments::{ForeignInvestorStatus, InvestmentPortfolio},
 	locations::RestrictedTransferLocation,
 	oracles::OracleKey,
 	permissions::{PermissionRoles, PermissionScope, PermissionedCurrencyRole, PoolRole, Role},
@@ -80,7 +82,7 @@ use pallet_evm::{
 };
 use pallet_liquidity_pools_gateway::message::GatewayMessage;
 pub use pallet_loans::entities::{input::PriceCollectionInput, loans::ActiveLoanInfo};
-use pallet_loans::types::cashflow::CashflowPayment;
+use pallet_loans::types::cashflow::{CashflowPayment, PaymentStatus};
 use pallet_pool_system::{
 	pool_types::{PoolDetails, ScheduledUpdateDetails},
 	tranches::{TrancheIndex, TrancheLoc, TrancheSolution},
@@ -117,8 +119,8 @@ use runtime_common::{
 	permissions::{IsUnfrozenTrancheInvestor, PoolAdminCheck},
 	remarks::Remark,
 	rewards::SingleCurrencyMovement,
-	routing::{LPGatewayRouterProvider, MessageSerializer, RouterDispatcher, RouterId},
-	transfer_filter::{PreLpTransfer, PreNativeTransfer},
+	routing::{LPGatewayRouterProvider, MessageSerializer, RouterDispatcher, RouterFees, RouterId},
+	transfer_filter::{PreLpTransfer, PreNativeTransfer, PreTrancheTransfer},
 	xcm::AccountIdToLocation,
 	xcm_transactor, AllowanceDeposit, CurrencyED,
 };
@@ -1215,7 +1217,7 @@ impl<C: orml_tokens::Config> MutationHooks<AccountId, CurrencyId, Balance> for C
 	type PostDeposit = ();
 	type PostTransfer = ();
 	type PreDeposit = ();
-	type PreTransfer = ();
+	type PreTransfer = PreTrancheTransfer<Runtime>;
 }
 
 impl orml_tokens::Config for Runtime {
@@ -1351,6 +1353,14 @@ parameter_types! {
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxWriteOffPolicySize: u32 = 100;
 
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxCollateralsPerLoan: u32 = 10;
+
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxParticipantsPerLoan: u32 = 5;
+
+	pub const MaxParticipationsPerPool: u32 = 100;
+
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxFeedersPerKey: u32 = 5;
 }
@@ -1402,6 +1412,9 @@ impl pallet_loans::Config for Runtime {
 	type ItemId = ItemId;
 	type LoanId = LoanId;
 	type MaxActiveLoansPerPool = MaxActiveLoansPerPool;
+	type MaxCollateralsPerLoan = MaxCollateralsPerLoan;
+	type MaxParticipantsPerLoan = MaxParticipantsPerLoan;
+	type MaxParticipationsPerPool = MaxParticipationsPerPool;
 	type MaxWriteOffPolicySize = MaxWriteOffPolicySize;
 	type Moment = Millis;
 	type NonFungible = Uniques;
@@ -1469,6 +1482,12 @@ parameter_types! {
 		30 * MINUTES
 	};
 
+	// Bisection steps per order of the on-chain epoch solver
+	pub const EpochSolverSteps: u32 = 32;
+
+	// Investors put up for redemption at once when a tranche has matured
+	pub const MaxMaturedRedemptions: u32 = 100;
+
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = 23 * SECONDS_PER_HOUR + 50 * SECONDS_PER_MINUTE; // Just under a day
 	pub const DefaultMaxNAVAge: u64 = 0;
@@ -1508,7 +1527,9 @@ impl pallet_pool_system::Config for Runtime {
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
 	type DefaultMinEpochTime = DefaultMinEpochTime;
 	type EpochId = PoolEpochId;
+	type EpochSolverSteps = EpochSolverSteps;
 	type Investments = Investments;
+	type MaxMaturedRedemptions = MaxMaturedRedemptions;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
@@ -1606,7 +1627,8 @@ impl PoolUpdateGuard for UpdateGuard {
 		PoolId,
 		MaxTranches,
 	>;
-	type ScheduledUpdateDetails = ScheduledUpdateDetails<Rate, AssetStringLimit, MaxTranches>;
+	type ScheduledUpdateDetails =
+		ScheduledUpdateDetails<Rate, AssetStringLimit, MaxTranches, Balance>;
 
 	fn released(
 		pool: &Self::PoolDetails,
@@ -1759,28 +1781,57 @@ parameter_types! {
 	pub Sender: DomainAddress = gateway::get_gateway_domain_address::<Runtime>();
 	pub const MaxIncomingMessageSize: u32 = 1024;
 	pub const MaxRouterCount: u32 = 8;
+	pub const MaxOutboundRecords: u32 = 100;
+	pub const MaxOutboundResends: u32 = 3;
+	pub const InboundEntryExpiry: u64 = 30 * SECONDS_PER_DAY; // 1 month
+	pub const MessageRecoveryDisputeWindow: u64 = 2 * SECONDS_PER_DAY;
 }
 
 impl pallet_liquidity_pools_gateway::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type InboundEntryExpiry = InboundEntryExpiry;
 	type InboundMessageHandler = LiquidityPools;
 	type MaxIncomingMessageSize = MaxIncomingMessageSize;
+	type MaxOutboundRecords = MaxOutboundRecords;
+	type MaxOutboundResends = MaxOutboundResends;
 	type MaxRouterCount = MaxRouterCount;
 	type Message = pallet_liquidity_pools::Message;
 	type MessageQueue = LiquidityPoolsGatewayQueue;
+	type MessageRecoveryDisputeWindow = MessageRecoveryDisputeWindow;
 	type MessageSender = MessageSerializer<RouterDispatcher<Runtime>, ()>;
+	type OutboundFees = RouterFees;
+	type RateLimitKey = pallet_liquidity_pools::RateLimitKey;
 	type RouterId = RouterId;
 	type RouterProvider = LPGatewayRouterProvider;
 	type RuntimeEvent = RuntimeEvent;
 	type Sender = Sender;
 	type SessionId = LPGatewaySessionId;
+	type Time = Timestamp;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub LPGatewayQueueLanes: Vec<(gateway::MessageLane, Perbill)> = vec![
+		(gateway::MessageLane::Admin, Perbill::from_percent(20)),
+		(gateway::MessageLane::Price, Perbill::from_percent(30)),
+		(gateway::MessageLane::Investor, Perbill::from_percent(50)),
+	];
+	pub const LPGatewayQueueMaxOrderingKeys: u32 = 50;
+	pub const LPGatewayQueueMaxRetries: u32 = 5;
+	pub const LPGatewayQueueRetryDelay: BlockNumber = 10 * MINUTES;
+}
+
 impl pallet_liquidity_pools_gateway_queue::Config for Runtime {
+	type Lane = gateway::MessageLane;
+	type LaneProvider = gateway::LPGatewayLaneProvider;
+	type Lanes = LPGatewayQueueLanes;
+	type MaxOrderingKeys = LPGatewayQueueMaxOrderingKeys;
+	type MaxRetries = LPGatewayQueueMaxRetries;
 	type Message = GatewayMessage<pallet_liquidity_pools::Message, RouterId>;
 	type MessageNonce = LPGatewayQueueMessageNonce;
 	type MessageProcessor = LiquidityPoolsGateway;
+	type OrderingKey = Option<u64>;
+	type RetryDelay = LPGatewayQueueRetryDelay;
 	type RuntimeEvent = RuntimeEvent;
 }
 
@@ -1896,6 +1947,14 @@ impl pallet_axelar_router::Config for Runtime {
 	type Transactor = EthereumTransaction;
 }
 
+impl pallet_hyperlane_router::Config for Runtime {
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Middleware = RouterId;
+	type Receiver = MessageSerializer<(), LiquidityPoolsGateway>;
+	type RuntimeEvent = RuntimeEvent;
+	type Transactor = EthereumTransaction;
+}
+
 impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type MaxTurnout =
@@ -2116,6 +2175,7 @@ construct_runtime!(
 		Ethereum: pallet_ethereum::{Pallet, Config<T>, Call, Storage, Event, Origin} = 163,
 		EthereumTransaction: pallet_ethereum_transaction::{Pallet, Storage} = 164,
 		AxelarRouter: pallet_axelar_router::{Pallet, Call, Storage, Event<T>} = 165,
+		HyperlaneRouter: pallet_hyperlane_router::{Pallet, Call, Storage, Event<T>} = 167,
 
 		// Our pallets (part 2)
 		// Removed: Migration = 199
@@ -2352,6 +2412,15 @@ impl_runtime_apis! {
 			).ok()
 		}
 
+		fn solve_epoch(pool_id: PoolId) -> Option<EpochSolution<Balance, MaxTranches>>{
+			let pool = pallet_pool_system::Pool::<Runtime>::get(pool_id)?;
+			let epoch_execution_info = pallet_pool_system::EpochExecution::<Runtime>::get(pool_id)?;
+			pallet_pool_system::Pallet::<Runtime>::solve_epoch(
+				&pool,
+				&epoch_execution_info
+			).ok()?
+		}
+
 		fn tranche_token_price(pool_id: PoolId, tranche: TrancheLoc<TrancheId>) -> Option<Quantity>{
 			let index: usize = PoolSystem::pool(pool_id)?.tranches.tranche_index(&tranche)?.try_into().ok()?;
 			Self::tranche_token_prices(pool_id)?.get(index).cloned()
@@ -2436,6 +2505,10 @@ impl_runtime_apis! {
 		fn expected_cashflows(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<CashflowPayment<Balance>>, DispatchError> {
 			Loans::expected_cashflows(pool_id, loan_id)
 		}
+
+		fn payment_statuses(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<(CashflowPayment<Balance>, PaymentStatus)>, DispatchError> {
+			Loans::payment_statuses(pool_id, loan_id)
+		}
 	}
 
 	// Investment Runtime APIs
@@ -2445,6 +2518,13 @@ impl_runtime_apis! {
 		}
 	}
 
+	// ForeignInvestmentsApi
+	impl runtime_common::apis::ForeignInvestmentsApi<Block, DomainAddress, InvestmentId, ForeignInvestorStatus<Balance, CurrencyId, MessageHash>> for Runtime {
+		fn investor_status(investor: DomainAddress, investment_id: InvestmentId) -> Option<ForeignInvestorStatus<Balance, CurrencyId, MessageHash>> {
+			runtime_common::foreign_investor_status::get_investor_status::<Runtime>(investor, investment_id)
+		}
+	}
+
 	// AccountConversionApi
 	impl runtime_common::apis::AccountConversionApi<Block, AccountId> for Runtime {
 		fn conversion_of(location: Location) -> Option<AccountId> {
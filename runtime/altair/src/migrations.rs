@@ -59,4 +59,57 @@ pub type UpgradeAltair1505 = (
 		PalletLiquidityPoolsAxelarGateway,
 		<Runtime as frame_system::Config>::DbWeight,
 	>,
+	// Add compounding schedules and day count conventions to interest accrual rates
+	VersionedMigration<
+		3,
+		4,
+		pallet_interest_accrual::migrations::Migration<Runtime>,
+		pallet_interest_accrual::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Add day count conventions to loan interest rates and pending loan changes
+	VersionedMigration<
+		4,
+		5,
+		(
+			pallet_loans::migrations::Migration<Runtime>,
+			runtime_common::migrations::loans_noted_changes::Migration<Runtime>,
+		),
+		pallet_loans::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Add account code for the Hyperlane router precompile
+	runtime_common::migrations::precompile_account_codes::Migration<Runtime>,
+	// Assign the queued LP gateway messages to the queue lanes
+	VersionedMigration<
+		0,
+		1,
+		runtime_common::migrations::gateway_queue_lanes::Migration<Runtime>,
+		pallet_liquidity_pools_gateway_queue::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Add retry counters to the failed LP gateway messages and schedule their retries
+	VersionedMigration<
+		1,
+		2,
+		runtime_common::migrations::gateway_queue_retries::Migration<Runtime>,
+		pallet_liquidity_pools_gateway_queue::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Add tranche limits and terms to the scheduled pool updates
+	VersionedMigration<
+		2,
+		3,
+		pallet_pool_system::migrations::Migration<Runtime>,
+		pallet_pool_system::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Widen the LP gateway domain hooks to fit non-EVM addresses
+	VersionedMigration<
+		3,
+		4,
+		runtime_common::migrations::gateway_domain_hooks::Migration<Runtime>,
+		pallet_liquidity_pools_gateway::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
 );
@@ -394,4 +394,140 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `Permissions::Permission` (r:1 w:0)
+	/// Proof: `Permissions::Permission` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::ActiveLoans` (r:1 w:0)
+	/// Proof: `Loans::ActiveLoans` (`max_values`: None, `max_size`: Some(373026), added: 375501, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::NotedChange` (r:0 w:1)
+	/// Proof: `PoolSystem::NotedChange` (`max_values`: None, `max_size`: Some(5184), added: 7659, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn propose_collateral_substitution(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `971 + n * (316 ±0)`
+		//  Estimated: `376491`
+		// Minimum execution time: 40_185_000 picoseconds.
+		Weight::from_parts(41_576_487, 0)
+			.saturating_add(Weight::from_parts(0, 376491))
+			// Standard Error: 12_727
+			.saturating_add(Weight::from_parts(456_804, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PoolSystem::NotedChange` (r:1 w:1)
+	/// Proof: `PoolSystem::NotedChange` (`max_values`: None, `max_size`: Some(5184), added: 7659, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::Pool` (r:1 w:0)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::PortfolioValuation` (r:1 w:1)
+	/// Proof: `Loans::PortfolioValuation` (`max_values`: None, `max_size`: Some(24050), added: 26525, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::ActiveLoans` (r:1 w:1)
+	/// Proof: `Loans::ActiveLoans` (`max_values`: None, `max_size`: Some(373026), added: 375501, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `InterestAccrual::Rates` (r:1 w:0)
+	/// Proof: `InterestAccrual::Rates` (`max_values`: Some(1), `max_size`: Some(36002), added: 36497, mode: `MaxEncodedLen`)
+	/// Storage: `InterestAccrual::LastUpdated` (r:1 w:0)
+	/// Proof: `InterestAccrual::LastUpdated` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:11 w:11)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:22)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::ItemPriceOf` (r:0 w:11)
+	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn apply_collateral_substitution(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37405 + n * (340 ±0)`
+		//  Estimated: `376491`
+		// Minimum execution time: 390_415_000 picoseconds.
+		Weight::from_parts(393_565_919, 0)
+			.saturating_add(Weight::from_parts(0, 376491))
+			// Standard Error: 38_082
+			.saturating_add(Weight::from_parts(633_275, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(47))
+	}
+	/// Storage: `Permissions::Permission` (r:1 w:0)
+	/// Proof: `Permissions::Permission` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::CreatedLoan` (r:1 w:0)
+	/// Proof: `Loans::CreatedLoan` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Syndications` (r:1 w:0)
+	/// Proof: `Loans::Syndications` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::Pool` (r:5 w:0)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::NotedChange` (r:0 w:1)
+	/// Proof: `PoolSystem::NotedChange` (`max_values`: None, `max_size`: Some(5184), added: 7659, mode: `MaxEncodedLen`)
+	fn propose_syndication() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2356`
+		//  Estimated: `17430`
+		// Minimum execution time: 61_233_000 picoseconds.
+		Weight::from_parts(62_816_000, 0)
+			.saturating_add(Weight::from_parts(0, 17430))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PoolSystem::NotedChange` (r:1 w:1)
+	/// Proof: `PoolSystem::NotedChange` (`max_values`: None, `max_size`: Some(5184), added: 7659, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::Pool` (r:6 w:0)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::CreatedLoan` (r:1 w:0)
+	/// Proof: `Loans::CreatedLoan` (`max_values`: None, `max_size`: Some(245), added: 2720, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Syndications` (r:1 w:1)
+	/// Proof: `Loans::Syndications` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `Permissions::Permission` (r:5 w:0)
+	/// Proof: `Permissions::Permission` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::LastLoanId` (r:5 w:5)
+	/// Proof: `Loans::LastLoanId` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Participations` (r:5 w:5)
+	/// Proof: `Loans::Participations` (`max_values`: None, `max_size`: Some(4009), added: 6484, mode: `MaxEncodedLen`)
+	fn apply_syndication() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3721`
+		//  Estimated: `33430`
+		// Minimum execution time: 142_509_000 picoseconds.
+		Weight::from_parts(146_127_000, 0)
+			.saturating_add(Weight::from_parts(0, 33430))
+			.saturating_add(T::DbWeight::get().reads(25))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: `PoolSystem::Pool` (r:1 w:0)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `InterestAccrual::Rates` (r:1 w:0)
+	/// Proof: `InterestAccrual::Rates` (`max_values`: Some(1), `max_size`: Some(10802), added: 11297, mode: `MaxEncodedLen`)
+	/// Storage: `OraclePriceCollection::Collection` (r:2 w:0)
+	/// Proof: `OraclePriceCollection::Collection` (`max_values`: None, `max_size`: Some(7542), added: 10017, mode: `MaxEncodedLen`)
+	/// Storage: `OraclePriceCollection::CollectionInfo` (r:1 w:0)
+	/// Proof: `OraclePriceCollection::CollectionInfo` (`max_values`: None, `max_size`: Some(3058), added: 5533, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::ActiveLoans` (r:2 w:0)
+	/// Proof: `Loans::ActiveLoans` (`max_values`: None, `max_size`: Some(373026), added: 375501, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Participations` (r:1 w:0)
+	/// Proof: `Loans::Participations` (`max_values`: None, `max_size`: Some(4009), added: 6484, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::Syndications` (r:1 w:0)
+	/// Proof: `Loans::Syndications` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::PortfolioValuation` (r:0 w:1)
+	/// Proof: `Loans::PortfolioValuation` (`max_values`: None, `max_size`: Some(26450), added: 28925, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn participation_valuation(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `12387 + n * (412 ±0)`
+		//  Estimated: `752982`
+		// Minimum execution time: 121_870_000 picoseconds.
+		Weight::from_parts(84_906_214, 0)
+			.saturating_add(Weight::from_parts(0, 752982))
+			// Standard Error: 71_208
+			.saturating_add(Weight::from_parts(35_127_405, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
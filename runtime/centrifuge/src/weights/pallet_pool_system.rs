@@ -286,4 +286,140 @@ impl<T: frame_system::Config> pallet_pool_system::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2604).saturating_mul(n.into()))
 	}
+	/// Storage: `Permissions::Permission` (r:1 w:0)
+	/// Proof: `Permissions::Permission` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::Pool` (r:1 w:0)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::NotedChange` (r:0 w:1)
+	/// Proof: `PoolSystem::NotedChange` (`max_values`: None, `max_size`: Some(5184), added: 7659, mode: `MaxEncodedLen`)
+	fn propose_status_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `554`
+		//  Estimated: `4278`
+		// Minimum execution time: 28_133_000 picoseconds.
+		Weight::from_parts(29_044_000, 0)
+			.saturating_add(Weight::from_parts(0, 4278))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PoolSystem::NotedChange` (r:1 w:1)
+	/// Proof: `PoolSystem::NotedChange` (`max_values`: None, `max_size`: Some(5184), added: 7659, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::Pool` (r:1 w:1)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrmlTokens::TotalIssuance` (r:5 w:0)
+	/// Proof: `OrmlTokens::TotalIssuance` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::PortfolioValuation` (r:1 w:0)
+	/// Proof: `Loans::PortfolioValuation` (`max_values`: None, `max_size`: Some(24050), added: 26525, mode: `MaxEncodedLen`)
+	/// Storage: `Investments::ActiveInvestOrders` (r:5 w:0)
+	/// Proof: `Investments::ActiveInvestOrders` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Investments::ActiveRedeemOrders` (r:5 w:0)
+	/// Proof: `Investments::ActiveRedeemOrders` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::PoolDeposit` (r:1 w:1)
+	/// Proof: `PoolSystem::PoolDeposit` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::AccountDeposit` (r:1 w:1)
+	/// Proof: `PoolSystem::AccountDeposit` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::ScheduledUpdate` (r:0 w:1)
+	/// Proof: `PoolSystem::ScheduledUpdate` (`max_values`: None, `max_size`: Some(1504), added: 3979, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::TrancheLimits` (r:0 w:5)
+	/// Proof: `PoolSystem::TrancheLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::TrancheTerms` (r:0 w:5)
+	/// Proof: `PoolSystem::TrancheTerms` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::TrancheLosses` (r:0 w:5)
+	/// Proof: `PoolSystem::TrancheLosses` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 5]`.
+	fn apply_status_change(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1466 + n * (156 ±0)`
+		//  Estimated: `27515 + n * (2531 ±0)`
+		// Minimum execution time: 78_408_000 picoseconds.
+		Weight::from_parts(61_970_317, 0)
+			.saturating_add(Weight::from_parts(0, 27515))
+			// Standard Error: 49_105
+			.saturating_add(Weight::from_parts(16_839_214, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(n.into()))
+	}
+	/// Storage: `PoolSystem::TrancheLimits` (r:5 w:0)
+	/// Proof: `PoolSystem::TrancheLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 5]`.
+	fn solve_epoch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1149 + n * (105 ±0)`
+		//  Estimated: `1906 + n * (2547 ±0)`
+		// Minimum execution time: 412_605_000 picoseconds.
+		Weight::from_parts(96_481_322, 0)
+			.saturating_add(Weight::from_parts(0, 1906))
+			// Standard Error: 1_203_511
+			.saturating_add(Weight::from_parts(318_774_603, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(n.into()))
+	}
+	/// Storage: `PoolSystem::Pool` (r:1 w:0)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::TrancheTerms` (r:1 w:0)
+	/// Proof: `PoolSystem::TrancheTerms` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `OrmlTokens::Accounts` (r:101 w:101)
+	/// Proof: `OrmlTokens::Accounts` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `Investments::RedeemOrders` (r:100 w:100)
+	/// Proof: `Investments::RedeemOrders` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Investments::RedeemOrderId` (r:1 w:0)
+	/// Proof: `Investments::RedeemOrderId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Permissions::Permission` (r:100 w:0)
+	/// Proof: `Permissions::Permission` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::LastDeposit` (r:200 w:0)
+	/// Proof: `PoolSystem::LastDeposit` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Investments::ActiveRedeemOrders` (r:1 w:1)
+	/// Proof: `Investments::ActiveRedeemOrders` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn redeem_matured(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1203 + n * (412 ±0)`
+		//  Estimated: `4278 + n * (5142 ±0)`
+		// Minimum execution time: 40_816_000 picoseconds.
+		Weight::from_parts(37_650_113, 0)
+			.saturating_add(Weight::from_parts(0, 4278))
+			// Standard Error: 29_874
+			.saturating_add(Weight::from_parts(52_104_890, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5142).saturating_mul(n.into()))
+	}
+	/// Storage: `PoolSystem::Pool` (r:1 w:0)
+	/// Proof: `PoolSystem::Pool` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Loans::PortfolioValuation` (r:1 w:0)
+	/// Proof: `Loans::PortfolioValuation` (`max_values`: None, `max_size`: Some(24050), added: 26525, mode: `MaxEncodedLen`)
+	/// Storage: `PoolFees::PortfolioValuation` (r:1 w:0)
+	/// Proof: `PoolFees::PortfolioValuation` (`max_values`: None, `max_size`: Some(4850), added: 7325, mode: `MaxEncodedLen`)
+	/// Storage: `PoolSystem::TrancheLosses` (r:5 w:5)
+	/// Proof: `PoolSystem::TrancheLosses` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OrmlTokens::TotalIssuance` (r:5 w:0)
+	/// Proof: `OrmlTokens::TotalIssuance` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 5]`.
+	fn allocate_losses(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1627 + n * (121 ±0)`
+		//  Estimated: `27515 + n * (2563 ±0)`
+		// Minimum execution time: 57_902_000 picoseconds.
+		Weight::from_parts(44_318_640, 0)
+			.saturating_add(Weight::from_parts(0, 27515))
+			// Standard Error: 40_556
+			.saturating_add(Weight::from_parts(14_051_902, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(n.into()))
+	}
 }
@@ -13,6 +13,7 @@
 //! Runtime apis useful in the Centrifuge ecosystem
 pub use account_conversion::*;
 pub use anchors::*;
+pub use foreign_investments::*;
 pub use investments::*;
 pub use loans::*;
 pub use order_book::*;
@@ -22,6 +23,7 @@ pub use rewards::*;
 
 mod account_conversion;
 mod anchors;
+mod foreign_investments;
 mod investments;
 mod loans;
 mod order_book;
@@ -25,6 +25,7 @@ decl_runtime_apis! {
 	///
 	/// Note: The runtime api is pallet specific, while the RPC methods
 	///       are more focused on domain-specific logic
+	#[api_version(2)]
 	pub trait PoolsApi<PoolId, TrancheId, Balance, Currency, BalanceRatio, MaxTranches>
 	where
 		PoolId: Codec,
@@ -38,6 +39,8 @@ decl_runtime_apis! {
 
 		fn inspect_epoch_solution(pool_id: PoolId, solution: Vec<TrancheSolution>) -> Option<EpochSolution<Balance, MaxTranches>>;
 
+		fn solve_epoch(pool_id: PoolId) -> Option<EpochSolution<Balance, MaxTranches>>;
+
 		fn tranche_token_price(pool_id: PoolId, tranche: TrancheLoc<TrancheId>) -> Option<BalanceRatio>;
 
 		fn tranche_token_prices(pool_id: PoolId) -> Option<Vec<BalanceRatio>>;
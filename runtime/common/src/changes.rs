@@ -2,7 +2,10 @@ use frame_support::pallet_prelude::RuntimeDebug;
 use pallet_loans::entities::changes::Change as LoansChange;
 use pallet_oracle_collection::types::Change as OracleCollectionChange;
 use pallet_pool_fees::types::Change as PoolFeesChange;
-use pallet_pool_system::pool_types::changes::{PoolChangeProposal, Requirement};
+use pallet_pool_system::pool_types::{
+	changes::{Change as PoolSystemChange, PoolChangeProposal, Requirement},
+	PoolStatus,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
@@ -24,6 +27,7 @@ pub enum RuntimeChange<T: Changeable, Options: Clone = ()> {
 	Loans(LoansChange<T>),
 	OracleCollection(OracleCollectionChange<T>),
 	PoolFee(PoolFeesChange<T>),
+	PoolSystem(PoolSystemChange),
 	_Unreachable(PhantomData<Options>),
 }
 
@@ -53,6 +57,7 @@ impl<T: Changeable, Options: Clone> RuntimeChange<T, Options> {
 					LoanMutation::InterestPayments(_) => vec![week, blocked],
 					LoanMutation::PayDownSchedule(_) => vec![week, blocked],
 					LoanMutation::InterestRate(_) => vec![epoch],
+					LoanMutation::Restructure(_) => vec![week, blocked],
 					LoanMutation::Internal(mutation) => match mutation {
 						InternalMutation::ValuationMethod(_) => vec![week, blocked],
 						InternalMutation::ProbabilityOfDefault(_) => vec![epoch],
@@ -62,6 +67,8 @@ impl<T: Changeable, Options: Clone> RuntimeChange<T, Options> {
 				},
 				LoansChange::<T>::Policy(_) => vec![week, blocked],
 				LoansChange::<T>::TransferDebt(_, _, _, _) => vec![],
+				LoansChange::<T>::CollateralSubstitution(_, _) => vec![week, blocked],
+				LoansChange::<T>::Syndication(_, _) => vec![week, blocked],
 			},
 			RuntimeChange::OracleCollection(change) => match change {
 				OracleCollectionChange::CollectionInfo(_) => vec![],
@@ -69,6 +76,13 @@ impl<T: Changeable, Options: Clone> RuntimeChange<T, Options> {
 			RuntimeChange::PoolFee(pool_fees_change) => match pool_fees_change {
 				PoolFeesChange::AppendFee(_, _, _) => vec![week],
 			},
+			RuntimeChange::PoolSystem(pool_system_change) => match pool_system_change {
+				PoolSystemChange::Status(status) => match status {
+					PoolStatus::Open | PoolStatus::Paused => vec![],
+					PoolStatus::WindDown => vec![week],
+					PoolStatus::Closed => vec![epoch],
+				},
+			},
 			RuntimeChange::_Unreachable(_) => vec![],
 		}
 	}
@@ -125,3 +139,48 @@ macro_rules! runtime_change_support {
 runtime_change_support!(LoansChange, Loans);
 runtime_change_support!(OracleCollectionChange, OracleCollection);
 runtime_change_support!(PoolFeesChange, PoolFee);
+
+/// Used by `ChangeGuard::note()`
+impl<T: Changeable, Option: Clone> From<PoolSystemChange> for RuntimeChange<T, Option> {
+	fn from(change: PoolSystemChange) -> RuntimeChange<T, Option> {
+		RuntimeChange::PoolSystem(change)
+	}
+}
+
+/// Used `ChangeGuard::released()`
+impl<T: Changeable, Option: Clone> TryInto<PoolSystemChange> for RuntimeChange<T, Option> {
+	type Error = DispatchError;
+
+	fn try_into(self) -> Result<PoolSystemChange, DispatchError> {
+		match self {
+			RuntimeChange::PoolSystem(change) => Ok(change),
+			_ => Err(DispatchError::Other("Expected another RuntimeChange")),
+		}
+	}
+}
+
+/// Runtime changes before the loans v5 storage, for migration
+pub mod v4 {
+	use pallet_loans::entities::changes::v4::Change as LoansChange;
+
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub enum RuntimeChange<T: Changeable, Options: Clone = ()> {
+		Loans(LoansChange<T>),
+		OracleCollection(OracleCollectionChange<T>),
+		PoolFee(PoolFeesChange<T>),
+		_Unreachable(PhantomData<Options>),
+	}
+
+	impl<T: Changeable, Options: Clone> RuntimeChange<T, Options> {
+		pub fn migrate(self) -> super::RuntimeChange<T, Options> {
+			match self {
+				Self::Loans(change) => super::RuntimeChange::Loans(change.migrate()),
+				Self::OracleCollection(change) => super::RuntimeChange::OracleCollection(change),
+				Self::PoolFee(change) => super::RuntimeChange::PoolFee(change),
+				Self::_Unreachable(options) => super::RuntimeChange::_Unreachable(options),
+			}
+		}
+	}
+}
@@ -49,6 +49,7 @@ type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, Callab
 // - https://github.com/centrifuge/liquidity-pools/blob/release-v1.0/src/gateway/routers/axelar/Forwarder.sol#L29
 
 pub const LP_AXELAR_GATEWAY: u64 = 0x800;
+pub const LP_HYPERLANE_RECIPIENT: u64 = 0x803;
 
 #[precompile_utils::precompile_name_from_address]
 pub type RuntimePrecompilesAt<R, Symbol> = (
@@ -79,6 +80,11 @@ pub type RuntimePrecompilesAt<R, Symbol> = (
 		pallet_axelar_router::Pallet<R>,
 		CallableByContract,
 	>,
+	PrecompileAt<
+		AddressU64<LP_HYPERLANE_RECIPIENT>,
+		pallet_hyperlane_router::Pallet<R>,
+		CallableByContract,
+	>,
 );
 
 pub type Precompiles<R, Symbol> = PrecompileSetBuilder<R, RuntimePrecompilesAt<R, Symbol>>;
@@ -10,11 +10,17 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
+use cfg_traits::liquidity_pools::MessageLaneProvider;
 use cfg_types::domain_address::{Domain, DomainAddress};
+use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo};
+use pallet_liquidity_pools::Message;
+use pallet_liquidity_pools_gateway::message::GatewayMessage;
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_core::crypto::AccountId32;
 use sp_runtime::traits::{AccountIdConversion, Get};
 
+use crate::routing::RouterId;
+
 pub fn get_gateway_domain_address<T>() -> DomainAddress
 where
 	T: pallet_evm_chain_id::Config + staging_parachain_info::Config,
@@ -25,3 +31,110 @@ where
 
 	DomainAddress::new(Domain::Evm(chain_id), sender_account.into())
 }
+
+/// Lanes of the LP gateway queue, in priority order.
+#[derive(
+	Debug, Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+)]
+pub enum MessageLane {
+	/// Upgrades, restrictions and pool, tranche and asset configuration.
+	Admin,
+
+	/// Tranche price updates.
+	Price,
+
+	/// Transfers, investments, redemptions and proofs.
+	Investor,
+}
+
+impl MessageLane {
+	fn of(message: &Message) -> Self {
+		match message {
+			Message::Batch(content) => content
+				.clone()
+				.into_iter()
+				.map(|message| Self::of(&message))
+				.min()
+				.unwrap_or(MessageLane::Investor),
+			Message::Forwarded { message, .. } => Self::of(&message.clone().into()),
+			Message::ScheduleUpgrade { .. }
+			| Message::CancelUpgrade { .. }
+			| Message::RecoverAssets { .. }
+			| Message::UpdateCentrifugeGasPrice { .. }
+			| Message::AddAsset { .. }
+			| Message::AddPool { .. }
+			| Message::AddTranche { .. }
+			| Message::AllowAsset { .. }
+			| Message::DisallowAsset { .. }
+			| Message::UpdateTrancheMetadata { .. }
+			| Message::UpdateTrancheHook { .. }
+			| Message::UpdateRestriction { .. }
+			| Message::InitiateMessageRecovery { .. }
+			| Message::DisputeMessageRecovery { .. } => MessageLane::Admin,
+			Message::UpdateTranchePrice { .. } => MessageLane::Price,
+			_ => MessageLane::Investor,
+		}
+	}
+}
+
+/// Returns the pool of the message, or `None` if it has no pool or it is a
+/// batch with messages of different pools.
+fn message_pool_id(message: &Message) -> Option<u64> {
+	match message {
+		Message::Batch(content) => {
+			let mut pool_ids = content
+				.clone()
+				.into_iter()
+				.map(|message| message_pool_id(&message));
+
+			let first = pool_ids.next().flatten();
+
+			if pool_ids.all(|pool_id| pool_id == first) {
+				first
+			} else {
+				None
+			}
+		}
+		Message::Forwarded { message, .. } => message_pool_id(&message.clone().into()),
+		Message::AddPool { pool_id }
+		| Message::AddTranche { pool_id, .. }
+		| Message::AllowAsset { pool_id, .. }
+		| Message::DisallowAsset { pool_id, .. }
+		| Message::UpdateTranchePrice { pool_id, .. }
+		| Message::UpdateTrancheMetadata { pool_id, .. }
+		| Message::UpdateTrancheHook { pool_id, .. }
+		| Message::TransferTrancheTokens { pool_id, .. }
+		| Message::UpdateRestriction { pool_id, .. }
+		| Message::DepositRequest { pool_id, .. }
+		| Message::RedeemRequest { pool_id, .. }
+		| Message::FulfilledDepositRequest { pool_id, .. }
+		| Message::FulfilledRedeemRequest { pool_id, .. }
+		| Message::CancelDepositRequest { pool_id, .. }
+		| Message::CancelRedeemRequest { pool_id, .. }
+		| Message::FulfilledCancelDepositRequest { pool_id, .. }
+		| Message::FulfilledCancelRedeemRequest { pool_id, .. }
+		| Message::TriggerRedeemRequest { pool_id, .. } => Some(*pool_id),
+		_ => None,
+	}
+}
+
+/// Lane provider used in the LP gateway queue.
+///
+/// Messages are ordered by pool, while messages without a pool are ordered
+/// among themselves.
+pub struct LPGatewayLaneProvider;
+
+impl MessageLaneProvider<GatewayMessage<Message, RouterId>> for LPGatewayLaneProvider {
+	type Lane = MessageLane;
+	type OrderingKey = Option<u64>;
+
+	fn lane(message: &GatewayMessage<Message, RouterId>) -> (MessageLane, Option<u64>) {
+		let message = match message {
+			GatewayMessage::Inbound { message, .. } | GatewayMessage::Outbound { message, .. } => {
+				message
+			}
+		};
+
+		(MessageLane::of(message), message_pool_id(message))
+	}
+}
@@ -440,6 +440,160 @@ pub mod investment_portfolios {
 	}
 }
 
+pub mod foreign_investor_status {
+	use cfg_primitives::{AccountId, Balance, InvestmentId, PoolId};
+	use cfg_traits::{
+		liquidity_pools::{LpMessageBatch, MessageHash},
+		swaps::TokenSwaps,
+		PoolInspect,
+	};
+	use cfg_types::{
+		domain_address::DomainAddress,
+		investments::{ForeignInvestorStatus, PendingSwap},
+		tokens::CurrencyId,
+	};
+	use frame_support::traits::Get;
+	use pallet_liquidity_pools::Message;
+
+	/// Get the state of the cross-chain deposit and redeem requests of an
+	/// investor for an investment.
+	///
+	/// Returns `None` if the pool of the investment does not exist.
+	pub fn get_investor_status<T>(
+		investor: DomainAddress,
+		investment_id: InvestmentId,
+	) -> Option<ForeignInvestorStatus<Balance, CurrencyId, MessageHash>>
+	where
+		T: frame_system::Config<AccountId = AccountId>
+			+ pallet_foreign_investments::Config<
+				InvestmentId = InvestmentId,
+				ForeignBalance = Balance,
+				TrancheBalance = Balance,
+				SwapBalance = Balance,
+				CurrencyId = CurrencyId,
+			> + pallet_investments::Config<InvestmentId = InvestmentId, Amount = Balance>
+			+ pallet_liquidity_pools_gateway::Config<Message = Message>
+			+ pallet_pool_system::Config<PoolId = PoolId, CurrencyId = CurrencyId>,
+	{
+		pallet_pool_system::Pallet::<T>::currency_for(investment_id.0)?;
+
+		let who = investor.account();
+
+		let pending_swap = |order_id| {
+			<T as pallet_foreign_investments::Config>::OrderBook::get_order_details(order_id).map(
+				|info| PendingSwap {
+					currency_in: info.swap.currency_in,
+					currency_out: info.swap.currency_out,
+					amount_out: info.swap.amount_out,
+				},
+			)
+		};
+
+		let investment =
+			pallet_foreign_investments::ForeignInvestmentInfo::<T>::get(&who, investment_id);
+		let redemption =
+			pallet_foreign_investments::ForeignRedemptionInfo::<T>::get(&who, investment_id);
+
+		let invest_order = pallet_investments::InvestOrders::<T>::get(&who, investment_id)
+			.map(|order| order.amount())
+			.unwrap_or_default();
+		let redeem_order = pallet_investments::RedeemOrders::<T>::get(&who, investment_id)
+			.map(|order| order.amount())
+			.unwrap_or_default();
+
+		let collectable_investment =
+			pallet_investments::Pallet::<T>::collectable_investment(&who, investment_id)
+				.unwrap_or_default();
+		let collectable_redemption =
+			pallet_investments::Pallet::<T>::collectable_redemption(&who, investment_id)
+				.unwrap_or_default();
+
+		let last_record_nonce = pallet_liquidity_pools_gateway::OutboundRecordNonce::<T>::get();
+		let first_record_nonce = last_record_nonce.saturating_sub(
+			<T as pallet_liquidity_pools_gateway::Config>::MaxOutboundRecords::get().into(),
+		);
+
+		let fulfilled_message_hashes =
+			(first_record_nonce..=last_record_nonce)
+				.filter_map(pallet_liquidity_pools_gateway::OutboundRecordHashes::<T>::get)
+				.filter(|hash| {
+					pallet_liquidity_pools_gateway::OutboundRecords::<T>::get(hash).is_some_and(
+						|record| {
+							record.domain == investor.domain()
+								&& record.message.submessages().iter().any(|message| {
+									is_fulfillment_of(message, investment_id, &investor)
+								})
+						},
+					)
+				})
+				.collect();
+
+		Some(ForeignInvestorStatus {
+			deposit_currency: investment.as_ref().map(|info| info.foreign_currency),
+			pending_foreign_deposit: investment
+				.as_ref()
+				.map(|info| info.foreign_amount)
+				.unwrap_or_default(),
+			deposit_swap: investment
+				.and_then(|info| info.order_id)
+				.and_then(pending_swap),
+			redeem_currency: redemption.as_ref().map(|info| info.foreign_currency),
+			pending_redeemed_tranche_tokens: redemption
+				.as_ref()
+				.map(|info| info.collected_tranche_tokens)
+				.unwrap_or_default(),
+			redeem_swap: redemption
+				.and_then(|info| info.order_id)
+				.and_then(pending_swap),
+			invest_order,
+			redeem_order,
+			collectable_tranche_tokens: collectable_investment.amount_collected,
+			collectable_currency: collectable_redemption.amount_collected,
+			fulfilled_message_hashes,
+		})
+	}
+
+	/// Checks if the message fulfills a request of the investor for the
+	/// investment. Messages address the investor by its bytes in its domain.
+	fn is_fulfillment_of(
+		message: &Message,
+		(pool_id, tranche_id): InvestmentId,
+		investor: &DomainAddress,
+	) -> bool {
+		match message {
+			Message::FulfilledDepositRequest {
+				pool_id: message_pool_id,
+				tranche_id: message_tranche_id,
+				investor: message_investor,
+				..
+			}
+			| Message::FulfilledRedeemRequest {
+				pool_id: message_pool_id,
+				tranche_id: message_tranche_id,
+				investor: message_investor,
+				..
+			}
+			| Message::FulfilledCancelDepositRequest {
+				pool_id: message_pool_id,
+				tranche_id: message_tranche_id,
+				investor: message_investor,
+				..
+			}
+			| Message::FulfilledCancelRedeemRequest {
+				pool_id: message_pool_id,
+				tranche_id: message_tranche_id,
+				investor: message_investor,
+				..
+			} => {
+				*message_pool_id == pool_id
+					&& *message_tranche_id == tranche_id
+					&& *message_investor == investor.bytes()
+			}
+			_ => false,
+		}
+	}
+}
+
 pub mod xcm_transactor {
 	use parity_scale_codec::{Decode, Encode};
 	use scale_info::TypeInfo;
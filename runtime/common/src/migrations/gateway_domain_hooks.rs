@@ -0,0 +1,82 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+#[cfg(feature = "try-runtime")]
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use pallet_liquidity_pools_gateway::DomainHookAddress;
+#[cfg(feature = "try-runtime")]
+use sp_arithmetic::traits::SaturatedConversion;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+const LOG_PREFIX: &str = "GatewayDomainHooks";
+
+/// Widens the 20 bytes EVM domain hooks to the 32 bytes hooks which also fit
+/// non-EVM addresses. EVM hooks are kept in the first 20 bytes.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for Migration<T>
+where
+	T: pallet_liquidity_pools_gateway::Config,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: u64 = 0;
+
+		DomainHookAddress::<T>::translate_values::<[u8; 20], _>(|hook| {
+			count = count.saturating_add(1);
+
+			let mut address = [0; 32];
+			address[..20].copy_from_slice(&hook);
+
+			Some(address)
+		});
+
+		log::info!("{LOG_PREFIX}: Migrated {count} domain hooks");
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		let count: u64 = DomainHookAddress::<T>::iter_keys().count().saturated_into();
+
+		log::info!("{LOG_PREFIX}: Pre checks done!");
+
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+		let pre_count: u64 = Decode::decode(&mut pre_state.as_slice())
+			.expect("pre_upgrade provides a valid state; qed");
+
+		let count: u64 = DomainHookAddress::<T>::iter_values()
+			.count()
+			.saturated_into();
+
+		assert_eq!(
+			pre_count, count,
+			"{LOG_PREFIX} POST: Mismatching number of domain hooks after migration!"
+		);
+
+		log::info!("{LOG_PREFIX}: Post checks done!");
+
+		Ok(())
+	}
+}
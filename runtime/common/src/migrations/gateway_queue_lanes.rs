@@ -0,0 +1,72 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+#[cfg(feature = "try-runtime")]
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use pallet_liquidity_pools_gateway_queue::{MessageQueue, Pallet};
+use sp_arithmetic::traits::{SaturatedConversion, Saturating};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::DispatchError;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+const LOG_PREFIX: &str = "GatewayQueueLanes";
+
+/// Assigns the messages queued before the introduction of lanes to their
+/// lanes, keeping their order.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for Migration<T>
+where
+	T: pallet_liquidity_pools_gateway_queue::Config,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut queued = MessageQueue::<T>::iter().collect::<Vec<_>>();
+		queued.sort_by_key(|(nonce, _)| *nonce);
+
+		for (nonce, message) in &queued {
+			Pallet::<T>::push_to_lane(*nonce, message);
+		}
+
+		let count: u64 = queued.len().saturated_into();
+
+		log::info!("{LOG_PREFIX}: Assigned {count} queued messages to lanes");
+
+		// 1 read for each message and 2 reads and 2 writes for each lane update
+		T::DbWeight::get().reads_writes(count.saturating_mul(3), count.saturating_mul(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		let pending: u64 = MessageQueue::<T>::iter_keys().count().saturated_into();
+
+		Ok(pending.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+		let pre_pending: u64 = Decode::decode(&mut pre_state.as_slice())
+			.expect("pre_upgrade provides a valid state; qed");
+
+		let pending: u64 = MessageQueue::<T>::iter_keys().count().saturated_into();
+
+		assert_eq!(
+			pre_pending, pending,
+			"{LOG_PREFIX} POST: Mismatching number of pending messages in queue after migration!"
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,91 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+#[cfg(feature = "try-runtime")]
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use pallet_liquidity_pools_gateway_queue::{FailedMessageQueue, RetrySchedule};
+use sp_arithmetic::traits::{SaturatedConversion, Saturating};
+use sp_runtime::DispatchError;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+const LOG_PREFIX: &str = "GatewayQueueRetries";
+
+/// Adds the retry counter to the failed messages and schedules their first
+/// automatic retry.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for Migration<T>
+where
+	T: pallet_liquidity_pools_gateway_queue::Config,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let retry_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::RetryDelay::get());
+
+		let mut nonces = Vec::new();
+
+		FailedMessageQueue::<T>::translate::<(T::Message, DispatchError), _>(
+			|nonce, (message, error)| {
+				nonces.push(nonce);
+
+				Some((message, error, 0))
+			},
+		);
+
+		for nonce in &nonces {
+			RetrySchedule::<T>::insert(retry_at, nonce, ());
+		}
+
+		let count: u64 = nonces.len().saturated_into();
+
+		log::info!("{LOG_PREFIX}: Scheduled the retry of {count} failed messages");
+
+		// 1 read for the block number
+		// 1 read and 1 write for each failed message and 1 write for each schedule
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_mul(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		let failed: u64 = FailedMessageQueue::<T>::iter_keys()
+			.count()
+			.saturated_into();
+
+		Ok(failed.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+		let pre_failed: u64 = Decode::decode(&mut pre_state.as_slice())
+			.expect("pre_upgrade provides a valid state; qed");
+
+		let failed: u64 = FailedMessageQueue::<T>::iter_values()
+			.count()
+			.saturated_into();
+		let scheduled: u64 = RetrySchedule::<T>::iter_keys().count().saturated_into();
+
+		assert_eq!(
+			pre_failed, failed,
+			"{LOG_PREFIX} POST: Mismatching number of failed messages after migration!"
+		);
+		assert_eq!(
+			failed, scheduled,
+			"{LOG_PREFIX} POST: Mismatching number of scheduled retries after migration!"
+		);
+
+		Ok(())
+	}
+}
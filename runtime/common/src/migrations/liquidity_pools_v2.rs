@@ -159,7 +159,10 @@ pub mod v2_update_message_queue {
 	use pallet_liquidity_pools_gateway::message::GatewayMessage;
 	use sp_arithmetic::traits::SaturatedConversion;
 
-	use super::{types::v2, *};
+	use super::{
+		types::{v2, v3},
+		*,
+	};
 
 	const LOG_PREFIX: &str = "InitMessageQueue";
 
@@ -185,7 +188,7 @@ pub mod v2_update_message_queue {
 				_,
 			>(|message| message.migrate());
 
-			pallet_liquidity_pools_gateway_queue::FailedMessageQueue::<T>::translate_values::<
+			v3::FailedMessageQueue::<T>::translate_values::<
 				(v2::GatewayMessage<T::AccountId, Message>, DispatchError),
 				_,
 			>(|(message, error)| message.migrate().map(|m| (m, error)));
@@ -278,7 +281,10 @@ pub mod v0_init_message_queue {
 	use sp_arithmetic::traits::{SaturatedConversion, Saturating};
 
 	use super::{
-		types::v0::{FailedOutboundMessages, OutboundMessageNonceStore, OutboundMessageQueue},
+		types::{
+			v0::{FailedOutboundMessages, OutboundMessageNonceStore, OutboundMessageQueue},
+			v3,
+		},
 		*,
 	};
 
@@ -408,7 +414,7 @@ pub mod v0_init_message_queue {
 					);
 
 					// Should never be none since target are outbound messages
-					pallet_liquidity_pools_gateway_queue::FailedMessageQueue::<T>::insert(
+					v3::FailedMessageQueue::<T>::insert(
 						nonce,
 						(GatewayMessage::Outbound { message, router_id }, err),
 					);
@@ -640,6 +646,24 @@ pub mod init_axelar_router {
 mod types {
 	use super::*;
 
+	pub(crate) mod v3 {
+		use frame_support::{pallet_prelude::OptionQuery, storage_alias, Blake2_128Concat};
+		use sp_runtime::DispatchError;
+
+		/// The failed messages before the introduction of automatic retries.
+		#[storage_alias]
+		pub type FailedMessageQueue<T: pallet_liquidity_pools_gateway_queue::Config> = StorageMap<
+			pallet_liquidity_pools_gateway_queue::Pallet<T>,
+			Blake2_128Concat,
+			<T as pallet_liquidity_pools_gateway_queue::Config>::MessageNonce,
+			(
+				<T as pallet_liquidity_pools_gateway_queue::Config>::Message,
+				DispatchError,
+			),
+			OptionQuery,
+		>;
+	}
+
 	pub(crate) mod v2 {
 		use cfg_types::domain_address::{Domain, DomainAddress};
 		use frame_support::{
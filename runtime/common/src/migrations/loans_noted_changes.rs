@@ -0,0 +1,85 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::Seconds;
+use frame_support::{
+	pallet_prelude::{Decode, Encode},
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use pallet_pool_system::{pool_types::NotedPoolChange, NotedChange};
+#[cfg(feature = "try-runtime")]
+use sp_arithmetic::traits::SaturatedConversion;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::changes::{v4, Changeable, RuntimeChange};
+
+const LOG_PREFIX: &str = "LoansNotedChanges";
+
+#[derive(Encode, Decode)]
+struct NotedPoolChangeV4<T: Changeable, Options: Clone> {
+	submitted_time: Seconds,
+	change: v4::RuntimeChange<T, Options>,
+}
+
+/// Migrates the pending changes of the change guard to the loans v5 types,
+/// adding the day count convention to the noted interest rates.
+/// The change ids are kept, so the changes can be applied as noted.
+pub struct Migration<T, Options = ()>(PhantomData<(T, Options)>);
+
+impl<T, Options> OnRuntimeUpgrade for Migration<T, Options>
+where
+	T: Changeable + pallet_pool_system::Config<RuntimeChange = RuntimeChange<T, Options>>,
+	Options: Clone,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: u64 = 0;
+
+		NotedChange::<T>::translate_values::<NotedPoolChangeV4<T, Options>, _>(|noted| {
+			count += 1;
+			Some(NotedPoolChange {
+				submitted_time: noted.submitted_time,
+				change: noted.change.migrate(),
+			})
+		});
+
+		log::info!("{LOG_PREFIX}: Migrated {count} noted changes");
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let count: u64 = NotedChange::<T>::iter_keys().count().saturated_into();
+
+		log::info!("{LOG_PREFIX}: Pre checks done!");
+
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(pre_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let pre_count: u64 = Decode::decode(&mut pre_state.as_slice())
+			.expect("pre_upgrade provides a valid state; qed");
+		let post_count: u64 = NotedChange::<T>::iter_values().count().saturated_into();
+		assert_eq!(
+			pre_count, post_count,
+			"{LOG_PREFIX}: Mismatching number of noted changes after migration!"
+		);
+
+		log::info!("{LOG_PREFIX}: Post checks done!");
+
+		Ok(())
+	}
+}
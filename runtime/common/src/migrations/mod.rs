@@ -14,8 +14,12 @@
 
 pub mod asset_registry_local_usdc_dai_usds;
 pub mod foreign_investments_v2;
+pub mod gateway_domain_hooks;
+pub mod gateway_queue_lanes;
+pub mod gateway_queue_retries;
 pub mod increase_storage_version;
 pub mod liquidity_pools_v2;
+pub mod loans_noted_changes;
 pub mod nuke;
 pub mod permissions_v1;
 pub mod precompile_account_codes;
@@ -1,5 +1,5 @@
 use cfg_traits::liquidity_pools::{
-	LpMessageSerializer, MessageReceiver, MessageSender, RouterProvider,
+	LpMessageSerializer, MessageReceiver, MessageSender, MessageSenderWithFees, RouterProvider,
 };
 use cfg_types::domain_address::{Domain, DomainAddress};
 use frame_support::{
@@ -7,7 +7,9 @@ use frame_support::{
 	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
 };
 pub use pallet_axelar_router::AxelarId;
+pub use pallet_hyperlane_router::HyperlaneId;
 use pallet_liquidity_pools::Message;
+use sp_core::U256;
 use sp_std::{marker::PhantomData, vec, vec::Vec};
 
 /// Identification of the router where the messages are sent and received.
@@ -17,8 +19,13 @@ use sp_std::{marker::PhantomData, vec, vec::Vec};
 /// to reach them.
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum RouterId {
-	/// The message must be sent/received by EVM using Axelar
+	/// The message must be sent/received by EVM or non-EVM domains using
+	/// Axelar
 	Axelar(AxelarId),
+
+	/// The message must be sent/received by EVM or non-EVM domains using
+	/// a Hyperlane mailbox
+	Hyperlane(HyperlaneId),
 }
 
 impl From<AxelarId> for RouterId {
@@ -27,14 +34,36 @@ impl From<AxelarId> for RouterId {
 	}
 }
 
+impl From<HyperlaneId> for RouterId {
+	fn from(hyperlane_id: HyperlaneId) -> Self {
+		RouterId::Hyperlane(hyperlane_id)
+	}
+}
+
 impl From<RouterId> for Domain {
 	fn from(router_id: RouterId) -> Self {
 		match router_id {
 			RouterId::Axelar(AxelarId::Evm(chain_id)) => Domain::Evm(chain_id),
+			RouterId::Axelar(AxelarId::NonEvm(chain_id)) => Domain::NonEvm(chain_id),
+			RouterId::Hyperlane(hyperlane_id) => hyperlane_id.into(),
 		}
 	}
 }
 
+/// Fees paid when sending a message through any router, replacing the ones
+/// configured in the router.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RouterFees {
+	/// The value used when executing the EVM call.
+	pub value: U256,
+
+	/// The gas price used when executing the EVM call.
+	pub gas_price: U256,
+
+	/// The gas limit used when executing the EVM call.
+	pub gas_limit: U256,
+}
+
 /// Static router provider used in the LP gateway.
 pub struct LPGatewayRouterProvider;
 
@@ -43,7 +72,14 @@ impl RouterProvider<Domain> for LPGatewayRouterProvider {
 
 	fn routers_for_domain(domain: Domain) -> Vec<Self::RouterId> {
 		match domain {
-			Domain::Evm(chain_id) => vec![RouterId::Axelar(AxelarId::Evm(chain_id))],
+			Domain::Evm(chain_id) => vec![
+				RouterId::Axelar(AxelarId::Evm(chain_id)),
+				RouterId::Hyperlane(HyperlaneId::Evm(chain_id)),
+			],
+			Domain::NonEvm(chain_id) => vec![
+				RouterId::Axelar(AxelarId::NonEvm(chain_id)),
+				RouterId::Hyperlane(HyperlaneId::NonEvm(chain_id)),
+			],
 			Domain::Centrifuge => vec![],
 		}
 	}
@@ -53,7 +89,7 @@ impl RouterProvider<Domain> for LPGatewayRouterProvider {
 pub struct RouterDispatcher<Routers>(PhantomData<Routers>);
 impl<Routers> MessageSender for RouterDispatcher<Routers>
 where
-	Routers: pallet_axelar_router::Config,
+	Routers: pallet_axelar_router::Config + pallet_hyperlane_router::Config,
 {
 	type Message = Vec<u8>;
 	type Middleware = RouterId;
@@ -64,6 +100,48 @@ where
 			RouterId::Axelar(axelar_id) => {
 				pallet_axelar_router::Pallet::<Routers>::send(axelar_id, origin, message)
 			}
+			RouterId::Hyperlane(hyperlane_id) => {
+				pallet_hyperlane_router::Pallet::<Routers>::send(hyperlane_id, origin, message)
+			}
+		}
+	}
+}
+
+impl<Routers> MessageSenderWithFees for RouterDispatcher<Routers>
+where
+	Routers: pallet_axelar_router::Config + pallet_hyperlane_router::Config,
+{
+	type Fees = RouterFees;
+
+	fn send_with_fees(
+		router_id: RouterId,
+		origin: Self::Origin,
+		message: Self::Message,
+		fees: RouterFees,
+	) -> DispatchResult {
+		match router_id {
+			RouterId::Axelar(axelar_id) => pallet_axelar_router::Pallet::<Routers>::send_with_fees(
+				axelar_id,
+				origin,
+				message,
+				pallet_axelar_router::FeeValues {
+					value: fees.value,
+					gas_price: fees.gas_price,
+					gas_limit: fees.gas_limit,
+				},
+			),
+			RouterId::Hyperlane(hyperlane_id) => {
+				pallet_hyperlane_router::Pallet::<Routers>::send_with_fees(
+					hyperlane_id,
+					origin,
+					message,
+					pallet_hyperlane_router::FeeValues {
+						value: fees.value,
+						gas_price: fees.gas_price,
+						gas_limit: fees.gas_limit,
+					},
+				)
+			}
 		}
 	}
 }
@@ -88,6 +166,22 @@ where
 	}
 }
 
+impl<Sender, Receiver> MessageSenderWithFees for MessageSerializer<Sender, Receiver>
+where
+	Sender: MessageSenderWithFees<Message = Vec<u8>, Middleware = RouterId, Origin = DomainAddress>,
+{
+	type Fees = Sender::Fees;
+
+	fn send_with_fees(
+		middleware: Self::Middleware,
+		origin: Self::Origin,
+		message: Self::Message,
+		fees: Self::Fees,
+	) -> DispatchResult {
+		Sender::send_with_fees(middleware, origin, message.serialize(), fees)
+	}
+}
+
 impl<Sender, Receiver> MessageReceiver for MessageSerializer<Sender, Receiver>
 where
 	Receiver: MessageReceiver<Middleware = RouterId, Origin = Domain, Message = Message>,
@@ -10,7 +10,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_primitives::{AccountId, Balance};
+use cfg_primitives::{AccountId, Balance, InvestmentId};
 use cfg_traits::{PreConditions, TransferAllowance};
 use cfg_types::{
 	domain_address::DomainAddress,
@@ -18,6 +18,7 @@ use cfg_types::{
 	tokens::{CurrencyId, FilterCurrency},
 };
 use frame_support::{traits::IsSubType, RuntimeDebugNoBound};
+use orml_traits::currency::OnTransfer;
 use pallet_restricted_tokens::TransferDetails;
 use pallet_restricted_xtokens::TransferEffects;
 use parity_scale_codec::{Decode, Encode};
@@ -198,6 +199,29 @@ impl<
 	}
 }
 
+/// Rejects transfers of tranche tokens that are still locked up by the
+/// lock-up period of their tranche.
+pub struct PreTrancheTransfer<T>(sp_std::marker::PhantomData<T>);
+
+impl<T> OnTransfer<AccountId, CurrencyId, Balance> for PreTrancheTransfer<T>
+where
+	T: pallet_pool_system::Config<AccountId = AccountId, TrancheCurrency = InvestmentId>,
+{
+	fn on_transfer(
+		currency_id: CurrencyId,
+		from: &AccountId,
+		_to: &AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
+		match currency_id {
+			CurrencyId::Tranche(pool_id, tranche_id) => {
+				pallet_pool_system::Pallet::<T>::ensure_unlocked((pool_id, tranche_id), from)
+			}
+			_ => Ok(()),
+		}
+	}
+}
+
 // NOTE: This code here is really critical. The test are resided in the
 // integration tests section for this reason. The importance is, that
 // nobody is able to create a call that can possibly bypass this filtering.
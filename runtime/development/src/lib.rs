@@ -30,14 +30,14 @@ use cfg_primitives::{
 	LPGatewayQueueMessageNonce, LPGatewaySessionId,
 };
 use cfg_traits::{
-	investments::OrderManager, Millis, Permissions as PermissionsT, PoolUpdateGuard, PreConditions,
-	Seconds,
+	investments::OrderManager, liquidity_pools::MessageHash, Millis, Permissions as PermissionsT,
+	PoolUpdateGuard, PreConditions, Seconds,
 };
 use cfg_types::{
 	domain_address::DomainAddress,
 	fee_keys::{Fee, FeeKey},
 	fixed_point::{Quantity, Rate, Ratio},
-	investments::InvestmentPortfolio,
+	investments::{ForeignInvestorStatus, InvestmentPortfolio},
 	locations::RestrictedTransferLocation,
 	oracles::OracleKey,
 	permissions::{PermissionRoles, PermissionScope, PermissionedCurrencyRole, PoolRole, Role},
@@ -84,7 +84,7 @@ use pallet_evm::{
 };
 use pallet_liquidity_pools_gateway::message::GatewayMessage;
 pub use pallet_loans::entities::{input::PriceCollectionInput, loans::ActiveLoanInfo};
-use pallet_loans::types::cashflow::CashflowPayment;
+use pallet_loans::types::cashflow::{CashflowPayment, PaymentStatus};
 use pallet_pool_system::{
 	pool_types::{PoolDetails, ScheduledUpdateDetails},
 	tranches::{TrancheIndex, TrancheLoc, TrancheSolution},
@@ -123,8 +123,8 @@ use runtime_common::{
 	permissions::{IsUnfrozenTrancheInvestor, PoolAdminCheck},
 	remarks::Remark,
 	rewards::SingleCurrencyMovement,
-	routing::{LPGatewayRouterProvider, MessageSerializer, RouterDispatcher, RouterId},
-	transfer_filter::{PreLpTransfer, PreNativeTransfer},
+	routing::{LPGatewayRouterProvider, MessageSerializer, RouterDispatcher, RouterFees, RouterId},
+	transfer_filter::{PreLpTransfer, PreNativeTransfer, PreTrancheTransfer},
 	xcm::AccountIdToLocation,
 	xcm_transactor, AllowanceDeposit, CurrencyED,
 };
@@ -1114,6 +1114,12 @@ parameter_types! {
 		2 * MINUTES
 	};
 
+	// Bisection steps per order of the on-chain epoch solver
+	pub const EpochSolverSteps: u32 = 32;
+
+	// Investors put up for redemption at once when a tranche has matured
+	pub const MaxMaturedRedemptions: u32 = 100;
+
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = 0; // No minimum epoch time
 	pub const DefaultMaxNAVAge: u64 = 1 * SECONDS_PER_MINUTE; // 1 minute
@@ -1143,7 +1149,9 @@ impl pallet_pool_system::Config for Runtime {
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
 	type DefaultMinEpochTime = DefaultMinEpochTime;
 	type EpochId = PoolEpochId;
+	type EpochSolverSteps = EpochSolverSteps;
 	type Investments = Investments;
+	type MaxMaturedRedemptions = MaxMaturedRedemptions;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
@@ -1241,7 +1249,8 @@ impl PoolUpdateGuard for UpdateGuard {
 		PoolId,
 		MaxTranches,
 	>;
-	type ScheduledUpdateDetails = ScheduledUpdateDetails<Rate, AssetStringLimit, MaxTranches>;
+	type ScheduledUpdateDetails =
+		ScheduledUpdateDetails<Rate, AssetStringLimit, MaxTranches, Balance>;
 
 	fn released(
 		pool: &Self::PoolDetails,
@@ -1359,6 +1368,14 @@ parameter_types! {
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxWriteOffPolicySize: u32 = 10;
 
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxCollateralsPerLoan: u32 = 10;
+
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxParticipantsPerLoan: u32 = 5;
+
+	pub const MaxParticipationsPerPool: u32 = 100;
+
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxFeedersPerKey: u32 = 5;
 }
@@ -1410,6 +1427,9 @@ impl pallet_loans::Config for Runtime {
 	type ItemId = ItemId;
 	type LoanId = LoanId;
 	type MaxActiveLoansPerPool = MaxActiveLoansPerPool;
+	type MaxCollateralsPerLoan = MaxCollateralsPerLoan;
+	type MaxParticipantsPerLoan = MaxParticipantsPerLoan;
+	type MaxParticipationsPerPool = MaxParticipationsPerPool;
 	type MaxWriteOffPolicySize = MaxWriteOffPolicySize;
 	type Moment = Millis;
 	type NonFungible = Uniques;
@@ -1573,7 +1593,7 @@ impl<C: orml_tokens::Config> MutationHooks<AccountId, CurrencyId, Balance> for C
 	type PostDeposit = ();
 	type PostTransfer = ();
 	type PreDeposit = ();
-	type PreTransfer = ();
+	type PreTransfer = PreTrancheTransfer<Runtime>;
 }
 
 impl orml_tokens::Config for Runtime {
@@ -1863,28 +1883,57 @@ parameter_types! {
 	pub Sender: DomainAddress = gateway::get_gateway_domain_address::<Runtime>();
 	pub const MaxIncomingMessageSize: u32 = 1024;
 	pub const MaxRouterCount: u32 = 8;
+	pub const MaxOutboundRecords: u32 = 100;
+	pub const MaxOutboundResends: u32 = 3;
+	pub const InboundEntryExpiry: u64 = 30 * SECONDS_PER_DAY; // 1 month
+	pub const MessageRecoveryDisputeWindow: u64 = 2 * SECONDS_PER_DAY;
 }
 
 impl pallet_liquidity_pools_gateway::Config for Runtime {
 	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
+	type InboundEntryExpiry = InboundEntryExpiry;
 	type InboundMessageHandler = LiquidityPools;
 	type MaxIncomingMessageSize = MaxIncomingMessageSize;
+	type MaxOutboundRecords = MaxOutboundRecords;
+	type MaxOutboundResends = MaxOutboundResends;
 	type MaxRouterCount = MaxRouterCount;
 	type Message = pallet_liquidity_pools::Message;
 	type MessageQueue = LiquidityPoolsGatewayQueue;
+	type MessageRecoveryDisputeWindow = MessageRecoveryDisputeWindow;
 	type MessageSender = MessageSerializer<RouterDispatcher<Runtime>, ()>;
+	type OutboundFees = RouterFees;
+	type RateLimitKey = pallet_liquidity_pools::RateLimitKey;
 	type RouterId = RouterId;
 	type RouterProvider = LPGatewayRouterProvider;
 	type RuntimeEvent = RuntimeEvent;
 	type Sender = Sender;
 	type SessionId = LPGatewaySessionId;
+	type Time = Timestamp;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub LPGatewayQueueLanes: Vec<(gateway::MessageLane, Perbill)> = vec![
+		(gateway::MessageLane::Admin, Perbill::from_percent(20)),
+		(gateway::MessageLane::Price, Perbill::from_percent(30)),
+		(gateway::MessageLane::Investor, Perbill::from_percent(50)),
+	];
+	pub const LPGatewayQueueMaxOrderingKeys: u32 = 50;
+	pub const LPGatewayQueueMaxRetries: u32 = 5;
+	pub const LPGatewayQueueRetryDelay: BlockNumber = 10 * MINUTES;
+}
+
 impl pallet_liquidity_pools_gateway_queue::Config for Runtime {
+	type Lane = gateway::MessageLane;
+	type LaneProvider = gateway::LPGatewayLaneProvider;
+	type Lanes = LPGatewayQueueLanes;
+	type MaxOrderingKeys = LPGatewayQueueMaxOrderingKeys;
+	type MaxRetries = LPGatewayQueueMaxRetries;
 	type Message = GatewayMessage<pallet_liquidity_pools::Message, RouterId>;
 	type MessageNonce = LPGatewayQueueMessageNonce;
 	type MessageProcessor = LiquidityPoolsGateway;
+	type OrderingKey = Option<u64>;
+	type RetryDelay = LPGatewayQueueRetryDelay;
 	type RuntimeEvent = RuntimeEvent;
 }
 
@@ -2001,6 +2050,14 @@ impl pallet_axelar_router::Config for Runtime {
 	type Transactor = EthereumTransaction;
 }
 
+impl pallet_hyperlane_router::Config for Runtime {
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Middleware = RouterId;
+	type Receiver = MessageSerializer<(), LiquidityPoolsGateway>;
+	type RuntimeEvent = RuntimeEvent;
+	type Transactor = EthereumTransaction;
+}
+
 parameter_types! {
 	pub const NativeCfg: CurrencyId = CurrencyId::Native;
 	pub const IouCfg: CurrencyId = cfg_types::tokens::usdc::CURRENCY_ID_IOU_CFG;
@@ -2241,6 +2298,7 @@ construct_runtime!(
 		Ethereum: pallet_ethereum::{Pallet, Config<T>, Call, Storage, Event, Origin} = 163,
 		EthereumTransaction: pallet_ethereum_transaction::{Pallet, Storage} = 164,
 		AxelarRouter: pallet_axelar_router::{Pallet, Call, Storage, Event<T>} = 165,
+		HyperlaneRouter: pallet_hyperlane_router::{Pallet, Call, Storage, Event<T>} = 167,
 
 		// Removed: Migration = 199
 		// admin stuff
@@ -2471,6 +2529,15 @@ impl_runtime_apis! {
 			).ok()
 		}
 
+		fn solve_epoch(pool_id: PoolId) -> Option<EpochSolution<Balance, MaxTranches>>{
+			let pool = pallet_pool_system::Pool::<Runtime>::get(pool_id)?;
+			let epoch_execution_info = pallet_pool_system::EpochExecution::<Runtime>::get(pool_id)?;
+			pallet_pool_system::Pallet::<Runtime>::solve_epoch(
+				&pool,
+				&epoch_execution_info
+			).ok()?
+		}
+
 		fn tranche_token_price(pool_id: PoolId, tranche: TrancheLoc<TrancheId>) -> Option<Quantity>{
 			let index: usize = PoolSystem::pool(pool_id)?.tranches.tranche_index(&tranche)?.try_into().ok()?;
 			Self::tranche_token_prices(pool_id)?.get(index).cloned()
@@ -2556,6 +2623,10 @@ impl_runtime_apis! {
 		fn expected_cashflows(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<CashflowPayment<Balance>>, DispatchError> {
 			Loans::expected_cashflows(pool_id, loan_id)
 		}
+
+		fn payment_statuses(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<(CashflowPayment<Balance>, PaymentStatus)>, DispatchError> {
+			Loans::payment_statuses(pool_id, loan_id)
+		}
 	}
 
 	// Investment Runtime APIs
@@ -2565,6 +2636,13 @@ impl_runtime_apis! {
 		}
 	}
 
+	// ForeignInvestmentsApi
+	impl runtime_common::apis::ForeignInvestmentsApi<Block, DomainAddress, InvestmentId, ForeignInvestorStatus<Balance, CurrencyId, MessageHash>> for Runtime {
+		fn investor_status(investor: DomainAddress, investment_id: InvestmentId) -> Option<ForeignInvestorStatus<Balance, CurrencyId, MessageHash>> {
+			runtime_common::foreign_investor_status::get_investor_status::<Runtime>(investor, investment_id)
+		}
+	}
+
 	// AccountConversionApi
 	impl runtime_common::apis::AccountConversionApi<Block, AccountId> for Runtime {
 		fn conversion_of(location: Location) -> Option<AccountId> {
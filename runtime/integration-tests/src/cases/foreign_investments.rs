@@ -2,8 +2,9 @@ use cfg_primitives::{
 	currency_decimals, parachains, AccountId, Balance, InvestmentId, OrderId, PoolId, TrancheId,
 };
 use cfg_traits::{
-	investments::OrderManager, liquidity_pools::InboundMessageHandler, IdentityCurrencyConversion,
-	Permissions, PoolInspect, PoolMutate, Seconds,
+	investments::OrderManager,
+	liquidity_pools::{InboundMessageHandler, LpMessageHash},
+	IdentityCurrencyConversion, Permissions, PoolInspect, PoolMutate, Seconds,
 };
 use cfg_types::{
 	domain_address::{Domain, DomainAddress},
@@ -28,8 +29,9 @@ use pallet_investments::CollectOutcome;
 use pallet_liquidity_pools::Message;
 use pallet_liquidity_pools_gateway::message::GatewayMessage;
 use pallet_liquidity_pools_gateway_queue::MessageNonceStore;
-use pallet_pool_system::tranches::{TrancheInput, TrancheLoc, TrancheType};
+use pallet_pool_system::tranches::{TrancheInput, TrancheLimits, TrancheLoc, TrancheType};
 use runtime_common::{
+	apis::runtime_decl_for_foreign_investments_api::ForeignInvestmentsApiV1,
 	foreign_investments::IdentityPoolCurrencyConverter, routing::RouterId, xcm::general_key,
 };
 use sp_core::Get;
@@ -63,6 +65,7 @@ pub const DEFAULT_BALANCE_GLMR: Balance = 10_000_000_000_000_000_000;
 pub const POOL_ADMIN: Keyring = Keyring::Bob;
 pub const POOL_ID: PoolId = 42;
 pub const CHAIN_ID: u64 = 1284;
+pub const NON_EVM_CHAIN_ID: u64 = 5;
 pub const DEFAULT_VALIDITY: Seconds = 2555583502;
 pub const EVM_DOMAIN: Domain = Domain::Evm(CHAIN_ID);
 pub const DEFAULT_ROUTER_ID: RouterId = RouterId::Axelar(AxelarId::Evm(CHAIN_ID));
@@ -661,6 +664,67 @@ mod same_currencies {
 		});
 	}
 
+	#[test_runtimes([development])]
+	fn increase_deposit_request_below_minimum<T: Runtime>() {
+		let mut env = RuntimeEnv::<T>::from_parachain_storage(
+			Genesis::default()
+				.add(genesis::balances::<T>(cfg(1_000)))
+				.storage(),
+		);
+
+		setup_test(&mut env);
+
+		env.parachain_state_mut(|| {
+			let pool_id = POOL_ID;
+			let amount = 10 * decimals(12);
+			let investor = DomainAddress::Evm(CHAIN_ID, Keyring::Bob.in_eth()).account();
+			let currency_id = AUSD_CURRENCY_ID;
+			let currency_decimals = currency_decimals::AUSD;
+
+			create_currency_pool::<T>(pool_id, currency_id, currency_decimals.into());
+			crate::utils::pool::give_role::<T>(
+				investor.clone(),
+				pool_id,
+				PoolRole::TrancheInvestor(default_tranche_id::<T>(pool_id), DEFAULT_VALIDITY),
+			);
+			pallet_pool_system::TrancheLimits::<T>::insert(
+				pool_id,
+				default_tranche_id::<T>(pool_id),
+				TrancheLimits {
+					max_supply: None,
+					max_value: None,
+					min_investment: Some(2 * amount),
+				},
+			);
+
+			let msg = |amount| LiquidityPoolMessage::DepositRequest {
+				pool_id,
+				tranche_id: default_tranche_id::<T>(pool_id),
+				investor: investor.clone().into(),
+				currency: general_currency_index::<T>(currency_id),
+				amount,
+			};
+
+			// Foreign investments must reach the minimum investment as well
+			assert_noop!(
+				pallet_liquidity_pools::Pallet::<T>::handle(EVM_DOMAIN, msg(amount)),
+				pallet_pool_system::Error::<T>::InvestmentBelowMinimum
+			);
+
+			assert_ok!(pallet_liquidity_pools::Pallet::<T>::handle(
+				EVM_DOMAIN,
+				msg(2 * amount)
+			));
+			assert_eq!(
+				pallet_investments::Pallet::<T>::acc_active_invest_order(
+					default_investment_id::<T>(),
+				)
+				.amount,
+				2 * amount
+			);
+		});
+	}
+
 	#[test_runtimes([development])]
 	fn decrease_deposit_request<T: Runtime>() {
 		let mut env = RuntimeEnv::<T>::from_parachain_storage(
@@ -969,6 +1033,175 @@ mod same_currencies {
 		});
 	}
 
+	#[test_runtimes([development])]
+	fn investor_status<T: Runtime>() {
+		let mut env = RuntimeEnv::<T>::from_parachain_storage(
+			Genesis::default()
+				.add(genesis::balances::<T>(cfg(1_000)))
+				.storage(),
+		);
+
+		setup_test(&mut env);
+
+		env.parachain_state_mut(|| {
+			let pool_id = POOL_ID;
+			let amount = 10 * decimals(12);
+			let domain_address = DomainAddress::Evm(CHAIN_ID, Keyring::Bob.in_eth());
+			let investor = domain_address.account();
+			let currency_id = AUSD_CURRENCY_ID;
+			let currency_decimals = currency_decimals::AUSD;
+			enable_liquidity_pool_transferability::<T>(currency_id);
+
+			// The status of an unknown investment is not found
+			assert_eq!(
+				T::Api::investor_status(domain_address.clone(), default_investment_id::<T>()),
+				None
+			);
+
+			// Create new pool
+			create_currency_pool::<T>(pool_id, currency_id, currency_decimals.into());
+
+			// Set permissions and execute initial investment
+			do_initial_increase_investment::<T>(pool_id, amount, investor.clone(), currency_id);
+
+			let status =
+				T::Api::investor_status(domain_address.clone(), default_investment_id::<T>())
+					.unwrap();
+			assert_eq!(status.deposit_currency, Some(currency_id));
+			assert_eq!(status.deposit_swap, None);
+			assert_eq!(status.invest_order, amount);
+			assert_eq!(status.collectable_tranche_tokens, 0);
+
+			// Process and fulfill order
+			assert_ok!(pallet_investments::Pallet::<T>::process_invest_orders(
+				default_investment_id::<T>()
+			));
+			assert_ok!(pallet_investments::Pallet::<T>::invest_fulfillment(
+				default_investment_id::<T>(),
+				FulfillmentWithPrice {
+					of_amount: Perquintill::one(),
+					price: Ratio::one(),
+				}
+			));
+
+			let status =
+				T::Api::investor_status(domain_address.clone(), default_investment_id::<T>())
+					.unwrap();
+			assert_eq!(status.invest_order, amount);
+			assert_eq!(status.collectable_tranche_tokens, amount);
+
+			// Collect investment
+			assert_ok!(pallet_investments::Pallet::<T>::collect_investments_for(
+				RawOrigin::Signed(Keyring::Alice.into()).into(),
+				investor.clone(),
+				default_investment_id::<T>()
+			));
+
+			let fulfillment = LiquidityPoolMessage::FulfilledDepositRequest {
+				pool_id,
+				tranche_id: default_tranche_id::<T>(pool_id),
+				investor: investor.clone().into(),
+				currency: general_currency_index::<T>(currency_id),
+				currency_payout: amount,
+				tranche_tokens_payout: amount,
+			};
+
+			let status =
+				T::Api::investor_status(domain_address.clone(), default_investment_id::<T>())
+					.unwrap();
+			assert_eq!(status.invest_order, 0);
+			assert_eq!(status.collectable_tranche_tokens, 0);
+			assert_eq!(
+				status.fulfilled_message_hashes,
+				vec![fulfillment.get_message_hash()]
+			);
+		});
+	}
+
+	#[test_runtimes([development])]
+	fn investor_status_non_evm<T: Runtime>() {
+		let mut env = RuntimeEnv::<T>::from_parachain_storage(
+			Genesis::default()
+				.add(genesis::balances::<T>(cfg(1_000)))
+				.storage(),
+		);
+
+		setup_test(&mut env);
+
+		env.parachain_state_mut(|| {
+			let pool_id = POOL_ID;
+			let amount = 10 * decimals(12);
+			let domain_address = DomainAddress::NonEvm(NON_EVM_CHAIN_ID, [7; 32]);
+			let investor = domain_address.account();
+			let currency_id = AUSD_CURRENCY_ID;
+			let currency_decimals = currency_decimals::AUSD;
+			enable_liquidity_pool_transferability::<T>(currency_id);
+
+			assert_ok!(pallet_liquidity_pools_gateway::Pallet::<T>::set_routers(
+				<T as frame_system::Config>::RuntimeOrigin::root(),
+				BoundedVec::try_from(vec![
+					DEFAULT_ROUTER_ID,
+					RouterId::Axelar(AxelarId::NonEvm(NON_EVM_CHAIN_ID))
+				])
+				.unwrap(),
+			));
+
+			create_currency_pool::<T>(pool_id, currency_id, currency_decimals.into());
+			crate::utils::pool::give_role::<T>(
+				investor.clone(),
+				pool_id,
+				PoolRole::TrancheInvestor(default_tranche_id::<T>(pool_id), DEFAULT_VALIDITY),
+			);
+
+			assert_ok!(pallet_liquidity_pools::Pallet::<T>::handle(
+				domain_address.domain(),
+				LiquidityPoolMessage::DepositRequest {
+					pool_id,
+					tranche_id: default_tranche_id::<T>(pool_id),
+					investor: domain_address.bytes(),
+					currency: general_currency_index::<T>(currency_id),
+					amount,
+				}
+			));
+
+			assert_ok!(pallet_investments::Pallet::<T>::process_invest_orders(
+				default_investment_id::<T>()
+			));
+			assert_ok!(pallet_investments::Pallet::<T>::invest_fulfillment(
+				default_investment_id::<T>(),
+				FulfillmentWithPrice {
+					of_amount: Perquintill::one(),
+					price: Ratio::one(),
+				}
+			));
+			assert_ok!(pallet_investments::Pallet::<T>::collect_investments_for(
+				RawOrigin::Signed(Keyring::Alice.into()).into(),
+				investor.clone(),
+				default_investment_id::<T>()
+			));
+
+			// The fulfillment addresses the investor by its non-EVM address
+			let fulfillment = LiquidityPoolMessage::FulfilledDepositRequest {
+				pool_id,
+				tranche_id: default_tranche_id::<T>(pool_id),
+				investor: domain_address.bytes(),
+				currency: general_currency_index::<T>(currency_id),
+				currency_payout: amount,
+				tranche_tokens_payout: amount,
+			};
+
+			let status =
+				T::Api::investor_status(domain_address.clone(), default_investment_id::<T>())
+					.unwrap();
+			assert_eq!(status.invest_order, 0);
+			assert_eq!(status.collectable_tranche_tokens, 0);
+			assert_eq!(
+				status.fulfilled_message_hashes,
+				vec![fulfillment.get_message_hash()]
+			);
+		});
+	}
+
 	#[test_runtimes([development])]
 	fn collect_investment<T: Runtime>() {
 		let mut env = RuntimeEnv::<T>::from_parachain_storage(
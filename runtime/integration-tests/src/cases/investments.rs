@@ -3,7 +3,7 @@ use cfg_traits::Seconds;
 use cfg_types::{investments::InvestmentPortfolio, permissions::PoolRole, tokens::CurrencyId};
 use frame_support::traits::fungibles::MutateHold;
 use runtime_common::apis::{
-	runtime_decl_for_investments_api::InvestmentsApiV1, runtime_decl_for_pools_api::PoolsApiV1,
+	runtime_decl_for_investments_api::InvestmentsApiV1, runtime_decl_for_pools_api::PoolsApiV2,
 };
 use sp_core::Get;
 
@@ -1,6 +1,6 @@
 use cfg_primitives::{Balance, CollectionId, ItemId, LoanId, PoolId, SECONDS_PER_MINUTE};
 use cfg_traits::{
-	interest::{CompoundingSchedule, InterestRate},
+	interest::{CompoundingSchedule, DayCountConvention, InterestRate},
 	Seconds,
 };
 use cfg_types::{
@@ -8,7 +8,7 @@ use cfg_types::{
 	oracles::OracleKey,
 	permissions::PoolRole,
 };
-use frame_support::{assert_err, assert_ok, traits::Get};
+use frame_support::{assert_err, assert_ok, traits::Get, BoundedVec};
 use pallet_loans::{
 	entities::{
 		changes::LoanMutation,
@@ -27,7 +27,7 @@ use pallet_loans::{
 	},
 };
 use runtime_common::{
-	apis::{runtime_decl_for_loans_api::LoansApiV3, runtime_decl_for_pools_api::PoolsApiV1},
+	apis::{runtime_decl_for_loans_api::LoansApiV4, runtime_decl_for_pools_api::PoolsApiV2},
 	oracle::Feeder,
 };
 use sp_runtime::FixedPointNumber;
@@ -138,8 +138,9 @@ mod common {
 			interest_rate: InterestRate::Fixed {
 				rate_per_year: rate_from_percent(20),
 				compounding: CompoundingSchedule::Secondly,
+				day_count: DayCountConvention::Actual365,
 			},
-			collateral: NFT_A,
+			collateral: BoundedVec::truncate_from(vec![NFT_A]),
 			pricing: pricing,
 			restrictions: LoanRestrictions {
 				borrows: BorrowRestrictions::NotWrittenOff,
@@ -247,7 +248,7 @@ mod call {
 
 	pub fn propose_loan_mutation<T: Runtime>(
 		loan_id: LoanId,
-		mutation: LoanMutation<Rate>,
+		mutation: LoanMutation<Rate, OracleKey>,
 	) -> pallet_loans::Call<T> {
 		pallet_loans::Call::propose_loan_mutation {
 			pool_id: POOL_A,
@@ -767,7 +767,7 @@ fn update_tranche_hook<T: Runtime>() {
 			POOL_A,
 			pool_a_tranche_1_id::<T>(),
 			EVM_DOMAIN,
-			new_hook
+			DomainAddress::Evm(EVM_DOMAIN_CHAIN_ID, new_hook.into()).bytes()
 		));
 
 		utils::process_gateway_message::<T>(utils::verify_gateway_message_success::<T>);
@@ -93,7 +93,11 @@ pub fn setup<T: Runtime, F: FnOnce(&mut <RuntimeEnv<T> as EnvEvmExtension<T>>::E
 			pallet_liquidity_pools_gateway::Pallet::<T>::set_domain_hook_address(
 				RawOrigin::Root.into(),
 				Domain::Evm(EVM_DOMAIN_CHAIN_ID),
-				LOCAL_RESTRICTION_MANAGER_ADDRESS.into(),
+				DomainAddress::Evm(
+					EVM_DOMAIN_CHAIN_ID,
+					LOCAL_RESTRICTION_MANAGER_ADDRESS.into()
+				)
+				.bytes(),
 			)
 		);
 
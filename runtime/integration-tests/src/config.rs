@@ -4,10 +4,11 @@ use cfg_primitives::{
 	AccountId, Address, AuraId, Balance, CollectionId, Header, IBalance, InvestmentId, ItemId,
 	LoanId, Nonce, OrderId, PoolId, Signature, TrancheId,
 };
-use cfg_traits::Millis;
+use cfg_traits::{liquidity_pools::MessageHash, Millis};
 use cfg_types::{
+	domain_address::DomainAddress,
 	fixed_point::{Quantity, Rate, Ratio},
-	investments::InvestmentPortfolio,
+	investments::{ForeignInvestorStatus, InvestmentPortfolio},
 	locations::RestrictedTransferLocation,
 	oracles::OracleKey,
 	permissions::{PermissionScope, Role},
@@ -189,6 +190,7 @@ pub trait Runtime:
 		Rewards = pallet_rewards::Pallet<Self, instances::BlockRewards>,
 		Tokens = <Self as pallet_pool_system::Config>::Tokens,
 	> + pallet_axelar_router::Config
+	+ pallet_hyperlane_router::Config
 	+ pallet_token_mux::Config<
 		BalanceIn = Balance,
 		BalanceOut = Balance,
@@ -312,14 +314,14 @@ pub trait Runtime:
 	/// You can extend this bounds to give extra API support
 	type Api: sp_api::runtime_decl_for_core::CoreV4<Self::BlockExt>
 		+ sp_block_builder::runtime_decl_for_block_builder::BlockBuilderV6<Self::BlockExt>
-		+ apis::runtime_decl_for_loans_api::LoansApiV3<
+		+ apis::runtime_decl_for_loans_api::LoansApiV4<
 			Self::BlockExt,
 			PoolId,
 			LoanId,
 			pallet_loans::entities::loans::ActiveLoanInfo<Self>,
 			Balance,
 			pallet_loans::entities::input::PriceCollectionInput<Self>,
-		> + apis::runtime_decl_for_pools_api::PoolsApiV1<
+		> + apis::runtime_decl_for_pools_api::PoolsApiV2<
 			Self::BlockExt,
 			PoolId,
 			TrancheId,
@@ -332,6 +334,11 @@ pub trait Runtime:
 			AccountId,
 			InvestmentId,
 			InvestmentPortfolio<Balance, CurrencyId>,
+		> + apis::runtime_decl_for_foreign_investments_api::ForeignInvestmentsApiV1<
+			Self::BlockExt,
+			DomainAddress,
+			InvestmentId,
+			ForeignInvestorStatus<Balance, CurrencyId, MessageHash>,
 		> + apis::runtime_decl_for_account_conversion_api::AccountConversionApiV1<
 			Self::BlockExt,
 			AccountId,